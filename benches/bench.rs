@@ -1,4 +1,4 @@
-#![allow(clippy::let_unit_value, clippy::unit_cmp)]
+#![allow(clippy::let_unit_value, clippy::unit_cmp, clippy::unit_arg)]
 
 mod utils;
 
@@ -380,7 +380,7 @@ mod join {
     async fn tuple_join() {
         let futures = futures_tuple();
         let output = futures.join().await;
-        assert_eq!(output.0, ());
+        assert!(output.0 < 10);
     }
 }
 
@@ -435,18 +435,18 @@ mod race {
     async fn vec_race(max: usize) {
         let futures = futures_vec(max);
         let output = futures.race().await;
-        assert_eq!(output, ());
+        assert_eq!(output, 0);
     }
 
     async fn array_race<const N: usize>() {
         let futures = futures_array::<N>();
         let output = futures.race().await;
-        assert_eq!(output, ());
+        assert_eq!(output, 0);
     }
 
     async fn tuple_race() {
         let futures = futures_tuple();
         let output = futures.race().await;
-        assert_eq!(output, ());
+        assert_eq!(output, 0);
     }
 }
@@ -0,0 +1,9 @@
+//! Concurrency types for [B-tree maps][std::collections::btree_map] (`BTreeMap<K, V>`)
+//!
+//! You will rarely need to interact with this module directly unless you need
+//! to name one of the future types.
+//!
+//! [std::collections::btree_map]: https://doc.rust-lang.org/std/collections/btree_map/index.html
+
+pub use crate::future::join::btree_map::Join;
+pub use crate::future::try_join::btree_map::TryJoin;
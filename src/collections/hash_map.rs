@@ -0,0 +1,9 @@
+//! Concurrency types for [hash maps][std::collections::hash_map] (`HashMap<K, V>`)
+//!
+//! You will rarely need to interact with this module directly unless you need
+//! to name one of the future types.
+//!
+//! [std::collections::hash_map]: https://doc.rust-lang.org/std/collections/hash_map/index.html
+
+pub use crate::future::join::hash_map::Join;
+pub use crate::future::try_join::hash_map::TryJoin;
@@ -1,2 +1,6 @@
 #[cfg(feature = "alloc")]
+pub mod btree_map;
+#[cfg(feature = "std")]
+pub mod hash_map;
+#[cfg(feature = "alloc")]
 pub mod vec;
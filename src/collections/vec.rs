@@ -13,11 +13,16 @@ use alloc::vec::Vec;
 use core::future::Ready;
 
 pub use crate::future::join::vec::Join;
+pub use crate::future::join_stream::vec::JoinStream;
 pub use crate::future::race::vec::Race;
-pub use crate::future::race_ok::vec::{AggregateError, RaceOk};
+pub use crate::future::race_keep_rest::vec::RaceKeepRest;
+pub use crate::future::race_ok::vec::{AggregateError, RaceOk, RaceOkIndexed};
 pub use crate::future::try_join::vec::TryJoin;
 pub use crate::stream::chain::vec::Chain;
-pub use crate::stream::merge::vec::Merge;
+pub use crate::stream::interleave::vec::Interleave;
+pub use crate::stream::merge::vec::{Merge, MergeIndexed};
+pub use crate::stream::race::vec::Race as RaceStream;
+pub use crate::stream::try_merge::vec::TryMerge;
 pub use crate::stream::zip::vec::Zip;
 
 /// Concurrent async iterator that moves out of a vector.
@@ -39,6 +44,10 @@ impl<T> ConcurrentStream for IntoConcurrentStream<T> {
     fn concurrency_limit(&self) -> Option<core::num::NonZeroUsize> {
         self.0.concurrency_limit()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
 }
 
 impl<T> concurrent_stream::IntoConcurrentStream for Vec<T> {
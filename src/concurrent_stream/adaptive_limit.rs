@@ -0,0 +1,250 @@
+use pin_project::pin_project;
+
+use super::race_progress::next_or_progress;
+use super::{ConcurrentStream, Consumer, ConsumerState};
+
+use crate::future::FutureGroup;
+use core::future::{ready, Future, Ready};
+use core::num::NonZeroUsize;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use core::time::Duration;
+use futures_lite::StreamExt;
+use std::time::Instant;
+
+/// A concurrent iterator that automatically adjusts the amount of concurrency
+/// applied, based on how long items take to complete.
+///
+/// This `struct` is created by the [`adaptive_limit`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`adaptive_limit`]: ConcurrentStream::adaptive_limit
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct AdaptiveLimit<CS: ConcurrentStream> {
+    inner: CS,
+    min: NonZeroUsize,
+    max: NonZeroUsize,
+}
+
+impl<CS: ConcurrentStream> AdaptiveLimit<CS> {
+    pub(crate) fn new(inner: CS, min: NonZeroUsize, max: NonZeroUsize) -> Self {
+        assert!(
+            min <= max,
+            "the minimum limit must not exceed the maximum limit"
+        );
+        Self { inner, min, max }
+    }
+}
+
+impl<CS: ConcurrentStream> ConcurrentStream for AdaptiveLimit<CS> {
+    type Item = CS::Item;
+    type Future = Ready<Self::Item>;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        self.inner
+            .drive(AdaptiveConsumer {
+                inner: consumer,
+                group: FutureGroup::new(),
+                window: Window::new(self.min.get(), self.max.get()),
+            })
+            .await
+    }
+
+    // The window never exceeds the maximum, so that's the most concurrency
+    // later consumers need to account for.
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        Some(self.max)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// The number of futures which may be in-flight at the same time.
+///
+/// The window grows by one every time a full window of futures completes
+/// without slowing down, and is halved when a future takes more than twice as
+/// long as the fastest future we've seen (additive increase, multiplicative
+/// decrease).
+#[derive(Debug)]
+struct Window {
+    min: usize,
+    max: usize,
+    limit: usize,
+    // Completions since the window last changed.
+    completed: usize,
+    // The lowest latency observed so far.
+    baseline: Option<Duration>,
+}
+
+impl Window {
+    fn new(min: usize, max: usize) -> Self {
+        Self {
+            min,
+            max,
+            limit: min,
+            completed: 0,
+            baseline: None,
+        }
+    }
+
+    /// Record how long a future took to complete, and adjust the limit.
+    fn record(&mut self, latency: Duration) {
+        let baseline = *self.baseline.get_or_insert(latency);
+        if latency < baseline {
+            self.baseline = Some(latency);
+        }
+
+        if latency > baseline * 2 {
+            self.limit = (self.limit / 2).max(self.min);
+            self.completed = 0;
+        } else {
+            self.completed += 1;
+            if self.completed >= self.limit {
+                self.limit = (self.limit + 1).min(self.max);
+                self.completed = 0;
+            }
+        }
+    }
+}
+
+#[pin_project]
+struct AdaptiveConsumer<C, FutT> {
+    #[pin]
+    inner: C,
+    #[pin]
+    group: FutureGroup<TimedFuture<FutT>>,
+    window: Window,
+}
+
+impl<C, FutT> AdaptiveConsumer<C, FutT>
+where
+    FutT: Future,
+    C: Consumer<FutT::Output, Ready<FutT::Output>>,
+{
+    /// Forward items from the in-flight futures to the inner consumer, until
+    /// either there are no more in-flight futures or the inner consumer is
+    /// done.
+    async fn drain(self: Pin<&mut Self>) -> ConsumerState {
+        let this = self.project();
+        let mut group = this.group;
+        let mut inner = this.inner;
+        loop {
+            let (item, latency) = match next_or_progress(inner.as_mut(), group.as_mut()).await {
+                ControlFlow::Continue(item) => item,
+                ControlFlow::Break(state) => return state,
+            };
+
+            this.window.record(latency);
+            if let ConsumerState::Break = inner.as_mut().send(ready(item)).await {
+                return ConsumerState::Break;
+            }
+        }
+    }
+}
+
+impl<C, FutT> Consumer<FutT::Output, FutT> for AdaptiveConsumer<C, FutT>
+where
+    FutT: Future,
+    C: Consumer<FutT::Output, Ready<FutT::Output>>,
+{
+    type Output = C::Output;
+
+    async fn send(self: Pin<&mut Self>, future: FutT) -> ConsumerState {
+        let mut this = self.project();
+        // If we have no space, we're going to provide backpressure until we have space
+        while this.group.len() >= this.window.limit {
+            let Some((item, latency)) = this.group.next().await else {
+                break;
+            };
+            this.window.record(latency);
+            if let ConsumerState::Break = this.inner.as_mut().send(ready(item)).await {
+                return ConsumerState::Break;
+            }
+        }
+
+        this.group.as_mut().insert_pinned(TimedFuture::new(future));
+        ConsumerState::Continue
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> ConsumerState {
+        self.drain().await
+    }
+
+    async fn flush(mut self: Pin<&mut Self>) -> Self::Output {
+        // We will no longer receive any additional futures; wait until all
+        // the in-flight futures have resolved.
+        self.as_mut().drain().await;
+        self.project().inner.flush().await
+    }
+}
+
+/// Measures how long it takes for a future to complete
+#[pin_project]
+struct TimedFuture<FutT> {
+    #[pin]
+    fut_t: FutT,
+    start: Instant,
+}
+
+impl<FutT> TimedFuture<FutT> {
+    fn new(fut_t: FutT) -> Self {
+        Self {
+            fut_t,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl<FutT: Future> Future for TimedFuture<FutT> {
+    type Output = (FutT::Output, Duration);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let item = ready!(this.fut_t.poll(cx));
+        Poll::Ready((item, this.start.elapsed()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn window_grows_and_shrinks() {
+        let mut window = Window::new(1, 4);
+        for _ in 0..10 {
+            window.record(Duration::from_millis(10));
+        }
+        assert_eq!(window.limit, 4);
+
+        window.record(Duration::from_millis(30));
+        assert_eq!(window.limit, 2);
+        window.record(Duration::from_millis(30));
+        assert_eq!(window.limit, 1);
+        window.record(Duration::from_millis(30));
+        assert_eq!(window.limit, 1);
+    }
+
+    #[test]
+    fn adaptive_limit() {
+        futures_lite::future::block_on(async {
+            let mut v: Vec<_> = stream::iter(0..10)
+                .co()
+                .map(|n| async move { n * 2 })
+                .adaptive_limit(NonZeroUsize::MIN, NonZeroUsize::new(4).unwrap())
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, (0..10).map(|n| n * 2).collect::<Vec<_>>());
+        });
+    }
+}
@@ -97,7 +97,7 @@ impl<FutT, T> EnumerateFuture<FutT, T>
 where
     FutT: Future<Output = T>,
 {
-    fn new(fut_t: FutT, count: usize) -> Self {
+    pub(crate) fn new(fut_t: FutT, count: usize) -> Self {
         Self {
             done: false,
             fut_t,
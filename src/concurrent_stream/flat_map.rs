@@ -0,0 +1,88 @@
+use super::{ConcurrentStream, Consumer, Flatten, Map};
+use crate::stream::IntoStream;
+
+use core::future::{Future, Ready};
+use core::marker::PhantomData;
+use core::num::NonZeroUsize;
+
+/// A concurrent iterator that maps each item to a stream, and flattens the result.
+///
+/// This `struct` is created by the [`flat_map`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`flat_map`]: ConcurrentStream::flat_map
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct FlatMap<CS, F, FutU, U>
+where
+    CS: ConcurrentStream,
+    F: Fn(CS::Item) -> FutU,
+    F: Clone,
+    FutU: Future<Output = U>,
+    U: IntoStream,
+{
+    inner: CS,
+    f: F,
+    _phantom: PhantomData<(FutU, U)>,
+}
+
+impl<CS, F, FutU, U> FlatMap<CS, F, FutU, U>
+where
+    CS: ConcurrentStream,
+    F: Fn(CS::Item) -> FutU,
+    F: Clone,
+    FutU: Future<Output = U>,
+    U: IntoStream,
+{
+    pub(crate) fn new(inner: CS, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<CS, F, FutU, U> ConcurrentStream for FlatMap<CS, F, FutU, U>
+where
+    CS: ConcurrentStream,
+    F: Fn(CS::Item) -> FutU,
+    F: Clone,
+    FutU: Future<Output = U>,
+    U: IntoStream,
+{
+    type Item = U::Item;
+    type Future = Ready<Self::Item>;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        Flatten::new(Map::new(self.inner, self.f))
+            .drive(consumer)
+            .await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.inner.concurrency_limit()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn flat_map() {
+        futures_lite::future::block_on(async {
+            let mut v: Vec<_> = vec!["a b", "c"]
+                .into_co_stream()
+                .flat_map(|s| async move { stream::iter(s.split(' ')) })
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &["a", "b", "c"]);
+        });
+    }
+}
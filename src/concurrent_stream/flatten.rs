@@ -0,0 +1,207 @@
+use pin_project::pin_project;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
+
+use super::race_progress::next_or_progress;
+use super::{ConcurrentStream, Consumer, ConsumerState};
+use crate::stream::{IntoStream, StreamGroup};
+
+use core::future::{ready, Future, Ready};
+use core::num::NonZeroUsize;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::Stream;
+use futures_lite::StreamExt;
+
+/// A concurrent iterator that flattens nested streams.
+///
+/// This `struct` is created by the [`flatten`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`flatten`]: ConcurrentStream::flatten
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct Flatten<CS: ConcurrentStream> {
+    inner: CS,
+}
+
+impl<CS: ConcurrentStream> Flatten<CS> {
+    pub(crate) fn new(inner: CS) -> Self {
+        Self { inner }
+    }
+}
+
+impl<CS> ConcurrentStream for Flatten<CS>
+where
+    CS: ConcurrentStream,
+    CS::Item: IntoStream,
+{
+    type Item = <CS::Item as IntoStream>::Item;
+    type Future = Ready<Self::Item>;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        let limit = match self.inner.concurrency_limit() {
+            Some(n) => n.get(),
+            None => usize::MAX,
+        };
+        self.inner
+            .drive(FlattenConsumer {
+                inner: consumer,
+                group: StreamGroup::new(),
+                limit,
+            })
+            .await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.inner.concurrency_limit()
+    }
+
+    // Every item may expand into any number of items, so we can't say
+    // anything about the number of items we'll yield.
+}
+
+#[pin_project]
+struct FlattenConsumer<C, Fut, S> {
+    #[pin]
+    inner: C,
+    // The streams which are currently being flattened, along with the futures
+    // which will resolve into streams. These are boxed so we can keep
+    // inserting into the group after it's been polled.
+    group: StreamGroup<Pin<Box<FlattenStream<Fut, S>>>>,
+    limit: usize,
+}
+
+impl<C, Fut, S> FlattenConsumer<C, Fut, S>
+where
+    Fut: Future,
+    Fut::Output: IntoStream<IntoStream = S>,
+    S: Stream,
+    C: Consumer<S::Item, Ready<S::Item>>,
+{
+    /// Forward items from the nested streams to the inner consumer, until
+    /// either there are no more nested streams or the inner consumer is done.
+    async fn drain(self: Pin<&mut Self>) -> ConsumerState {
+        let mut this = self.project();
+        loop {
+            let item = match next_or_progress(this.inner.as_mut(), Pin::new(&mut *this.group)).await
+            {
+                ControlFlow::Continue(item) => item,
+                ControlFlow::Break(state) => return state,
+            };
+            if let ConsumerState::Break = this.inner.as_mut().send(ready(item)).await {
+                return ConsumerState::Break;
+            }
+        }
+    }
+}
+
+impl<C, Fut, S> Consumer<Fut::Output, Fut> for FlattenConsumer<C, Fut, S>
+where
+    Fut: Future,
+    Fut::Output: IntoStream<IntoStream = S>,
+    S: Stream,
+    C: Consumer<S::Item, Ready<S::Item>>,
+{
+    type Output = C::Output;
+
+    async fn send(mut self: Pin<&mut Self>, future: Fut) -> ConsumerState {
+        // If we have no space, we're going to provide backpressure until we
+        // have space. Nested streams only make space once they're exhausted.
+        while self.group.len() >= self.limit {
+            let this = self.as_mut().project();
+            match this.group.next().await {
+                Some(item) => {
+                    if let ConsumerState::Break = this.inner.send(ready(item)).await {
+                        return ConsumerState::Break;
+                    }
+                }
+                None => break,
+            }
+        }
+
+        let this = self.project();
+        this.group.insert(Box::pin(FlattenStream::Future(future)));
+        ConsumerState::Continue
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> ConsumerState {
+        self.drain().await
+    }
+
+    async fn flush(mut self: Pin<&mut Self>) -> Self::Output {
+        // We will no longer receive any additional futures; forward the
+        // remaining items and wait for the inner consumer to finish up.
+        self.as_mut().drain().await;
+        self.project().inner.flush().await
+    }
+}
+
+/// A future which resolves into a stream, followed by the items of that stream.
+#[pin_project(project = FlattenStreamProj)]
+enum FlattenStream<Fut, S> {
+    Future(#[pin] Fut),
+    Stream(#[pin] S),
+}
+
+impl<Fut, S> Stream for FlattenStream<Fut, S>
+where
+    Fut: Future,
+    Fut::Output: IntoStream<IntoStream = S>,
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.as_mut().project() {
+                FlattenStreamProj::Future(fut) => {
+                    let stream = ready!(fut.poll(cx)).into_stream();
+                    self.set(FlattenStream::Stream(stream));
+                }
+                FlattenStreamProj::Stream(stream) => return stream.poll_next(cx),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use core::num::NonZeroUsize;
+    use futures_lite::{stream, StreamExt};
+
+    #[test]
+    fn flatten() {
+        futures_lite::future::block_on(async {
+            let mut v: Vec<_> = stream::iter([1, 2, 3])
+                .co()
+                .map(|n| async move { stream::repeat(n).take(n) })
+                .flatten()
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &[1, 2, 2, 3, 3, 3]);
+        });
+    }
+
+    #[test]
+    fn flatten_limit() {
+        futures_lite::future::block_on(async {
+            let mut v: Vec<_> = stream::iter([1, 2, 3])
+                .co()
+                .limit(NonZeroUsize::new(1))
+                .map(|n| async move { stream::iter([n, n * 10]) })
+                .flatten()
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &[1, 2, 3, 10, 20, 30]);
+        });
+    }
+}
@@ -1,37 +1,34 @@
 use super::{Consumer, ConsumerState};
-use futures_buffered::FuturesUnordered;
+use crate::future::FutureGroup;
 use futures_lite::StreamExt;
 use pin_project::pin_project;
 
-use alloc::sync::Arc;
 use core::future::Future;
 use core::marker::PhantomData;
 use core::num::NonZeroUsize;
 use core::pin::Pin;
-use core::sync::atomic::{AtomicUsize, Ordering};
-use core::task::{ready, Context, Poll};
+use core::task::{Context, Poll};
 
-// OK: validated! - all bounds should check out
 #[pin_project]
 pub(crate) struct ForEachConsumer<FutT, T, F, FutB>
 where
     FutT: Future<Output = T>,
-    F: Fn(T) -> FutB,
+    F: FnMut(T) -> FutB,
     FutB: Future<Output = ()>,
 {
-    // NOTE: we can remove the `Arc` here if we're willing to make this struct self-referential
-    count: Arc<AtomicUsize>,
+    // Every item occupies a single slot in the group, first while its value
+    // is being computed and then while the closure's future runs.
     #[pin]
-    group: FuturesUnordered<ForEachFut<F, FutT, T, FutB>>,
+    group: FutureGroup<ForEachFut<FutT, FutB>>,
     limit: usize,
     f: F,
-    _phantom: PhantomData<(T, FutB)>,
+    _phantom: PhantomData<T>,
 }
 
 impl<A, T, F, B> ForEachConsumer<A, T, F, B>
 where
     A: Future<Output = T>,
-    F: Fn(T) -> B,
+    F: FnMut(T) -> B,
     B: Future<Output = ()>,
 {
     pub(crate) fn new(limit: Option<NonZeroUsize>, f: F) -> Self {
@@ -43,8 +40,7 @@ where
             limit,
             f,
             _phantom: PhantomData,
-            count: Arc::new(AtomicUsize::new(0)),
-            group: FuturesUnordered::new(),
+            group: FutureGroup::new(),
         }
     }
 }
@@ -53,8 +49,7 @@ where
 impl<FutT, T, F, B> Consumer<T, FutT> for ForEachConsumer<FutT, T, F, B>
 where
     FutT: Future<Output = T>,
-    F: Fn(T) -> B,
-    F: Clone,
+    F: FnMut(T) -> B,
     B: Future<Output = ()>,
 {
     type Output = ();
@@ -62,99 +57,76 @@ where
     async fn send(self: Pin<&mut Self>, future: FutT) -> super::ConsumerState {
         let mut this = self.project();
         // If we have no space, we're going to provide backpressure until we have space
-        while this.count.load(Ordering::Relaxed) >= *this.limit {
-            this.group.next().await;
+        while this.group.len() >= *this.limit {
+            match this.group.next().await {
+                Some(Step::Item(item)) => {
+                    let fut = ForEachFut::Body((this.f)(item));
+                    this.group.as_mut().insert_pinned(fut);
+                }
+                Some(Step::Done(())) => {}
+                None => break,
+            }
         }
 
         // Space was available! - insert the item for posterity
-        this.count.fetch_add(1, Ordering::Relaxed);
-        let fut = ForEachFut::new(this.f.clone(), future, this.count.clone());
-        this.group.as_mut().push(fut);
+        this.group.as_mut().insert_pinned(ForEachFut::Item(future));
 
         ConsumerState::Continue
     }
 
     async fn progress(self: Pin<&mut Self>) -> super::ConsumerState {
         let mut this = self.project();
-        while (this.group.next().await).is_some() {}
+        while let Some(step) = this.group.next().await {
+            if let Step::Item(item) = step {
+                let fut = ForEachFut::Body((this.f)(item));
+                this.group.as_mut().insert_pinned(fut);
+            }
+        }
         ConsumerState::Empty
     }
 
     async fn flush(self: Pin<&mut Self>) -> Self::Output {
-        let mut this = self.project();
         // 4. We will no longer receive any additional futures from the
         // underlying stream; wait until all the futures in the group have
         // resolved.
-        while (this.group.next().await).is_some() {}
+        self.progress().await;
     }
 }
 
-/// Takes a future and maps it to another future via a closure
-#[derive(Debug)]
-pub struct ForEachFut<F, FutT, T, FutB>
-where
-    FutT: Future<Output = T>,
-    F: Fn(T) -> FutB,
-    FutB: Future<Output = ()>,
-{
-    done: bool,
-    count: Arc<AtomicUsize>,
-    f: F,
-    fut_t: Option<FutT>,
-    fut_b: Option<FutB>,
+/// The output of a [`ForEachFut`].
+pub(crate) enum Step<T, B> {
+    /// The value of an item has been computed, and should be passed to the
+    /// closure.
+    Item(T),
+    /// The future returned by the closure has completed.
+    Done(B),
 }
 
-impl<F, FutT, T, FutB> ForEachFut<F, FutT, T, FutB>
-where
-    FutT: Future<Output = T>,
-    F: Fn(T) -> FutB,
-    FutB: Future<Output = ()>,
-{
-    fn new(f: F, fut_t: FutT, count: Arc<AtomicUsize>) -> Self {
-        Self {
-            done: false,
-            count,
-            f,
-            fut_t: Some(fut_t),
-            fut_b: None,
-        }
-    }
+/// Computes the value of an item, and then runs the future the closure
+/// returned for it.
+///
+/// The closure is called by the consumer rather than by this future, so it
+/// only needs to be `FnMut`.
+#[pin_project(project = ForEachFutProj)]
+pub(crate) enum ForEachFut<FutT, FutB> {
+    /// The future containing the value of `T`
+    Item(#[pin] FutT),
+    /// The future returned by the closure
+    Body(#[pin] FutB),
 }
 
-impl<F, FutT, T, FutB> Future for ForEachFut<F, FutT, T, FutB>
+impl<FutT, FutB> Future for ForEachFut<FutT, FutB>
 where
-    FutT: Future<Output = T>,
-    F: Fn(T) -> FutB,
-    FutB: Future<Output = ()>,
+    FutT: Future,
+    FutB: Future,
 {
-    type Output = ();
+    type Output = Step<FutT::Output, FutB::Output>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        // SAFETY: we need to access the inner future's fields to project them
-        let this = unsafe { self.get_unchecked_mut() };
-        if this.done {
-            panic!("future has already been polled to completion once");
-        }
-
-        // Poll forward the future containing the value of `T`
-        if let Some(fut) = this.fut_t.as_mut() {
-            // SAFETY: we're pin projecting here
-            let t = ready!(unsafe { Pin::new_unchecked(fut) }.poll(cx));
-            let fut_b = (this.f)(t);
-            this.fut_t = None;
-            this.fut_b = Some(fut_b);
-        }
-
-        // Poll forward the future returned by the closure
-        if let Some(fut) = this.fut_b.as_mut() {
-            // SAFETY: we're pin projecting here
-            ready!(unsafe { Pin::new_unchecked(fut) }.poll(cx));
-            this.count.fetch_sub(1, Ordering::Relaxed);
-            this.done = true;
-            return Poll::Ready(());
+        match self.project() {
+            ForEachFutProj::Item(fut) => fut.poll(cx).map(Step::Item),
+            ForEachFutProj::Body(fut) => fut.poll(cx).map(Step::Done),
         }
-
-        unreachable!("neither future `a` nor future `b` were ready");
     }
 }
 
@@ -162,6 +134,8 @@ where
 mod test {
     use super::*;
     use crate::prelude::*;
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicUsize, Ordering};
     use futures_lite::stream;
 
     #[test]
@@ -203,4 +177,20 @@ mod test {
             assert_eq!(count.load(Ordering::Relaxed), 10);
         });
     }
+
+    #[test]
+    fn mutable_state() {
+        futures_lite::future::block_on(async {
+            let mut total = 0;
+            stream::iter([1, 2, 3])
+                .co()
+                .for_each(|n| {
+                    total += n;
+                    async {}
+                })
+                .await;
+
+            assert_eq!(total, 6);
+        });
+    }
 }
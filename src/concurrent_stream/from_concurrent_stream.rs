@@ -1,11 +1,19 @@
 use super::{ConcurrentStream, Consumer, ConsumerState, IntoConcurrentStream};
+use crate::ops::Try;
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
+
+use crate::future::FutureGroup;
+use alloc::collections::{BTreeMap, BTreeSet};
 use core::future::Future;
+use core::ops::ControlFlow;
 use core::pin::Pin;
-use futures_buffered::FuturesUnordered;
 use futures_lite::StreamExt;
 use pin_project::pin_project;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::hash::{BuildHasher, Hash};
 
 /// Conversion from a [`ConcurrentStream`]
 #[allow(async_fn_in_trait)]
@@ -23,110 +31,241 @@ impl<T> FromConcurrentStream<T> for Vec<T> {
     {
         let stream = iter.into_co_stream();
         let mut output = Vec::with_capacity(stream.size_hint().1.unwrap_or_default());
-        stream.drive(VecConsumer::new(&mut output)).await;
+        stream.drive(ExtendConsumer::new(&mut output)).await;
         output
     }
 }
 
-impl<T, E> FromConcurrentStream<Result<T, E>> for Result<Vec<T>, E> {
+impl<T: Ord> FromConcurrentStream<T> for BTreeSet<T> {
+    async fn from_concurrent_stream<S>(iter: S) -> Self
+    where
+        S: IntoConcurrentStream<Item = T>,
+    {
+        extend(BTreeSet::new(), iter).await
+    }
+}
+
+impl<K: Ord, V> FromConcurrentStream<(K, V)> for BTreeMap<K, V> {
+    async fn from_concurrent_stream<S>(iter: S) -> Self
+    where
+        S: IntoConcurrentStream<Item = (K, V)>,
+    {
+        extend(BTreeMap::new(), iter).await
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, H> FromConcurrentStream<T> for HashSet<T, H>
+where
+    T: Eq + Hash,
+    H: BuildHasher + Default,
+{
+    async fn from_concurrent_stream<S>(iter: S) -> Self
+    where
+        S: IntoConcurrentStream<Item = T>,
+    {
+        extend(HashSet::default(), iter).await
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, H> FromConcurrentStream<(K, V)> for HashMap<K, V, H>
+where
+    K: Eq + Hash,
+    H: BuildHasher + Default,
+{
+    async fn from_concurrent_stream<S>(iter: S) -> Self
+    where
+        S: IntoConcurrentStream<Item = (K, V)>,
+    {
+        extend(HashMap::default(), iter).await
+    }
+}
+
+impl FromConcurrentStream<char> for String {
+    async fn from_concurrent_stream<S>(iter: S) -> Self
+    where
+        S: IntoConcurrentStream<Item = char>,
+    {
+        extend(String::new(), iter).await
+    }
+}
+
+impl<'a> FromConcurrentStream<&'a str> for String {
+    async fn from_concurrent_stream<S>(iter: S) -> Self
+    where
+        S: IntoConcurrentStream<Item = &'a str>,
+    {
+        extend(String::new(), iter).await
+    }
+}
+
+impl FromConcurrentStream<String> for String {
+    async fn from_concurrent_stream<S>(iter: S) -> Self
+    where
+        S: IntoConcurrentStream<Item = String>,
+    {
+        extend(String::new(), iter).await
+    }
+}
+
+impl<C, T, E> FromConcurrentStream<Result<T, E>> for Result<C, E>
+where
+    C: Default + Extend<T>,
+{
     async fn from_concurrent_stream<S>(iter: S) -> Self
     where
         S: IntoConcurrentStream<Item = Result<T, E>>,
     {
-        let stream = iter.into_co_stream();
-        let mut output = Ok(Vec::with_capacity(stream.size_hint().1.unwrap_or_default()));
-        stream.drive(ResultVecConsumer::new(&mut output)).await;
-        output
+        try_extend(iter).await
+    }
+}
+
+impl<C, T> FromConcurrentStream<Option<T>> for Option<C>
+where
+    C: Default + Extend<T>,
+{
+    async fn from_concurrent_stream<S>(iter: S) -> Self
+    where
+        S: IntoConcurrentStream<Item = Option<T>>,
+    {
+        try_extend(iter).await
+    }
+}
+
+/// Drive a concurrent stream to completion, extending `output` with its items.
+async fn extend<C, S>(mut output: C, iter: S) -> C
+where
+    C: Extend<S::Item>,
+    S: IntoConcurrentStream,
+{
+    iter.into_co_stream()
+        .drive(ExtendConsumer::new(&mut output))
+        .await;
+    output
+}
+
+/// Drive a concurrent stream to completion, extending a collection with its
+/// items until the first residual is encountered.
+pub(crate) async fn try_extend<C, S, R, B>(iter: S) -> B
+where
+    C: Default + Extend<R::Output>,
+    S: IntoConcurrentStream<Item = R>,
+    R: Try,
+    B: Try<Output = C, Residual = R::Residual>,
+{
+    let mut output = C::default();
+    let mut residual = None;
+    iter.into_co_stream()
+        .drive(TryExtendConsumer::new(&mut output, &mut residual))
+        .await;
+    match residual {
+        Some(residual) => B::from_residual(residual),
+        None => B::from_output(output),
     }
 }
 
 // TODO: replace this with a generalized `fold` operation
 #[pin_project]
-pub(crate) struct VecConsumer<'a, Fut: Future> {
+pub(crate) struct ExtendConsumer<'a, Fut: Future, C> {
     #[pin]
-    group: FuturesUnordered<Fut>,
-    output: &'a mut Vec<Fut::Output>,
+    group: FutureGroup<Fut>,
+    output: &'a mut C,
 }
 
-impl<'a, Fut: Future> VecConsumer<'a, Fut> {
-    pub(crate) fn new(output: &'a mut Vec<Fut::Output>) -> Self {
+impl<'a, Fut: Future, C> ExtendConsumer<'a, Fut, C> {
+    pub(crate) fn new(output: &'a mut C) -> Self {
         Self {
-            group: FuturesUnordered::new(),
+            group: FutureGroup::new(),
             output,
         }
     }
 }
 
-impl<Item, Fut> Consumer<Item, Fut> for VecConsumer<'_, Fut>
+impl<Item, Fut, C> Consumer<Item, Fut> for ExtendConsumer<'_, Fut, C>
 where
     Fut: Future<Output = Item>,
+    C: Extend<Item>,
 {
     type Output = ();
 
     async fn send(self: Pin<&mut Self>, future: Fut) -> super::ConsumerState {
         let mut this = self.project();
         // unbounded concurrency, so we just goooo
-        this.group.as_mut().push(future);
+        this.group.as_mut().insert_pinned(future);
         ConsumerState::Continue
     }
 
     async fn progress(self: Pin<&mut Self>) -> super::ConsumerState {
         let mut this = self.project();
         while let Some(item) = this.group.next().await {
-            this.output.push(item);
+            this.output.extend(Some(item));
         }
         ConsumerState::Empty
     }
     async fn flush(self: Pin<&mut Self>) -> Self::Output {
         let mut this = self.project();
         while let Some(item) = this.group.next().await {
-            this.output.push(item);
+            this.output.extend(Some(item));
         }
     }
 }
 
 #[pin_project]
-pub(crate) struct ResultVecConsumer<'a, Fut: Future, T, E> {
+pub(crate) struct TryExtendConsumer<'a, Fut: Future, C>
+where
+    Fut::Output: Try,
+{
     #[pin]
-    group: FuturesUnordered<Fut>,
-    output: &'a mut Result<Vec<T>, E>,
+    group: FutureGroup<Fut>,
+    output: &'a mut C,
+    residual: &'a mut Option<<Fut::Output as Try>::Residual>,
 }
 
-impl<'a, Fut: Future, T, E> ResultVecConsumer<'a, Fut, T, E> {
-    pub(crate) fn new(output: &'a mut Result<Vec<T>, E>) -> Self {
+impl<'a, Fut: Future, C> TryExtendConsumer<'a, Fut, C>
+where
+    Fut::Output: Try,
+{
+    pub(crate) fn new(
+        output: &'a mut C,
+        residual: &'a mut Option<<Fut::Output as Try>::Residual>,
+    ) -> Self {
         Self {
-            group: FuturesUnordered::new(),
+            group: FutureGroup::new(),
             output,
+            residual,
         }
     }
 }
 
-impl<Fut, T, E> Consumer<Result<T, E>, Fut> for ResultVecConsumer<'_, Fut, T, E>
+impl<Fut, C> Consumer<Fut::Output, Fut> for TryExtendConsumer<'_, Fut, C>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: Try,
+    C: Extend<<Fut::Output as Try>::Output>,
 {
     type Output = ();
 
     async fn send(self: Pin<&mut Self>, future: Fut) -> super::ConsumerState {
         let mut this = self.project();
         // unbounded concurrency, so we just goooo
-        this.group.as_mut().push(future);
+        this.group.as_mut().insert_pinned(future);
         ConsumerState::Continue
     }
 
     async fn progress(self: Pin<&mut Self>) -> super::ConsumerState {
         let mut this = self.project();
-        let Ok(items) = this.output else {
+        if this.residual.is_some() {
             return ConsumerState::Break;
-        };
+        }
 
         while let Some(item) = this.group.next().await {
-            match item {
-                Ok(item) => {
-                    items.push(item);
+            match item.branch() {
+                ControlFlow::Continue(item) => {
+                    this.output.extend(Some(item));
                 }
-                Err(e) => {
-                    **this.output = Err(e);
+                ControlFlow::Break(residual) => {
+                    **this.residual = Some(residual);
                     return ConsumerState::Break;
                 }
             }
@@ -160,6 +299,59 @@ mod test {
         });
     }
 
+    #[test]
+    fn collect_to_option() {
+        futures_lite::future::block_on(async {
+            let v: Option<Vec<_>> = stream::repeat(Some(1)).co().take(2).collect().await;
+            assert_eq!(v, Some(vec![1, 1]));
+
+            let v: Option<Vec<_>> = stream::repeat(None::<u8>).co().take(2).collect().await;
+            assert_eq!(v, None);
+        });
+    }
+
+    #[test]
+    fn try_collect() {
+        use core::ops::ControlFlow;
+
+        futures_lite::future::block_on(async {
+            let v: Result<Vec<_>, ()> = stream::repeat(Ok(1)).co().take(2).try_collect().await;
+            assert_eq!(v, Ok(vec![1, 1]));
+
+            let v: ControlFlow<&str, Vec<u8>> =
+                stream::iter([ControlFlow::Continue(1), ControlFlow::Break("stop")])
+                    .co()
+                    .try_collect()
+                    .await;
+            assert_eq!(v, ControlFlow::Break("stop"));
+        });
+    }
+
+    #[test]
+    fn collect_to_collections() {
+        use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+        futures_lite::future::block_on(async {
+            let s: String = stream::iter(["a", "b"]).co().ordered().collect().await;
+            assert_eq!(s, "ab");
+
+            let s: BTreeSet<_> = stream::iter([1, 2, 1]).co().collect().await;
+            assert_eq!(s, BTreeSet::from([1, 2]));
+
+            let s: HashSet<_> = stream::iter([1, 2, 1]).co().collect().await;
+            assert_eq!(s, HashSet::from([1, 2]));
+
+            let m: BTreeMap<_, _> = stream::iter([(1, "a")]).co().collect().await;
+            assert_eq!(m, BTreeMap::from([(1, "a")]));
+
+            let m: HashMap<_, _> = stream::iter([(1, "a")]).co().collect().await;
+            assert_eq!(m, HashMap::from([(1, "a")]));
+
+            let m: Result<HashMap<_, _>, ()> = stream::iter([Ok((1, "a"))]).co().collect().await;
+            assert_eq!(m, Ok(HashMap::from([(1, "a")])));
+        });
+    }
+
     #[test]
     fn collect_to_result_err() {
         futures_lite::future::block_on(async {
@@ -0,0 +1,153 @@
+use pin_project::pin_project;
+
+use super::{ConcurrentStream, Consumer};
+use core::future::Future;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+/// A concurrent iterator that calls a function with a reference to each
+/// element before yielding it.
+///
+/// This `struct` is created by the [`inspect`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`inspect`]: ConcurrentStream::inspect
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct Inspect<CS: ConcurrentStream, F> {
+    inner: CS,
+    f: F,
+}
+
+impl<CS: ConcurrentStream, F> Inspect<CS, F> {
+    pub(crate) fn new(inner: CS, f: F) -> Self {
+        Self { inner, f }
+    }
+}
+
+impl<CS, F> ConcurrentStream for Inspect<CS, F>
+where
+    CS: ConcurrentStream,
+    F: Fn(&CS::Item),
+    F: Clone,
+{
+    type Item = CS::Item;
+    type Future = InspectFuture<CS::Future, F>;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        self.inner
+            .drive(InspectConsumer {
+                inner: consumer,
+                f: self.f,
+            })
+            .await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.inner.concurrency_limit()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[pin_project]
+struct InspectConsumer<C, F> {
+    #[pin]
+    inner: C,
+    f: F,
+}
+impl<C, F, Item, Fut> Consumer<Item, Fut> for InspectConsumer<C, F>
+where
+    Fut: Future<Output = Item>,
+    C: Consumer<Item, InspectFuture<Fut, F>>,
+    F: Fn(&Item),
+    F: Clone,
+{
+    type Output = C::Output;
+
+    async fn send(self: Pin<&mut Self>, future: Fut) -> super::ConsumerState {
+        let this = self.project();
+        let fut = InspectFuture::new(future, this.f.clone());
+        this.inner.send(fut).await
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> super::ConsumerState {
+        let this = self.project();
+        this.inner.progress().await
+    }
+
+    async fn flush(self: Pin<&mut Self>) -> Self::Output {
+        let this = self.project();
+        this.inner.flush().await
+    }
+}
+
+/// Takes a future and calls a closure with a reference to its output
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct InspectFuture<FutT, F> {
+    done: bool,
+    #[pin]
+    fut_t: FutT,
+    f: F,
+}
+
+impl<FutT, F> InspectFuture<FutT, F> {
+    fn new(fut_t: FutT, f: F) -> Self {
+        Self {
+            done: false,
+            fut_t,
+            f,
+        }
+    }
+}
+
+impl<FutT, F> Future for InspectFuture<FutT, F>
+where
+    FutT: Future,
+    F: Fn(&FutT::Output),
+{
+    type Output = FutT::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if *this.done {
+            panic!("future has already been polled to completion once");
+        }
+
+        let item = ready!(this.fut_t.poll(cx));
+        (this.f)(&item);
+        *this.done = true;
+        Poll::Ready(item)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use futures_lite::stream;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn inspect() {
+        futures_lite::future::block_on(async {
+            let sum = AtomicUsize::new(0);
+            let v: Vec<_> = stream::iter(1..=3)
+                .co()
+                .inspect(|n| {
+                    sum.fetch_add(*n, Ordering::Relaxed);
+                })
+                .map(|n| async move { n * 2 })
+                .collect()
+                .await;
+            assert_eq!(v.into_iter().sum::<usize>(), 12);
+            assert_eq!(sum.load(Ordering::Relaxed), 6);
+        });
+    }
+}
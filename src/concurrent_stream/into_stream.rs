@@ -0,0 +1,181 @@
+use super::{Consumer, ConsumerState};
+use crate::future::FutureGroup;
+use futures_core::Stream;
+use futures_lite::StreamExt;
+use pin_project::pin_project;
+
+use crate::utils::Mutex;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use core::fmt;
+use core::future::{poll_fn, Future};
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// A stream which yields the items of a concurrent stream as they complete.
+///
+/// This `struct` is created by the [`into_stream`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`into_stream`]: super::ConcurrentStream::into_stream
+/// [`ConcurrentStream`]: super::ConcurrentStream
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct IntoStream<Fut, T> {
+    // The future driving the concurrent stream, until it completes.
+    #[pin]
+    drive: Option<Fut>,
+    buffer: Arc<Mutex<VecDeque<T>>>,
+}
+
+impl<Fut, T> IntoStream<Fut, T> {
+    pub(crate) fn new(drive: Fut, buffer: Arc<Mutex<VecDeque<T>>>) -> Self {
+        Self {
+            drive: Some(drive),
+            buffer,
+        }
+    }
+}
+
+impl<Fut, T> fmt::Debug for IntoStream<Fut, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoStream").finish_non_exhaustive()
+    }
+}
+
+impl<Fut, T> Stream for IntoStream<Fut, T>
+where
+    Fut: Future<Output = ()>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if let Some(item) = this.buffer.lock().pop_front() {
+            return Poll::Ready(Some(item));
+        }
+
+        // Drive the concurrent stream forward until it has produced an item.
+        if let Some(drive) = this.drive.as_mut().as_pin_mut() {
+            if drive.poll(cx).is_ready() {
+                this.drive.set(None);
+            }
+        }
+
+        match this.buffer.lock().pop_front() {
+            Some(item) => Poll::Ready(Some(item)),
+            None if this.drive.is_none() => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
+
+/// Wait until the stream has taken all buffered items.
+///
+/// This doesn't register a waker: if there are buffered items, the stream
+/// yields them and will poll us again on the next call to `poll_next`.
+async fn wait_until_taken<T>(buffer: &Mutex<VecDeque<T>>) {
+    poll_fn(|_| match buffer.lock().is_empty() {
+        true => Poll::Ready(()),
+        false => Poll::Pending,
+    })
+    .await
+}
+
+#[pin_project]
+pub(crate) struct IntoStreamConsumer<Fut: Future> {
+    #[pin]
+    group: FutureGroup<Fut>,
+    limit: usize,
+    buffer: Arc<Mutex<VecDeque<Fut::Output>>>,
+}
+
+impl<Fut: Future> IntoStreamConsumer<Fut> {
+    pub(crate) fn new(
+        limit: Option<NonZeroUsize>,
+        buffer: Arc<Mutex<VecDeque<Fut::Output>>>,
+    ) -> Self {
+        let limit = match limit {
+            Some(n) => n.get(),
+            None => usize::MAX,
+        };
+        Self {
+            group: FutureGroup::new(),
+            limit,
+            buffer,
+        }
+    }
+}
+
+impl<Item, Fut> Consumer<Item, Fut> for IntoStreamConsumer<Fut>
+where
+    Fut: Future<Output = Item>,
+{
+    type Output = ();
+
+    async fn send(self: Pin<&mut Self>, future: Fut) -> ConsumerState {
+        let mut this = self.project();
+        // If we have no space, we're going to provide backpressure until we have space
+        while this.group.len() >= *this.limit {
+            wait_until_taken(this.buffer).await;
+            match this.group.next().await {
+                Some(item) => this.buffer.lock().push_back(item),
+                None => break,
+            }
+        }
+
+        this.group.as_mut().insert_pinned(future);
+        ConsumerState::Continue
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> ConsumerState {
+        let mut this = self.project();
+        loop {
+            wait_until_taken(this.buffer).await;
+            match this.group.next().await {
+                Some(item) => this.buffer.lock().push_back(item),
+                None => return ConsumerState::Empty,
+            }
+        }
+    }
+
+    async fn flush(self: Pin<&mut Self>) -> Self::Output {
+        self.progress().await;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use core::num::NonZeroUsize;
+    use futures_lite::{stream, StreamExt};
+
+    #[test]
+    fn into_stream() {
+        futures_lite::future::block_on(async {
+            let mut v: Vec<_> = stream::iter([1, 2, 3])
+                .co()
+                .map(|n| async move { n * 2 })
+                .into_stream()
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &[2, 4, 6]);
+        });
+    }
+
+    #[test]
+    fn into_stream_limit() {
+        futures_lite::future::block_on(async {
+            let s = stream::iter(0..)
+                .co()
+                .limit(NonZeroUsize::new(2))
+                .map(|n| async move { n })
+                .into_stream();
+            let v: Vec<_> = s.take(5).collect().await;
+            assert_eq!(v.len(), 5);
+        });
+    }
+}
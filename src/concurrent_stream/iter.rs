@@ -0,0 +1,146 @@
+use super::{ConcurrentStream, Consumer, FromStream, IntoConcurrentStream};
+use crate::prelude::*;
+use crate::utils::{from_iter, FromIter};
+
+use core::future::Ready;
+use core::num::NonZeroUsize;
+use core::ops::{Range, RangeInclusive};
+
+/// A concurrent stream which yields the items of an iterator.
+///
+/// This `struct` is created by the [`iter`] function. See its documentation
+/// for more.
+#[derive(Debug)]
+pub struct Iter<I: Iterator>(FromStream<FromIter<I>>);
+
+/// Convert an iterator into a concurrent stream.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::concurrent_stream;
+/// use futures_concurrency::prelude::*;
+///
+/// # futures::executor::block_on(async {
+/// let mut v: Vec<_> = concurrent_stream::iter("abc".chars())
+///     .map(|c| async move { c.to_ascii_uppercase() })
+///     .collect()
+///     .await;
+/// v.sort();
+/// assert_eq!(v, &['A', 'B', 'C']);
+/// # });
+/// ```
+pub fn iter<I: IntoIterator>(iter: I) -> Iter<I::IntoIter> {
+    Iter(from_iter(iter).co())
+}
+
+impl<I: Iterator> ConcurrentStream for Iter<I> {
+    type Item = I::Item;
+    type Future = Ready<I::Item>;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        self.0.drive(consumer).await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.0.concurrency_limit()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T, const N: usize> IntoConcurrentStream for [T; N] {
+    type Item = T;
+    type IntoConcurrentStream = Iter<core::array::IntoIter<T, N>>;
+
+    fn into_co_stream(self) -> Self::IntoConcurrentStream {
+        iter(self)
+    }
+}
+
+impl<'a, T> IntoConcurrentStream for &'a [T] {
+    type Item = &'a T;
+    type IntoConcurrentStream = Iter<core::slice::Iter<'a, T>>;
+
+    fn into_co_stream(self) -> Self::IntoConcurrentStream {
+        iter(self)
+    }
+}
+
+impl<A> IntoConcurrentStream for Range<A>
+where
+    Range<A>: Iterator<Item = A>,
+{
+    type Item = A;
+    type IntoConcurrentStream = Iter<Range<A>>;
+
+    fn into_co_stream(self) -> Self::IntoConcurrentStream {
+        iter(self)
+    }
+}
+
+impl<A> IntoConcurrentStream for RangeInclusive<A>
+where
+    RangeInclusive<A>: Iterator<Item = A>,
+{
+    type Item = A;
+    type IntoConcurrentStream = Iter<RangeInclusive<A>>;
+
+    fn into_co_stream(self) -> Self::IntoConcurrentStream {
+        iter(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn array() {
+        futures_lite::future::block_on(async {
+            let mut v: Vec<_> = [1, 2, 3]
+                .into_co_stream()
+                .map(|n| async move { n * 2 })
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &[2, 4, 6]);
+        });
+    }
+
+    #[test]
+    fn slice() {
+        futures_lite::future::block_on(async {
+            let items = [1, 2, 3];
+            let mut v: Vec<_> = items[..]
+                .into_co_stream()
+                .map(|n| async move { *n * 2 })
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &[2, 4, 6]);
+        });
+    }
+
+    #[test]
+    fn ranges() {
+        futures_lite::future::block_on(async {
+            let range = (0..1024).into_co_stream();
+            assert_eq!(range.size_hint(), (1024, Some(1024)));
+            assert_eq!(range.count().await, 1024);
+
+            let mut v: Vec<_> = (1..=3)
+                .into_co_stream()
+                .map(|n| async move { n * 2 })
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &[2, 4, 6]);
+        });
+    }
+}
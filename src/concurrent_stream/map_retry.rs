@@ -0,0 +1,207 @@
+use pin_project::pin_project;
+
+use super::{ConcurrentStream, Consumer, ConsumerState};
+use crate::future::retry::Attempts;
+use crate::future::RetryPolicy;
+use crate::time::Timer;
+
+use core::fmt;
+use core::future::Future;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+/// Convert items using a fallible operation, retrying it when it fails.
+///
+/// This `struct` is created by the [`map_retry`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`map_retry`]: ConcurrentStream::map_retry
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct MapRetry<CS, F, P, Tm> {
+    inner: CS,
+    f: F,
+    policy: P,
+    timer: Tm,
+}
+
+impl<CS, F, P, Tm> MapRetry<CS, F, P, Tm> {
+    pub(crate) fn new(inner: CS, policy: P, timer: Tm, f: F) -> Self {
+        Self {
+            inner,
+            f,
+            policy,
+            timer,
+        }
+    }
+}
+
+impl<CS, F, Fut, T, E, P, Tm> ConcurrentStream for MapRetry<CS, F, P, Tm>
+where
+    CS: ConcurrentStream,
+    CS::Item: Clone,
+    F: Fn(CS::Item) -> Fut + Clone,
+    Fut: Future<Output = Result<T, E>>,
+    P: RetryPolicy<E> + Clone,
+    Tm: Timer + Clone,
+{
+    type Item = Result<T, E>;
+    type Future = MapRetryFuture<CS::Future, F, Fut, P, Tm>;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        let consumer = MapRetryConsumer {
+            inner: consumer,
+            f: self.f,
+            policy: self.policy,
+            timer: self.timer,
+        };
+        self.inner.drive(consumer).await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.inner.concurrency_limit()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[pin_project]
+struct MapRetryConsumer<C, F, P, Tm> {
+    #[pin]
+    inner: C,
+    f: F,
+    policy: P,
+    timer: Tm,
+}
+
+impl<C, FutT, F, Fut, T, E, P, Tm> Consumer<FutT::Output, FutT> for MapRetryConsumer<C, F, P, Tm>
+where
+    FutT: Future,
+    FutT::Output: Clone,
+    F: Fn(FutT::Output) -> Fut + Clone,
+    Fut: Future<Output = Result<T, E>>,
+    P: RetryPolicy<E> + Clone,
+    Tm: Timer + Clone,
+    C: Consumer<Result<T, E>, MapRetryFuture<FutT, F, Fut, P, Tm>>,
+{
+    type Output = C::Output;
+
+    async fn send(self: Pin<&mut Self>, future: FutT) -> ConsumerState {
+        let this = self.project();
+        let future = MapRetryFuture {
+            f: this.f.clone(),
+            policy: this.policy.clone(),
+            timer: this.timer.clone(),
+            item: None,
+            source: Some(future),
+            attempts: None,
+        };
+        this.inner.send(future).await
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> ConsumerState {
+        self.project().inner.progress().await
+    }
+
+    async fn flush(self: Pin<&mut Self>) -> Self::Output {
+        self.project().inner.flush().await
+    }
+}
+
+/// Waits for an item, and then retries a fallible operation on it.
+#[pin_project]
+pub struct MapRetryFuture<FutT: Future, F, Fut, P, Tm: Timer> {
+    f: F,
+    policy: P,
+    timer: Tm,
+    item: Option<FutT::Output>,
+    #[pin]
+    source: Option<FutT>,
+    #[pin]
+    attempts: Option<Attempts<Fut, Tm::Sleep>>,
+}
+
+impl<FutT: Future, F, Fut, P, Tm: Timer> fmt::Debug for MapRetryFuture<FutT, F, Fut, P, Tm> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapRetryFuture").finish_non_exhaustive()
+    }
+}
+
+impl<FutT, F, Fut, T, E, P, Tm> Future for MapRetryFuture<FutT, F, Fut, P, Tm>
+where
+    FutT: Future,
+    FutT::Output: Clone,
+    F: Fn(FutT::Output) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    P: RetryPolicy<E>,
+    Tm: Timer,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        // Wait for the item, and then make the first attempt.
+        if let Some(source) = this.source.as_mut().as_pin_mut() {
+            let item = ready!(source.poll(cx));
+            this.source.set(None);
+            this.attempts
+                .set(Some(Attempts::new((this.f)(item.clone()))));
+            *this.item = Some(item);
+        }
+
+        let attempts = this
+            .attempts
+            .as_mut()
+            .as_pin_mut()
+            .expect("future polled after completing");
+        let item = this
+            .item
+            .as_ref()
+            .expect("the item is set before the first attempt");
+        let f = this.f;
+        let out = ready!(attempts.poll_attempts(cx, this.policy, this.timer, || f(item.clone())));
+        this.attempts.set(None);
+        Poll::Ready(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::future::Backoff;
+    use crate::prelude::*;
+    use core::cell::Cell;
+    use core::time::Duration;
+    use futures_lite::stream;
+
+    #[test]
+    fn retries_each_item() {
+        futures_lite::future::block_on(async {
+            let calls = Cell::new(0);
+            let policy = Backoff::fixed(Duration::ZERO).max_attempts(3);
+            let mut v: Vec<_> = stream::iter([1, 2, 3])
+                .co()
+                .map_retry(policy, async_io::Timer::after, |n| {
+                    let calls = &calls;
+                    async move {
+                        calls.set(calls.get() + 1);
+                        match n {
+                            2 => Err(n),
+                            n => Ok(n * 10),
+                        }
+                    }
+                })
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, [Ok(10), Ok(30), Err(2)]);
+            assert_eq!(calls.get(), 5);
+        });
+    }
+}
@@ -36,29 +36,78 @@
 //! # });
 //! ```
 
+#[cfg(feature = "std")]
+mod adaptive_limit;
 mod enumerate;
+mod flat_map;
+mod flatten;
 mod for_each;
 mod from_concurrent_stream;
 mod from_stream;
+mod inspect;
 mod into_concurrent_stream;
+#[cfg(feature = "std")]
+mod into_stream;
+mod iter;
 mod limit;
 mod map;
+mod map_retry;
+mod ordered;
+mod race_progress;
+mod scan;
+mod spawned_on;
 mod take;
+mod take_while;
 mod try_for_each;
+mod until;
+#[cfg(target_has_atomic = "ptr")]
+mod with_progress;
 
-use core::future::Future;
+use crate::future::RetryPolicy;
+use crate::ops::Try;
+use crate::stream;
+use crate::time::Timer;
+#[cfg(feature = "std")]
+use crate::utils::Mutex;
+#[cfg(feature = "std")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "std")]
+use alloc::sync::Arc;
+use core::future::{Future, IntoFuture};
 use core::num::NonZeroUsize;
+use core::ops::ControlFlow;
 use core::pin::Pin;
 use for_each::ForEachConsumer;
+#[cfg(feature = "std")]
+use into_stream::IntoStreamConsumer;
 use try_for_each::TryForEachConsumer;
 
+#[cfg(feature = "std")]
+pub use adaptive_limit::AdaptiveLimit;
 pub use enumerate::Enumerate;
+pub use flat_map::FlatMap;
+pub use flatten::Flatten;
 pub use from_concurrent_stream::FromConcurrentStream;
 pub use from_stream::FromStream;
+pub use inspect::Inspect;
 pub use into_concurrent_stream::IntoConcurrentStream;
+#[cfg(feature = "std")]
+pub use into_stream::IntoStream;
+pub use iter::{iter, Iter};
 pub use limit::Limit;
 pub use map::Map;
+pub use map_retry::MapRetry;
+pub use ordered::Ordered;
+pub use scan::Scan;
+pub use spawned_on::{
+    LocalSpawnFuture, LocalSpawner, SpawnFuture, SpawnLocal, SpawnTask, SpawnedOn, SpawnedOnLocal,
+    Spawner,
+};
 pub use take::Take;
+pub use take_while::TakeWhile;
+pub use until::Until;
+#[cfg(target_has_atomic = "ptr")]
+pub use with_progress::{Progress, ProgressFuture, WithProgress};
 
 /// Describes a type which can receive data.
 ///
@@ -133,6 +182,47 @@ pub trait ConcurrentStream {
         Limit::new(self, limit)
     }
 
+    /// Limit the amount of concurrency, adjusting it automatically based on
+    /// how long items take to complete.
+    ///
+    /// The limit starts at `min`, and grows by one each time a full window of
+    /// items completes without slowing down. When an item takes more than
+    /// twice as long as the fastest item seen so far, the limit is halved,
+    /// but never drops below `min` or grows above `max`.
+    ///
+    /// Only the futures passed to this adapter are timed, so it should be
+    /// placed after the adapters which do the actual work.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `min` is greater than `max`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let max = NonZeroUsize::new(16).unwrap();
+    /// let mut v: Vec<_> = vec![1, 2, 3]
+    ///     .into_co_stream()
+    ///     .map(|n| async move { n * 2 })
+    ///     .adaptive_limit(NonZeroUsize::MIN, max)
+    ///     .collect()
+    ///     .await;
+    /// v.sort();
+    /// assert_eq!(v, &[2, 4, 6]);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn adaptive_limit(self, min: NonZeroUsize, max: NonZeroUsize) -> AdaptiveLimit<Self>
+    where
+        Self: Sized,
+    {
+        AdaptiveLimit::new(self, min, max)
+    }
+
     /// Creates a stream that yields the first `n` elements, or fewer if the
     /// underlying iterator ends sooner.
     fn take(self, limit: usize) -> Take<Self>
@@ -142,6 +232,82 @@ pub trait ConcurrentStream {
         Take::new(self, limit)
     }
 
+    /// Creates a stream that yields elements as long as a predicate holds.
+    ///
+    /// Once the predicate returns `false` for an item, no more items are taken
+    /// from the underlying stream. Futures which are already in-flight are
+    /// still driven to completion, and their items are yielded if the
+    /// predicate holds for them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream;
+    /// use std::num::NonZeroUsize;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let v: Vec<_> = stream::iter(["a", "b", "STOP", "c"])
+    ///     .co()
+    ///     .limit(NonZeroUsize::new(1))
+    ///     .take_while(|s| {
+    ///         let stop = *s == "STOP";
+    ///         async move { !stop }
+    ///     })
+    ///     .collect()
+    ///     .await;
+    /// assert_eq!(v, &["a", "b"]);
+    /// # });
+    /// ```
+    fn take_while<F, FutP>(self, f: F) -> TakeWhile<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Item) -> FutP,
+        F: Clone,
+        FutP: Future<Output = bool>,
+    {
+        TakeWhile::new(self, f)
+    }
+
+    /// Stop taking new items from the stream once the deadline resolves.
+    ///
+    /// Any future can be used as a deadline, such as a timer or a
+    /// [`StopToken`]. Futures which are already in-flight when the deadline
+    /// resolves are still driven to completion, so the items computed so far
+    /// are returned to the caller rather than being lost.
+    ///
+    /// The deadline is checked whenever an item is taken from the stream, and
+    /// while in-flight futures are being driven. A stream which stops
+    /// yielding items altogether is only stopped once it yields its next one.
+    ///
+    /// [`StopToken`]: crate::sync::StopToken
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    /// use futures_concurrency::sync::StopSource;
+    /// use futures_lite::stream;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let source = StopSource::new();
+    /// let v: Vec<_> = stream::iter(0..)
+    ///     .co()
+    ///     .inspect(|n| if *n == 5 { source.stop() })
+    ///     .until(source.token())
+    ///     .collect()
+    ///     .await;
+    /// assert!(v.len() >= 5);
+    /// # });
+    /// ```
+    fn until<D>(self, deadline: D) -> Until<Self, D::IntoFuture>
+    where
+        Self: Sized,
+        D: IntoFuture,
+    {
+        Until::new(self, deadline.into_future())
+    }
+
     /// Convert items from one type into another
     fn map<F, FutB, B>(self, f: F) -> Map<Self, F, Self::Future, Self::Item, FutB, B>
     where
@@ -153,12 +319,337 @@ pub trait ConcurrentStream {
         Map::new(self, f)
     }
 
+    /// Convert items using a fallible operation, retrying it according to a
+    /// policy when it fails.
+    ///
+    /// Every attempt calls `f` with a clone of the item. Each item gets its
+    /// own copy of the `policy`, see [`retry`] for more.
+    ///
+    /// [`retry`]: crate::future::retry
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::Backoff;
+    /// use futures_concurrency::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let policy = Backoff::fixed(Duration::from_millis(1));
+    /// let v: Vec<_> = vec![1, 2, 3]
+    ///     .into_co_stream()
+    ///     .map_retry(policy, async_io::Timer::after, |n| async move {
+    ///         if n > 0 { Ok(n * 2) } else { Err("not positive") }
+    ///     })
+    ///     .collect()
+    ///     .await;
+    /// assert_eq!(v.len(), 3);
+    /// # });
+    /// ```
+    fn map_retry<F, Fut, T, E, P, Tm>(self, policy: P, timer: Tm, f: F) -> MapRetry<Self, F, P, Tm>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: Fn(Self::Item) -> Fut + Clone,
+        Fut: Future<Output = Result<T, E>>,
+        P: RetryPolicy<E> + Clone,
+        Tm: Timer + Clone,
+    {
+        MapRetry::new(self, policy, timer, f)
+    }
+
+    /// Call a closure with a reference to each item as it passes through.
+    ///
+    /// This is useful for logging or collecting metrics. The closure is
+    /// called as soon as an item has been computed, which means items may be
+    /// observed in any order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let v: Vec<_> = vec![1, 2]
+    ///     .into_co_stream()
+    ///     .inspect(|n| println!("computed {n}"))
+    ///     .collect()
+    ///     .await;
+    /// # });
+    /// ```
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Item),
+        F: Clone,
+    {
+        Inspect::new(self, f)
+    }
+
+    /// Report the progress of the stream to a [`Progress`] handle.
+    ///
+    /// The handle counts how many items have been started and completed, how
+    /// many are currently in flight, and how many are waiting to be started.
+    /// This makes it possible to observe long-running jobs without
+    /// instrumenting each closure by hand.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::concurrent_stream::Progress;
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let progress = Progress::new();
+    /// vec![1, 2, 3]
+    ///     .into_co_stream()
+    ///     .map(|n| async move { n * 2 })
+    ///     .with_progress(progress.clone())
+    ///     .for_each(|_| async {})
+    ///     .await;
+    /// assert_eq!(progress.completed(), 3);
+    /// assert_eq!(progress.total(), Some(3));
+    /// # });
+    /// ```
+    #[cfg(target_has_atomic = "ptr")]
+    fn with_progress(self, progress: Progress) -> WithProgress<Self>
+    where
+        Self: Sized,
+    {
+        WithProgress::new(self, progress)
+    }
+
+    /// Thread mutable state through the items of the stream.
+    ///
+    /// Items are computed concurrently, and passed to the closure in the
+    /// order in which they complete, along with a mutable reference to the
+    /// state. The closure can yield a derived item by returning `Some`, or
+    /// end the stream by returning `None`. Futures which are still in-flight
+    /// when the stream ends are cancelled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// // Compute a running total
+    /// let mut v: Vec<_> = vec![1, 2, 3]
+    ///     .into_co_stream()
+    ///     .scan(0, |total, n| {
+    ///         *total += n;
+    ///         Some(*total)
+    ///     })
+    ///     .collect()
+    ///     .await;
+    /// v.sort();
+    /// assert_eq!(v.last(), Some(&6));
+    /// # });
+    /// ```
+    fn scan<St, F, B>(self, initial_state: St, f: F) -> Scan<Self, St, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut St, Self::Item) -> Option<B>,
+    {
+        Scan::new(self, initial_state, f)
+    }
+
+    /// Creates a stream which flattens nested streams.
+    ///
+    /// Items of the nested streams are forwarded as soon as they're
+    /// available, so they may be yielded in any order. The concurrency limit
+    /// applies to the number of nested streams being iterated over at the
+    /// same time.
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self: Sized,
+        Self::Item: stream::IntoStream,
+    {
+        Flatten::new(self)
+    }
+
+    /// Map each item to a stream, and flatten the resulting streams.
+    ///
+    /// This is useful when every item expands into more items, such as a
+    /// page which links to more pages. Iterators can be returned from the
+    /// closure by converting them with [`futures_lite::stream::iter`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let mut v: Vec<_> = vec![1, 2]
+    ///     .into_co_stream()
+    ///     .flat_map(|n| async move { stream::iter(vec![n; n]) })
+    ///     .collect()
+    ///     .await;
+    /// v.sort();
+    /// assert_eq!(v, &[1, 2, 2]);
+    /// # });
+    /// ```
+    fn flat_map<F, FutU, U>(self, f: F) -> FlatMap<Self, F, FutU, U>
+    where
+        Self: Sized,
+        F: Fn(Self::Item) -> FutU,
+        F: Clone,
+        FutU: Future<Output = U>,
+        U: stream::IntoStream,
+    {
+        FlatMap::new(self, f)
+    }
+
+    /// Yield items in the order of the underlying stream.
+    ///
+    /// Items are still computed concurrently, but items which complete early
+    /// are buffered until all items before them have been yielded. This is
+    /// useful for collecting results in the same order as their inputs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let v: Vec<_> = vec![1, 2, 3]
+    ///     .into_co_stream()
+    ///     .map(|n| async move { n * 2 })
+    ///     .ordered()
+    ///     .collect()
+    ///     .await;
+    /// assert_eq!(v, &[2, 4, 6]);
+    /// # });
+    /// ```
+    fn ordered(self) -> Ordered<Self>
+    where
+        Self: Sized,
+    {
+        Ordered::new(self)
+    }
+
+    /// Run each item as a separate task on an executor.
+    ///
+    /// By default all items are computed within the task which drives the
+    /// stream. This spawns every item as its own task instead, which allows
+    /// the executor to schedule them independently, or run them in parallel.
+    /// Tasks are only spawned once there is room for them, so the
+    /// concurrency limit still applies. Whether tasks are cancelled when the
+    /// stream is dropped depends on the join handles of the executor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    /// use futures_concurrency::concurrent_stream::Spawner;
+    /// use std::future::Future;
+    ///
+    /// #[derive(Clone)]
+    /// struct AsyncStd;
+    ///
+    /// impl Spawner for AsyncStd {
+    ///     type JoinHandle<T: Send + 'static> = async_std::task::JoinHandle<T>;
+    ///
+    ///     fn spawn<Fut>(&self, future: Fut) -> Self::JoinHandle<Fut::Output>
+    ///     where
+    ///         Fut: Future + Send + 'static,
+    ///         Fut::Output: Send + 'static,
+    ///     {
+    ///         async_std::task::spawn(future)
+    ///     }
+    /// }
+    ///
+    /// # async_std::task::block_on(async {
+    /// let mut v: Vec<_> = vec![1, 2, 3]
+    ///     .into_co_stream()
+    ///     .map(|n| async move { n * 2 })
+    ///     .spawned_on(AsyncStd)
+    ///     .collect()
+    ///     .await;
+    /// v.sort();
+    /// assert_eq!(v, &[2, 4, 6]);
+    /// # });
+    /// ```
+    fn spawned_on<Sp>(self, spawner: Sp) -> SpawnedOn<Self, Sp>
+    where
+        Self: Sized,
+        Self::Future: Send + 'static,
+        Self::Item: Send + 'static,
+        Sp: Spawner + Clone,
+    {
+        SpawnedOn::new(self, spawner)
+    }
+
+    /// Run each item as a separate task on the current thread.
+    ///
+    /// This is the counterpart to [`spawned_on`] for executors with a
+    /// `spawn_local` function. Neither the items nor their futures need to be
+    /// `Send`, so closures may capture types such as `Rc`.
+    ///
+    /// [`spawned_on`]: ConcurrentStream::spawned_on
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures::executor::{self, LocalPool};
+    /// use futures::future::RemoteHandle;
+    /// use futures::task::LocalSpawnExt;
+    /// use futures_concurrency::concurrent_stream::LocalSpawner;
+    /// use futures_concurrency::prelude::*;
+    /// use std::future::Future;
+    /// use std::rc::Rc;
+    ///
+    /// #[derive(Clone)]
+    /// struct Local(executor::LocalSpawner);
+    ///
+    /// impl LocalSpawner for Local {
+    ///     type JoinHandle<T: 'static> = RemoteHandle<T>;
+    ///
+    ///     fn spawn_local<Fut>(&self, future: Fut) -> Self::JoinHandle<Fut::Output>
+    ///     where
+    ///         Fut: Future + 'static,
+    ///         Fut::Output: 'static,
+    ///     {
+    ///         self.0.spawn_local_with_handle(future).unwrap()
+    ///     }
+    /// }
+    ///
+    /// let mut pool = LocalPool::new();
+    /// let spawner = Local(pool.spawner());
+    /// pool.run_until(async {
+    ///     let factor = Rc::new(2);
+    ///     let mut v: Vec<_> = vec![1, 2, 3]
+    ///         .into_co_stream()
+    ///         .map(move |n| {
+    ///             let factor = factor.clone();
+    ///             async move { n * *factor }
+    ///         })
+    ///         .spawned_on_local(spawner)
+    ///         .collect()
+    ///         .await;
+    ///     v.sort();
+    ///     assert_eq!(v, &[2, 4, 6]);
+    /// });
+    /// ```
+    fn spawned_on_local<Sp>(self, spawner: Sp) -> SpawnedOnLocal<Self, Sp>
+    where
+        Self: Sized,
+        Self::Future: 'static,
+        Self::Item: 'static,
+        Sp: LocalSpawner + Clone,
+    {
+        SpawnedOnLocal::new_local(self, spawner)
+    }
+
     /// Iterate over each item concurrently
+    ///
+    /// The closure is called on the task driving the stream as soon as an
+    /// item has been computed, so it may capture mutable state.
     async fn for_each<F, Fut>(self, f: F)
     where
         Self: Sized,
-        F: Fn(Self::Item) -> Fut,
-        F: Clone,
+        F: FnMut(Self::Item) -> Fut,
         Fut: Future<Output = ()>,
     {
         let limit = self.concurrency_limit();
@@ -167,18 +658,175 @@ pub trait ConcurrentStream {
 
     /// Iterate over each item concurrently, short-circuit on error.
     ///
-    /// If an error is returned this will cancel all other futures.
-    async fn try_for_each<F, Fut, E>(self, f: F) -> Result<(), E>
+    /// If an error is returned this will cancel all other futures. The
+    /// closure may return a `Result`, an `Option` or a `ControlFlow`, which
+    /// short-circuit on `Err`, `None` and `Break` respectively.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::ops::ControlFlow;
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let res = vec![1, 2, 3]
+    ///     .into_co_stream()
+    ///     .try_for_each(|n| async move {
+    ///         match n {
+    ///             2 => ControlFlow::Break(n),
+    ///             _ => ControlFlow::Continue(()),
+    ///         }
+    ///     })
+    ///     .await;
+    /// assert_eq!(res, ControlFlow::Break(2));
+    /// # });
+    /// ```
+    async fn try_for_each<F, Fut, B>(self, f: F) -> B
     where
         Self: Sized,
-        F: Fn(Self::Item) -> Fut,
-        F: Clone,
-        Fut: Future<Output = Result<(), E>>,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = B>,
+        B: Try<Output = ()>,
     {
         let limit = self.concurrency_limit();
         self.drive(TryForEachConsumer::new(limit, f)).await
     }
 
+    /// Tests if any item matches a predicate.
+    ///
+    /// The predicate is evaluated concurrently for every item. As soon as it
+    /// returns `true` for an item, all other in-flight futures are cancelled
+    /// and no more items are taken from the stream.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let found = vec![1, 2, 3]
+    ///     .into_co_stream()
+    ///     .any(|n| async move { n == 2 })
+    ///     .await;
+    /// assert!(found);
+    /// # });
+    /// ```
+    async fn any<F, Fut>(self, mut f: F) -> bool
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        let limit = self.concurrency_limit();
+        let consumer = TryForEachConsumer::new(limit, move |item| {
+            let fut = f(item);
+            async move {
+                match fut.await {
+                    true => ControlFlow::Break(()),
+                    false => ControlFlow::Continue(()),
+                }
+            }
+        });
+        self.drive(consumer).await.is_break()
+    }
+
+    /// Tests if every item matches a predicate.
+    ///
+    /// The predicate is evaluated concurrently for every item. As soon as it
+    /// returns `false` for an item, all other in-flight futures are cancelled
+    /// and no more items are taken from the stream.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let all_even = vec![2, 4, 6]
+    ///     .into_co_stream()
+    ///     .all(|n| async move { n % 2 == 0 })
+    ///     .await;
+    /// assert!(all_even);
+    /// # });
+    /// ```
+    async fn all<F, Fut>(self, mut f: F) -> bool
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        let limit = self.concurrency_limit();
+        let consumer = TryForEachConsumer::new(limit, move |item| {
+            let fut = f(item);
+            async move {
+                match fut.await {
+                    true => ControlFlow::Continue(()),
+                    false => ControlFlow::Break(()),
+                }
+            }
+        });
+        self.drive(consumer).await.is_continue()
+    }
+
+    /// Drive the stream to completion, returning the number of items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let count = vec![1, 2, 3].into_co_stream().count().await;
+    /// assert_eq!(count, 3);
+    /// # });
+    /// ```
+    async fn count(self) -> usize
+    where
+        Self: Sized,
+    {
+        let mut count = 0;
+        self.for_each(|_| {
+            count += 1;
+            core::future::ready(())
+        })
+        .await;
+        count
+    }
+
+    /// Convert this into a [`Stream`][futures_core::Stream] which yields items
+    /// as they complete.
+    ///
+    /// Items are still computed concurrently, up to the concurrency limit.
+    /// This makes it possible to use the output of a concurrent stream with
+    /// combinators which operate on regular streams.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::StreamExt;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let mut v: Vec<_> = vec![1, 2, 3]
+    ///     .into_co_stream()
+    ///     .map(|n| async move { n * 2 })
+    ///     .into_stream()
+    ///     .collect()
+    ///     .await;
+    /// v.sort();
+    /// assert_eq!(v, &[2, 4, 6]);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    fn into_stream(self) -> IntoStream<impl Future<Output = ()>, Self::Item>
+    where
+        Self: Sized,
+    {
+        let buffer = Arc::new(Mutex::new(VecDeque::new()));
+        let consumer = IntoStreamConsumer::new(self.concurrency_limit(), buffer.clone());
+        IntoStream::new(async move { self.drive(consumer).await }, buffer)
+    }
+
     /// Transforms an iterator into a collection.
     async fn collect<B>(self) -> B
     where
@@ -187,6 +835,39 @@ pub trait ConcurrentStream {
     {
         B::from_concurrent_stream(self).await
     }
+
+    /// Transforms a stream of fallible items into a collection, short-circuit
+    /// on error.
+    ///
+    /// Every item must implement [`Try`], and the collection is wrapped in a
+    /// type with the same residual. This means a stream of `Result<T, E>` can
+    /// be collected into a `Result<Vec<T>, E>`, but user-defined types which
+    /// implement [`Try`] are supported too. As soon as an item short-circuits,
+    /// all other futures are cancelled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let res: Option<Vec<u8>> = vec![1, 2, 3]
+    ///     .into_co_stream()
+    ///     .map(|n| async move { (n != 2).then_some(n) })
+    ///     .try_collect()
+    ///     .await;
+    /// assert_eq!(res, None);
+    /// # });
+    /// ```
+    async fn try_collect<B>(self) -> B
+    where
+        Self: Sized,
+        Self::Item: Try,
+        B: Try<Residual = <Self::Item as Try>::Residual>,
+        B::Output: Default + Extend<<Self::Item as Try>::Output>,
+    {
+        from_concurrent_stream::try_extend(self).await
+    }
 }
 
 /// The state of the consumer, used to communicate back to the source.
@@ -223,6 +904,68 @@ mod test {
         });
     }
 
+    #[test]
+    fn any() {
+        futures_lite::future::block_on(async {
+            let s = stream::iter([1, 2, 3]).co();
+            assert!(s.any(|n| async move { n == 3 }).await);
+            let s = stream::iter([1, 2, 3]).co();
+            assert!(!s.any(|n| async move { n == 4 }).await);
+        });
+    }
+
+    #[test]
+    fn any_short_circuits() {
+        futures_lite::future::block_on(async {
+            // The stream never ends, and one of the futures never resolves.
+            let found = stream::iter(0..)
+                .co()
+                .any(|n| async move {
+                    if n == 0 {
+                        futures_lite::future::pending::<()>().await;
+                    }
+                    n == 2
+                })
+                .await;
+            assert!(found);
+        });
+    }
+
+    #[test]
+    fn all() {
+        futures_lite::future::block_on(async {
+            let s = stream::iter([1, 2, 3]).co();
+            assert!(s.all(|n| async move { n < 4 }).await);
+            let s = stream::iter([1, 2, 3]).co();
+            assert!(!s.all(|n| async move { n < 3 }).await);
+        });
+    }
+
+    #[test]
+    fn all_short_circuits() {
+        futures_lite::future::block_on(async {
+            let all = stream::repeat(1)
+                .co()
+                .limit(NonZeroUsize::new(1))
+                .all(|n| async move { n == 2 })
+                .await;
+            assert!(!all);
+        });
+    }
+
+    #[test]
+    fn count() {
+        futures_lite::future::block_on(async {
+            let count = stream::repeat(1)
+                .take(5)
+                .co()
+                .limit(NonZeroUsize::new(2))
+                .count()
+                .await;
+            assert_eq!(count, 5);
+        });
+    }
+
     #[test]
     fn for_each() {
         futures_lite::future::block_on(async {
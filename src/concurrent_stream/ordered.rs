@@ -0,0 +1,249 @@
+use pin_project::pin_project;
+
+use super::enumerate::EnumerateFuture;
+use super::race_progress::next_or_progress;
+use super::{ConcurrentStream, Consumer, ConsumerState};
+
+use crate::future::FutureGroup;
+use alloc::collections::BTreeMap;
+use core::future::{ready, Future, Ready};
+use core::num::NonZeroUsize;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use futures_lite::StreamExt;
+
+/// A concurrent iterator that yields items in the order of the underlying
+/// stream, rather than in the order in which they complete.
+///
+/// This `struct` is created by the [`ordered`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`ordered`]: ConcurrentStream::ordered
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct Ordered<CS: ConcurrentStream> {
+    inner: CS,
+}
+
+impl<CS: ConcurrentStream> Ordered<CS> {
+    pub(crate) fn new(inner: CS) -> Self {
+        Self { inner }
+    }
+}
+
+impl<CS: ConcurrentStream> ConcurrentStream for Ordered<CS> {
+    type Item = CS::Item;
+    type Future = Ready<Self::Item>;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        let limit = match self.inner.concurrency_limit() {
+            Some(n) => n.get(),
+            None => usize::MAX,
+        };
+        self.inner
+            .drive(OrderedConsumer {
+                inner: consumer,
+                group: FutureGroup::new(),
+                limit,
+                buffer: Buffer {
+                    items: BTreeMap::new(),
+                    count: 0,
+                    next: 0,
+                },
+            })
+            .await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.inner.concurrency_limit()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Items which completed before the items preceding them.
+struct Buffer<T> {
+    items: BTreeMap<usize, T>,
+    // The index of the next future to be submitted.
+    count: usize,
+    // The index of the next item to be yielded.
+    next: usize,
+}
+
+impl<T> Buffer<T> {
+    /// Insert an item, and forward all items which are now in order to the consumer.
+    ///
+    /// The consumer may itself process items concurrently, which would undo
+    /// the ordering. So every item is driven through the consumer before the
+    /// next one is sent.
+    async fn insert<C>(&mut self, mut consumer: Pin<&mut C>, index: usize, item: T) -> ConsumerState
+    where
+        C: Consumer<T, Ready<T>>,
+    {
+        self.items.insert(index, item);
+        while let Some(item) = self.items.remove(&self.next) {
+            self.next += 1;
+            if let ConsumerState::Break = consumer.as_mut().send(ready(item)).await {
+                return ConsumerState::Break;
+            }
+            loop {
+                match consumer.as_mut().progress().await {
+                    ConsumerState::Break => return ConsumerState::Break,
+                    ConsumerState::Continue => continue,
+                    ConsumerState::Empty => break,
+                }
+            }
+        }
+        ConsumerState::Continue
+    }
+}
+
+#[pin_project]
+struct OrderedConsumer<C, FutT: Future> {
+    #[pin]
+    inner: C,
+    #[pin]
+    group: FutureGroup<EnumerateFuture<FutT, FutT::Output>>,
+    limit: usize,
+    buffer: Buffer<FutT::Output>,
+}
+
+impl<C, FutT> OrderedConsumer<C, FutT>
+where
+    FutT: Future,
+    C: Consumer<FutT::Output, Ready<FutT::Output>>,
+{
+    /// Forward items from the in-flight futures to the inner consumer, until
+    /// either there are no more in-flight futures or the inner consumer is
+    /// done.
+    async fn drain(self: Pin<&mut Self>) -> ConsumerState {
+        let this = self.project();
+        let mut group = this.group;
+        let mut inner = this.inner;
+        loop {
+            let (index, item) = match next_or_progress(inner.as_mut(), group.as_mut()).await {
+                ControlFlow::Continue(item) => item,
+                ControlFlow::Break(state) => return state,
+            };
+
+            if let ConsumerState::Break = this.buffer.insert(inner.as_mut(), index, item).await {
+                return ConsumerState::Break;
+            }
+        }
+    }
+}
+
+impl<C, FutT> Consumer<FutT::Output, FutT> for OrderedConsumer<C, FutT>
+where
+    FutT: Future,
+    C: Consumer<FutT::Output, Ready<FutT::Output>>,
+{
+    type Output = C::Output;
+
+    async fn send(self: Pin<&mut Self>, future: FutT) -> ConsumerState {
+        let mut this = self.project();
+        // If we have no space, we're going to provide backpressure until we have space
+        while this.group.len() >= *this.limit {
+            let Some((index, item)) = this.group.next().await else {
+                break;
+            };
+            if let ConsumerState::Break = this.buffer.insert(this.inner.as_mut(), index, item).await
+            {
+                return ConsumerState::Break;
+            }
+        }
+
+        let index = this.buffer.count;
+        this.buffer.count += 1;
+        this.group
+            .as_mut()
+            .insert_pinned(EnumerateFuture::new(future, index));
+        ConsumerState::Continue
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> ConsumerState {
+        self.drain().await
+    }
+
+    async fn flush(mut self: Pin<&mut Self>) -> Self::Output {
+        // We will no longer receive any additional futures; wait until all
+        // the in-flight futures have resolved.
+        self.as_mut().drain().await;
+        self.project().inner.flush().await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use core::num::NonZeroUsize;
+    use core::time::Duration;
+    use futures_lite::stream;
+
+    #[test]
+    fn ordered() {
+        async_io::block_on(async {
+            let v: Vec<_> = stream::iter([3, 1, 2])
+                .co()
+                .map(|n| async move {
+                    // Complete the items in reverse order of their size
+                    async_io::Timer::after(Duration::from_millis(n * 10)).await;
+                    n
+                })
+                .ordered()
+                .collect()
+                .await;
+            assert_eq!(v, &[3, 1, 2]);
+        });
+    }
+
+    #[test]
+    fn ordered_many() {
+        async_io::block_on(async {
+            let run = |limit| async move {
+                stream::iter(0..60)
+                    .co()
+                    .limit(NonZeroUsize::new(limit))
+                    .map(|n| async move {
+                        // Mix short and long delays, so items complete out of order
+                        async_io::Timer::after(Duration::from_millis(n * 7 % 13)).await;
+                        n
+                    })
+                    .ordered()
+                    .collect::<Vec<_>>()
+                    .await
+            };
+            let expected: Vec<_> = (0..60).collect();
+            assert_eq!(run(0).await, expected);
+            assert_eq!(run(4).await, expected);
+        });
+    }
+
+    #[test]
+    fn ordered_for_each() {
+        async_io::block_on(async {
+            let mut out = Vec::new();
+            stream::iter(0..30)
+                .co()
+                .map(|n| async move {
+                    async_io::Timer::after(Duration::from_millis(n * 7 % 13)).await;
+                    n
+                })
+                .ordered()
+                .for_each(|n| {
+                    out.push(n);
+                    async move {
+                        // Later items finish their body sooner
+                        async_io::Timer::after(Duration::from_millis(30 - n)).await;
+                    }
+                })
+                .await;
+            assert_eq!(out, (0..30).collect::<Vec<_>>());
+        });
+    }
+}
@@ -0,0 +1,74 @@
+use super::{Consumer, ConsumerState};
+use crate::future::Race;
+
+use core::future::Future;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use futures_core::Stream;
+use futures_lite::StreamExt;
+
+/// Wait for a future to resolve, while driving the consumer forward.
+///
+/// Returns the output of the future, or the state of the consumer if it
+/// breaks off or runs out of work first. In that case the future is left
+/// where it was, so the caller can keep waiting on it.
+pub(crate) async fn race_progress<C, Item, Fut, F>(
+    mut consumer: Pin<&mut C>,
+    mut future: Pin<&mut F>,
+) -> Result<F::Output, ConsumerState>
+where
+    C: Consumer<Item, Fut>,
+    Fut: Future<Output = Item>,
+    F: Future,
+{
+    loop {
+        let progress = async {
+            let state = consumer.as_mut().progress().await;
+            State::Progress(state)
+        };
+        let ready = async {
+            let output = future.as_mut().await;
+            State::Ready(output)
+        };
+        match (progress, ready).race().await {
+            State::Progress(ConsumerState::Continue) => continue,
+            State::Progress(state) => return Err(state),
+            State::Ready(output) => return Ok(output),
+        }
+    }
+}
+
+/// Wait for the next item from the in-flight futures or streams, while
+/// driving the consumer forward.
+///
+/// Breaks with the state to return from `progress` once the consumer breaks
+/// off, or once there are no more items and the consumer has caught up.
+pub(crate) async fn next_or_progress<C, Item, Fut, S>(
+    mut consumer: Pin<&mut C>,
+    mut source: Pin<&mut S>,
+) -> ControlFlow<ConsumerState, S::Item>
+where
+    C: Consumer<Item, Fut>,
+    Fut: Future<Output = Item>,
+    S: Stream + ?Sized,
+{
+    let mut next = core::pin::pin!(source.next());
+    let item = match race_progress(consumer.as_mut(), next.as_mut()).await {
+        Ok(item) => item,
+        // The consumer is idle, so we only need to wait for the source.
+        Err(ConsumerState::Empty) => match next.await {
+            Some(item) => Some(item),
+            None => return ControlFlow::Break(ConsumerState::Empty),
+        },
+        Err(state) => return ControlFlow::Break(state),
+    };
+    match item {
+        Some(item) => ControlFlow::Continue(item),
+        None => ControlFlow::Break(consumer.progress().await),
+    }
+}
+
+enum State<T> {
+    Progress(ConsumerState),
+    Ready(T),
+}
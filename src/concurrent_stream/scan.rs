@@ -0,0 +1,203 @@
+use pin_project::pin_project;
+
+use super::race_progress::next_or_progress;
+use super::{ConcurrentStream, Consumer, ConsumerState};
+
+use crate::future::FutureGroup;
+use core::future::{ready, Future, Ready};
+use core::num::NonZeroUsize;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use futures_lite::StreamExt;
+
+/// A concurrent iterator that threads state through its items.
+///
+/// This `struct` is created by the [`scan`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`scan`]: ConcurrentStream::scan
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct Scan<CS: ConcurrentStream, St, F> {
+    inner: CS,
+    state: St,
+    f: F,
+}
+
+impl<CS: ConcurrentStream, St, F> Scan<CS, St, F> {
+    pub(crate) fn new(inner: CS, state: St, f: F) -> Self {
+        Self { inner, state, f }
+    }
+}
+
+impl<CS, St, F, B> ConcurrentStream for Scan<CS, St, F>
+where
+    CS: ConcurrentStream,
+    F: FnMut(&mut St, CS::Item) -> Option<B>,
+{
+    type Item = B;
+    type Future = Ready<Self::Item>;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        let limit = match self.inner.concurrency_limit() {
+            Some(n) => n.get(),
+            None => usize::MAX,
+        };
+        self.inner
+            .drive(ScanConsumer {
+                inner: consumer,
+                group: FutureGroup::new(),
+                limit,
+                state: self.state,
+                f: self.f,
+                done: false,
+            })
+            .await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.inner.concurrency_limit()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+#[pin_project]
+struct ScanConsumer<C, FutT, St, F> {
+    #[pin]
+    inner: C,
+    #[pin]
+    group: FutureGroup<FutT>,
+    limit: usize,
+    state: St,
+    f: F,
+    // Set once the closure has returned `None`.
+    done: bool,
+}
+
+impl<C, FutT, St, F, B> ScanConsumer<C, FutT, St, F>
+where
+    FutT: Future,
+    F: FnMut(&mut St, FutT::Output) -> Option<B>,
+    C: Consumer<B, Ready<B>>,
+{
+    /// Forward items from the in-flight futures to the inner consumer, until
+    /// either there are no more in-flight futures, the closure returns `None`,
+    /// or the inner consumer is done.
+    async fn drain(self: Pin<&mut Self>) -> ConsumerState {
+        let this = self.project();
+        let mut group = this.group;
+        let mut inner = this.inner;
+        loop {
+            let item = match next_or_progress(inner.as_mut(), group.as_mut()).await {
+                ControlFlow::Continue(item) => item,
+                ControlFlow::Break(state) => return state,
+            };
+
+            match (this.f)(this.state, item) {
+                Some(item) => {
+                    if let ConsumerState::Break = inner.as_mut().send(ready(item)).await {
+                        return ConsumerState::Break;
+                    }
+                }
+                None => {
+                    *this.done = true;
+                    return ConsumerState::Break;
+                }
+            }
+        }
+    }
+}
+
+impl<C, FutT, St, F, B> Consumer<FutT::Output, FutT> for ScanConsumer<C, FutT, St, F>
+where
+    FutT: Future,
+    F: FnMut(&mut St, FutT::Output) -> Option<B>,
+    C: Consumer<B, Ready<B>>,
+{
+    type Output = C::Output;
+
+    async fn send(self: Pin<&mut Self>, future: FutT) -> ConsumerState {
+        let mut this = self.project();
+        // If we have no space, we're going to provide backpressure until we have space
+        while this.group.len() >= *this.limit {
+            let Some(item) = this.group.next().await else {
+                break;
+            };
+            match (this.f)(this.state, item) {
+                Some(item) => {
+                    if let ConsumerState::Break = this.inner.as_mut().send(ready(item)).await {
+                        return ConsumerState::Break;
+                    }
+                }
+                None => {
+                    *this.done = true;
+                    return ConsumerState::Break;
+                }
+            }
+        }
+
+        this.group.as_mut().insert_pinned(future);
+        ConsumerState::Continue
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> ConsumerState {
+        if self.done {
+            return ConsumerState::Break;
+        }
+        self.drain().await
+    }
+
+    async fn flush(mut self: Pin<&mut Self>) -> Self::Output {
+        // We will no longer receive any additional futures; wait until all
+        // the in-flight futures have resolved, unless the scan has ended.
+        if !self.done {
+            self.as_mut().drain().await;
+        }
+        self.project().inner.flush().await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use core::num::NonZeroUsize;
+    use futures_lite::stream;
+
+    #[test]
+    fn running_total() {
+        futures_lite::future::block_on(async {
+            let mut v: Vec<_> = stream::iter([1, 2, 3, 4])
+                .co()
+                .scan(0, |total, n| {
+                    *total += n;
+                    Some(*total)
+                })
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v.last(), Some(&10));
+        });
+    }
+
+    #[test]
+    fn ends_on_none() {
+        futures_lite::future::block_on(async {
+            let v: Vec<_> = stream::iter(0..)
+                .co()
+                .limit(NonZeroUsize::new(1))
+                .scan(0, |count, n| {
+                    *count += 1;
+                    (*count <= 3).then_some(n)
+                })
+                .collect()
+                .await;
+            assert_eq!(v, &[0, 1, 2]);
+        });
+    }
+}
@@ -0,0 +1,402 @@
+use pin_project::pin_project;
+
+use super::{ConcurrentStream, Consumer};
+use core::fmt;
+use core::future::Future;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// A type which can spawn futures as tasks on an executor.
+///
+/// This makes it possible to run the items of a [`ConcurrentStream`] as
+/// separate tasks, rather than within the task which drives the stream. See
+/// [`ConcurrentStream::spawned_on`] for more.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::concurrent_stream::Spawner;
+/// use std::future::Future;
+///
+/// #[derive(Clone)]
+/// struct AsyncStd;
+///
+/// impl Spawner for AsyncStd {
+///     type JoinHandle<T: Send + 'static> = async_std::task::JoinHandle<T>;
+///
+///     fn spawn<Fut>(&self, future: Fut) -> Self::JoinHandle<Fut::Output>
+///     where
+///         Fut: Future + Send + 'static,
+///         Fut::Output: Send + 'static,
+///     {
+///         async_std::task::spawn(future)
+///     }
+/// }
+/// ```
+pub trait Spawner {
+    /// A handle which resolves to the output of a spawned task.
+    type JoinHandle<T: Send + 'static>: Future<Output = T>;
+
+    /// Spawn a future as a new task.
+    fn spawn<Fut>(&self, future: Fut) -> Self::JoinHandle<Fut::Output>
+    where
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static;
+}
+
+/// A type which can spawn futures which aren't `Send` as tasks on the current
+/// thread.
+///
+/// This is the single-threaded counterpart to [`Spawner`], for executors which
+/// provide a `spawn_local` function. See [`ConcurrentStream::spawned_on_local`]
+/// for more.
+///
+/// # Example
+///
+/// ```rust
+/// use futures::executor;
+/// use futures::future::RemoteHandle;
+/// use futures::task::LocalSpawnExt;
+/// use futures_concurrency::concurrent_stream::LocalSpawner;
+/// use std::future::Future;
+///
+/// #[derive(Clone)]
+/// struct LocalPool(executor::LocalSpawner);
+///
+/// impl LocalSpawner for LocalPool {
+///     type JoinHandle<T: 'static> = RemoteHandle<T>;
+///
+///     fn spawn_local<Fut>(&self, future: Fut) -> Self::JoinHandle<Fut::Output>
+///     where
+///         Fut: Future + 'static,
+///         Fut::Output: 'static,
+///     {
+///         self.0.spawn_local_with_handle(future).unwrap()
+///     }
+/// }
+/// ```
+pub trait LocalSpawner {
+    /// A handle which resolves to the output of a spawned task.
+    type JoinHandle<T: 'static>: Future<Output = T>;
+
+    /// Spawn a future as a new task on the current thread.
+    fn spawn_local<Fut>(&self, future: Fut) -> Self::JoinHandle<Fut::Output>
+    where
+        Fut: Future + 'static,
+        Fut::Output: 'static;
+}
+
+/// Spawns futures of type `Fut` as tasks.
+///
+/// This trait is sealed: it's implemented for every [`Spawner`], and for
+/// every [`LocalSpawner`] wrapped in a [`SpawnLocal`].
+pub trait SpawnTask<Fut: Future>: private::Sealed {
+    /// A handle which resolves to the output of a spawned task.
+    type Handle: Future<Output = Fut::Output>;
+
+    /// Spawn a future as a new task.
+    fn spawn_task(&self, future: Fut) -> Self::Handle;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+impl<Sp: Spawner> private::Sealed for Sp {}
+
+impl<Sp, Fut> SpawnTask<Fut> for Sp
+where
+    Sp: Spawner,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    type Handle = Sp::JoinHandle<Fut::Output>;
+
+    fn spawn_task(&self, future: Fut) -> Self::Handle {
+        self.spawn(future)
+    }
+}
+
+/// Spawns tasks on a [`LocalSpawner`].
+///
+/// This `struct` is created by the [`spawned_on_local`] method on
+/// [`ConcurrentStream`]. See its documentation for more.
+///
+/// [`spawned_on_local`]: ConcurrentStream::spawned_on_local
+#[derive(Debug, Clone)]
+pub struct SpawnLocal<Sp>(Sp);
+
+impl<Sp: LocalSpawner> private::Sealed for SpawnLocal<Sp> {}
+
+impl<Sp, Fut> SpawnTask<Fut> for SpawnLocal<Sp>
+where
+    Sp: LocalSpawner,
+    Fut: Future + 'static,
+    Fut::Output: 'static,
+{
+    type Handle = Sp::JoinHandle<Fut::Output>;
+
+    fn spawn_task(&self, future: Fut) -> Self::Handle {
+        self.0.spawn_local(future)
+    }
+}
+
+/// A concurrent iterator that runs each item as a separate task.
+///
+/// This `struct` is created by the [`spawned_on`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`spawned_on`]: ConcurrentStream::spawned_on
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct SpawnedOn<CS: ConcurrentStream, Sp> {
+    inner: CS,
+    spawner: Sp,
+}
+
+impl<CS: ConcurrentStream, Sp> SpawnedOn<CS, Sp> {
+    pub(crate) fn new(inner: CS, spawner: Sp) -> Self {
+        Self { inner, spawner }
+    }
+}
+
+/// A concurrent iterator that runs each item as a separate task on the
+/// current thread.
+///
+/// This type is created by the [`spawned_on_local`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`spawned_on_local`]: ConcurrentStream::spawned_on_local
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+pub type SpawnedOnLocal<CS, Sp> = SpawnedOn<CS, SpawnLocal<Sp>>;
+
+impl<CS: ConcurrentStream, Sp> SpawnedOnLocal<CS, Sp> {
+    pub(crate) fn new_local(inner: CS, spawner: Sp) -> Self {
+        Self::new(inner, SpawnLocal(spawner))
+    }
+}
+
+impl<CS, Sp> ConcurrentStream for SpawnedOn<CS, Sp>
+where
+    CS: ConcurrentStream,
+    Sp: SpawnTask<CS::Future> + Clone,
+{
+    type Item = CS::Item;
+    type Future = SpawnFuture<Sp, CS::Future>;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        self.inner
+            .drive(SpawnConsumer {
+                inner: consumer,
+                spawner: self.spawner,
+            })
+            .await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.inner.concurrency_limit()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[pin_project]
+struct SpawnConsumer<C, Sp> {
+    #[pin]
+    inner: C,
+    spawner: Sp,
+}
+
+impl<C, Sp, Item, Fut> Consumer<Item, Fut> for SpawnConsumer<C, Sp>
+where
+    Fut: Future<Output = Item>,
+    Sp: SpawnTask<Fut> + Clone,
+    C: Consumer<Item, SpawnFuture<Sp, Fut>>,
+{
+    type Output = C::Output;
+
+    async fn send(self: Pin<&mut Self>, future: Fut) -> super::ConsumerState {
+        let this = self.project();
+        let fut = SpawnFuture::new(this.spawner.clone(), future);
+        this.inner.send(fut).await
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> super::ConsumerState {
+        let this = self.project();
+        this.inner.progress().await
+    }
+
+    async fn flush(self: Pin<&mut Self>) -> Self::Output {
+        let this = self.project();
+        this.inner.flush().await
+    }
+}
+
+/// Spawns a future as a task the first time it's polled, and waits for the
+/// task to complete.
+///
+/// Spawning lazily means tasks are only started once the consumer has room
+/// for them, so the concurrency limit is respected.
+#[pin_project(project = SpawnFutureProj)]
+pub enum SpawnFuture<Sp: SpawnTask<Fut>, Fut: Future> {
+    /// The future hasn't been spawned yet.
+    Pending {
+        /// The spawner to spawn the future on.
+        spawner: Sp,
+        /// The future to spawn.
+        future: Option<Fut>,
+    },
+    /// The future is running as a task.
+    Spawned {
+        /// The handle to the task.
+        #[pin]
+        handle: Sp::Handle,
+    },
+}
+
+/// Spawns a future as a task on the current thread the first time it's
+/// polled, and waits for the task to complete.
+pub type LocalSpawnFuture<Sp, Fut> = SpawnFuture<SpawnLocal<Sp>, Fut>;
+
+impl<Sp: SpawnTask<Fut>, Fut: Future> SpawnFuture<Sp, Fut> {
+    fn new(spawner: Sp, future: Fut) -> Self {
+        Self::Pending {
+            spawner,
+            future: Some(future),
+        }
+    }
+}
+
+impl<Sp: SpawnTask<Fut>, Fut: Future> fmt::Debug for SpawnFuture<Sp, Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending { .. } => f.debug_struct("Pending").finish_non_exhaustive(),
+            Self::Spawned { .. } => f.debug_struct("Spawned").finish_non_exhaustive(),
+        }
+    }
+}
+
+impl<Sp: SpawnTask<Fut>, Fut: Future> Future for SpawnFuture<Sp, Fut> {
+    type Output = Fut::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            match self.as_mut().project() {
+                SpawnFutureProj::Pending { spawner, future } => {
+                    let future = future
+                        .take()
+                        .expect("future has already been polled to completion once");
+                    let handle = spawner.spawn_task(future);
+                    self.set(SpawnFuture::Spawned { handle });
+                }
+                SpawnFutureProj::Spawned { handle } => return handle.poll(cx),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use futures::executor::LocalPool;
+    use futures::future::RemoteHandle;
+    use futures::task::LocalSpawnExt;
+    use futures_lite::stream;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    struct AsyncStd;
+
+    impl Spawner for AsyncStd {
+        type JoinHandle<T: Send + 'static> = async_std::task::JoinHandle<T>;
+
+        fn spawn<Fut>(&self, future: Fut) -> Self::JoinHandle<Fut::Output>
+        where
+            Fut: Future + Send + 'static,
+            Fut::Output: Send + 'static,
+        {
+            async_std::task::spawn(future)
+        }
+    }
+
+    #[test]
+    fn spawned_on() {
+        async_std::task::block_on(async {
+            let mut v: Vec<_> = stream::iter([1, 2, 3])
+                .co()
+                .map(|n| async move { n * 2 })
+                .spawned_on(AsyncStd)
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &[2, 4, 6]);
+        });
+    }
+
+    #[test]
+    fn respects_limit() {
+        async_std::task::block_on(async {
+            let in_flight = Arc::new(AtomicUsize::new(0));
+            stream::iter(0..10)
+                .co()
+                .limit(NonZeroUsize::new(2))
+                .map(move |_| {
+                    let in_flight = in_flight.clone();
+                    async move {
+                        let count = in_flight.fetch_add(1, Ordering::SeqCst);
+                        assert!(count < 2);
+                        async_std::task::yield_now().await;
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                    }
+                })
+                .spawned_on(AsyncStd)
+                .for_each(|_| async {})
+                .await;
+        });
+    }
+
+    #[derive(Clone)]
+    struct Local(futures::executor::LocalSpawner);
+
+    impl LocalSpawner for Local {
+        type JoinHandle<T: 'static> = RemoteHandle<T>;
+
+        fn spawn_local<Fut>(&self, future: Fut) -> Self::JoinHandle<Fut::Output>
+        where
+            Fut: Future + 'static,
+            Fut::Output: 'static,
+        {
+            self.0.spawn_local_with_handle(future).unwrap()
+        }
+    }
+
+    #[test]
+    fn spawned_on_local() {
+        let mut pool = LocalPool::new();
+        let spawner = Local(pool.spawner());
+        pool.run_until(async {
+            // `Rc` isn't `Send`, so this can't be spawned on a `Spawner`.
+            let factor = Rc::new(2);
+            let mut v: Vec<_> = stream::iter([1, 2, 3])
+                .co()
+                .map(move |n| {
+                    let factor = factor.clone();
+                    async move { n * *factor }
+                })
+                .spawned_on_local(spawner)
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &[2, 4, 6]);
+        });
+    }
+}
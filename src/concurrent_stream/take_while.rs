@@ -0,0 +1,276 @@
+use pin_project::pin_project;
+
+use super::race_progress::next_or_progress;
+use super::{ConcurrentStream, Consumer, ConsumerState};
+
+use crate::future::FutureGroup;
+use core::future::{ready, Future, Ready};
+use core::num::NonZeroUsize;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_lite::StreamExt;
+
+/// A concurrent iterator that only yields elements while a predicate holds.
+///
+/// This `struct` is created by the [`take_while`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`take_while`]: ConcurrentStream::take_while
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct TakeWhile<CS: ConcurrentStream, F> {
+    inner: CS,
+    f: F,
+}
+
+impl<CS: ConcurrentStream, F> TakeWhile<CS, F> {
+    pub(crate) fn new(inner: CS, f: F) -> Self {
+        Self { inner, f }
+    }
+}
+
+impl<CS, F, FutP> ConcurrentStream for TakeWhile<CS, F>
+where
+    CS: ConcurrentStream,
+    F: Fn(&CS::Item) -> FutP,
+    F: Clone,
+    FutP: Future<Output = bool>,
+{
+    type Item = CS::Item;
+    type Future = Ready<Self::Item>;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        let limit = match self.inner.concurrency_limit() {
+            Some(n) => n.get(),
+            None => usize::MAX,
+        };
+        self.inner
+            .drive(TakeWhileConsumer {
+                inner: consumer,
+                group: FutureGroup::new(),
+                limit,
+                f: self.f,
+                done: false,
+            })
+            .await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.inner.concurrency_limit()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+#[pin_project]
+struct TakeWhileConsumer<C, F, FutT, FutP>
+where
+    FutT: Future,
+{
+    #[pin]
+    inner: C,
+    // The futures which are currently in-flight. They resolve to `None` if
+    // the predicate didn't hold.
+    #[pin]
+    group: FutureGroup<TakeWhileFuture<FutT, F, FutP>>,
+    limit: usize,
+    f: F,
+    // Set once the predicate has failed for an item.
+    done: bool,
+}
+
+impl<C, F, FutT, FutP> TakeWhileConsumer<C, F, FutT, FutP>
+where
+    FutT: Future,
+    F: Fn(&FutT::Output) -> FutP,
+    FutP: Future<Output = bool>,
+    C: Consumer<FutT::Output, Ready<FutT::Output>>,
+{
+    /// Forward items from the in-flight futures to the inner consumer, until
+    /// either there are no more in-flight futures, the predicate fails, or the
+    /// inner consumer is done.
+    async fn drain(self: Pin<&mut Self>) -> ConsumerState {
+        let this = self.project();
+        let mut group = this.group;
+        let mut inner = this.inner;
+        loop {
+            let item = match next_or_progress(inner.as_mut(), group.as_mut()).await {
+                ControlFlow::Continue(item) => item,
+                ControlFlow::Break(state) => return state,
+            };
+
+            match item {
+                Some(item) => {
+                    if let ConsumerState::Break = inner.as_mut().send(ready(item)).await {
+                        return ConsumerState::Break;
+                    }
+                }
+                None => {
+                    // Stop taking new items, but keep forwarding the items
+                    // which are already in-flight.
+                    *this.done = true;
+                    return ConsumerState::Break;
+                }
+            }
+        }
+    }
+}
+
+impl<C, F, FutT, FutP> Consumer<FutT::Output, FutT> for TakeWhileConsumer<C, F, FutT, FutP>
+where
+    FutT: Future,
+    F: Fn(&FutT::Output) -> FutP,
+    F: Clone,
+    FutP: Future<Output = bool>,
+    C: Consumer<FutT::Output, Ready<FutT::Output>>,
+{
+    type Output = C::Output;
+
+    async fn send(self: Pin<&mut Self>, future: FutT) -> ConsumerState {
+        let mut this = self.project();
+        // If we have no space, we're going to provide backpressure until we have space
+        while this.group.len() >= *this.limit {
+            match this.group.next().await {
+                None => break,
+                Some(Some(item)) => {
+                    if let ConsumerState::Break = this.inner.as_mut().send(ready(item)).await {
+                        return ConsumerState::Break;
+                    }
+                }
+                Some(None) => *this.done = true,
+            }
+        }
+
+        // Don't take any more items once the predicate has failed.
+        if *this.done {
+            return ConsumerState::Break;
+        }
+
+        let fut = TakeWhileFuture::new(future, this.f.clone());
+        this.group.as_mut().insert_pinned(fut);
+        ConsumerState::Continue
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> ConsumerState {
+        if self.done {
+            return ConsumerState::Break;
+        }
+        self.drain().await
+    }
+
+    async fn flush(self: Pin<&mut Self>) -> Self::Output {
+        let mut this = self.project();
+        // We will no longer receive any additional futures; wait until all
+        // the in-flight futures have resolved, and forward their items.
+        while let Some(item) = this.group.next().await {
+            if let Some(item) = item {
+                if let ConsumerState::Break = this.inner.as_mut().send(ready(item)).await {
+                    break;
+                }
+            }
+        }
+        this.inner.flush().await
+    }
+}
+
+/// Takes a future and evaluates a predicate on its output
+#[pin_project]
+struct TakeWhileFuture<FutT: Future, F, FutP> {
+    #[pin]
+    fut_t: FutT,
+    f: F,
+    item: Option<FutT::Output>,
+    #[pin]
+    fut_p: Option<FutP>,
+}
+
+impl<FutT: Future, F, FutP> TakeWhileFuture<FutT, F, FutP> {
+    fn new(fut_t: FutT, f: F) -> Self {
+        Self {
+            fut_t,
+            f,
+            item: None,
+            fut_p: None,
+        }
+    }
+}
+
+impl<FutT, F, FutP> Future for TakeWhileFuture<FutT, F, FutP>
+where
+    FutT: Future,
+    F: Fn(&FutT::Output) -> FutP,
+    FutP: Future<Output = bool>,
+{
+    type Output = Option<FutT::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        // Poll forward the future containing the item
+        if this.fut_p.is_none() {
+            let item = ready!(this.fut_t.poll(cx));
+            this.fut_p.set(Some((this.f)(&item)));
+            *this.item = Some(item);
+        }
+
+        // Poll forward the predicate
+        let fut_p = this.fut_p.as_pin_mut().unwrap();
+        let item = this
+            .item
+            .take()
+            .expect("future has already been polled to completion once");
+        match fut_p.poll(cx) {
+            Poll::Ready(true) => Poll::Ready(Some(item)),
+            Poll::Ready(false) => Poll::Ready(None),
+            Poll::Pending => {
+                *this.item = Some(item);
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use core::num::NonZeroUsize;
+    use futures_lite::stream;
+
+    #[test]
+    fn take_while() {
+        futures_lite::future::block_on(async {
+            let v: Vec<_> = stream::iter(0..)
+                .co()
+                .limit(NonZeroUsize::new(1))
+                .take_while(|n| {
+                    let n = *n;
+                    async move { n < 3 }
+                })
+                .collect()
+                .await;
+            assert_eq!(v, &[0, 1, 2]);
+        });
+    }
+
+    #[test]
+    fn take_while_unlimited() {
+        futures_lite::future::block_on(async {
+            let mut v: Vec<_> = stream::iter(0..)
+                .co()
+                .take_while(|n| {
+                    let n = *n;
+                    async move { n < 3 }
+                })
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &[0, 1, 2]);
+        });
+    }
+}
@@ -0,0 +1,183 @@
+use pin_project::pin_project;
+
+use super::race_progress::race_progress;
+use super::{ConcurrentStream, Consumer, ConsumerState};
+
+use core::future::{poll_fn, Future};
+use core::num::NonZeroUsize;
+use core::pin::{pin, Pin};
+use core::task::Poll;
+
+/// A concurrent iterator which stops taking new items once a deadline
+/// resolves.
+///
+/// This `struct` is created by the [`until`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`until`]: ConcurrentStream::until
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct Until<CS: ConcurrentStream, D> {
+    inner: CS,
+    deadline: D,
+}
+
+impl<CS: ConcurrentStream, D> Until<CS, D> {
+    pub(crate) fn new(inner: CS, deadline: D) -> Self {
+        Self { inner, deadline }
+    }
+}
+
+impl<CS, D> ConcurrentStream for Until<CS, D>
+where
+    CS: ConcurrentStream,
+    D: Future,
+{
+    type Item = CS::Item;
+    type Future = CS::Future;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        self.inner
+            .drive(UntilConsumer {
+                inner: consumer,
+                deadline: self.deadline,
+                stopped: false,
+            })
+            .await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.inner.concurrency_limit()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+#[pin_project]
+struct UntilConsumer<C, D> {
+    #[pin]
+    inner: C,
+    #[pin]
+    deadline: D,
+    stopped: bool,
+}
+
+impl<C, D: Future> UntilConsumer<C, D> {
+    /// Check whether the deadline has resolved, without waiting for it.
+    async fn is_stopped(self: Pin<&mut Self>) -> bool {
+        let mut this = self.project();
+        poll_fn(|cx| {
+            if !*this.stopped && this.deadline.as_mut().poll(cx).is_ready() {
+                *this.stopped = true;
+            }
+            Poll::Ready(*this.stopped)
+        })
+        .await
+    }
+}
+
+impl<C, D, Item, Fut> Consumer<Item, Fut> for UntilConsumer<C, D>
+where
+    Fut: Future<Output = Item>,
+    C: Consumer<Item, Fut>,
+    D: Future,
+{
+    type Output = C::Output;
+
+    async fn send(mut self: Pin<&mut Self>, future: Fut) -> ConsumerState {
+        if self.as_mut().is_stopped().await {
+            return ConsumerState::Break;
+        }
+        self.project().inner.send(future).await
+    }
+
+    async fn progress(mut self: Pin<&mut Self>) -> ConsumerState {
+        if self.as_mut().is_stopped().await {
+            return ConsumerState::Break;
+        }
+
+        // Wait for the deadline alongside the inner consumer, so we stop
+        // while the in-flight futures are still being driven.
+        let this = self.as_mut().project();
+        let mut deadline = this.deadline;
+        let wait = pin!(poll_fn(|cx| deadline.as_mut().poll(cx).map(|_| ())));
+        match race_progress(this.inner, wait).await {
+            // An idle consumer has to say so, or the source would keep
+            // waiting on us rather than on its next item. The deadline is
+            // checked again once the next item is sent.
+            Err(ConsumerState::Empty) => {
+                if self.as_mut().is_stopped().await {
+                    ConsumerState::Break
+                } else {
+                    ConsumerState::Empty
+                }
+            }
+            Err(state) => state,
+            Ok(()) => {
+                *self.project().stopped = true;
+                ConsumerState::Break
+            }
+        }
+    }
+
+    async fn flush(self: Pin<&mut Self>) -> Self::Output {
+        let this = self.project();
+        this.inner.flush().await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::sync::StopSource;
+    use futures_lite::stream;
+
+    #[test]
+    fn returns_partial_results() {
+        futures_lite::future::block_on(async {
+            let source = StopSource::new();
+            let v: Vec<_> = stream::iter(0..)
+                .co()
+                .inspect(|n| {
+                    if *n == 3 {
+                        source.stop();
+                    }
+                })
+                .until(source.token())
+                .collect()
+                .await;
+            assert!(v.len() >= 3, "{v:?}");
+            assert!(v.len() < 10, "{v:?}");
+        });
+    }
+
+    #[test]
+    fn flat_map_without_deadline() {
+        futures_lite::future::block_on(async {
+            let mut v: Vec<_> = stream::iter([1, 2, 3])
+                .co()
+                .flat_map(|n| async move { stream::iter([n, n * 10]) })
+                .until(core::future::pending::<()>())
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &[1, 2, 3, 10, 20, 30]);
+        });
+    }
+
+    #[test]
+    fn stops_pending_stream() {
+        futures_lite::future::block_on(async {
+            let source = StopSource::new();
+            let token = source.token();
+            source.stop();
+            let v: Vec<()> = stream::pending().co().until(token).collect().await;
+            assert!(v.is_empty());
+        });
+    }
+}
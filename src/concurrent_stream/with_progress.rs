@@ -0,0 +1,232 @@
+use pin_project::pin_project;
+
+use super::{ConcurrentStream, Consumer};
+use alloc::sync::Arc;
+use core::future::Future;
+use core::num::NonZeroUsize;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::task::{ready, Context, Poll};
+
+/// A handle which reports the progress of a concurrent stream.
+///
+/// Clones of a `Progress` share the same counters, so one clone can be passed
+/// to [`ConcurrentStream::with_progress`] while another is used to observe the
+/// stream, for example to render a progress bar.
+#[derive(Debug, Clone, Default)]
+pub struct Progress {
+    counters: Arc<Counters>,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    created: AtomicUsize,
+    started: AtomicUsize,
+    completed: AtomicUsize,
+    // The upper bound of the stream's size hint, plus one. Zero means unknown.
+    total: AtomicUsize,
+}
+
+impl Progress {
+    /// Create a new progress handle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of items which have started being computed.
+    pub fn started(&self) -> usize {
+        self.counters.started.load(Ordering::Relaxed)
+    }
+
+    /// The number of items which have finished being computed.
+    pub fn completed(&self) -> usize {
+        self.counters.completed.load(Ordering::Relaxed)
+    }
+
+    /// The number of items which are currently being computed.
+    pub fn in_flight(&self) -> usize {
+        self.started().saturating_sub(self.completed())
+    }
+
+    /// The number of items which have been taken from the stream, but haven't
+    /// started being computed yet.
+    pub fn queued(&self) -> usize {
+        let created = self.counters.created.load(Ordering::Relaxed);
+        created.saturating_sub(self.started())
+    }
+
+    /// The total number of items the stream is expected to yield, if known.
+    ///
+    /// This is the upper bound of the stream's [`size_hint`] at the moment it
+    /// started being driven.
+    ///
+    /// [`size_hint`]: ConcurrentStream::size_hint
+    pub fn total(&self) -> Option<usize> {
+        self.counters.total.load(Ordering::Relaxed).checked_sub(1)
+    }
+}
+
+/// A concurrent iterator that reports its progress to a [`Progress`] handle.
+///
+/// This `struct` is created by the [`with_progress`] method on [`ConcurrentStream`]. See its
+/// documentation for more.
+///
+/// [`with_progress`]: ConcurrentStream::with_progress
+/// [`ConcurrentStream`]: trait.ConcurrentStream.html
+#[derive(Debug)]
+pub struct WithProgress<CS: ConcurrentStream> {
+    inner: CS,
+    progress: Progress,
+}
+
+impl<CS: ConcurrentStream> WithProgress<CS> {
+    pub(crate) fn new(inner: CS, progress: Progress) -> Self {
+        Self { inner, progress }
+    }
+}
+
+impl<CS: ConcurrentStream> ConcurrentStream for WithProgress<CS> {
+    type Item = CS::Item;
+    type Future = ProgressFuture<CS::Future>;
+
+    async fn drive<C>(self, consumer: C) -> C::Output
+    where
+        C: Consumer<Self::Item, Self::Future>,
+    {
+        let total = match self.inner.size_hint().1 {
+            Some(n) => n.saturating_add(1),
+            None => 0,
+        };
+        let counters = self.progress.counters;
+        counters.total.store(total, Ordering::Relaxed);
+        self.inner
+            .drive(ProgressConsumer {
+                inner: consumer,
+                counters,
+            })
+            .await
+    }
+
+    fn concurrency_limit(&self) -> Option<NonZeroUsize> {
+        self.inner.concurrency_limit()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[pin_project]
+struct ProgressConsumer<C> {
+    #[pin]
+    inner: C,
+    counters: Arc<Counters>,
+}
+
+impl<C, Item, Fut> Consumer<Item, Fut> for ProgressConsumer<C>
+where
+    Fut: Future<Output = Item>,
+    C: Consumer<Item, ProgressFuture<Fut>>,
+{
+    type Output = C::Output;
+
+    async fn send(self: Pin<&mut Self>, future: Fut) -> super::ConsumerState {
+        let this = self.project();
+        this.counters.created.fetch_add(1, Ordering::Relaxed);
+        let fut = ProgressFuture::new(future, this.counters.clone());
+        this.inner.send(fut).await
+    }
+
+    async fn progress(self: Pin<&mut Self>) -> super::ConsumerState {
+        let this = self.project();
+        this.inner.progress().await
+    }
+
+    async fn flush(self: Pin<&mut Self>) -> Self::Output {
+        let this = self.project();
+        this.inner.flush().await
+    }
+}
+
+/// Takes a future and records when it starts and completes
+#[pin_project]
+pub struct ProgressFuture<FutT> {
+    started: bool,
+    done: bool,
+    #[pin]
+    fut_t: FutT,
+    counters: Arc<Counters>,
+}
+
+impl<FutT> ProgressFuture<FutT> {
+    fn new(fut_t: FutT, counters: Arc<Counters>) -> Self {
+        Self {
+            started: false,
+            done: false,
+            fut_t,
+            counters,
+        }
+    }
+}
+
+impl<FutT> core::fmt::Debug for ProgressFuture<FutT> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ProgressFuture")
+            .field("started", &self.started)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<FutT: Future> Future for ProgressFuture<FutT> {
+    type Output = FutT::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        if *this.done {
+            panic!("future has already been polled to completion once");
+        }
+
+        if !*this.started {
+            this.counters.started.fetch_add(1, Ordering::Relaxed);
+            *this.started = true;
+        }
+
+        let item = ready!(this.fut_t.poll(cx));
+        this.counters.completed.fetch_add(1, Ordering::Relaxed);
+        *this.done = true;
+        Poll::Ready(item)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn with_progress() {
+        futures_lite::future::block_on(async {
+            let progress = Progress::new();
+            assert_eq!(progress.total(), None);
+
+            let observer = progress.clone();
+            (0..5)
+                .into_co_stream()
+                .limit(NonZeroUsize::new(2))
+                .map(|n| async move { n })
+                .with_progress(progress)
+                .for_each(|_| {
+                    assert!(observer.in_flight() <= 2);
+                    async {}
+                })
+                .await;
+
+            assert_eq!(observer.total(), Some(5));
+            assert_eq!(observer.started(), 5);
+            assert_eq!(observer.completed(), 5);
+            assert_eq!(observer.in_flight(), 0);
+            assert_eq!(observer.queued(), 0);
+        });
+    }
+}
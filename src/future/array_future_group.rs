@@ -0,0 +1,336 @@
+use core::fmt::{self, Debug};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::utils::{Indexer, WakerArray};
+
+/// A fixed-capacity group of futures which act as a single unit.
+///
+/// This is like [`FutureGroup`][super::FutureGroup], but stores up to `N`
+/// futures in-line, so it doesn't need an allocator to hold them. This makes
+/// it usable on targets without an allocator. Because futures are stored
+/// in-line, the group needs to be pinned before futures can be inserted.
+///
+/// With the `alloc` feature on targets with pointer-sized atomics, the group
+/// creates a waker for each of its `N` entries when it's created, which takes
+/// a small allocation per entry. Only the futures which were woken are polled
+/// again. Otherwise the group never allocates, but polls every future
+/// whenever any of them is woken.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::future::ArrayFutureGroup;
+/// use futures_lite::StreamExt;
+/// use std::future;
+/// use std::pin::pin;
+///
+/// # futures_lite::future::block_on(async {
+/// let mut group = pin!(ArrayFutureGroup::<_, 4>::new());
+/// group.as_mut().try_insert(future::ready(2)).unwrap();
+/// group.as_mut().try_insert(future::ready(4)).unwrap();
+///
+/// let mut out = 0;
+/// while let Some(num) = group.next().await {
+///     out += num;
+/// }
+/// assert_eq!(out, 6);
+/// # });
+/// ```
+#[must_use = "`ArrayFutureGroup` does nothing if not iterated over"]
+pub struct ArrayFutureGroup<F, const N: usize> {
+    futures: [Option<F>; N],
+    wakers: WakerArray<N>,
+    indexer: Indexer,
+    len: usize,
+}
+
+impl<F, const N: usize> Debug for ArrayFutureGroup<F, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayFutureGroup")
+            .field("futures", &"[..]")
+            .field("len", &self.len)
+            .field("capacity", &N)
+            .finish()
+    }
+}
+
+impl<F, const N: usize> Default for ArrayFutureGroup<F, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F, const N: usize> ArrayFutureGroup<F, N> {
+    /// Create a new instance of `ArrayFutureGroup`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::ArrayFutureGroup;
+    ///
+    /// let group = ArrayFutureGroup::<_, 4>::new();
+    /// # let group: ArrayFutureGroup<usize, 4> = group;
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            futures: [(); N].map(|_| None),
+            wakers: WakerArray::new(),
+            indexer: Indexer::new(N),
+            len: 0,
+        }
+    }
+
+    /// Return the number of futures currently active in the group.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return the maximum number of futures the group can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Return `true` if there are no futures currently active in the group.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return `true` if no more futures can be inserted into the group.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns `true` if the group contains a value for the specified key.
+    pub fn contains_key(&self, key: Key) -> bool {
+        self.futures.get(key.0).is_some_and(Option::is_some)
+    }
+
+    /// Removes a future from the group. Returns whether the future was part
+    /// of the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::ArrayFutureGroup;
+    /// use std::future;
+    /// use std::pin::pin;
+    ///
+    /// let mut group = pin!(ArrayFutureGroup::<_, 4>::new());
+    /// let key = group.as_mut().try_insert(future::ready(4)).unwrap();
+    /// assert_eq!(group.len(), 1);
+    /// group.as_mut().remove(key);
+    /// assert_eq!(group.len(), 0);
+    /// ```
+    pub fn remove(self: Pin<&mut Self>, key: Key) -> bool {
+        // SAFETY: futures are never moved out of their slots, and assigning
+        // `None` drops the future in place.
+        let this = unsafe { self.get_unchecked_mut() };
+        match this.futures.get_mut(key.0) {
+            Some(slot @ Some(_)) => {
+                *slot = None;
+                this.len -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<F: Future, const N: usize> ArrayFutureGroup<F, N> {
+    /// Insert a new future into the group, or return it in an error if the
+    /// group is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::ArrayFutureGroup;
+    /// use std::future;
+    /// use std::pin::pin;
+    ///
+    /// let mut group = pin!(ArrayFutureGroup::<_, 1>::new());
+    /// assert!(group.as_mut().try_insert(future::ready(1)).is_ok());
+    /// assert!(group.as_mut().try_insert(future::ready(2)).is_err());
+    /// ```
+    pub fn try_insert(self: Pin<&mut Self>, future: F) -> Result<Key, CapacityError<F>> {
+        // SAFETY: inserting a future into a vacant slot doesn't move any of
+        // the other futures.
+        let this = unsafe { self.get_unchecked_mut() };
+        let Some(index) = this.futures.iter().position(Option::is_none) else {
+            return Err(CapacityError::new(future));
+        };
+        this.futures[index] = Some(future);
+        this.len += 1;
+        this.wakers.readiness().set_ready(index);
+        Ok(Key(index))
+    }
+
+    fn poll_next_inner(
+        self: Pin<&mut Self>,
+        cx: &Context<'_>,
+    ) -> Poll<Option<(Key, <F as Future>::Output)>> {
+        // SAFETY: futures are never moved out of their slots, and are only
+        // dropped in place.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // Short-circuit if we have no futures to iterate over
+        if this.len == 0 {
+            return Poll::Ready(None);
+        }
+
+        // Set the top-level waker and check readiness
+        let mut readiness = this.wakers.readiness();
+        readiness.set_waker(cx.waker());
+        if !readiness.any_ready() {
+            // Nothing is ready yet
+            return Poll::Pending;
+        }
+
+        // Start from a different slot every time, so a future which is always
+        // ready can't starve the ones after it.
+        for index in this.indexer.iter() {
+            if this.futures[index].is_none() || !readiness.clear_ready(index) {
+                continue;
+            }
+
+            // release readiness so we can borrow the wakers when polling
+            #[allow(clippy::drop_non_drop)]
+            drop(readiness);
+
+            // Obtain the intermediate waker.
+            let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+
+            // SAFETY: the future is pinned in its slot.
+            let future = unsafe { Pin::new_unchecked(this.futures[index].as_mut().unwrap()) };
+            if let Poll::Ready(item) = future.poll(&mut cx) {
+                this.futures[index] = None;
+                this.len -= 1;
+                return Poll::Ready(Some((Key(index), item)));
+            }
+
+            // Reacquire readiness so we can use it again
+            readiness = this.wakers.readiness();
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<F: Future, const N: usize> Stream for ArrayFutureGroup<F, N> {
+    type Item = <F as Future>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.poll_next_inner(cx) {
+            Poll::Ready(Some((_key, item))) => Poll::Ready(Some(item)),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every future yields exactly one item.
+        (self.len, Some(self.len))
+    }
+}
+
+impl<F: Future, const N: usize> FusedStream for ArrayFutureGroup<F, N> {
+    fn is_terminated(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// A key used to index into the `ArrayFutureGroup` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key(usize);
+
+/// The error returned when inserting into a group which is full.
+///
+/// It contains the value which could not be inserted.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T> {
+    value: T,
+}
+
+impl<T> CapacityError<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    /// Return the value which could not be inserted.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CapacityError").finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "insufficient capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Error for CapacityError<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::ArrayFutureGroup;
+    use core::future;
+    use core::pin::pin;
+    use futures_lite::prelude::*;
+
+    #[test]
+    fn smoke() {
+        futures_lite::future::block_on(async {
+            let mut group = pin!(ArrayFutureGroup::<_, 2>::new());
+            group.as_mut().try_insert(future::ready(2)).unwrap();
+            group.as_mut().try_insert(future::ready(4)).unwrap();
+
+            let mut out = 0;
+            while let Some(num) = group.next().await {
+                out += num;
+            }
+            assert_eq!(out, 6);
+            assert!(group.is_empty());
+        });
+    }
+
+    #[test]
+    fn insert_when_full() {
+        futures_lite::future::block_on(async {
+            let mut group = pin!(ArrayFutureGroup::<_, 1>::new());
+            group.as_mut().try_insert(future::ready(1)).unwrap();
+            assert!(group.is_full());
+            let err = group.as_mut().try_insert(future::ready(2)).unwrap_err();
+            assert_eq!(err.into_inner().await, 2);
+
+            // Slots are reused once a future completes.
+            assert_eq!(group.next().await, Some(1));
+            group.as_mut().try_insert(future::ready(3)).unwrap();
+            assert_eq!(group.next().await, Some(3));
+            assert_eq!(group.next().await, None);
+        });
+    }
+
+    #[test]
+    fn remove() {
+        futures_lite::future::block_on(async {
+            let mut group = pin!(ArrayFutureGroup::<_, 2>::new());
+            let key = group.as_mut().try_insert(future::pending::<u8>()).unwrap();
+            group.as_mut().try_insert(future::pending()).unwrap();
+            assert!(group.as_mut().remove(key));
+            assert!(!group.contains_key(key));
+            assert!(!group.as_mut().remove(key));
+            assert_eq!(group.len(), 1);
+        });
+    }
+}
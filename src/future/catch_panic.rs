@@ -0,0 +1,111 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe, UnwindSafe};
+
+/// Resolves a future, catching any panic which occurs while polling it.
+///
+/// This `struct` is created by the [`catch_panic`] method on [`FutureExt`].
+/// See its documentation for more.
+///
+/// [`catch_panic`]: crate::future::FutureExt::catch_panic
+/// [`FutureExt`]: crate::future::FutureExt
+#[derive(Debug)]
+#[pin_project::pin_project]
+#[must_use = "futures do nothing unless polled or .awaited"]
+pub struct CatchPanic<F> {
+    #[pin]
+    future: F,
+    done: bool,
+}
+
+impl<F> CatchPanic<F> {
+    pub(super) fn new(future: F) -> Self {
+        Self {
+            future,
+            done: false,
+        }
+    }
+}
+
+impl<F: Future + UnwindSafe> Future for CatchPanic<F> {
+    type Output = Result<F::Output, Box<dyn Any + Send>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        assert!(!*this.done, "future polled after completing");
+
+        let future = this.future;
+        match panic::catch_unwind(AssertUnwindSafe(|| future.poll(cx))) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(value)) => {
+                *this.done = true;
+                Poll::Ready(Ok(value))
+            }
+            // The future may be in an inconsistent state after panicking, so
+            // it must not be polled again.
+            Err(payload) => {
+                *this.done = true;
+                Poll::Ready(Err(payload))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::future::FutureGroup;
+    use crate::prelude::*;
+    use core::future;
+    use futures_lite::StreamExt;
+    use std::panic::AssertUnwindSafe;
+
+    #[test]
+    fn completes() {
+        futures_lite::future::block_on(async {
+            let res = future::ready(12).catch_panic().await;
+            assert_eq!(res.unwrap(), 12);
+        });
+    }
+
+    #[test]
+    fn join_isolates_panics() {
+        futures_lite::future::block_on(async {
+            let a = AssertUnwindSafe(async { panic!("oh no") }).catch_panic();
+            let b = future::ready(2).catch_panic();
+            let (a, b): (Result<(), _>, _) = (a, b).join().await;
+
+            let payload = a.unwrap_err();
+            assert_eq!(payload.downcast_ref::<&str>(), Some(&"oh no"));
+            assert_eq!(b.unwrap(), 2);
+        });
+    }
+
+    #[test]
+    fn group_keeps_running() {
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::new();
+            for n in 0..4 {
+                group.insert(
+                    AssertUnwindSafe(async move {
+                        assert!(n != 2, "bad task");
+                        n
+                    })
+                    .catch_panic(),
+                );
+            }
+
+            let mut ok = 0;
+            let mut panicked = 0;
+            while let Some(res) = group.next().await {
+                match res {
+                    Ok(n) => ok += n,
+                    Err(_) => panicked += 1,
+                }
+            }
+            assert_eq!(ok, 4);
+            assert_eq!(panicked, 1);
+        });
+    }
+}
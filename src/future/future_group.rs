@@ -1,16 +1,42 @@
 use alloc::collections::BTreeSet;
+use alloc::vec;
 use core::fmt::{self, Debug};
+use core::iter;
 use core::ops::{Deref, DerefMut};
 use core::pin::Pin;
 use core::task::{Context, Poll};
-use futures_core::stream::Stream;
+use core::time::Duration;
+use futures_core::stream::{FusedStream, Stream};
 use futures_core::Future;
-use slab::Slab;
 
-use crate::utils::{PollState, PollVec, WakerVec};
+#[cfg(feature = "std")]
+use crate::utils::Mutex;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::task::Waker;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+use crate::concurrent_stream::{FromStream, IntoConcurrentStream};
+use crate::stream::IntoStream;
+use crate::time::watchdog;
+use crate::time::{Timer, Watchdog, WatchdogEvent, WatchdogGroup};
+use crate::utils::{
+    trace, Budget, ChunkedVec, PollState, PollVec, ReadyOrder, ReadyQueue, WakerVec, ITEM_BUDGET,
+};
 
 /// A growable group of futures which act as a single unit.
 ///
+/// The group allocates when it's created, and as futures are inserted: the
+/// futures themselves are stored on the heap, and every entry gets a waker of
+/// its own. Small groups keep their bookkeeping in-line, which saves some
+/// allocations, but not all of them.
+/// [`ArrayFutureGroup`](crate::future::ArrayFutureGroup) stores a fixed number of futures
+/// in-line instead.
+///
 /// # Example
 ///
 /// **Basic example**
@@ -60,18 +86,29 @@ use crate::utils::{PollState, PollVec, WakerVec};
 #[must_use = "`FutureGroup` does nothing if not iterated over"]
 #[pin_project::pin_project]
 pub struct FutureGroup<F> {
-    #[pin]
-    futures: Slab<F>,
+    futures: ChunkedVec<F>,
     wakers: WakerVec,
     states: PollVec,
     keys: BTreeSet<usize>,
     capacity: usize,
+    budget: Budget,
+    completed: usize,
+    priorities: Vec<u8>,
+    /// Whether any future was ever inserted with a non-default priority. Until
+    /// then we can skip sorting the futures by priority.
+    prioritized: bool,
+    order: DrainOrder,
+    /// The futures which are ready, by priority. Only kept while the futures
+    /// are prioritized or aren't polled by key.
+    ready: ReadyQueue,
+    #[cfg(feature = "std")]
+    queue: Option<Arc<Mutex<Queue<F>>>>,
 }
 
 impl<T: Debug> Debug for FutureGroup<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("FutureGroup")
-            .field("slab", &"[..]")
+            .field("futures", &"[..]")
             .field("len", &self.len())
             .field("capacity", &self.capacity)
             .finish()
@@ -111,11 +148,19 @@ impl<F> FutureGroup<F> {
     /// ```
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            futures: Slab::with_capacity(capacity),
+            futures: ChunkedVec::with_capacity(capacity),
             wakers: WakerVec::new(capacity),
             states: PollVec::new(capacity),
             keys: BTreeSet::new(),
             capacity,
+            budget: Budget::default(),
+            completed: 0,
+            priorities: vec![0; capacity],
+            prioritized: false,
+            order: DrainOrder::default(),
+            ready: ReadyQueue::default(),
+            #[cfg(feature = "std")]
+            queue: None,
         }
     }
 
@@ -154,6 +199,111 @@ impl<F> FutureGroup<F> {
         self.capacity
     }
 
+    /// Poll at most `budget` futures per call to `poll_next`.
+    ///
+    /// When many futures are woken at once, polling all of them in one go can
+    /// keep the executor busy for a long time. Once the budget runs out the
+    /// group wakes itself and returns `Poll::Pending`, giving other tasks a
+    /// chance to run. Polling resumes where it left off on the next call. At
+    /// least one future is always polled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureGroup;
+    ///
+    /// let mut group = FutureGroup::new();
+    /// group.set_budget(32);
+    /// assert_eq!(group.budget(), Some(32));
+    /// # let group: FutureGroup<std::future::Ready<usize>> = group;
+    /// ```
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget.set_limit(budget);
+    }
+
+    /// Return the maximum number of futures polled per call to `poll_next`,
+    /// if any.
+    pub fn budget(&self) -> Option<usize> {
+        self.budget.limit()
+    }
+
+    /// Change the order in which futures which are ready at the same time are
+    /// polled, and so the order in which their outputs are yielded.
+    ///
+    /// With [`DrainOrder::Fifo`] and [`DrainOrder::Lifo`] the group keeps a
+    /// queue of the futures in the order they were woken, so every poll only
+    /// visits the futures which are ready. Futures with a higher priority are
+    /// still polled first, see [`FutureGroup::insert_with_priority`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::future_group::{DrainOrder, FutureGroup};
+    /// use futures_lite::StreamExt;
+    /// use std::future;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = FutureGroup::new();
+    /// group.set_drain_order(DrainOrder::Lifo);
+    /// group.insert(future::ready(1));
+    /// group.insert(future::ready(2));
+    ///
+    /// assert_eq!(group.next().await, Some(2));
+    /// assert_eq!(group.next().await, Some(1));
+    /// # });
+    /// ```
+    pub fn set_drain_order(&mut self, order: DrainOrder) {
+        let was_tracking = self.tracks_wakes();
+        self.order = order;
+        self.ready.set_order(order.into());
+        self.update_tracking(was_tracking);
+    }
+
+    /// Return the order in which futures which are ready at the same time are
+    /// polled.
+    pub fn drain_order(&self) -> DrainOrder {
+        self.order
+    }
+
+    /// Take a snapshot of the number of futures in each state.
+    ///
+    /// This is cheap enough to call on every iteration, for example to export
+    /// gauge metrics from a long-lived group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureGroup;
+    /// use futures_lite::{FutureExt, StreamExt};
+    /// use std::future;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = FutureGroup::with_capacity(4);
+    /// group.insert(future::pending().boxed());
+    /// group.insert(future::ready(1).boxed());
+    /// assert_eq!(group.next().await, Some(1));
+    ///
+    /// let stats = group.stats();
+    /// assert_eq!(stats.pending(), 1);
+    /// assert_eq!(stats.ready(), 0);
+    /// assert_eq!(stats.completed(), 1);
+    /// assert_eq!(stats.capacity(), 4);
+    /// # });
+    /// ```
+    pub fn stats(&self) -> Stats {
+        let ready = self
+            .keys
+            .iter()
+            .filter(|index| self.wakers.is_ready(**index))
+            .count();
+        Stats {
+            pending: self.len() - ready,
+            ready,
+            completed: self.completed,
+            capacity: self.capacity,
+        }
+    }
+
     /// Returns true if there are no futures currently active in the group.
     ///
     /// # Example
@@ -193,10 +343,157 @@ impl<F> FutureGroup<F> {
         if is_present {
             self.states[key.0].set_none();
             self.futures.remove(key.0);
+            // Don't hand the wake of this future to the next one using its key.
+            if self.wakers.readiness().clear_ready(key.0) && self.tracks_wakes() {
+                self.ready.remove(key.0);
+            }
+            trace!(key = key.0, "future group: future removed");
         }
         is_present
     }
 
+    /// Removes all futures from the group, keeping the allocated capacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::future::FutureGroup;
+    /// use std::future;
+    ///
+    /// let mut group = FutureGroup::with_capacity(2);
+    /// group.insert(future::ready(1));
+    /// group.insert(future::ready(2));
+    /// group.clear();
+    /// assert!(group.is_empty());
+    /// assert_eq!(group.capacity(), 2);
+    /// ```
+    pub fn clear(&mut self) {
+        for index in core::mem::take(&mut self.keys) {
+            self.states[index].set_none();
+        }
+        self.futures.clear();
+        self.reset();
+        trace!("future group: cleared");
+    }
+
+    /// Removes all futures from the group and returns them, keeping the
+    /// allocated capacity.
+    ///
+    /// The futures are returned in key order. Values are pinned once they're
+    /// inserted into the group, so this requires the futures to be `Unpin`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::future::FutureGroup;
+    /// use std::future;
+    ///
+    /// let mut group = FutureGroup::with_capacity(2);
+    /// group.insert(future::ready(1));
+    /// group.insert(future::ready(2));
+    ///
+    /// let other: FutureGroup<_> = group.drain().collect();
+    /// assert!(group.is_empty());
+    /// assert_eq!(group.capacity(), 2);
+    /// assert_eq!(other.len(), 2);
+    /// ```
+    pub fn drain(&mut self) -> impl Iterator<Item = F>
+    where
+        F: Unpin,
+    {
+        let futures: Vec<_> = core::mem::take(&mut self.keys)
+            .into_iter()
+            .filter_map(|index| {
+                self.states[index].set_none();
+                self.futures.take(index)
+            })
+            .collect();
+        // Reset the vacant list, so keys are handed out from the start again.
+        self.futures.clear();
+        self.reset();
+        trace!(count = futures.len(), "future group: drained");
+        futures.into_iter()
+    }
+
+    /// Forget the readiness and priorities of the futures which were removed
+    /// by clearing the group.
+    fn reset(&mut self) {
+        let was_tracking = self.tracks_wakes();
+        self.wakers.readiness().clear_all_ready();
+        self.ready.clear();
+        self.prioritized = false;
+        self.update_tracking(was_tracking);
+    }
+
+    /// Whether futures are polled from the ready queue, rather than by
+    /// visiting every key.
+    fn tracks_wakes(&self) -> bool {
+        self.prioritized || self.order != DrainOrder::Keys
+    }
+
+    /// Start or stop recording the order in which futures are woken, after
+    /// the drain order or priorities changed.
+    fn update_tracking(&mut self, was_tracking: bool) {
+        let tracking = self.tracks_wakes();
+        if tracking == was_tracking {
+            return;
+        }
+        let mut readiness = self.wakers.readiness();
+        readiness.track_wakes(tracking);
+        self.ready.clear();
+        if tracking {
+            // Futures which are ready already were woken before any others.
+            for &index in &self.keys {
+                if readiness.is_ready(index) {
+                    self.ready.push(index, self.priorities[index]);
+                }
+            }
+        }
+    }
+
+    /// Retains only the futures specified by the predicate.
+    ///
+    /// Removes every future for which `predicate` returns `false`. The
+    /// predicate is called once for each future, along with its key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::future::FutureGroup;
+    /// use std::future;
+    ///
+    /// let mut group = FutureGroup::new();
+    /// let keep = group.insert(future::ready(1));
+    /// let discard = group.insert(future::ready(2));
+    ///
+    /// group.retain(|key, _future| key == keep);
+    /// assert!(group.contains_key(keep));
+    /// assert!(!group.contains_key(discard));
+    /// ```
+    pub fn retain<P>(&mut self, mut predicate: P)
+    where
+        P: FnMut(Key, Pin<&mut F>) -> bool,
+    {
+        let tracking = self.tracks_wakes();
+        let futures = &mut self.futures;
+        let states = &mut self.states;
+        let mut readiness = self.wakers.readiness();
+        let ready = &mut self.ready;
+        self.keys.retain(|&index| {
+            let future = futures.get_pin_mut(index).unwrap();
+            let keep = predicate(Key(index), future);
+            if !keep {
+                states[index].set_none();
+                futures.remove(index);
+                if readiness.clear_ready(index) && tracking {
+                    ready.remove(index);
+                }
+                trace!(key = index, "future group: future removed");
+            }
+            keep
+        });
+    }
+
     /// Returns `true` if the `FutureGroup` contains a value for the specified key.
     ///
     /// # Example
@@ -243,8 +540,85 @@ impl<F> FutureGroup<F> {
         let new_cap = self.capacity + additional;
         self.wakers.resize(new_cap);
         self.states.resize(new_cap);
-        self.futures.reserve_exact(additional);
+        self.priorities.resize(new_cap, 0);
+        self.futures.reserve(additional);
+        self.capacity = new_cap;
+        trace!(capacity = new_cap, "future group: resized");
+    }
+
+    /// Shrinks the capacity of the group as much as possible.
+    ///
+    /// Futures never move once they're inserted, so they're stored in chunks
+    /// which can only be released as a whole, and only once none of their
+    /// keys are in use. The group keeps the capacity of every chunk up to the
+    /// one holding the highest key still in use, so [`FutureGroup::capacity`]
+    /// may remain larger than the number of futures in the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureGroup;
+    /// use std::future;
+    ///
+    /// let mut group = FutureGroup::with_capacity(1);
+    /// group.insert(future::ready(0));
+    /// let keys: Vec<_> = (1..10).map(|n| group.insert(future::ready(n))).collect();
+    /// for key in keys {
+    ///     group.remove(key);
+    /// }
+    ///
+    /// group.shrink_to_fit();
+    /// assert_eq!(group.capacity(), 1);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    /// Shrinks the capacity of the group with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both `min_capacity`
+    /// and the highest key still in use. Capacity is released a chunk at a
+    /// time, so it may remain larger than that, see
+    /// [`FutureGroup::shrink_to_fit`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureGroup;
+    /// use std::future;
+    ///
+    /// let mut group = FutureGroup::with_capacity(1);
+    /// group.insert(future::ready(0));
+    /// let keys: Vec<_> = (1..10).map(|n| group.insert(future::ready(n))).collect();
+    /// for key in keys {
+    ///     group.remove(key);
+    /// }
+    ///
+    /// group.shrink_to(4);
+    /// assert_eq!(group.capacity(), 4);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let needed = self.keys.last().map_or(0, |key| key + 1);
+        let new_cap = needed.max(min_capacity);
+        if new_cap >= self.capacity {
+            return;
+        }
+        self.futures.shrink_to(new_cap);
+        // Chunks which still hold futures can't be released, so report the
+        // capacity which is actually left.
+        let new_cap = self.futures.capacity().clamp(new_cap, self.capacity);
+        if new_cap == self.capacity {
+            return;
+        }
+        self.wakers.resize(new_cap);
+        self.wakers.shrink_to_fit();
+        self.states.resize(new_cap);
+        self.states.shrink_to_fit();
+        self.priorities.truncate(new_cap);
+        self.priorities.shrink_to_fit();
+        self.ready.truncate(new_cap);
         self.capacity = new_cap;
+        trace!(capacity = new_cap, "future group: resized");
     }
 }
 
@@ -264,21 +638,58 @@ impl<F: Future> FutureGroup<F> {
     where
         F: Future,
     {
+        self.insert_with_priority(future, 0)
+    }
+
+    /// Insert a new future into the group with the given priority.
+    ///
+    /// When several futures are ready at the same time, futures with a higher
+    /// priority are polled first, so their outputs are yielded first. Futures
+    /// inserted with [`FutureGroup::insert`] have a priority of `0`. Once any
+    /// future has a priority, the group keeps a queue of ready futures per
+    /// priority, so polling only visits the futures which were woken.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureGroup;
+    /// use futures_lite::StreamExt;
+    /// use std::future;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = FutureGroup::new();
+    /// group.insert(future::ready("bulk"));
+    /// group.insert_with_priority(future::ready("control"), 10);
+    ///
+    /// assert_eq!(group.next().await, Some("control"));
+    /// assert_eq!(group.next().await, Some("bulk"));
+    /// # });
+    /// ```
+    pub fn insert_with_priority(&mut self, future: F, priority: u8) -> Key {
         if self.capacity <= self.len() {
             self.reserve(self.capacity * 2 + 1);
         }
 
         let index = self.futures.insert(future);
         self.keys.insert(index);
+        trace!(key = index, "future group: future inserted");
 
         // Set the corresponding state
         self.states[index].set_pending();
+        self.priorities[index] = priority;
+        // A new future counts as woken when it's inserted.
         self.wakers.readiness().set_ready(index);
+        let was_tracking = self.tracks_wakes();
+        self.prioritized |= priority != 0;
+        if was_tracking {
+            self.ready.push(index, priority);
+        } else {
+            self.update_tracking(was_tracking);
+        }
 
         Key(index)
     }
 
-    #[allow(unused)]
     /// Insert a value into a pinned `FutureGroup`
     ///
     /// This method is private because it serves as an implementation detail for
@@ -289,25 +700,90 @@ impl<F: Future> FutureGroup<F> {
     where
         F: Future,
     {
-        let mut this = self.project();
-        // SAFETY: inserting a value into the futures slab does not ever move
-        // any of the existing values.
-        let index = unsafe { this.futures.as_mut().get_unchecked_mut() }.insert(future);
-        this.keys.insert(index);
-        let key = Key(index);
-
-        // If our slab allocated more space we need to
-        // update our tracking structures along with it.
-        let max_len = this.futures.as_ref().capacity().max(index);
-        this.wakers.resize(max_len);
-        this.states.resize(max_len);
+        // Inserting a value into the futures storage never moves any of the
+        // existing values, so we don't need to treat the group as pinned.
+        self.get_mut().insert(future)
+    }
 
-        // Set the corresponding state
-        this.states[index].set_pending();
-        let mut readiness = this.wakers.readiness();
-        readiness.set_ready(index);
+    /// Create a handle which can insert futures into the group from
+    /// elsewhere.
+    ///
+    /// The handle is cheap to clone, and can be moved to other tasks or
+    /// threads. Futures inserted through it are queued, and are moved into
+    /// the group the next time it's polled. Queued futures aren't counted by
+    /// [`len`](Self::len) until then.
+    ///
+    /// While any handle is alive, an empty group stays pending rather than
+    /// ending, since more futures may still be inserted. The group ends once
+    /// it's empty and all of its handles have been dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureGroup;
+    /// use futures_lite::StreamExt;
+    /// use std::future;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = FutureGroup::new();
+    /// let handle = group.handle();
+    ///
+    /// std::thread::spawn(move || {
+    ///     handle.insert(future::ready(2));
+    ///     handle.insert(future::ready(4));
+    /// });
+    ///
+    /// // The group ends once the handle has been dropped.
+    /// let mut out = 0;
+    /// while let Some(num) = group.next().await {
+    ///     out += num;
+    /// }
+    /// assert_eq!(out, 6);
+    /// # });
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn handle(&mut self) -> Handle<F> {
+        let queue = self.queue.get_or_insert_with(|| {
+            Arc::new(Mutex::new(Queue {
+                futures: VecDeque::new(),
+                waker: None,
+                handles: 0,
+            }))
+        });
+        queue.lock().handles += 1;
+        Handle {
+            queue: queue.clone(),
+        }
+    }
+
+    /// Move the futures inserted through a [`Handle`] into the group, and
+    /// register the waker to be notified of new insertions. Returns whether
+    /// any handles are still alive.
+    #[cfg(feature = "std")]
+    fn insert_queued(&mut self, waker: &Waker) -> bool {
+        let Some(queue) = &self.queue else {
+            return false;
+        };
+        let (queued, handles) = {
+            let mut queue = queue.lock();
+            match &mut queue.waker {
+                Some(prev) => prev.clone_from(waker),
+                None => queue.waker = Some(waker.clone()),
+            }
+            (core::mem::take(&mut queue.futures), queue.handles)
+        };
+        for future in queued {
+            self.insert(future);
+        }
+        handles > 0
+    }
 
-        key
+    /// Whether any handles to the group are still alive.
+    #[cfg(feature = "std")]
+    fn has_handles(&self) -> bool {
+        self.queue
+            .as_ref()
+            .is_some_and(|queue| queue.lock().handles > 0)
     }
 
     /// Create a stream which also yields the key of each item.
@@ -335,50 +811,156 @@ impl<F: Future> FutureGroup<F> {
     pub fn keyed(self) -> Keyed<F> {
         Keyed { group: self }
     }
+
+    /// Create a stream which inserts the futures yielded by `stream` into the
+    /// group while polling it.
+    ///
+    /// The stream yields the outputs of all futures in the group, including
+    /// the ones which were already in it. It ends once `stream` has been
+    /// exhausted and every future has completed. This makes it possible to
+    /// feed the group from a source such as a listener's stream of incoming
+    /// connections, without writing a select loop by hand.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureGroup;
+    /// use futures_lite::{stream, StreamExt};
+    /// use std::future;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = FutureGroup::new();
+    /// group.insert(future::ready(1));
+    ///
+    /// let incoming = stream::iter([2, 3]).map(future::ready);
+    /// let mut out = 0;
+    /// let mut group = group.extend_from_stream(incoming);
+    /// while let Some(num) = group.next().await {
+    ///     out += num;
+    /// }
+    /// assert_eq!(out, 6);
+    /// # });
+    /// ```
+    pub fn extend_from_stream<S>(self, stream: S) -> ExtendFromStream<F, S::IntoStream>
+    where
+        S: IntoStream<Item = F>,
+    {
+        ExtendFromStream {
+            group: self,
+            stream: stream.into_stream(),
+            done: false,
+        }
+    }
+
+    /// Create a stream which reports when the group stops making progress.
+    ///
+    /// The stream yields the output of each future along with its key. In
+    /// addition, it yields [`WatchdogEvent::Stalled`] whenever no future has
+    /// completed for `stall_after`, and [`WatchdogEvent::Overdue`] once for
+    /// every future which has been pending for longer than `overdue_after`.
+    /// Futures inserted through [`Watchdog::insert`] are tracked as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(miri)] fn main() {}
+    /// # #[cfg(not(miri))]
+    /// # fn main() {
+    /// use futures_concurrency::future::FutureGroup;
+    /// use futures_concurrency::time::WatchdogEvent;
+    /// use futures_lite::StreamExt;
+    /// use std::future;
+    /// use std::time::Duration;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = FutureGroup::new();
+    /// let key = group.insert(future::pending::<()>());
+    ///
+    /// let stall = Duration::from_secs(60);
+    /// let overdue = Duration::from_millis(10);
+    /// let mut group = group.watchdog(stall, overdue, async_io::Timer::after);
+    /// assert_eq!(group.next().await, Some(WatchdogEvent::Overdue(key)));
+    /// # });
+    /// # }
+    /// ```
+    pub fn watchdog<T: Timer>(
+        self,
+        stall_after: Duration,
+        overdue_after: Duration,
+        timer: T,
+    ) -> Watchdog<Self, T> {
+        Watchdog::new(self, stall_after, overdue_after, timer)
+    }
 }
 
 impl<F: Future> FutureGroup<F> {
-    fn poll_next_inner(
+    pub(crate) fn poll_next_inner(
         self: Pin<&mut Self>,
         cx: &Context<'_>,
     ) -> Poll<Option<(Key, <F as Future>::Output)>> {
-        let mut this = self.project();
+        // The group never moves its futures, so it doesn't need to be pinned.
+        let this = self.get_mut();
+        #[cfg(feature = "std")]
+        let has_handles = this.insert_queued(cx.waker());
+        #[cfg(not(feature = "std"))]
+        let has_handles = false;
 
-        // Short-circuit if we have no futures to iterate over
+        // Short-circuit if we have no futures to iterate over. Live handles
+        // may still insert more, and will wake us when they do.
         if this.futures.is_empty() {
+            if has_handles {
+                return Poll::Pending;
+            }
             return Poll::Ready(None);
         }
 
         // Set the top-level waker and check readiness
+        let tracking = this.tracks_wakes();
         let mut readiness = this.wakers.readiness();
         readiness.set_waker(cx.waker());
         if !readiness.any_ready() {
             // Nothing is ready yet
             return Poll::Pending;
         }
+        if tracking {
+            let (ready, priorities) = (&mut this.ready, &this.priorities);
+            readiness.take_woken(|index| ready.push(index, priorities[index]));
+        }
 
         // Setup our futures state
         let mut ret = Poll::Pending;
-        let states = this.states;
+        let states = &mut this.states;
+        let futures = &mut this.futures;
 
-        // SAFETY: We unpin the future group so we can later individually access
-        // single futures. Either to read from them or to drop them.
-        let futures = unsafe { this.futures.as_mut().get_unchecked_mut() };
+        // Resume where we left off if we ran out of budget last time. Futures
+        // which are prioritized or polled in the order they were woken are
+        // taken off the ready queue instead, which keeps whatever we didn't
+        // get to.
+        let start = this.budget.take_resume().unwrap_or(0);
+        let mut rotated =
+            (!tracking).then(|| this.keys.range(start..).chain(this.keys.range(..start)));
+        let ready = &mut this.ready;
+        let keys = iter::from_fn(|| match &mut rotated {
+            Some(rotated) => rotated.next().copied(),
+            None => ready.pop(),
+        });
+        let mut polled = 0;
+        let waker = cx.waker();
 
-        for index in this.keys.iter().cloned() {
+        for index in keys {
             if states[index].is_pending() && readiness.clear_ready(index) {
-                // unlock readiness so we don't deadlock when polling
+                // release readiness so we can borrow the wakers when polling
                 #[allow(clippy::drop_non_drop)]
                 drop(readiness);
 
                 // Obtain the intermediate waker.
                 let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
 
-                // SAFETY: this future here is a projection from the futures
-                // vec, which we're reading from.
-                let future = unsafe { Pin::new_unchecked(&mut futures[index]) };
+                trace!(key = index, "future group: polling future");
+                let future = futures.get_pin_mut(index).unwrap();
                 match future.poll(&mut cx) {
                     Poll::Ready(item) => {
+                        trace!(key = index, "future group: future completed");
                         // Set the return type for the function
                         ret = Poll::Ready(Some((Key(index), item)));
 
@@ -386,6 +968,7 @@ impl<F: Future> FutureGroup<F> {
                         // The only data we can't remove directly is the key entry.
                         states[index] = PollState::None;
                         futures.remove(index);
+                        this.completed += 1;
 
                         break;
                     }
@@ -393,7 +976,15 @@ impl<F: Future> FutureGroup<F> {
                     Poll::Pending => {}
                 };
 
-                // Lock readiness so we can use it again
+                // Yield to the executor once we've used up our budget
+                polled += 1;
+                if this.budget.is_exhausted(polled) {
+                    this.budget.resume_at(index + 1);
+                    waker.wake_by_ref();
+                    break;
+                }
+
+                // Reacquire readiness so we can use it again
                 readiness = this.wakers.readiness();
             }
         }
@@ -418,6 +1009,30 @@ impl<F: Future> Stream for FutureGroup<F> {
             Poll::Pending => Poll::Pending,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every future yields exactly one item.
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<F: Future> IntoConcurrentStream for FutureGroup<F> {
+    type Item = <F as Future>::Output;
+    type IntoConcurrentStream = FromStream<Self>;
+
+    fn into_co_stream(self) -> Self::IntoConcurrentStream {
+        FromStream::new(self)
+    }
+}
+
+impl<F: Future> FusedStream for FutureGroup<F> {
+    fn is_terminated(&self) -> bool {
+        #[cfg(feature = "std")]
+        if self.has_handles() {
+            return false;
+        }
+        self.is_empty()
+    }
 }
 
 impl<F: Future> Extend<F> for FutureGroup<F> {
@@ -440,18 +1055,187 @@ impl<F: Future> FromIterator<F> for FutureGroup<F> {
     }
 }
 
-/// A key used to index into the `FutureGroup` type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Key(usize);
+/// A handle which inserts futures into a [`FutureGroup`].
+///
+/// This is created by [`FutureGroup::handle`].
+#[cfg(feature = "std")]
+pub struct Handle<F> {
+    queue: Arc<Mutex<Queue<F>>>,
+}
 
-/// Iterate over items in the futures group with their associated keys.
-#[derive(Debug)]
-#[pin_project::pin_project]
-pub struct Keyed<F: Future> {
-    #[pin]
+/// The futures inserted through a [`Handle`], waiting for the group to be
+/// polled.
+#[cfg(feature = "std")]
+struct Queue<F> {
+    futures: VecDeque<F>,
+    waker: Option<Waker>,
+    // The number of live handles. The group is woken when the last one is
+    // dropped, so it can end.
+    handles: usize,
+}
+
+#[cfg(feature = "std")]
+impl<F> Handle<F> {
+    /// Insert a new future into the group.
+    ///
+    /// The future is moved into the group the next time it's polled, and
+    /// the task polling the group is woken up.
+    pub fn insert(&self, future: F) {
+        let mut queue = self.queue.lock();
+        queue.futures.push_back(future);
+        if let Some(waker) = queue.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F> Clone for Handle<F> {
+    fn clone(&self) -> Self {
+        self.queue.lock().handles += 1;
+        Self {
+            queue: self.queue.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F> Drop for Handle<F> {
+    fn drop(&mut self) {
+        let mut queue = self.queue.lock();
+        queue.handles -= 1;
+        if queue.handles == 0 {
+            if let Some(waker) = queue.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F> Debug for Handle<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Handle")
+            .field("queued", &self.queue.lock().futures.len())
+            .finish()
+    }
+}
+
+impl From<DrainOrder> for ReadyOrder {
+    fn from(order: DrainOrder) -> Self {
+        match order {
+            DrainOrder::Keys => Self::Index,
+            DrainOrder::Fifo => Self::Oldest,
+            DrainOrder::Lifo => Self::Newest,
+        }
+    }
+}
+
+/// A key used to index into the `FutureGroup` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key(usize);
+
+/// The order in which a group polls entries which are ready at the same time.
+///
+/// This is set with [`FutureGroup::set_drain_order`] and
+/// [`StreamGroup::set_drain_order`](crate::stream::StreamGroup::set_drain_order).
+/// Entries count as woken when they're inserted. Without pointer-sized
+/// atomics, entries which are woken between two polls are ordered by key.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DrainOrder {
+    /// Poll entries in order of their keys. Keys are reused once their entry
+    /// is removed, so this doesn't follow the order the entries were woken or
+    /// inserted in. This is the cheapest order.
+    #[default]
+    Keys,
+    /// Poll the entry which was woken the longest ago first.
+    Fifo,
+    /// Poll the entry which was woken most recently first.
+    Lifo,
+}
+
+/// A snapshot of the state of a [`FutureGroup`].
+///
+/// This `struct` is created by the [`FutureGroup::stats`] method. The counts are
+/// taken when the snapshot is created, and aren't updated afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Stats {
+    pending: usize,
+    ready: usize,
+    completed: usize,
+    capacity: usize,
+}
+
+impl Stats {
+    /// The number of futures which are waiting to be woken.
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+
+    /// The number of futures which have been woken, and will be polled the next
+    /// time the group is polled.
+    pub fn ready(&self) -> usize {
+        self.ready
+    }
+
+    /// The number of futures which completed since the group was created.
+    ///
+    /// Futures which were removed from the group aren't counted.
+    pub fn completed(&self) -> usize {
+        self.completed
+    }
+
+    /// The capacity of the group.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+/// Iterate over items in the futures group with their associated keys.
+///
+/// `Keyed` dereferences to the group, so futures can be inserted and removed
+/// while iterating. Combined with a lending stream, this makes it possible to
+/// feed the group from its own outputs while keeping per-key bookkeeping.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::future::FutureGroup;
+/// use lending_stream::prelude::*;
+/// use std::collections::HashMap;
+/// use std::future;
+///
+/// # futures_lite::future::block_on(async {
+/// let mut group = FutureGroup::new();
+/// let mut names = HashMap::new();
+/// names.insert(group.insert(future::ready(2)), "a");
+///
+/// let mut seen = vec![];
+/// let mut group = group.keyed().lend_mut();
+/// while let Some((group, (key, num))) = group.next().await {
+///     seen.push((names.remove(&key).unwrap(), num));
+///     if num > 0 {
+///         names.insert(group.insert(future::ready(num - 1)), "b");
+///     }
+/// }
+/// assert_eq!(seen, [("a", 2), ("b", 1), ("b", 0)]);
+/// # });
+/// ```
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct Keyed<F: Future> {
+    #[pin]
     group: FutureGroup<F>,
 }
 
+impl<F: Future> Keyed<F> {
+    /// Consume the `Keyed` stream, returning the underlying group.
+    pub fn into_inner(self) -> FutureGroup<F> {
+        self.group
+    }
+}
+
 impl<F: Future> Deref for Keyed<F> {
     type Target = FutureGroup<F>;
 
@@ -475,12 +1259,246 @@ impl<F: Future> Stream for Keyed<F> {
     }
 }
 
+impl<F: Future> FusedStream for Keyed<F> {
+    fn is_terminated(&self) -> bool {
+        self.group.is_terminated()
+    }
+}
+
+/// A stream which inserts the futures yielded by another stream into the
+/// group while polling it.
+///
+/// This `struct` is created by [`FutureGroup::extend_from_stream`]. See its
+/// documentation for more.
+///
+/// `ExtendFromStream` dereferences to the group, so futures can also be
+/// inserted and removed directly.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct ExtendFromStream<F: Future, S> {
+    #[pin]
+    group: FutureGroup<F>,
+    #[pin]
+    stream: S,
+    done: bool,
+}
+
+impl<F: Future, S> ExtendFromStream<F, S> {
+    /// Consume the `ExtendFromStream` stream, returning the underlying group
+    /// and the source stream.
+    pub fn into_inner(self) -> (FutureGroup<F>, S) {
+        (self.group, self.stream)
+    }
+}
+
+impl<F: Future, S> Deref for ExtendFromStream<F, S> {
+    type Target = FutureGroup<F>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.group
+    }
+}
+
+impl<F: Future, S> DerefMut for ExtendFromStream<F, S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.group
+    }
+}
+
+impl<F, S> Stream for ExtendFromStream<F, S>
+where
+    F: Future,
+    S: Stream<Item = F>,
+{
+    type Item = <F as Future>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Move the futures the source has ready into the group. We take a
+        // bounded number per call, so a source which is always ready can't
+        // keep us from polling the group.
+        let mut budget = ITEM_BUDGET;
+        while !*this.done && budget > 0 {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(future)) => {
+                    this.group.as_mut().insert_pinned(future);
+                    budget -= 1;
+                }
+                Poll::Ready(None) => *this.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        match this.group.poll_next(cx) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(item)),
+            Poll::Ready(None) if *this.done => Poll::Ready(None),
+            // The source may have more futures ready than we took.
+            Poll::Ready(None) | Poll::Pending if budget == 0 => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            // The group is empty, but the source may still yield more futures.
+            Poll::Ready(None) | Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<F, S> FusedStream for ExtendFromStream<F, S>
+where
+    F: Future,
+    S: Stream<Item = F>,
+{
+    fn is_terminated(&self) -> bool {
+        self.done && self.group.is_empty()
+    }
+}
+
+impl<F: Future> watchdog::private::Sealed for FutureGroup<F> {}
+
+impl<F: Future> WatchdogGroup for FutureGroup<F> {
+    type Key = Key;
+    type Item = F::Output;
+
+    fn keys(&self) -> impl Iterator<Item = Key> + '_ {
+        self.keys.iter().map(|index| Key(*index))
+    }
+
+    fn contains_entry(&self, key: Key) -> bool {
+        self.keys.contains(&key.0)
+    }
+
+    fn remove_entry(&mut self, key: Key) -> bool {
+        self.remove(key)
+    }
+
+    fn poll_progress(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<WatchdogEvent<Key, F::Output>>> {
+        self.poll_next_inner(cx)
+            .map(|item| item.map(|(key, item)| WatchdogEvent::Item(key, item)))
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::FutureGroup;
+    use super::{DrainOrder, FutureGroup};
     use core::future;
     use futures_lite::prelude::*;
 
+    #[test]
+    fn stats_track_wakeups() {
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::new();
+            group.insert(future::pending().boxed());
+            group.insert(future::pending().boxed());
+            group.insert(future::ready(1).boxed());
+
+            // Futures are ready to be polled as soon as they're inserted.
+            let stats = group.stats();
+            assert_eq!((stats.pending(), stats.ready()), (0, 3));
+
+            assert_eq!(group.next().await, Some(1));
+            let stats = group.stats();
+            assert_eq!((stats.pending(), stats.ready()), (2, 0));
+            assert_eq!(stats.completed(), 1);
+        });
+    }
+
+    #[test]
+    fn priorities() {
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::new();
+            group.insert_with_priority(future::ready(1), 1);
+            group.insert(future::ready(0));
+            group.insert_with_priority(future::ready(3), 3);
+            group.insert_with_priority(future::ready(2), 1);
+            group.insert_with_priority(future::ready(4), 3);
+
+            let out: Vec<_> = group.collect().await;
+            assert_eq!(out, [3, 4, 1, 2, 0]);
+        });
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn priorities_follow_wakes() {
+        use futures::channel::oneshot;
+
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::new();
+            let mut senders = Vec::new();
+            for priority in [0, 2, 1, 0] {
+                let (sender, receiver) = oneshot::channel();
+                group.insert_with_priority(receiver, priority);
+                senders.push(Some(sender));
+            }
+            assert!(futures_lite::future::poll_once(group.next())
+                .await
+                .is_none());
+
+            // Only the woken futures are ready, highest priority first.
+            for n in [3, 0, 2] {
+                senders[n].take().unwrap().send(n).unwrap();
+            }
+            let out: Vec<_> = (&mut group).take(3).map(Result::unwrap).collect().await;
+            assert_eq!(out, [2, 0, 3]);
+            assert_eq!(group.len(), 1);
+        });
+    }
+
+    #[test]
+    fn drain_order() {
+        futures_lite::future::block_on(async {
+            let run = |order| async move {
+                let mut group = FutureGroup::new();
+                group.set_drain_order(order);
+                group.insert(future::ready(0));
+                let key = group.insert(future::ready(1));
+                group.insert(future::ready(2));
+                // The new future reuses the key of the removed one.
+                group.remove(key);
+                group.insert(future::ready(3));
+                group.collect::<Vec<_>>().await
+            };
+            assert_eq!(run(DrainOrder::Keys).await, [0, 3, 2]);
+            assert_eq!(run(DrainOrder::Fifo).await, [0, 2, 3]);
+            assert_eq!(run(DrainOrder::Lifo).await, [3, 2, 0]);
+        });
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn drain_order_follows_wakes() {
+        use futures::channel::oneshot;
+
+        futures_lite::future::block_on(async {
+            let run = |order| async move {
+                let mut group = FutureGroup::new();
+                group.set_drain_order(order);
+                let mut senders = Vec::new();
+                for _ in 0..3 {
+                    let (sender, receiver) = oneshot::channel();
+                    group.insert(receiver);
+                    senders.push(Some(sender));
+                }
+                assert!(futures_lite::future::poll_once(group.next())
+                    .await
+                    .is_none());
+
+                // Wake the futures in a different order than their keys.
+                for n in [2, 0, 1] {
+                    senders[n].take().unwrap().send(n).unwrap();
+                }
+                group.map(Result::unwrap).collect::<Vec<_>>().await
+            };
+            assert_eq!(run(DrainOrder::Keys).await, [0, 1, 2]);
+            assert_eq!(run(DrainOrder::Fifo).await, [2, 0, 1]);
+            assert_eq!(run(DrainOrder::Lifo).await, [1, 0, 2]);
+        });
+    }
+
     #[test]
     fn smoke() {
         futures_lite::future::block_on(async {
@@ -509,4 +1527,342 @@ mod test {
             assert!(group.capacity() > cap);
         });
     }
+
+    #[test]
+    fn into_co_stream() {
+        use crate::concurrent_stream::{ConcurrentStream, IntoConcurrentStream};
+
+        futures_lite::future::block_on(async {
+            let group: FutureGroup<_> = [1, 2, 3].into_iter().map(future::ready).collect();
+            assert_eq!(group.size_hint(), (3, Some(3)));
+
+            let co_stream = group.into_co_stream();
+            assert_eq!(co_stream.size_hint(), (3, Some(3)));
+            let mut v: Vec<_> = co_stream.map(|n| async move { n * 2 }).collect().await;
+            v.sort();
+            assert_eq!(v, &[2, 4, 6]);
+        });
+    }
+
+    #[test]
+    fn watchdog() {
+        use crate::time::WatchdogEvent;
+        use std::pin::Pin;
+        use std::time::Duration;
+
+        futures_lite::future::block_on(async {
+            let mut group: FutureGroup<Pin<Box<dyn Future<Output = u8>>>> = FutureGroup::new();
+            let pending = group.insert(Box::pin(future::pending()));
+            let ready = group.insert(Box::pin(future::ready(1)));
+
+            let stall = Duration::from_millis(50);
+            let overdue = Duration::from_millis(10);
+            let mut group = group.watchdog(stall, overdue, async_io::Timer::after);
+            assert_eq!(group.next().await, Some(WatchdogEvent::Item(ready, 1)));
+            assert_eq!(group.next().await, Some(WatchdogEvent::Overdue(pending)));
+            assert_eq!(group.next().await, Some(WatchdogEvent::Stalled));
+
+            let key = group.insert(Box::pin(future::ready(2)));
+            assert_eq!(group.next().await, Some(WatchdogEvent::Item(key, 2)));
+            assert!(group.remove(pending));
+            assert_eq!(group.next().await, None);
+        });
+    }
+
+    #[test]
+    fn growing_never_moves_futures() {
+        use core::cell::Cell;
+        use core::marker::PhantomPinned;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+
+        /// Yields once, and checks it hasn't moved when it's polled again.
+        #[derive(Default)]
+        struct Unmovable {
+            addr: Cell<usize>,
+            _pinned: PhantomPinned,
+        }
+
+        impl Future for Unmovable {
+            type Output = ();
+
+            fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+                let addr = &*self as *const Self as usize;
+                match self.addr.replace(addr) {
+                    0 => {
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    }
+                    prev => {
+                        assert_eq!(prev, addr, "future moved after being polled");
+                        Poll::Ready(())
+                    }
+                }
+            }
+        }
+
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::with_capacity(1);
+            let mut count = 0;
+            for _ in 0..4 {
+                // Poll the existing futures once, then grow the group.
+                if let Some(Some(())) = futures_lite::future::poll_once(group.next()).await {
+                    count += 1;
+                }
+                for _ in 0..8 {
+                    group.insert(Unmovable::default());
+                }
+            }
+            while group.next().await.is_some() {
+                count += 1;
+            }
+            assert_eq!(count, 32);
+        });
+    }
+
+    #[test]
+    fn budget() {
+        use alloc::rc::Rc;
+        use core::cell::Cell;
+        use core::task::Poll;
+
+        futures_lite::future::block_on(async {
+            // Futures which never complete, but wake themselves every time.
+            let polls: Rc<[Cell<usize>]> = (0..10).map(|_| Cell::new(0)).collect();
+            let mut group = FutureGroup::new();
+            group.set_budget(3);
+            for index in 0..10 {
+                let polls = polls.clone();
+                group.insert(future::poll_fn(move |cx| {
+                    polls[index].set(polls[index].get() + 1);
+                    cx.waker().wake_by_ref();
+                    Poll::<()>::Pending
+                }));
+            }
+
+            let total = || polls.iter().map(Cell::get).sum::<usize>();
+            assert!(futures_lite::future::poll_once(group.next())
+                .await
+                .is_none());
+            assert_eq!(total(), 3);
+            assert!(futures_lite::future::poll_once(group.next())
+                .await
+                .is_none());
+            assert_eq!(total(), 6);
+
+            // Polling resumes where it left off, so every future gets a turn.
+            futures_lite::future::poll_once(group.next()).await;
+            futures_lite::future::poll_once(group.next()).await;
+            assert!(polls.iter().all(|polls| polls.get() > 0));
+        });
+    }
+
+    #[test]
+    fn keyed_into_inner() {
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::new();
+            let a = group.insert(future::ready(1));
+            let b = group.insert(future::ready(2));
+
+            let mut keyed = group.keyed();
+            assert_eq!(keyed.next().await, Some((a, 1)));
+            let mut group = keyed.into_inner();
+            assert_eq!(group.len(), 1);
+            assert!(group.contains_key(b));
+        });
+    }
+
+    #[test]
+    fn clear() {
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::with_capacity(4);
+            let key = group.insert(future::ready(1).boxed());
+            group.insert(future::ready(2).boxed());
+            assert_eq!(group.next().await, Some(1));
+
+            group.insert_with_priority(future::pending().boxed(), 1);
+
+            group.clear();
+            assert!(group.is_empty());
+            assert!(!group.contains_key(key));
+            assert_eq!(group.capacity(), 4);
+            // Nothing of the removed futures is left behind.
+            assert!(!group.prioritized);
+            assert!(!group.wakers.readiness().any_ready());
+            assert_eq!(group.next().await, None);
+
+            group.insert(future::ready(3).boxed());
+            assert_eq!(group.next().await, Some(3));
+        });
+    }
+
+    #[test]
+    fn drain() {
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::with_capacity(4);
+            let key = group.insert(future::ready(1));
+            group.insert(future::ready(2));
+
+            let other: FutureGroup<_> = group.drain().collect();
+            assert!(group.is_empty());
+            assert!(!group.contains_key(key));
+            assert_eq!(group.capacity(), 4);
+            assert_eq!(group.next().await, None);
+
+            let out: Vec<_> = other.collect().await;
+            assert_eq!(out, [1, 2]);
+
+            // The group keeps working after draining.
+            assert_eq!(group.insert(future::ready(3)), key);
+            assert_eq!(group.next().await, Some(3));
+        });
+    }
+
+    #[test]
+    fn retain() {
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::new();
+            for n in 0..6 {
+                group.insert(future::ready(n));
+            }
+            group.retain(|_key, _future| true);
+            assert_eq!(group.len(), 6);
+
+            let mut count = 0;
+            group.retain(|_key, _future| {
+                count += 1;
+                count % 2 == 0
+            });
+            assert_eq!(group.len(), 3);
+
+            let mut out: Vec<_> = group.collect().await;
+            out.sort();
+            assert_eq!(out, [1, 3, 5]);
+        });
+    }
+
+    #[test]
+    fn handle_wakes_group() {
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::new();
+            group.insert(future::pending().boxed());
+            let handle = group.handle();
+            assert!(futures_lite::future::poll_once(group.next())
+                .await
+                .is_none());
+
+            let thread = std::thread::spawn({
+                let handle = handle.clone();
+                move || handle.insert(future::ready(1).boxed())
+            });
+            assert_eq!(group.next().await, Some(1));
+            thread.join().unwrap();
+
+            drop(handle);
+            assert_eq!(group.len(), 1);
+        });
+    }
+
+    #[test]
+    fn empty_group_waits_for_handles() {
+        use futures_core::stream::FusedStream;
+
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::<future::Ready<u8>>::new();
+            let handle = group.handle();
+            assert!(!group.is_terminated());
+            assert!(futures_lite::future::poll_once(group.next())
+                .await
+                .is_none());
+
+            let thread = std::thread::spawn(move || handle.insert(future::ready(1)));
+            assert_eq!(group.next().await, Some(1));
+            thread.join().unwrap();
+
+            // The last handle is gone, so the group can end.
+            assert!(group.is_terminated());
+            assert_eq!(group.next().await, None);
+        });
+    }
+
+    #[test]
+    fn extend_from_stream() {
+        use futures::channel::mpsc;
+        use futures_core::stream::FusedStream;
+
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::new();
+            group.insert(future::ready(1));
+
+            let (sender, receiver) = mpsc::unbounded();
+            let mut group = group.extend_from_stream(receiver);
+            assert_eq!(group.next().await, Some(1));
+
+            // Futures are picked up while the group is being polled.
+            sender.unbounded_send(future::ready(2)).unwrap();
+            assert_eq!(group.next().await, Some(2));
+            assert!(!group.is_terminated());
+
+            drop(sender);
+            assert_eq!(group.next().await, None);
+            assert!(group.is_terminated());
+        });
+    }
+
+    #[test]
+    fn extend_from_always_ready_stream() {
+        futures_lite::future::block_on(async {
+            let source = futures_lite::stream::repeat_with(|| future::ready(1));
+            let mut group = FutureGroup::new().extend_from_stream(source);
+            assert_eq!(group.next().await, Some(1));
+        });
+    }
+
+    #[test]
+    fn shrink_keeps_occupied_chunks() {
+        futures_lite::future::block_on(async {
+            // All entries share a single chunk, which is still in use.
+            let mut group = FutureGroup::with_capacity(10);
+            group.insert(future::ready(1));
+            group.shrink_to_fit();
+            assert_eq!(group.capacity(), 10);
+
+            for n in 2..=10 {
+                group.insert(future::ready(n));
+            }
+            assert_eq!(group.capacity(), 10);
+            let mut out = 0;
+            while let Some(num) = group.next().await {
+                out += num;
+            }
+            assert_eq!(out, 55);
+        });
+    }
+
+    #[test]
+    fn shrink_after_burst() {
+        futures_lite::future::block_on(async {
+            let mut group = FutureGroup::new();
+            let first = group.insert(future::ready(1).boxed());
+            let keys: Vec<_> = (0..100)
+                .map(|_| group.insert(future::pending().boxed()))
+                .collect();
+            assert!(group.capacity() >= 101);
+            for key in keys {
+                group.remove(key);
+            }
+
+            group.shrink_to_fit();
+            assert_eq!(group.capacity(), 1);
+            assert!(group.contains_key(first));
+
+            // The group keeps working after shrinking.
+            group.insert(future::ready(2).boxed());
+            let mut out = 0;
+            while let Some(num) = group.next().await {
+                out += num;
+            }
+            assert_eq!(out, 3);
+        });
+    }
 }
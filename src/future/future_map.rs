@@ -0,0 +1,272 @@
+use alloc::collections::BTreeMap;
+use core::fmt::{self, Debug};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+use futures_core::Future;
+
+use super::future_group::{FutureGroup, Key};
+
+/// A growable group of futures indexed by caller-provided keys.
+///
+/// This is like [`FutureGroup`], except that futures are inserted under a key
+/// of the caller's choosing (e.g. a connection id), and every output is
+/// yielded together with the key its future was inserted under.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::future::FutureMap;
+/// use futures_lite::StreamExt;
+/// use std::future;
+///
+/// # futures_lite::future::block_on(async {
+/// let mut map = FutureMap::new();
+/// map.insert_with_key("a", future::ready(2));
+/// map.insert_with_key("b", future::ready(4));
+///
+/// let mut out = vec![];
+/// while let Some((key, num)) = map.next().await {
+///     out.push((key, num));
+/// }
+/// out.sort();
+/// assert_eq!(out, [("a", 2), ("b", 4)]);
+/// # });
+/// ```
+#[must_use = "`FutureMap` does nothing if not iterated over"]
+#[pin_project::pin_project]
+pub struct FutureMap<K, F> {
+    #[pin]
+    group: FutureGroup<F>,
+    keys: BTreeMap<K, Key>,
+    ids: BTreeMap<Key, K>,
+}
+
+impl<K: Debug, F> Debug for FutureMap<K, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FutureMap")
+            .field("keys", &self.keys.keys())
+            .field("len", &self.len())
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+impl<K, F> Default for FutureMap<K, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, F> FutureMap<K, F> {
+    /// Create a new instance of `FutureMap`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureMap;
+    ///
+    /// let map = FutureMap::new();
+    /// # let map: FutureMap<u32, std::future::Ready<usize>> = map;
+    /// ```
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create a new instance of `FutureMap` with a given capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureMap;
+    ///
+    /// let map = FutureMap::with_capacity(2);
+    /// # let map: FutureMap<u32, std::future::Ready<usize>> = map;
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            group: FutureGroup::with_capacity(capacity),
+            keys: BTreeMap::new(),
+            ids: BTreeMap::new(),
+        }
+    }
+
+    /// Return the number of futures currently active in the map.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.group.len()
+    }
+
+    /// Return the capacity of the `FutureMap`.
+    pub fn capacity(&self) -> usize {
+        self.group.capacity()
+    }
+
+    /// Returns true if there are no futures currently active in the map.
+    pub fn is_empty(&self) -> bool {
+        self.group.is_empty()
+    }
+
+    /// Reserves capacity for `additional` more futures to be inserted.
+    /// Does nothing if the capacity is already sufficient.
+    pub fn reserve(&mut self, additional: usize) {
+        self.group.reserve(additional);
+    }
+}
+
+impl<K: Ord, F> FutureMap<K, F> {
+    /// Removes the future inserted under `key` from the map. Returns whether
+    /// a future was present for the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::future::FutureMap;
+    /// use std::future;
+    ///
+    /// let mut map = FutureMap::new();
+    /// map.insert_with_key(12, future::ready(4));
+    /// assert_eq!(map.len(), 1);
+    /// assert!(map.remove(&12));
+    /// assert_eq!(map.len(), 0);
+    /// ```
+    pub fn remove(&mut self, key: &K) -> bool {
+        match self.keys.remove(key) {
+            Some(index) => {
+                self.ids.remove(&index);
+                self.group.remove(index)
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the `FutureMap` contains a future for the specified key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::future::FutureMap;
+    /// use std::future;
+    ///
+    /// let mut map = FutureMap::new();
+    /// map.insert_with_key(12, future::ready(4));
+    /// assert!(map.contains_key(&12));
+    /// map.remove(&12);
+    /// assert!(!map.contains_key(&12));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.keys.contains_key(key)
+    }
+}
+
+impl<K: Ord + Clone, F: Future> FutureMap<K, F> {
+    /// Insert a new future into the map under the given key.
+    ///
+    /// If a future was already present under `key` it is dropped and replaced,
+    /// and `false` is returned. Otherwise `true` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::future::FutureMap;
+    /// use std::future;
+    ///
+    /// let mut map = FutureMap::new();
+    /// assert!(map.insert_with_key(1, future::ready(12)));
+    /// assert!(!map.insert_with_key(1, future::ready(13)));
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn insert_with_key(&mut self, key: K, future: F) -> bool {
+        let is_new = !self.remove(&key);
+        let index = self.group.insert(future);
+        self.ids.insert(index, key.clone());
+        self.keys.insert(key, index);
+        is_new
+    }
+}
+
+impl<K: Ord, F: Future> Stream for FutureMap<K, F> {
+    type Item = (K, <F as Future>::Output);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match this.group.poll_next_inner(cx) {
+            Poll::Ready(Some((index, item))) => {
+                let key = this
+                    .ids
+                    .remove(&index)
+                    .expect("every future in the group has a matching key");
+                this.keys.remove(&key);
+                Poll::Ready(Some((key, item)))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<K: Ord, F: Future> FusedStream for FutureMap<K, F> {
+    fn is_terminated(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<K: Ord + Clone, F: Future> Extend<(K, F)> for FutureMap<K, F> {
+    fn extend<T: IntoIterator<Item = (K, F)>>(&mut self, iter: T) {
+        let iter = iter.into_iter();
+        let len = iter.size_hint().1.unwrap_or_default();
+        self.reserve(len);
+
+        for (key, future) in iter {
+            self.insert_with_key(key, future);
+        }
+    }
+}
+
+impl<K: Ord + Clone, F: Future> FromIterator<(K, F)> for FutureMap<K, F> {
+    fn from_iter<T: IntoIterator<Item = (K, F)>>(iter: T) -> Self {
+        let mut this = Self::new();
+        this.extend(iter);
+        this
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FutureMap;
+    use core::future;
+    use futures_lite::prelude::*;
+
+    #[test]
+    fn smoke() {
+        futures_lite::future::block_on(async {
+            let mut map = FutureMap::new();
+            map.insert_with_key('a', future::ready(2));
+            map.insert_with_key('b', future::ready(4));
+
+            let mut out = 0;
+            while let Some((key, num)) = map.next().await {
+                assert!(matches!((key, num), ('a', 2) | ('b', 4)));
+                out += num;
+            }
+            assert_eq!(out, 6);
+            assert!(map.is_empty());
+            assert!(!map.contains_key(&'a'));
+        });
+    }
+
+    #[test]
+    fn replace_and_remove() {
+        futures_lite::future::block_on(async {
+            let mut map: FutureMap<_, future::Ready<u8>> = FutureMap::new();
+            assert!(map.insert_with_key(1, future::ready(1)));
+            assert!(!map.insert_with_key(1, future::ready(2)));
+            assert!(map.insert_with_key(2, future::ready(3)));
+            assert!(map.remove(&2));
+            assert!(!map.remove(&2));
+
+            assert_eq!(map.next().await, Some((1, 2)));
+            assert_eq!(map.next().await, None);
+        });
+    }
+}
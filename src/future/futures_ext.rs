@@ -5,7 +5,11 @@ use futures_core::Future;
 
 use super::join::tuple::Join2;
 use super::race::tuple::Race2;
-use super::WaitUntil;
+#[cfg(feature = "std")]
+use super::CatchPanic;
+use super::{Timeout, Until, WaitUntil};
+use crate::time::Timer;
+use core::time::Duration;
 
 /// An extension trait for the `Future` trait.
 pub trait FutureExt: Future {
@@ -58,6 +62,141 @@ pub trait FutureExt: Future {
     {
         WaitUntil::new(self, deadline.into_future())
     }
+
+    /// Resolve the future, or return an error if it takes longer than
+    /// `duration`.
+    ///
+    /// The duration is measured using the given [`Timer`], so this works with
+    /// any runtime.
+    ///
+    /// This is like the `timeout` method of other future extension traits,
+    /// such as the one in `futures-time`, but has a different name so it
+    /// doesn't clash with them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(miri)]fn main() {}
+    /// # #[cfg(not(miri))]
+    /// # fn main() {
+    /// use async_io::Timer;
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::{self, block_on};
+    /// use std::time::Duration;
+    ///
+    /// block_on(async {
+    ///     let duration = Duration::from_millis(100);
+    ///
+    ///     let res = async { "meow" }.timeout_after(duration, Timer::after).await;
+    ///     assert_eq!(res, Ok("meow"));
+    ///
+    ///     let res = future::pending::<()>()
+    ///         .timeout_after(duration, Timer::after)
+    ///         .await;
+    ///     assert!(res.is_err());
+    /// });
+    /// # }
+    /// ```
+    fn timeout_after<T>(self, duration: Duration, timer: T) -> Timeout<Self, T::Sleep>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        Timeout::new(self, timer.sleep(duration))
+    }
+
+    /// Resolve the future, or return an error if the deadline resolves first.
+    ///
+    /// In addition to using a time source as a deadline, any future can be
+    /// used as a deadline too. If both futures are ready at the same time,
+    /// the output of this future is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::{self, block_on};
+    ///
+    /// block_on(async {
+    ///     let res = future::pending::<()>().deadline(async {}).await;
+    ///     assert!(res.is_err());
+    /// });
+    /// ```
+    fn deadline<D>(self, deadline: D) -> Timeout<Self, D::IntoFuture>
+    where
+        Self: Sized,
+        D: IntoFuture,
+    {
+        Timeout::new(self, deadline.into_future())
+    }
+
+    /// Resolve the future, or return `None` if it's cancelled first.
+    ///
+    /// Any future can be used to cancel the future, such as a
+    /// [`StopToken`]. If both futures are ready at the same time, the output
+    /// of this future is returned.
+    ///
+    /// [`StopToken`]: crate::sync::StopToken
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_concurrency::sync::StopSource;
+    /// use futures_lite::future::{self, block_on};
+    ///
+    /// block_on(async {
+    ///     let source = StopSource::new();
+    ///     let token = source.token();
+    ///     source.stop();
+    ///
+    ///     let res = future::pending::<()>().until(token).await;
+    ///     assert_eq!(res, None);
+    /// });
+    /// ```
+    fn until<D>(self, deadline: D) -> Until<Self, D::IntoFuture>
+    where
+        Self: Sized,
+        D: IntoFuture,
+    {
+        Until::new(self, deadline.into_future())
+    }
+
+    /// Catch any panic which occurs while polling the future, and return it
+    /// as an error.
+    ///
+    /// This keeps a single panicking future from unwinding through a `join`
+    /// or group and tearing down the futures next to it. Futures which aren't
+    /// [`UnwindSafe`][std::panic::UnwindSafe] can be wrapped in
+    /// [`AssertUnwindSafe`][std::panic::AssertUnwindSafe].
+    ///
+    /// This works like the `catch_unwind` methods of other future extension
+    /// traits, such as the ones in `futures` and `futures-lite`, but has a
+    /// different name so it doesn't clash with them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::{self, block_on};
+    /// use std::panic::AssertUnwindSafe;
+    ///
+    /// block_on(async {
+    ///     let a = AssertUnwindSafe(async { panic!("oh no") }).catch_panic();
+    ///     let b = future::ready(2).catch_panic();
+    ///     let (a, b): (Result<(), _>, _) = (a, b).join().await;
+    ///
+    ///     assert!(a.is_err());
+    ///     assert_eq!(b.unwrap(), 2);
+    /// });
+    /// ```
+    #[cfg(feature = "std")]
+    fn catch_panic(self) -> CatchPanic<Self>
+    where
+        Self: Sized + std::panic::UnwindSafe,
+    {
+        CatchPanic::new(self)
+    }
 }
 
 impl<F1> FutureExt for F1
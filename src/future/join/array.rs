@@ -1,10 +1,8 @@
 use super::Join as JoinTrait;
-use crate::utils::{FutureArray, OutputArray, PollArray, WakerArray};
+use crate::utils::{trace, MaybeDoneArray, PollArray, WakerArray};
 
 use core::fmt;
 use core::future::{Future, IntoFuture};
-use core::mem::ManuallyDrop;
-use core::ops::DerefMut;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
@@ -27,16 +25,15 @@ where
     consumed: bool,
     /// The number of futures which are currently still in-flight
     pending: usize,
-    /// The output data, to be returned after the future completes
-    items: OutputArray<<Fut as Future>::Output, N>,
     /// A structure holding the waker passed to the future, and the various
     /// sub-wakers passed to the contained futures.
     wakers: WakerArray<N>,
     /// The individual poll state of each future.
     state: PollArray<N>,
     #[pin]
-    /// The array of futures passed to the structure.
-    futures: FutureArray<Fut, N>,
+    /// The array of futures passed to the structure, which are replaced by
+    /// their outputs as they complete.
+    futures: MaybeDoneArray<Fut, <Fut as Future>::Output, N>,
 }
 
 impl<Fut, const N: usize> Join<Fut, N>
@@ -48,10 +45,9 @@ where
         Join {
             consumed: false,
             pending: N,
-            items: OutputArray::uninit(),
             wakers: WakerArray::new(),
             state: PollArray::new_pending(),
-            futures: FutureArray::new(futures),
+            futures: MaybeDoneArray::new(futures),
         }
     }
 }
@@ -86,7 +82,7 @@ where
 
     #[inline]
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let this = self.project();
+        let mut this = self.project();
 
         assert!(
             !*this.consumed,
@@ -101,9 +97,9 @@ where
         }
 
         // Poll all ready futures
-        for (i, mut fut) in this.futures.iter().enumerate() {
+        for (i, mut slot) in this.futures.as_mut().iter().enumerate() {
             if this.state[i].is_pending() && readiness.clear_ready(i) {
-                // unlock readiness so we don't deadlock when polling
+                // release readiness so we can borrow the wakers when polling
                 #[allow(clippy::drop_non_drop)]
                 drop(readiness);
 
@@ -111,21 +107,21 @@ where
                 let mut cx = Context::from_waker(this.wakers.get(i).unwrap());
 
                 // Poll the future
+                trace!(index = i, "join: polling future");
                 // SAFETY: the future's state was "pending", so it's safe to poll
-                if let Poll::Ready(value) = unsafe {
-                    fut.as_mut()
-                        .map_unchecked_mut(|t| t.deref_mut())
-                        .poll(&mut cx)
-                } {
-                    this.items.write(i, value);
+                if let Poll::Ready(value) = unsafe { slot.as_mut().poll(&mut cx) } {
+                    // SAFETY: the future has completed, so we'll no longer poll
+                    // it. It's dropped in place and replaced by its output.
+                    // It's no longer tracked as pending by then, so it isn't
+                    // dropped a second time if its destructor panics.
+                    this.state[i].set_none();
+                    unsafe { slot.complete(value) };
                     this.state[i].set_ready();
                     *this.pending -= 1;
-                    // SAFETY: the future state has been changed to "ready" which
-                    // means we'll no longer poll the future, so it's safe to drop
-                    unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
+                    trace!(index = i, pending = *this.pending, "join: future completed");
                 }
 
-                // Lock readiness so we can use it again
+                // Reacquire readiness so we can use it again
                 readiness = this.wakers.readiness();
             }
         }
@@ -144,7 +140,7 @@ where
 
             // SAFETY: we've checked with the state that all of our outputs have been
             // filled, which means we're ready to take the data and assume it's initialized.
-            Poll::Ready(unsafe { this.items.take() })
+            Poll::Ready(unsafe { this.futures.take() })
         } else {
             Poll::Pending
         }
@@ -164,14 +160,15 @@ where
         for i in this.state.ready_indexes() {
             // SAFETY: we've just filtered down to *only* the initialized values.
             // We can assume they're initialized, and this is where we drop them.
-            unsafe { this.items.drop(i) };
+            unsafe { this.futures.as_mut().drop_output(i) };
         }
 
         // Drop all pending futures.
         for i in this.state.pending_indexes() {
             // SAFETY: we've just filtered down to *only* the pending futures,
             // which have not yet been dropped.
-            unsafe { this.futures.as_mut().drop(i) };
+            unsafe { this.futures.as_mut().drop_future(i) };
+            trace!(index = i, "join: future cancelled");
         }
     }
 }
@@ -199,6 +196,21 @@ mod test {
         });
     }
 
+    #[test]
+    fn outputs_reuse_future_slots() {
+        use core::mem::size_of;
+
+        // The futures and their outputs share storage, so the join doesn't
+        // need room for both of them.
+        type Fut = future::Ready<[u8; 256]>;
+        assert!(size_of::<Join<Fut, 4>>() < 4 * (size_of::<Fut>() + 256));
+
+        futures_lite::future::block_on(async {
+            let outputs = [1, 2, 3, 4].map(|n| future::ready([n; 256])).join().await;
+            assert_eq!(outputs.map(|output| output[255]), [1, 2, 3, 4]);
+        });
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn debug() {
@@ -216,4 +228,20 @@ mod test {
         let _ = fut.as_mut().poll(&mut cx);
         assert_eq!(format!("{:?}", fut), "[None, None]");
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn panicking_drop_is_not_repeated() {
+        use crate::utils::PanicOnDrop;
+        use std::cell::Cell;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let drops = Rc::new(Cell::new(0));
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            futures_lite::future::block_on([PanicOnDrop::new((), drops.clone())].join())
+        }));
+        assert!(res.is_err());
+        assert_eq!(drops.get(), 1);
+    }
 }
@@ -0,0 +1,94 @@
+use super::vec::Join as VecJoin;
+use super::Join as JoinTrait;
+
+use alloc::collections::BTreeMap;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use pin_project::pin_project;
+
+/// A future which waits for all futures in a map to complete.
+///
+/// This `struct` is created by the [`join`] method on the [`Join`] trait. See
+/// its documentation for more.
+///
+/// [`join`]: crate::future::Join::join
+/// [`Join`]: crate::future::Join
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct Join<K, Fut>
+where
+    Fut: Future,
+{
+    keys: Vec<K>,
+    #[pin]
+    futures: VecJoin<Fut>,
+}
+
+impl<K, Fut> JoinTrait for BTreeMap<K, Fut>
+where
+    K: Ord,
+    Fut: IntoFuture,
+{
+    type Output = BTreeMap<K, Fut::Output>;
+    type Future = Join<K, Fut::IntoFuture>;
+
+    fn join(self) -> Self::Future {
+        let (keys, futures) = self
+            .into_iter()
+            .map(|(key, fut)| (key, fut.into_future()))
+            .unzip();
+        Join {
+            keys,
+            futures: VecJoin::new(futures),
+        }
+    }
+}
+
+impl<K, Fut> fmt::Debug for Join<K, Fut>
+where
+    K: fmt::Debug,
+    Fut: Future + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Join")
+            .field("keys", &self.keys)
+            .field("futures", &self.futures)
+            .finish()
+    }
+}
+
+impl<K, Fut> Future for Join<K, Fut>
+where
+    K: Ord,
+    Fut: Future,
+{
+    type Output = BTreeMap<K, Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let outputs = ready!(this.futures.poll(cx));
+        let keys = core::mem::take(this.keys);
+        Poll::Ready(keys.into_iter().zip(outputs).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future;
+
+    #[test]
+    fn smoke() {
+        futures_lite::future::block_on(async {
+            let futures = BTreeMap::from([(1, future::ready("a")), (2, future::ready("b"))]);
+            let outputs = futures.join().await;
+            assert_eq!(outputs, BTreeMap::from([(1, "a"), (2, "b")]));
+        });
+    }
+}
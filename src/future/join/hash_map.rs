@@ -0,0 +1,102 @@
+use super::vec::Join as VecJoin;
+use super::Join as JoinTrait;
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use std::collections::HashMap;
+
+use pin_project::pin_project;
+
+/// A future which waits for all futures in a map to complete.
+///
+/// This `struct` is created by the [`join`] method on the [`Join`] trait. See
+/// its documentation for more.
+///
+/// [`join`]: crate::future::Join::join
+/// [`Join`]: crate::future::Join
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct Join<K, Fut, S>
+where
+    Fut: Future,
+{
+    keys: Vec<K>,
+    #[pin]
+    futures: VecJoin<Fut>,
+    _hasher: PhantomData<fn() -> S>,
+}
+
+impl<K, Fut, S> JoinTrait for HashMap<K, Fut, S>
+where
+    K: Eq + Hash,
+    Fut: IntoFuture,
+    S: BuildHasher + Default,
+{
+    type Output = HashMap<K, Fut::Output, S>;
+    type Future = Join<K, Fut::IntoFuture, S>;
+
+    fn join(self) -> Self::Future {
+        let (keys, futures) = self
+            .into_iter()
+            .map(|(key, fut)| (key, fut.into_future()))
+            .unzip();
+        Join {
+            keys,
+            futures: VecJoin::new(futures),
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<K, Fut, S> fmt::Debug for Join<K, Fut, S>
+where
+    K: fmt::Debug,
+    Fut: Future + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Join")
+            .field("keys", &self.keys)
+            .field("futures", &self.futures)
+            .finish()
+    }
+}
+
+impl<K, Fut, S> Future for Join<K, Fut, S>
+where
+    K: Eq + Hash,
+    Fut: Future,
+    S: BuildHasher + Default,
+{
+    type Output = HashMap<K, Fut::Output, S>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let outputs = ready!(this.futures.poll(cx));
+        let keys = core::mem::take(this.keys);
+        Poll::Ready(keys.into_iter().zip(outputs).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future;
+
+    #[test]
+    fn smoke() {
+        futures_lite::future::block_on(async {
+            let mut futures = HashMap::new();
+            futures.insert("a", future::ready(1));
+            futures.insert("b", future::ready(2));
+
+            let outputs = futures.join().await;
+            assert_eq!(outputs.len(), 2);
+            assert_eq!(outputs["a"], 1);
+            assert_eq!(outputs["b"], 2);
+        });
+    }
+}
@@ -1,6 +1,10 @@
-use core::future::Future;
+use core::future::{Future, IntoFuture};
 
 pub(crate) mod array;
+#[cfg(feature = "alloc")]
+pub(crate) mod btree_map;
+#[cfg(feature = "std")]
+pub(crate) mod hash_map;
 pub(crate) mod tuple;
 #[cfg(feature = "alloc")]
 pub(crate) mod vec;
@@ -63,3 +67,28 @@ pub trait Join {
     /// This function returns a new future which polls all futures concurrently.
     fn join(self) -> Self::Future;
 }
+
+/// Wait for two futures to complete.
+///
+/// This is a shorthand for calling [`Join::join`] on a pair of futures. To
+/// wait for more futures, call `join` on a tuple, array or vector of futures
+/// instead.
+///
+/// # Example
+///
+/// ```rust
+/// # futures::executor::block_on(async {
+/// use futures_concurrency::future;
+///
+/// let a = core::future::ready(1);
+/// let b = async { "hello" };
+/// assert_eq!(future::join(a, b).await, (1, "hello"));
+/// # })
+/// ```
+pub fn join<A, B>(a: A, b: B) -> <(A, B) as Join>::Future
+where
+    A: IntoFuture,
+    B: IntoFuture,
+{
+    (a, b).join()
+}
@@ -1,5 +1,5 @@
 use super::Join as JoinTrait;
-use crate::utils::{PollArray, WakerArray};
+use crate::utils::{trace, PollArray, WakerArray};
 
 use core::fmt::{self, Debug};
 use core::future::{Future, IntoFuture};
@@ -17,7 +17,7 @@ use pin_project::{pin_project, pinned_drop};
 //
 // This is implemented as a tt-muncher of the future name `$($F:ident)`
 // and the future index `$($rest)`, taking advantage that we only support
-// tuples up to  12 elements
+// tuples up to 24 elements (or 12, without the `large-tuples` feature)
 //
 // # References
 // TT Muncher: https://veykril.github.io/tlborm/decl-macros/patterns/tt-muncher.html
@@ -34,6 +34,7 @@ macro_rules! unsafe_poll {
                 $this.outputs.$fut_idx.write(value);
                 *$this.completed += 1;
                 $this.state[$fut_idx].set_ready();
+                trace!(index = $fut_idx, "join: future completed");
                 // SAFETY: the future state has been changed to "ready" which
                 // means we'll no longer poll the future, so it's safe to drop
                 unsafe { ManuallyDrop::drop($futures.$fut_name.as_mut().get_unchecked_mut()) };
@@ -47,7 +48,7 @@ macro_rules! unsafe_poll {
 
     // macro start
     ($iteration:ident, $this:ident, $futures:ident, $cx:ident, $LEN:ident, $($F:ident,)+) => {
-        unsafe_poll!(@inner $iteration, $this, $futures, $cx, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11);
+        unsafe_poll!(@inner $iteration, $this, $futures, $cx, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23);
     };
 }
 
@@ -69,7 +70,7 @@ macro_rules! drop_initialized_values {
 
     // macro start
     ($($outs:ident,)+ | $states:expr) => {
-        drop_initialized_values!(@drop $($outs,)+ | $states, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11,);
+        drop_initialized_values!(@drop $($outs,)+ | $states, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,);
     };
 }
 
@@ -83,6 +84,7 @@ macro_rules! drop_pending_futures {
             // SAFETY: we've just filtered down to *only* the initialized values.
             // We can assume they're initialized, and this is where we drop them.
             unsafe { ManuallyDrop::drop(&mut futures.$fut_name) };
+            trace!(index = $fut_idx, "join: future cancelled");
         }
         drop_pending_futures!(@inner $states, $futures, $($F)* | $($rest)*);
     };
@@ -92,7 +94,7 @@ macro_rules! drop_pending_futures {
 
     // macro start
     ($states:ident, $futures:ident, $($F:ident,)+) => {
-        drop_pending_futures!(@inner $states, $futures, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11);
+        drop_pending_futures!(@inner $states, $futures, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23);
     };
 }
 
@@ -211,7 +213,7 @@ macro_rules! impl_join_tuple {
                         continue;
                     }
 
-                    // unlock readiness so we don't deadlock when polling
+                    // release readiness so we can borrow the wakers when polling
                     #[allow(clippy::drop_non_drop)]
                     drop(readiness);
 
@@ -219,6 +221,7 @@ macro_rules! impl_join_tuple {
                     let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
 
                     // generate the needed code to poll `futures.{index}`
+                    trace!(index, "join: polling future");
                     // SAFETY: the future's state should be "pending", so it's safe to poll
                     unsafe_poll!(index, this, futures, cx, LEN, $($F,)+);
 
@@ -290,6 +293,30 @@ impl_join_tuple! { join9 Join9 A B C D E F G H I }
 impl_join_tuple! { join10 Join10 A B C D E F G H I J }
 impl_join_tuple! { join11 Join11 A B C D E F G H I J K }
 impl_join_tuple! { join12 Join12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join13 Join13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join14 Join14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join15 Join15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join16 Join16 A B C D E F G H I J K L M N O P }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join17 Join17 A B C D E F G H I J K L M N O P Q }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join18 Join18 A B C D E F G H I J K L M N O P Q R }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join19 Join19 A B C D E F G H I J K L M N O P Q R U }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join20 Join20 A B C D E F G H I J K L M N O P Q R U V }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join21 Join21 A B C D E F G H I J K L M N O P Q R U V W }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join22 Join22 A B C D E F G H I J K L M N O P Q R U V W X }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join23 Join23 A B C D E F G H I J K L M N O P Q R U V W X Y }
+#[cfg(feature = "large-tuples")]
+impl_join_tuple! { join24 Join24 A B C D E F G H I J K L M N O P Q R U V W X Y Z }
 
 #[cfg(test)]
 mod test {
@@ -366,4 +393,43 @@ mod test {
             assert!(*flag.borrow());
         })
     }
+
+    #[test]
+    #[cfg(feature = "large-tuples")]
+    fn join_24() {
+        futures_lite::future::block_on(async {
+            let f = future::ready;
+            let (a, b, c, d, e, f_, g, h, i, j, k, l, m, n, o, p, q, r, s, t, u, v, w, x) = (
+                f(0),
+                f(1),
+                f(2),
+                f(3),
+                f(4),
+                f(5),
+                f(6),
+                f(7),
+                f(8),
+                f(9),
+                f(10),
+                f(11),
+                f(12),
+                f(13),
+                f(14),
+                f(15),
+                f(16),
+                f(17),
+                f(18),
+                f(19),
+                f(20),
+                f(21),
+                f(22),
+                f(23),
+            )
+                .join()
+                .await;
+            let sum = a + b + c + d + e + f_ + g + h + i + j + k + l;
+            let sum = sum + m + n + o + p + q + r + s + t + u + v + w + x;
+            assert_eq!(sum, (0..24).sum());
+        });
+    }
 }
@@ -1,13 +1,12 @@
 use super::Join as JoinTrait;
-use crate::utils::{FutureVec, OutputVec, PollVec, WakerVec};
+use crate::future::FutureGroup;
+use crate::utils::{trace, MaybeDoneVec, PollVec, WakerVec};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 
 use core::fmt;
 use core::future::{Future, IntoFuture};
-use core::mem::ManuallyDrop;
-use core::ops::DerefMut;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
@@ -28,11 +27,10 @@ where
 {
     consumed: bool,
     pending: usize,
-    items: OutputVec<<Fut as Future>::Output>,
     wakers: WakerVec,
     state: PollVec,
     #[pin]
-    futures: FutureVec<Fut>,
+    futures: MaybeDoneVec<Fut, <Fut as Future>::Output>,
 }
 
 impl<Fut> Join<Fut>
@@ -44,14 +42,67 @@ where
         Join {
             consumed: false,
             pending: len,
-            items: OutputVec::uninit(len),
             wakers: WakerVec::new(len),
             state: PollVec::new_pending(len),
-            futures: FutureVec::new(futures),
+            futures: MaybeDoneVec::new(futures),
         }
     }
 }
 
+impl<Fut> Join<Fut>
+where
+    Fut: Future + Unpin,
+{
+    /// Convert the join into a [`FutureGroup`], so that more futures can be
+    /// inserted later on.
+    ///
+    /// The futures which are still pending are moved into the group. The
+    /// outputs of futures which have already completed are returned alongside
+    /// it, each with the index of the future it came from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::future::block_on;
+    /// use futures_lite::StreamExt;
+    /// use std::future;
+    ///
+    /// block_on(async {
+    ///     let join = vec![future::ready(1), future::ready(2)].join();
+    ///     let (mut group, outputs) = join.into_group();
+    ///     assert!(outputs.is_empty());
+    ///     group.insert(future::ready(3));
+    ///
+    ///     let mut out: Vec<_> = group.collect().await;
+    ///     out.sort();
+    ///     assert_eq!(out, vec![1, 2, 3]);
+    /// })
+    /// ```
+    pub fn into_group(mut self) -> (FutureGroup<Fut>, Vec<(usize, Fut::Output)>) {
+        // SAFETY: the futures are `Unpin`, and we never move anything else out
+        // of the join.
+        let mut this = unsafe { Pin::new_unchecked(&mut self) }.project();
+        let mut group = FutureGroup::with_capacity(*this.pending);
+        let mut outputs = Vec::new();
+        for (i, state) in this.state.iter_mut().enumerate() {
+            if state.is_pending() {
+                // Mark the slot as "none" first, so the destructor won't touch
+                // it even if inserting the future into the group panics.
+                state.set_none();
+                // SAFETY: the future was pending, so its slot holds the future.
+                group.insert(unsafe { this.futures.as_mut().take_future(i) });
+            } else if state.is_ready() {
+                state.set_none();
+                // SAFETY: the future was ready, so its slot holds the output.
+                outputs.push((i, unsafe { this.futures.as_mut().take_output(i) }));
+            }
+        }
+        *this.pending = 0;
+        (group, outputs)
+    }
+}
+
 impl<Fut> JoinTrait for Vec<Fut>
 where
     Fut: IntoFuture,
@@ -97,9 +148,9 @@ where
         // Poll all ready futures
         let futures = this.futures.as_mut();
         let states = &mut this.state[..];
-        for (i, mut fut) in futures.iter().enumerate() {
+        for (i, mut slot) in futures.iter().enumerate() {
             if states[i].is_pending() && readiness.clear_ready(i) {
-                // unlock readiness so we don't deadlock when polling
+                // release readiness so we can borrow the wakers when polling
                 #[allow(clippy::drop_non_drop)]
                 drop(readiness);
 
@@ -107,21 +158,21 @@ where
                 let mut cx = Context::from_waker(this.wakers.get(i).unwrap());
 
                 // Poll the future
+                trace!(index = i, "join: polling future");
                 // SAFETY: the future's state was "pending", so it's safe to poll
-                if let Poll::Ready(value) = unsafe {
-                    fut.as_mut()
-                        .map_unchecked_mut(|t| t.deref_mut())
-                        .poll(&mut cx)
-                } {
-                    this.items.write(i, value);
+                if let Poll::Ready(value) = unsafe { slot.as_mut().poll(&mut cx) } {
+                    // SAFETY: the future has completed, so we'll no longer poll
+                    // it. It's dropped in place and replaced by its output.
+                    // It's no longer tracked as pending by then, so it isn't
+                    // dropped a second time if its destructor panics.
+                    states[i].set_none();
+                    unsafe { slot.complete(value) };
                     states[i].set_ready();
                     *this.pending -= 1;
-                    // SAFETY: the future state has been changed to "ready" which
-                    // means we'll no longer poll the future, so it's safe to drop
-                    unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
+                    trace!(index = i, pending = *this.pending, "join: future completed");
                 }
 
-                // Lock readiness so we can use it again
+                // Reacquire readiness so we can use it again
                 readiness = this.wakers.readiness();
             }
         }
@@ -140,7 +191,7 @@ where
 
             // SAFETY: we've checked with the state that all of our outputs have been
             // filled, which means we're ready to take the data and assume it's initialized.
-            Poll::Ready(unsafe { this.items.take() })
+            Poll::Ready(unsafe { this.futures.take() })
         } else {
             Poll::Pending
         }
@@ -160,14 +211,15 @@ where
         for i in this.state.ready_indexes() {
             // SAFETY: we've just filtered down to *only* the initialized values.
             // We can assume they're initialized, and this is where we drop them.
-            unsafe { this.items.drop(i) };
+            unsafe { this.futures.as_mut().drop_output(i) };
         }
 
         // Drop all pending futures.
         for i in this.state.pending_indexes() {
             // SAFETY: we've just filtered down to *only* the pending futures,
             // which have not yet been dropped.
-            unsafe { this.futures.as_mut().drop(i) };
+            unsafe { this.futures.as_mut().drop_future(i) };
+            trace!(index = i, "join: future cancelled");
         }
     }
 }
@@ -210,4 +262,49 @@ mod test {
         let _ = fut.as_mut().poll(&mut cx);
         assert_eq!(format!("{:?}", fut), "[None, None]");
     }
+
+    #[test]
+    fn into_group_keeps_pending_futures_and_outputs() {
+        use futures_lite::future::{poll_once, yield_now, FutureExt};
+        use futures_lite::StreamExt;
+
+        futures_lite::future::block_on(async {
+            let mut fut = vec![
+                future::ready(1).boxed(),
+                async {
+                    yield_now().await;
+                    2
+                }
+                .boxed(),
+            ]
+            .join();
+            assert!(poll_once(&mut fut).await.is_none());
+
+            // The first future already completed, so only the second one moves
+            // into the group. The output of the first one is returned.
+            let (mut group, outputs) = fut.into_group();
+            assert_eq!(outputs, vec![(0, 1)]);
+            assert_eq!(group.len(), 1);
+            group.insert(future::ready(3).boxed());
+            let mut out: Vec<_> = group.collect().await;
+            out.sort();
+            assert_eq!(out, vec![2, 3]);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn panicking_drop_is_not_repeated() {
+        use crate::utils::PanicOnDrop;
+        use std::cell::Cell;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let drops = Rc::new(Cell::new(0));
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            futures_lite::future::block_on(vec![PanicOnDrop::new((), drops.clone())].join())
+        }));
+        assert!(res.is_err());
+        assert_eq!(drops.get(), 1);
+    }
 }
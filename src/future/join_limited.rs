@@ -0,0 +1,246 @@
+use crate::future::FutureGroup;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::vec;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+/// Wait for all futures to complete, running at most a fixed number of them
+/// at the same time.
+pub trait JoinLimited {
+    /// The resulting output type.
+    type Output;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Self::Output>;
+
+    /// Waits for all futures to complete, polling at most `limit` of them
+    /// concurrently.
+    ///
+    /// Futures are started in order, and the next future is started as soon
+    /// as a running future completes. The outputs are returned in the same
+    /// order as the futures they came from.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `limit` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # futures::executor::block_on(async {
+    /// use core::future::ready;
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// let futs: Vec<_> = (0..100).map(ready).collect();
+    /// let outputs = futs.join_limited(8).await;
+    /// assert_eq!(outputs, (0..100).collect::<Vec<_>>());
+    /// # })
+    /// ```
+    fn join_limited(self, limit: usize) -> Self::Future;
+}
+
+impl<Fut> JoinLimited for Vec<Fut>
+where
+    Fut: IntoFuture,
+{
+    type Output = Vec<Fut::Output>;
+    type Future = JoinLimitedFuture<Fut::IntoFuture>;
+
+    fn join_limited(self, limit: usize) -> Self::Future {
+        JoinLimitedFuture::new(self, limit)
+    }
+}
+
+/// A future which waits for all futures to complete, running at most a fixed
+/// number of them at the same time.
+///
+/// This `struct` is created by the [`join_limited`] method on the
+/// [`JoinLimited`] trait. See its documentation for more.
+///
+/// [`join_limited`]: crate::future::JoinLimited::join_limited
+/// [`JoinLimited`]: crate::future::JoinLimited
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct JoinLimitedFuture<Fut>
+where
+    Fut: Future,
+{
+    /// The futures which haven't been started yet.
+    queued: vec::IntoIter<Fut>,
+    /// The index of the next future to start.
+    next: usize,
+    /// The futures which are currently running.
+    running: FutureGroup<Indexed<Fut>>,
+    /// The outputs of the futures which have completed, in input order.
+    outputs: Vec<Option<Fut::Output>>,
+    limit: usize,
+    consumed: bool,
+}
+
+impl<Fut> JoinLimitedFuture<Fut>
+where
+    Fut: Future,
+{
+    fn new<I>(futures: Vec<I>, limit: usize) -> Self
+    where
+        I: IntoFuture<IntoFuture = Fut>,
+    {
+        assert!(limit > 0, "join_limited limit must be non-zero");
+        let len = futures.len();
+        let queued: Vec<_> = futures.into_iter().map(IntoFuture::into_future).collect();
+        Self {
+            queued: queued.into_iter(),
+            next: 0,
+            running: FutureGroup::with_capacity(limit.min(len)),
+            outputs: (0..len).map(|_| None).collect(),
+            limit,
+            consumed: false,
+        }
+    }
+}
+
+impl<Fut> fmt::Debug for JoinLimitedFuture<Fut>
+where
+    Fut: Future,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinLimitedFuture")
+            .field("queued", &self.queued.len())
+            .field("running", &self.running.len())
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<Fut> Future for JoinLimitedFuture<Fut>
+where
+    Fut: Future,
+{
+    type Output = Vec<Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // None of the fields are pinned: the queued futures haven't been
+        // polled yet, and the running futures are pinned by the group.
+        let this = self.project();
+
+        assert!(
+            !*this.consumed,
+            "Futures must not be polled after completing"
+        );
+
+        loop {
+            // Start new futures until we've reached the limit.
+            while this.running.len() < *this.limit {
+                let Some(future) = this.queued.next() else {
+                    break;
+                };
+                this.running.insert(Indexed {
+                    index: *this.next,
+                    future,
+                });
+                *this.next += 1;
+            }
+
+            match Pin::new(&mut *this.running).poll_next(cx) {
+                Poll::Ready(Some((index, output))) => this.outputs[index] = Some(output),
+                // Nothing is running, and nothing is left to start.
+                Poll::Ready(None) => {
+                    *this.consumed = true;
+                    let outputs = core::mem::take(this.outputs);
+                    return Poll::Ready(
+                        outputs
+                            .into_iter()
+                            .map(|output| output.expect("every future has completed"))
+                            .collect(),
+                    );
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// A future which pairs its output with its position in the input.
+#[pin_project]
+pub(crate) struct Indexed<Fut> {
+    pub(crate) index: usize,
+    #[pin]
+    pub(crate) future: Fut,
+}
+
+impl<Fut: Future> Future for Indexed<Fut> {
+    type Output = (usize, Fut::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        this.future.poll(cx).map(|output| (*this.index, output))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+    use core::future;
+    use futures_lite::future::yield_now;
+
+    #[test]
+    fn smoke() {
+        futures_lite::future::block_on(async {
+            let futs = vec![future::ready("hello"), future::ready("world")];
+            assert_eq!(futs.join_limited(1).await, vec!["hello", "world"]);
+        });
+    }
+
+    #[test]
+    fn empty() {
+        futures_lite::future::block_on(async {
+            let futs: Vec<future::Ready<()>> = vec![];
+            assert_eq!(futs.join_limited(4).await, vec![]);
+        });
+    }
+
+    #[test]
+    fn respects_limit() {
+        futures_lite::future::block_on(async {
+            let running = Rc::new(Cell::new(0));
+            let peak = Rc::new(Cell::new(0));
+            let futs: Vec<_> = (0..20)
+                .map(|i| {
+                    let running = running.clone();
+                    let peak = peak.clone();
+                    async move {
+                        running.set(running.get() + 1);
+                        peak.set(peak.get().max(running.get()));
+                        // Later futures finish sooner, so outputs complete
+                        // out of order.
+                        for _ in 0..(20 - i) % 4 {
+                            yield_now().await;
+                        }
+                        running.set(running.get() - 1);
+                        i
+                    }
+                })
+                .collect();
+
+            let outputs = futs.join_limited(3).await;
+            assert_eq!(outputs, (0..20).collect::<Vec<_>>());
+            assert_eq!(peak.get(), 3);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "join_limited limit must be non-zero")]
+    fn zero_limit() {
+        drop(vec![future::ready(())].join_limited(0));
+    }
+}
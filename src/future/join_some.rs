@@ -0,0 +1,229 @@
+use super::TryJoin;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project::pin_project;
+
+/// Wait for all futures to return `Some`, or abort early on `None`.
+///
+/// This is the counterpart of [`TryJoin`] for futures which return an
+/// `Option`. In the case a future returns `None`, all other futures will be
+/// cancelled. If futures have been completed, their outputs will be
+/// discarded.
+pub trait JoinSome {
+    /// The resulting output type.
+    type Output;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Option<Self::Output>>;
+
+    /// Waits for multiple futures to complete, either returning when all
+    /// futures return `Some`, or return early when any future returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # futures::executor::block_on(async {
+    /// use core::future::ready;
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// let a = async { Some(1) };
+    /// let b = async { Some("hello") };
+    /// assert_eq!((a, b).join_some().await, Some((1, "hello")));
+    ///
+    /// let futs = [ready(Some(1)), ready(None)];
+    /// assert_eq!(futs.join_some().await, None);
+    /// # })
+    /// ```
+    fn join_some(self) -> Self::Future;
+}
+
+/// A future which waits for all futures to return `Some`, or abort early on `None`.
+///
+/// This `struct` is created by the [`join_some`] method on the [`JoinSome`]
+/// trait. See its documentation for more.
+///
+/// [`join_some`]: crate::future::JoinSome::join_some
+/// [`JoinSome`]: crate::future::JoinSome
+#[derive(Debug)]
+#[pin_project]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct JoinSomeFuture<Fut> {
+    #[pin]
+    future: Fut,
+}
+
+impl<Fut, T, E> Future for JoinSomeFuture<Fut>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().future.poll(cx).map(Result::ok)
+    }
+}
+
+/// A future which maps `Option` outputs to `Result`, so that they can be
+/// passed to the `Result`-based operations.
+#[derive(Debug)]
+#[pin_project]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SomeOk<Fut> {
+    #[pin]
+    future: Fut,
+}
+
+impl<Fut: Future> SomeOk<Fut> {
+    pub(crate) fn new(future: impl IntoFuture<IntoFuture = Fut>) -> Self {
+        Self {
+            future: future.into_future(),
+        }
+    }
+}
+
+impl<Fut, T> Future for SomeOk<Fut>
+where
+    Fut: Future<Output = Option<T>>,
+{
+    type Output = Result<T, ()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project()
+            .future
+            .poll(cx)
+            .map(|output| output.ok_or(()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Fut, T> JoinSome for Vec<Fut>
+where
+    Fut: IntoFuture<Output = Option<T>>,
+{
+    type Output = Vec<T>;
+    type Future = JoinSomeFuture<<Vec<SomeOk<Fut::IntoFuture>> as TryJoin>::Future>;
+
+    fn join_some(self) -> Self::Future {
+        let futures: Vec<_> = self.into_iter().map(SomeOk::new).collect();
+        JoinSomeFuture {
+            future: futures.try_join(),
+        }
+    }
+}
+
+impl<Fut, T, const N: usize> JoinSome for [Fut; N]
+where
+    Fut: IntoFuture<Output = Option<T>>,
+{
+    type Output = [T; N];
+    type Future = JoinSomeFuture<<[SomeOk<Fut::IntoFuture>; N] as TryJoin>::Future>;
+
+    fn join_some(self) -> Self::Future {
+        JoinSomeFuture {
+            future: self.map(SomeOk::new).try_join(),
+        }
+    }
+}
+
+macro_rules! impl_join_some_tuple {
+    ($(($F:ident $T:ident))+) => {
+        impl<$($F, $T),+> JoinSome for ($($F,)+)
+        where
+            $($F: IntoFuture<Output = Option<$T>>,)+
+        {
+            type Output = ($($T,)+);
+            type Future = JoinSomeFuture<<($(SomeOk<$F::IntoFuture>,)+) as TryJoin>::Future>;
+
+            #[allow(non_snake_case)]
+            fn join_some(self) -> Self::Future {
+                let ($($F,)+) = self;
+                JoinSomeFuture {
+                    future: ($(SomeOk::new($F),)+).try_join(),
+                }
+            }
+        }
+    };
+}
+
+impl_join_some_tuple! { (A ResA) }
+impl_join_some_tuple! { (A ResA) (B ResB) }
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) }
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) }
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) }
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) }
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) }
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) }
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) }
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) }
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) }
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) (V ResV) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) (V ResV) (W ResW) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) (V ResV) (W ResW) (X ResX) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) (V ResV) (W ResW) (X ResX) (Y ResY) }
+#[cfg(feature = "large-tuples")]
+impl_join_some_tuple! { (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) (V ResV) (W ResW) (X ResX) (Y ResY) (Z ResZ) }
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future::{pending, ready};
+
+    #[test]
+    fn all_some() {
+        futures_lite::future::block_on(async {
+            let res = (ready(Some(1)), ready(Some("hello"))).join_some().await;
+            assert_eq!(res, Some((1, "hello")));
+
+            let res = [ready(Some(1)), ready(Some(2))].join_some().await;
+            assert_eq!(res, Some([1, 2]));
+        });
+    }
+
+    #[test]
+    fn short_circuit() {
+        futures_lite::future::block_on(async {
+            let res = (pending::<Option<u8>>(), ready(None::<()>))
+                .join_some()
+                .await;
+            assert_eq!(res, None);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn vec() {
+        futures_lite::future::block_on(async {
+            let res = alloc::vec![ready(Some(1)), ready(Some(2))]
+                .join_some()
+                .await;
+            assert_eq!(res, Some(alloc::vec![1, 2]));
+
+            let res = alloc::vec![ready(Some(1)), ready(None)].join_some().await;
+            assert_eq!(res, None);
+        });
+    }
+}
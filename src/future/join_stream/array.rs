@@ -0,0 +1,186 @@
+use super::JoinStream as JoinStreamTrait;
+use crate::utils::{trace, FutureArray, PollArray, WakerArray};
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::mem::ManuallyDrop;
+use core::ops::DerefMut;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+use pin_project::{pin_project, pinned_drop};
+
+/// A stream which yields the outputs of multiple futures as they complete.
+///
+/// This `struct` is created by the [`join_stream`] method on the
+/// [`JoinStream`] trait. See its documentation for more.
+///
+/// [`join_stream`]: crate::future::JoinStream::join_stream
+/// [`JoinStream`]: crate::future::JoinStream
+#[must_use = "streams do nothing unless you `.await` or poll them"]
+#[pin_project(PinnedDrop)]
+pub struct JoinStream<Fut, const N: usize>
+where
+    Fut: Future,
+{
+    /// The number of futures which are currently still in-flight
+    pending: usize,
+    /// A structure holding the waker passed to the stream, and the various
+    /// sub-wakers passed to the contained futures.
+    wakers: WakerArray<N>,
+    /// The individual poll state of each future.
+    state: PollArray<N>,
+    #[pin]
+    /// The array of futures passed to the structure.
+    futures: FutureArray<Fut, N>,
+}
+
+impl<Fut, const N: usize> JoinStream<Fut, N>
+where
+    Fut: Future,
+{
+    #[inline]
+    pub(crate) fn new(futures: [Fut; N]) -> Self {
+        JoinStream {
+            pending: N,
+            wakers: WakerArray::new(),
+            state: PollArray::new_pending(),
+            futures: FutureArray::new(futures),
+        }
+    }
+}
+
+impl<Fut, const N: usize> JoinStreamTrait for [Fut; N]
+where
+    Fut: IntoFuture,
+{
+    type Output = Fut::Output;
+    type Stream = JoinStream<Fut::IntoFuture, N>;
+
+    #[inline]
+    fn join_stream(self) -> Self::Stream {
+        JoinStream::new(self.map(IntoFuture::into_future))
+    }
+}
+
+impl<Fut, const N: usize> fmt::Debug for JoinStream<Fut, N>
+where
+    Fut: Future + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.state.iter()).finish()
+    }
+}
+
+impl<Fut, const N: usize> Stream for JoinStream<Fut, N>
+where
+    Fut: Future,
+{
+    type Item = (usize, Fut::Output);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if *this.pending == 0 {
+            return Poll::Ready(None);
+        }
+
+        let mut readiness = this.wakers.readiness();
+        readiness.set_waker(cx.waker());
+        if !readiness.any_ready() {
+            // Nothing is ready yet
+            return Poll::Pending;
+        }
+
+        // Poll ready futures until one of them completes
+        for (i, mut fut) in this.futures.iter().enumerate() {
+            if this.state[i].is_pending() && readiness.clear_ready(i) {
+                // release readiness so we can borrow the wakers when polling
+                #[allow(clippy::drop_non_drop)]
+                drop(readiness);
+
+                // Obtain the intermediate waker.
+                let mut cx = Context::from_waker(this.wakers.get(i).unwrap());
+
+                // Poll the future
+                trace!(index = i, "join stream: polling future");
+                // SAFETY: the future's state was "pending", so it's safe to poll
+                if let Poll::Ready(value) = unsafe {
+                    fut.as_mut()
+                        .map_unchecked_mut(|t| t.deref_mut())
+                        .poll(&mut cx)
+                } {
+                    this.state[i].set_none();
+                    *this.pending -= 1;
+                    trace!(
+                        index = i,
+                        pending = *this.pending,
+                        "join stream: future completed"
+                    );
+                    // SAFETY: the future state has been changed to "none" which
+                    // means we'll no longer poll the future, so it's safe to drop
+                    unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
+                    return Poll::Ready(Some((i, value)));
+                }
+
+                // Reacquire readiness so we can use it again
+                readiness = this.wakers.readiness();
+            }
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pending, Some(self.pending))
+    }
+}
+
+impl<Fut, const N: usize> FusedStream for JoinStream<Fut, N>
+where
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.pending == 0
+    }
+}
+
+/// Drop the pending futures on cancellation.
+#[pinned_drop]
+impl<Fut, const N: usize> PinnedDrop for JoinStream<Fut, N>
+where
+    Fut: Future,
+{
+    fn drop(self: Pin<&mut Self>) {
+        let mut this = self.project();
+
+        for i in this.state.pending_indexes() {
+            // SAFETY: we've just filtered down to *only* the pending futures,
+            // which have not yet been dropped.
+            unsafe { this.futures.as_mut().drop(i) };
+            trace!(index = i, "join stream: future cancelled");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future;
+    use futures_lite::StreamExt;
+
+    #[test]
+    fn smoke() {
+        futures_lite::future::block_on(async {
+            let futures = [future::ready(1), future::ready(2), future::ready(3)];
+            let mut stream = futures.join_stream();
+            assert_eq!(stream.size_hint(), (3, Some(3)));
+
+            let mut outputs: Vec<_> = (&mut stream).collect().await;
+            outputs.sort();
+            assert_eq!(outputs, [(0, 1), (1, 2), (2, 3)]);
+            assert!(stream.is_terminated());
+        });
+    }
+}
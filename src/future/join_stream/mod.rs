@@ -0,0 +1,39 @@
+use futures_core::Stream;
+
+pub(crate) mod array;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Wait for all futures to complete, yielding their outputs as they come in.
+///
+/// This is the streaming counterpart to [`Join`][crate::future::Join]: all
+/// futures are still awaited, but each output can be processed as soon as its
+/// future completes rather than once all of them have.
+pub trait JoinStream {
+    /// The output type of each future.
+    type Output;
+
+    /// Which kind of stream are we turning this into?
+    type Stream: Stream<Item = (usize, Self::Output)>;
+
+    /// Create a stream which yields the output of each future along with
+    /// its index, in the order the futures complete.
+    ///
+    /// The stream ends once every future has completed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::StreamExt;
+    /// use std::future;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let futures = [future::ready("a"), future::ready("b")];
+    /// let mut outputs: Vec<_> = futures.join_stream().collect().await;
+    /// outputs.sort();
+    /// assert_eq!(outputs, [(0, "a"), (1, "b")]);
+    /// # });
+    /// ```
+    fn join_stream(self) -> Self::Stream;
+}
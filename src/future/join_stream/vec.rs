@@ -0,0 +1,200 @@
+use super::JoinStream as JoinStreamTrait;
+use crate::utils::{trace, FutureVec, PollVec, WakerVec};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::mem::ManuallyDrop;
+use core::ops::DerefMut;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+use pin_project::{pin_project, pinned_drop};
+
+/// A stream which yields the outputs of multiple futures as they complete.
+///
+/// This `struct` is created by the [`join_stream`] method on the
+/// [`JoinStream`] trait. See its documentation for more.
+///
+/// [`join_stream`]: crate::future::JoinStream::join_stream
+/// [`JoinStream`]: crate::future::JoinStream
+#[must_use = "streams do nothing unless you `.await` or poll them"]
+#[pin_project(PinnedDrop)]
+pub struct JoinStream<Fut>
+where
+    Fut: Future,
+{
+    pending: usize,
+    wakers: WakerVec,
+    state: PollVec,
+    #[pin]
+    futures: FutureVec<Fut>,
+}
+
+impl<Fut> JoinStream<Fut>
+where
+    Fut: Future,
+{
+    pub(crate) fn new(futures: Vec<Fut>) -> Self {
+        let len = futures.len();
+        JoinStream {
+            pending: len,
+            wakers: WakerVec::new(len),
+            state: PollVec::new_pending(len),
+            futures: FutureVec::new(futures),
+        }
+    }
+}
+
+impl<Fut> JoinStreamTrait for Vec<Fut>
+where
+    Fut: IntoFuture,
+{
+    type Output = Fut::Output;
+    type Stream = JoinStream<Fut::IntoFuture>;
+
+    fn join_stream(self) -> Self::Stream {
+        JoinStream::new(self.into_iter().map(IntoFuture::into_future).collect())
+    }
+}
+
+impl<Fut> fmt::Debug for JoinStream<Fut>
+where
+    Fut: Future + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.state.iter()).finish()
+    }
+}
+
+impl<Fut> Stream for JoinStream<Fut>
+where
+    Fut: Future,
+{
+    type Item = (usize, Fut::Output);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.pending == 0 {
+            return Poll::Ready(None);
+        }
+
+        let mut readiness = this.wakers.readiness();
+        readiness.set_waker(cx.waker());
+        if !readiness.any_ready() {
+            // Nothing is ready yet
+            return Poll::Pending;
+        }
+
+        // Poll ready futures until one of them completes
+        let futures = this.futures.as_mut();
+        let states = &mut this.state[..];
+        for (i, mut fut) in futures.iter().enumerate() {
+            if states[i].is_pending() && readiness.clear_ready(i) {
+                // release readiness so we can borrow the wakers when polling
+                #[allow(clippy::drop_non_drop)]
+                drop(readiness);
+
+                // Obtain the intermediate waker.
+                let mut cx = Context::from_waker(this.wakers.get(i).unwrap());
+
+                // Poll the future
+                trace!(index = i, "join stream: polling future");
+                // SAFETY: the future's state was "pending", so it's safe to poll
+                if let Poll::Ready(value) = unsafe {
+                    fut.as_mut()
+                        .map_unchecked_mut(|t| t.deref_mut())
+                        .poll(&mut cx)
+                } {
+                    states[i].set_none();
+                    *this.pending -= 1;
+                    trace!(
+                        index = i,
+                        pending = *this.pending,
+                        "join stream: future completed"
+                    );
+                    // SAFETY: the future state has been changed to "none" which
+                    // means we'll no longer poll the future, so it's safe to drop
+                    unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
+                    return Poll::Ready(Some((i, value)));
+                }
+
+                // Reacquire readiness so we can use it again
+                readiness = this.wakers.readiness();
+            }
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pending, Some(self.pending))
+    }
+}
+
+impl<Fut> FusedStream for JoinStream<Fut>
+where
+    Fut: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.pending == 0
+    }
+}
+
+/// Drop the pending futures on cancellation.
+#[pinned_drop]
+impl<Fut> PinnedDrop for JoinStream<Fut>
+where
+    Fut: Future,
+{
+    fn drop(self: Pin<&mut Self>) {
+        let mut this = self.project();
+
+        for i in this.state.pending_indexes() {
+            // SAFETY: we've just filtered down to *only* the pending futures,
+            // which have not yet been dropped.
+            unsafe { this.futures.as_mut().drop(i) };
+            trace!(index = i, "join stream: future cancelled");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use core::future;
+    use futures_lite::{FutureExt, StreamExt};
+
+    #[test]
+    fn yields_as_completed() {
+        futures_lite::future::block_on(async {
+            let mut polled = false;
+            let slow = future::poll_fn(move |cx| {
+                if polled {
+                    return Poll::Ready("slow");
+                }
+                polled = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            });
+            let futures = vec![slow.boxed(), future::ready("fast").boxed()];
+            let outputs: Vec<_> = futures.join_stream().collect().await;
+            assert_eq!(outputs, [(1, "fast"), (0, "slow")]);
+        });
+    }
+
+    #[test]
+    fn empty() {
+        futures_lite::future::block_on(async {
+            let futures: Vec<future::Ready<()>> = vec![];
+            let mut stream = futures.join_stream();
+            assert_eq!(stream.next().await, None);
+            assert!(stream.is_terminated());
+        });
+    }
+}
@@ -66,25 +66,72 @@
 //!
 //! - `future::TryMerge`: wait for all futures in the set to complete _successfully_, or return on the first error.
 //! - `future::RaceOk`: wait for the first _successful_ future in the set to
-//!    complete, or return an `Err` if *no* futures complete successfully.
+//!   complete, or return an `Err` if *no* futures complete successfully.
 //!
+pub use array_future_group::ArrayFutureGroup;
+#[cfg(feature = "std")]
+pub use catch_panic::CatchPanic;
 #[doc(inline)]
 #[cfg(feature = "alloc")]
 pub use future_group::FutureGroup;
+#[doc(inline)]
+#[cfg(feature = "alloc")]
+pub use future_map::FutureMap;
 pub use futures_ext::FutureExt;
-pub use join::Join;
-pub use race::Race;
-pub use race_ok::RaceOk;
-pub use try_join::TryJoin;
+pub use join::{join, Join};
+#[cfg(feature = "alloc")]
+pub use join_limited::{JoinLimited, JoinLimitedFuture};
+pub use join_some::{JoinSome, JoinSomeFuture};
+pub use join_stream::JoinStream;
+pub use option::{OptionFuture, OrPending};
+pub use race::{race, Race, RaceBiased};
+#[cfg(feature = "alloc")]
+pub use race_hedged::{race_hedged, RaceHedged};
+pub use race_keep_rest::RaceKeepRest;
+pub use race_ok::{race_ok, RaceOk, RaceOkHeterogeneous, RaceOkIndexed};
+#[cfg(feature = "alloc")]
+pub use race_ok_quorum::{RaceOkQuorum, RaceOkQuorumFuture};
+pub use race_some::{RaceSome, RaceSomeFuture};
+pub use retry::{retry, Backoff, Retry, RetryPolicy};
+#[cfg(feature = "alloc")]
+pub use supervisor::{OnError, Restart, RestartPolicy, Supervisor, SupervisorEvent};
+pub use timeout::Timeout;
+pub use try_join::{try_join, TryJoin};
+pub use until::Until;
 pub use wait_until::WaitUntil;
 
+/// A fixed-capacity group of futures which act as a single unit.
+pub mod array_future_group;
+
 /// A growable group of futures which act as a single unit.
 #[cfg(feature = "alloc")]
 pub mod future_group;
 
+/// A growable group of futures indexed by caller-provided keys.
+#[cfg(feature = "alloc")]
+pub mod future_map;
+
+#[cfg(feature = "std")]
+pub(crate) mod catch_panic;
 mod futures_ext;
 pub(crate) mod join;
+#[cfg(feature = "alloc")]
+pub(crate) mod join_limited;
+pub(crate) mod join_some;
+pub(crate) mod join_stream;
+pub(crate) mod option;
 pub(crate) mod race;
+#[cfg(feature = "alloc")]
+pub(crate) mod race_hedged;
+pub(crate) mod race_keep_rest;
 pub(crate) mod race_ok;
+#[cfg(feature = "alloc")]
+pub(crate) mod race_ok_quorum;
+pub(crate) mod race_some;
+pub(crate) mod retry;
+#[cfg(feature = "alloc")]
+pub(crate) mod supervisor;
+pub(crate) mod timeout;
 pub(crate) mod try_join;
+pub(crate) mod until;
 pub(crate) mod wait_until;
@@ -0,0 +1,134 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project::pin_project;
+
+/// A future which may be absent, and resolves to `None` right away if it is.
+///
+/// This makes it possible to include conditionally-constructed futures in
+/// a [`Join`]: absent futures are skipped, and their output is `None`. Because
+/// the standard library may implement `IntoFuture` for `Option` someday,
+/// `Option<Fut>` can't be joined directly.
+///
+/// This doesn't work with [`TryJoin`], which expects every future to resolve
+/// to a `Result`. To race optional futures, see [`OrPending`] instead.
+///
+/// [`Join`]: crate::future::Join
+/// [`TryJoin`]: crate::future::TryJoin
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::future::OptionFuture;
+/// use futures_concurrency::prelude::*;
+///
+/// # futures::executor::block_on(async {
+/// let cache = None::<std::future::Ready<&str>>;
+/// let db = Some(async { "row" });
+///
+/// let (cache, db) = (OptionFuture::from(cache), OptionFuture::from(db)).join().await;
+/// assert_eq!(cache, None);
+/// assert_eq!(db, Some("row"));
+/// # });
+/// ```
+#[derive(Debug)]
+#[pin_project]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct OptionFuture<F> {
+    #[pin]
+    future: Option<F>,
+}
+
+impl<F: Future> From<Option<F>> for OptionFuture<F> {
+    fn from(future: Option<F>) -> Self {
+        Self { future }
+    }
+}
+
+impl<F: Future> Future for OptionFuture<F> {
+    type Output = Option<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project().future.as_pin_mut() {
+            Some(future) => future.poll(cx).map(Some),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+/// A future which may be absent, and never resolves if it is.
+///
+/// This makes it possible to include conditionally-constructed futures in
+/// [`Race`]: absent futures never win the race. Note that racing only absent
+/// futures never completes.
+///
+/// To join optional futures, see [`OptionFuture`] instead.
+///
+/// [`Race`]: crate::future::Race
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::future::OrPending;
+/// use futures_concurrency::prelude::*;
+///
+/// # futures::executor::block_on(async {
+/// let fallback = None::<std::future::Ready<&str>>;
+/// let primary = Some(async { "primary" });
+///
+/// let winner = (OrPending::from(fallback), OrPending::from(primary)).race().await;
+/// assert_eq!(winner, "primary");
+/// # });
+/// ```
+#[derive(Debug)]
+#[pin_project]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct OrPending<F> {
+    #[pin]
+    future: Option<F>,
+}
+
+impl<F: Future> From<Option<F>> for OrPending<F> {
+    fn from(future: Option<F>) -> Self {
+        Self { future }
+    }
+}
+
+impl<F: Future> Future for OrPending<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project().future.as_pin_mut() {
+            Some(future) => future.poll(cx),
+            None => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use core::future::{ready, Ready};
+
+    #[test]
+    fn join_skips_absent() {
+        futures_lite::future::block_on(async {
+            let futures: [OptionFuture<Ready<u8>>; 3] =
+                [Some(ready(1)).into(), None.into(), Some(ready(3)).into()];
+            assert_eq!(futures.join().await, [Some(1), None, Some(3)]);
+        });
+    }
+
+    #[test]
+    fn race_skips_absent() {
+        futures_lite::future::block_on(async {
+            for _ in 0..3 {
+                let futures: Vec<OrPending<Ready<u8>>> =
+                    vec![None.into(), Some(ready(2)).into(), None.into()];
+                assert_eq!(futures.race().await, 2);
+            }
+        });
+    }
+}
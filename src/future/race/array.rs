@@ -1,6 +1,6 @@
 use crate::utils::{self, Indexer};
 
-use super::Race as RaceTrait;
+use super::{Race as RaceTrait, RaceBiased};
 
 use core::fmt;
 use core::future::{Future, IntoFuture};
@@ -78,11 +78,36 @@ where
     }
 }
 
+impl<Fut, const N: usize> RaceBiased for [Fut; N]
+where
+    Fut: IntoFuture,
+{
+    fn race_biased(self) -> Self::Future {
+        let mut race = self.race();
+        race.indexer.set_ordered();
+        race
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use core::future;
 
+    #[test]
+    fn biased() {
+        futures_lite::future::block_on(async {
+            // Both futures are only ready the second time they're polled,
+            // at which point an unbiased race would start with "world".
+            let delayed = |s| async move {
+                futures_lite::future::yield_now().await;
+                s
+            };
+            let res = [delayed("hello"), delayed("world")].race_biased().await;
+            assert_eq!(res, "hello");
+        });
+    }
+
     // NOTE: we should probably poll in random order.
     #[test]
     fn no_fairness() {
@@ -1,4 +1,4 @@
-use core::future::Future;
+use core::future::{Future, IntoFuture};
 
 pub(crate) mod array;
 pub(crate) mod tuple;
@@ -24,3 +24,55 @@ pub trait Race {
     /// This function returns a new future which polls all futures concurrently.
     fn race(self) -> Self::Future;
 }
+
+/// Wait for the first future to complete, polling the futures in order.
+pub trait RaceBiased: Race {
+    /// Wait for the first future to complete, polling the futures in order.
+    ///
+    /// Unlike [`race`][Race::race], which varies the order in which futures
+    /// are polled to keep things fair, this always polls futures earlier in
+    /// the collection first. If multiple futures are ready at the same time,
+    /// the first of them wins. This is useful when priority is meaningful,
+    /// such as checking a shutdown signal before doing more work, and for
+    /// writing deterministic tests. Note that a future which is always ready
+    /// will starve the futures after it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let shutdown = future::ready("shutdown");
+    /// let work = future::ready("work");
+    /// assert_eq!((shutdown, work).race_biased().await, "shutdown");
+    /// # });
+    /// ```
+    fn race_biased(self) -> Self::Future;
+}
+
+/// Wait for the first of two futures to complete.
+///
+/// This is a shorthand for calling [`Race::race`] on a pair of futures. The
+/// other future is cancelled. To race more futures, call `race` on a tuple,
+/// array or vector of futures instead.
+///
+/// # Example
+///
+/// ```rust
+/// # futures::executor::block_on(async {
+/// use futures_concurrency::future;
+///
+/// let a = core::future::pending();
+/// let b = core::future::ready("hello");
+/// assert_eq!(future::race(a, b).await, "hello");
+/// # })
+/// ```
+pub fn race<T, A, B>(a: A, b: B) -> <(A, B) as Race>::Future
+where
+    A: IntoFuture<Output = T>,
+    B: IntoFuture<Output = T>,
+{
+    (a, b).race()
+}
@@ -1,4 +1,4 @@
-use super::Race as RaceTrait;
+use super::{Race as RaceTrait, RaceBiased};
 use crate::utils;
 
 use core::fmt::{self, Debug};
@@ -57,6 +57,17 @@ macro_rules! impl_race_tuple {
             }
         }
 
+        impl<T, $($F),*> RaceBiased for ($($F,)*)
+        where $(
+            $F: IntoFuture<Output = T>,
+        )* {
+            fn race_biased(self) -> Self::Future {
+                let mut race = self.race();
+                race.indexer.set_ordered();
+                race
+            }
+        }
+
         impl<T, $($F: Future),*> Future for $StructName<T, $($F),*>
         where
             $($F: Future<Output = T>),*
@@ -102,6 +113,30 @@ impl_race_tuple! { Race9 A B C D E F G H I }
 impl_race_tuple! { Race10 A B C D E F G H I J }
 impl_race_tuple! { Race11 A B C D E F G H I J K }
 impl_race_tuple! { Race12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race16 A B C D E F G H I J K L M N O P }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race17 A B C D E F G H I J K L M N O P Q }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race18 A B C D E F G H I J K L M N O P Q R }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race19 A B C D E F G H I J K L M N O P Q R U }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race20 A B C D E F G H I J K L M N O P Q R U V }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race21 A B C D E F G H I J K L M N O P Q R U V W }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race22 A B C D E F G H I J K L M N O P Q R U V W X }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race23 A B C D E F G H I J K L M N O P Q R U V W X Y }
+#[cfg(feature = "large-tuples")]
+impl_race_tuple! { Race24 A B C D E F G H I J K L M N O P Q R U V W X Y Z }
 
 #[cfg(test)]
 mod test {
@@ -135,4 +170,18 @@ mod test {
             assert!(matches!(result, "hello" | "world"));
         });
     }
+
+    #[test]
+    fn race_biased() {
+        futures_lite::future::block_on(async {
+            // Every future is only ready the second time it's polled, at
+            // which point an unbiased race would no longer start with `a`.
+            let delayed = |s| async move {
+                futures_lite::future::yield_now().await;
+                s
+            };
+            let (a, b, c) = (delayed("a"), delayed("b"), delayed("c"));
+            assert_eq!((a, b, c).race_biased().await, "a");
+        });
+    }
 }
@@ -1,6 +1,6 @@
-use crate::utils::{self, Indexer};
+use crate::utils::{self, Indexer, WakerVec};
 
-use super::Race as RaceTrait;
+use super::{Race as RaceTrait, RaceBiased};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
@@ -27,6 +27,9 @@ where
 {
     #[pin]
     futures: Vec<Fut>,
+    /// A structure holding the waker passed to the future, and the various
+    /// sub-wakers passed to the contained futures.
+    wakers: WakerVec,
     indexer: Indexer,
     done: bool,
 }
@@ -51,15 +54,33 @@ where
         let mut this = self.project();
         assert!(!*this.done, "Futures must not be polled after completing");
 
+        let mut readiness = this.wakers.readiness();
+        readiness.set_waker(cx.waker());
+        if !readiness.any_ready() {
+            // Nothing is ready yet
+            return Poll::Pending;
+        }
+
         for index in this.indexer.iter() {
+            if !readiness.clear_ready(index) {
+                continue;
+            }
+
+            // release readiness so we can borrow the wakers when polling
+            #[allow(clippy::drop_non_drop)]
+            drop(readiness);
+
+            // Obtain the intermediate waker.
+            let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+
             let fut = utils::get_pin_mut_from_vec(this.futures.as_mut(), index).unwrap();
-            match fut.poll(cx) {
-                Poll::Ready(item) => {
-                    *this.done = true;
-                    return Poll::Ready(item);
-                }
-                Poll::Pending => continue,
+            if let Poll::Ready(item) = fut.poll(&mut cx) {
+                *this.done = true;
+                return Poll::Ready(item);
             }
+
+            // Reacquire readiness so we can use it again
+            readiness = this.wakers.readiness();
         }
         Poll::Pending
     }
@@ -74,6 +95,7 @@ where
 
     fn race(self) -> Self::Future {
         Race {
+            wakers: WakerVec::new(self.len()),
             indexer: Indexer::new(self.len()),
             futures: self.into_iter().map(|fut| fut.into_future()).collect(),
             done: false,
@@ -81,6 +103,17 @@ where
     }
 }
 
+impl<Fut> RaceBiased for Vec<Fut>
+where
+    Fut: IntoFuture,
+{
+    fn race_biased(self) -> Self::Future {
+        let mut race = self.race();
+        race.indexer.set_ordered();
+        race
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -97,4 +130,50 @@ mod test {
             assert!(matches!(res, "hello" | "world"));
         });
     }
+
+    #[test]
+    fn biased() {
+        futures_lite::future::block_on(async {
+            // Both futures are only ready the second time they're polled,
+            // at which point an unbiased race would start with "world".
+            let delayed = |s| async move {
+                futures_lite::future::yield_now().await;
+                s
+            };
+            let res = vec![delayed("hello"), delayed("world")].race_biased().await;
+            assert_eq!(res, "hello");
+        });
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn only_repolls_woken_futures() {
+        use core::cell::Cell;
+        use core::task::Poll;
+
+        futures_lite::future::block_on(async {
+            let polls = Cell::new(0);
+            let futures: Vec<_> = (0..100)
+                .map(|index| {
+                    let polls = &polls;
+                    let mut woken = false;
+                    future::poll_fn(move |cx| {
+                        polls.set(polls.get() + 1);
+                        match (index, woken) {
+                            (50, true) => Poll::Ready(index),
+                            (50, false) => {
+                                woken = true;
+                                cx.waker().wake_by_ref();
+                                Poll::Pending
+                            }
+                            _ => Poll::Pending,
+                        }
+                    })
+                })
+                .collect();
+
+            assert_eq!(futures.race().await, 50);
+            assert_eq!(polls.get(), 101);
+        });
+    }
 }
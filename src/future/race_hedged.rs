@@ -0,0 +1,289 @@
+use super::join_limited::Indexed;
+use super::race_ok::vec::AggregateError;
+use crate::future::FutureGroup;
+use crate::time::Timer;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::vec;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+/// Wait for the first successful future to complete, starting the futures one
+/// after another.
+///
+/// The first future is started right away. Every time `delay` passes without
+/// a success the next future is started as well, while the earlier ones keep
+/// running. If all running futures fail the next future is started right
+/// away. This is known as _hedging_: a slow request doesn't hold up the
+/// result, without sending every request at once.
+///
+/// Once a future succeeds all other futures are dropped, and the futures which
+/// haven't been started yet never are. If no future completes successfully,
+/// returns an aggregate error of all failed futures, in input order.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::future;
+/// use futures_lite::future::block_on;
+/// use std::future::pending;
+/// use std::time::Duration;
+///
+/// async fn fetch(replica: usize) -> Result<&'static str, ()> {
+///     match replica {
+///         // The primary replica never responds...
+///         0 => pending().await,
+///         _ => Ok("hello"),
+///     }
+/// }
+///
+/// block_on(async {
+///     // ... so after 10ms we ask the second one as well.
+///     let delay = Duration::from_millis(10);
+///     let res = future::race_hedged((0..3).map(fetch), delay, async_io::Timer::after).await;
+///     assert_eq!(res.ok(), Some("hello"));
+/// });
+/// ```
+pub fn race_hedged<I, T, E, Tm>(
+    futures: I,
+    delay: Duration,
+    timer: Tm,
+) -> RaceHedged<<I::Item as IntoFuture>::IntoFuture, E, Tm>
+where
+    I: IntoIterator,
+    I::Item: IntoFuture<Output = Result<T, E>>,
+    Tm: Timer,
+{
+    let queued: Vec<_> = futures.into_iter().map(IntoFuture::into_future).collect();
+    let len = queued.len();
+    RaceHedged {
+        queued: queued.into_iter(),
+        next: 0,
+        running: FutureGroup::new(),
+        errors: (0..len).map(|_| None).collect(),
+        delay,
+        timer,
+        sleep: None,
+        consumed: false,
+    }
+}
+
+/// A future which waits for the first successful future to complete, starting
+/// the futures one after another.
+///
+/// This `struct` is created by the [`race_hedged`] function. See its
+/// documentation for more.
+///
+/// [`race_hedged`]: crate::future::race_hedged
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct RaceHedged<Fut, E, Tm>
+where
+    Tm: Timer,
+{
+    /// The futures which haven't been started yet.
+    queued: vec::IntoIter<Fut>,
+    /// The index of the next future to start.
+    next: usize,
+    /// The futures which are currently running.
+    running: FutureGroup<Indexed<Fut>>,
+    /// The errors of the futures which have failed, in input order.
+    errors: Vec<Option<E>>,
+    delay: Duration,
+    timer: Tm,
+    /// Completes when the next future should be started.
+    #[pin]
+    sleep: Option<Tm::Sleep>,
+    consumed: bool,
+}
+
+impl<Fut, E, Tm> fmt::Debug for RaceHedged<Fut, E, Tm>
+where
+    Tm: Timer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RaceHedged")
+            .field("queued", &self.queued.len())
+            .field("running", &self.running.len())
+            .field("delay", &self.delay)
+            .finish()
+    }
+}
+
+impl<Fut, T, E, Tm> Future for RaceHedged<Fut, E, Tm>
+where
+    Fut: Future<Output = Result<T, E>>,
+    Tm: Timer,
+{
+    type Output = Result<T, AggregateError<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        assert!(
+            !*this.consumed,
+            "Futures must not be polled after completing"
+        );
+
+        loop {
+            // Start the next future if the delay has passed, or if there's
+            // nothing left running to wait for.
+            let delay_passed = match this.sleep.as_mut().as_pin_mut() {
+                Some(sleep) => sleep.poll(cx).is_ready(),
+                None => false,
+            };
+            if delay_passed || this.running.is_empty() {
+                if let Some(future) = this.queued.next() {
+                    this.running.insert(Indexed {
+                        index: *this.next,
+                        future,
+                    });
+                    *this.next += 1;
+                    let sleep = match this.queued.len() {
+                        0 => None,
+                        _ => Some(this.timer.sleep(*this.delay)),
+                    };
+                    this.sleep.set(sleep);
+                    // Poll the new timer, so we're woken once it fires.
+                    continue;
+                }
+            }
+
+            match Pin::new(&mut *this.running).poll_next(cx) {
+                Poll::Ready(Some((_, Ok(value)))) => {
+                    *this.consumed = true;
+                    // Cancel the futures which are still in flight.
+                    this.running.clear();
+                    this.sleep.set(None);
+                    return Poll::Ready(Ok(value));
+                }
+                Poll::Ready(Some((index, Err(err)))) => this.errors[index] = Some(err),
+                Poll::Ready(None) if this.queued.len() == 0 => {
+                    *this.consumed = true;
+                    let errors = core::mem::take(this.errors);
+                    return Poll::Ready(Err(AggregateError::new(
+                        errors
+                            .into_iter()
+                            .map(|err| err.expect("every future has failed"))
+                            .collect(),
+                    )));
+                }
+                // Every running future failed, start the next one right away.
+                Poll::Ready(None) => continue,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use core::cell::{Cell, RefCell};
+    use core::future::{self, Ready};
+    use futures_lite::future::{block_on, yield_now};
+
+    type BoxFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+
+    /// A timer which fires on the next poll, and counts how often it was used.
+    fn counting_timer() -> (impl Timer, Rc<Cell<usize>>) {
+        let count = Rc::new(Cell::new(0));
+        let timer = {
+            let count = count.clone();
+            move |_| {
+                count.set(count.get() + 1);
+                yield_now()
+            }
+        };
+        (timer, count)
+    }
+
+    #[test]
+    fn first_success_wins() {
+        block_on(async {
+            let (timer, sleeps) = counting_timer();
+            let futs = vec![
+                future::ready(Ok::<_, ()>("primary")),
+                future::ready(Ok("secondary")),
+            ];
+            let res = race_hedged(futs, Duration::from_secs(1), timer).await;
+            assert_eq!(res.ok(), Some("primary"));
+            assert_eq!(sleeps.get(), 1);
+        });
+    }
+
+    #[test]
+    fn starts_next_after_delay() {
+        block_on(async {
+            let (timer, _) = counting_timer();
+            let started = Rc::new(RefCell::new(vec![]));
+            let futs: Vec<BoxFuture<Result<usize, ()>>> = (0..3usize)
+                .map(|i| {
+                    let started = started.clone();
+                    Box::pin(async move {
+                        started.borrow_mut().push(i);
+                        if i == 1 {
+                            Ok(i)
+                        } else {
+                            future::pending().await
+                        }
+                    }) as BoxFuture<_>
+                })
+                .collect();
+            let res = race_hedged(futs, Duration::from_secs(1), timer).await;
+            assert_eq!(res.ok(), Some(1));
+            // The third future was never started.
+            assert_eq!(*started.borrow(), vec![0, 1]);
+        });
+    }
+
+    #[test]
+    fn failure_starts_next_immediately() {
+        block_on(async {
+            let futs = vec![
+                future::ready(Err("down")),
+                future::ready(Err("down")),
+                future::ready(Ok(2)),
+            ];
+            // The delay never passes, so only failures move us along.
+            let timer = |_| future::pending::<()>();
+            let res = race_hedged(futs, Duration::from_secs(1), timer).await;
+            assert_eq!(res.ok(), Some(2));
+        });
+    }
+
+    #[test]
+    fn all_fail() {
+        block_on(async {
+            let (timer, _) = counting_timer();
+            let futs = vec![future::ready(Err::<(), _>(0)), future::ready(Err(1))];
+            let errors = race_hedged(futs, Duration::from_secs(1), timer)
+                .await
+                .unwrap_err();
+            assert_eq!(errors.into_inner(), vec![0, 1]);
+        });
+    }
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let (timer, sleeps) = counting_timer();
+            let futs: Vec<Ready<Result<(), ()>>> = vec![];
+            let errors = race_hedged(futs, Duration::from_secs(1), timer)
+                .await
+                .unwrap_err();
+            assert!(errors.into_inner().is_empty());
+            assert_eq!(sleeps.get(), 0);
+        });
+    }
+}
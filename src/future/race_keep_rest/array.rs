@@ -0,0 +1,98 @@
+use crate::utils::Indexer;
+
+use super::RaceKeepRest as RaceKeepRestTrait;
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// A future which waits for the first future to complete, and keeps the
+/// other futures.
+///
+/// This `struct` is created by the [`race_and_keep_rest`] method on the
+/// [`RaceKeepRest`] trait. See its documentation for more.
+///
+/// [`race_and_keep_rest`]: crate::future::RaceKeepRest::race_and_keep_rest
+/// [`RaceKeepRest`]: crate::future::RaceKeepRest
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RaceKeepRest<Fut, const N: usize>
+where
+    Fut: Future + Unpin,
+{
+    futures: Option<[Fut; N]>,
+    indexer: Indexer,
+}
+
+impl<Fut, const N: usize> fmt::Debug for RaceKeepRest<Fut, N>
+where
+    Fut: Future + Unpin + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.futures.iter().flatten())
+            .finish()
+    }
+}
+
+impl<Fut, const N: usize> Future for RaceKeepRest<Fut, N>
+where
+    Fut: Future + Unpin,
+{
+    /// The remaining futures keep their position in the array. The slot of
+    /// the future which completed is `None`.
+    type Output = (Fut::Output, [Option<Fut>; N]);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let futures = this
+            .futures
+            .as_mut()
+            .expect("Futures must not be polled after completing");
+
+        for index in this.indexer.iter() {
+            if let Poll::Ready(item) = Pin::new(&mut futures[index]).poll(cx) {
+                let mut rest = this.futures.take().unwrap().map(Some);
+                rest[index] = None;
+                return Poll::Ready((item, rest));
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl<Fut, const N: usize> RaceKeepRestTrait for [Fut; N]
+where
+    Fut: IntoFuture,
+    Fut::IntoFuture: Unpin,
+{
+    type Output = Fut::Output;
+    type Rest = [Option<Fut::IntoFuture>; N];
+    type Future = RaceKeepRest<Fut::IntoFuture, N>;
+
+    fn race_and_keep_rest(self) -> Self::Future {
+        RaceKeepRest {
+            futures: Some(self.map(|fut| fut.into_future())),
+            indexer: Indexer::new(N),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::future::OrPending;
+    use core::future;
+
+    #[test]
+    fn keeps_the_rest() {
+        futures_lite::future::block_on(async {
+            let futures = [None, Some(future::ready(1)), None].map(OrPending::from);
+            let (out, rest) = futures.race_and_keep_rest().await;
+            assert_eq!(out, 1);
+            assert!(rest[0].is_some());
+            assert!(rest[1].is_none());
+            assert!(rest[2].is_some());
+        });
+    }
+}
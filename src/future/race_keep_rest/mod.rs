@@ -0,0 +1,45 @@
+use core::future::Future;
+
+pub(crate) mod array;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Wait for the first future to complete, and keep the other futures.
+///
+/// Unlike [`Race`][crate::future::Race], which cancels the other futures once
+/// one of them completes, this hands the remaining futures back so they can
+/// be awaited later.
+pub trait RaceKeepRest {
+    /// The resulting output type.
+    type Output;
+
+    /// The futures which didn't complete.
+    type Rest;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = (Self::Output, Self::Rest)>;
+
+    /// Wait for the first future to complete, and return its output along
+    /// with the other futures.
+    ///
+    /// The remaining futures have been polled, so they must be `Unpin` to be
+    /// moved out again. Box and pin futures which aren't.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let futures = vec![future::ready(1), future::ready(2)];
+    /// let (first, rest) = futures.race_and_keep_rest().await;
+    /// assert_eq!(rest.len(), 1);
+    ///
+    /// // Continue awaiting the rest.
+    /// let second = rest.race().await;
+    /// assert_eq!(first + second, 3);
+    /// # });
+    /// ```
+    fn race_and_keep_rest(self) -> Self::Future;
+}
@@ -0,0 +1,150 @@
+use crate::utils::{Indexer, WakerVec};
+
+use super::RaceKeepRest as RaceKeepRestTrait;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// A future which waits for the first future to complete, and keeps the
+/// other futures.
+///
+/// This `struct` is created by the [`race_and_keep_rest`] method on the
+/// [`RaceKeepRest`] trait. See its documentation for more.
+///
+/// [`race_and_keep_rest`]: crate::future::RaceKeepRest::race_and_keep_rest
+/// [`RaceKeepRest`]: crate::future::RaceKeepRest
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RaceKeepRest<Fut>
+where
+    Fut: Future + Unpin,
+{
+    futures: Option<Vec<Fut>>,
+    /// A structure holding the waker passed to the future, and the various
+    /// sub-wakers passed to the contained futures.
+    wakers: WakerVec,
+    indexer: Indexer,
+}
+
+impl<Fut> fmt::Debug for RaceKeepRest<Fut>
+where
+    Fut: Future + Unpin + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.futures.iter().flatten())
+            .finish()
+    }
+}
+
+impl<Fut> Future for RaceKeepRest<Fut>
+where
+    Fut: Future + Unpin,
+{
+    type Output = (Fut::Output, Vec<Fut>);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let futures = this
+            .futures
+            .as_mut()
+            .expect("Futures must not be polled after completing");
+
+        let mut readiness = this.wakers.readiness();
+        readiness.set_waker(cx.waker());
+        if !readiness.any_ready() {
+            // Nothing is ready yet
+            return Poll::Pending;
+        }
+
+        for index in this.indexer.iter() {
+            if !readiness.clear_ready(index) {
+                continue;
+            }
+
+            // release readiness so we can borrow the wakers when polling
+            #[allow(clippy::drop_non_drop)]
+            drop(readiness);
+
+            // Obtain the intermediate waker.
+            let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+
+            if let Poll::Ready(item) = Pin::new(&mut futures[index]).poll(&mut cx) {
+                let mut rest = this.futures.take().unwrap();
+                rest.remove(index);
+                return Poll::Ready((item, rest));
+            }
+
+            // Reacquire readiness so we can use it again
+            readiness = this.wakers.readiness();
+        }
+        Poll::Pending
+    }
+}
+
+impl<Fut> RaceKeepRestTrait for Vec<Fut>
+where
+    Fut: IntoFuture,
+    Fut::IntoFuture: Unpin,
+{
+    type Output = Fut::Output;
+    type Rest = Vec<Fut::IntoFuture>;
+    type Future = RaceKeepRest<Fut::IntoFuture>;
+
+    fn race_and_keep_rest(self) -> Self::Future {
+        RaceKeepRest {
+            wakers: WakerVec::new(self.len()),
+            indexer: Indexer::new(self.len()),
+            futures: Some(self.into_iter().map(|fut| fut.into_future()).collect()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use core::future;
+    use futures_lite::FutureExt;
+
+    #[test]
+    fn keeps_the_rest() {
+        futures_lite::future::block_on(async {
+            let futures = vec![
+                future::pending().boxed(),
+                future::ready(1).boxed(),
+                future::pending().boxed(),
+            ];
+            let (out, rest) = futures.race_and_keep_rest().await;
+            assert_eq!(out, 1);
+            assert_eq!(rest.len(), 2);
+        });
+    }
+
+    #[test]
+    fn rest_keeps_progress() {
+        use core::task::Poll;
+
+        futures_lite::future::block_on(async {
+            // The second future needs to be polled twice before it completes,
+            // so it's still pending when the first one wins.
+            let mut polled = false;
+            let slow = future::poll_fn(move |cx| {
+                if polled {
+                    return Poll::Ready(2);
+                }
+                polled = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            });
+            let futures = vec![future::ready(1).boxed(), slow.boxed()];
+            let (out, mut rest) = futures.race_and_keep_rest().await;
+            assert_eq!(out, 1);
+            assert_eq!(rest.pop().unwrap().await, 2);
+        });
+    }
+}
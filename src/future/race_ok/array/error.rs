@@ -1,9 +1,13 @@
 use core::fmt;
+use core::iter::Enumerate;
 use core::ops::{Deref, DerefMut};
+use core::slice;
 #[cfg(feature = "std")]
 use std::error::Error;
 
 /// A collection of errors.
+///
+/// The errors are stored in the same order as the futures they came from.
 #[repr(transparent)]
 pub struct AggregateError<E, const N: usize> {
     inner: [E; N],
@@ -13,6 +17,23 @@ impl<E, const N: usize> AggregateError<E, N> {
     pub(super) fn new(inner: [E; N]) -> Self {
         Self { inner }
     }
+
+    /// Consume the aggregate, returning the individual errors.
+    pub fn into_inner(self) -> [E; N] {
+        self.inner
+    }
+
+    /// Iterate over the errors together with the index of the future they
+    /// came from.
+    pub fn iter_indexed(&self) -> Enumerate<slice::Iter<'_, E>> {
+        self.inner.iter().enumerate()
+    }
+
+    /// Consume the aggregate, returning the errors together with the index of
+    /// the future they came from.
+    pub fn into_iter_indexed(self) -> Enumerate<core::array::IntoIter<E, N>> {
+        self.inner.into_iter().enumerate()
+    }
 }
 
 impl<E: fmt::Display, const N: usize> fmt::Debug for AggregateError<E, N> {
@@ -47,5 +68,45 @@ impl<E, const N: usize> DerefMut for AggregateError<E, N> {
     }
 }
 
+impl<E, const N: usize> From<AggregateError<E, N>> for [E; N] {
+    fn from(err: AggregateError<E, N>) -> Self {
+        err.inner
+    }
+}
+
+impl<E, const N: usize> IntoIterator for AggregateError<E, N> {
+    type Item = E;
+    type IntoIter = core::array::IntoIter<E, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, E, const N: usize> IntoIterator for &'a AggregateError<E, N> {
+    type Item = &'a E;
+    type IntoIter = slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<'a, E, const N: usize> IntoIterator for &'a mut AggregateError<E, N> {
+    type Item = &'a mut E;
+    type IntoIter = slice::IterMut<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
+
+/// The source of the aggregate is the first error, so that error reporters
+/// walking the source chain show at least one underlying cause. All errors are
+/// listed by the `Debug` implementation.
 #[cfg(feature = "std")]
-impl<E: Error, const N: usize> std::error::Error for AggregateError<E, N> {}
+impl<E: Error + 'static, const N: usize> Error for AggregateError<E, N> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.inner.first().map(|err| err as &(dyn Error + 'static))
+    }
+}
@@ -1,4 +1,5 @@
 use super::RaceOk as RaceOkTrait;
+use super::RaceOkIndexed as RaceOkIndexedTrait;
 use crate::utils::array_assume_init;
 use crate::utils::iter_pin_mut;
 use crate::utils::PollArray;
@@ -73,20 +74,33 @@ where
     type Output = Result<T, AggregateError<E, N>>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.poll_indexed(cx).map_ok(|(_, ok)| ok)
+    }
+}
+
+impl<Fut, T, E, const N: usize> RaceOk<Fut, T, E, N>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    fn poll_indexed(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(usize, T), AggregateError<E, N>>> {
         let this = self.project();
 
         let futures = iter_pin_mut(this.futures);
 
-        for ((fut, out), st) in futures
+        for (index, ((fut, out), st)) in futures
             .zip(this.errors.iter_mut())
             .zip(this.error_states.iter_mut())
+            .enumerate()
         {
             if st.is_ready() {
                 continue;
             }
             if let Poll::Ready(output) = fut.poll(cx) {
                 match output {
-                    Ok(ok) => return Poll::Ready(Ok(ok)),
+                    Ok(ok) => return Poll::Ready(Ok((index, ok))),
                     Err(err) => {
                         *out = MaybeUninit::new(err);
                         *this.completed += 1;
@@ -130,6 +144,60 @@ where
     }
 }
 
+/// A future which waits for the first successful future to complete, and
+/// reports its index.
+///
+/// This `struct` is created by the [`race_ok_indexed`] method on the
+/// [`RaceOkIndexed`] trait. See its documentation for more.
+///
+/// [`race_ok_indexed`]: crate::future::RaceOkIndexed::race_ok_indexed
+/// [`RaceOkIndexed`]: crate::future::RaceOkIndexed
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct RaceOkIndexed<Fut, T, E, const N: usize>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    #[pin]
+    inner: RaceOk<Fut, T, E, N>,
+}
+
+impl<Fut, T, E, const N: usize> fmt::Debug for RaceOkIndexed<Fut, T, E, N>
+where
+    Fut: Future<Output = Result<T, E>> + fmt::Debug,
+    Fut::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<Fut, T, E, const N: usize> Future for RaceOkIndexed<Fut, T, E, N>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Result<(usize, T), AggregateError<E, N>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().inner.poll_indexed(cx)
+    }
+}
+
+impl<Fut, T, E, const N: usize> RaceOkIndexedTrait for [Fut; N]
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = T;
+    type Error = AggregateError<E, N>;
+    type Future = RaceOkIndexed<Fut::IntoFuture, T, E, N>;
+
+    fn race_ok_indexed(self) -> Self::Future {
+        RaceOkIndexed {
+            inner: self.race_ok(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -170,6 +238,35 @@ mod test {
         });
     }
 
+    #[test]
+    fn consume_errors() {
+        futures_lite::future::block_on(async {
+            let res: Result<&str, AggregateError<_, 2>> =
+                [future::ready(Err("oops")), future::ready(Err("oh no"))]
+                    .race_ok()
+                    .await;
+            let mut errs = res.unwrap_err();
+            for err in &mut errs {
+                *err = "again";
+            }
+            assert_eq!(errs.into_inner(), ["again", "again"]);
+        });
+    }
+
+    #[test]
+    fn indexed() {
+        futures_lite::future::block_on(async {
+            let res = [
+                future::ready(Err("oops")),
+                future::ready(Err("oh no")),
+                future::ready(Ok("hello")),
+            ]
+            .race_ok_indexed()
+            .await;
+            assert_eq!(res.ok(), Some((2, "hello")));
+        });
+    }
+
     #[test]
     fn resume_after_completion() {
         use futures_lite::future::yield_now;
@@ -1,4 +1,5 @@
-use core::future::Future;
+use core::fmt;
+use core::future::{Future, IntoFuture};
 
 pub(crate) mod array;
 pub(crate) mod tuple;
@@ -23,3 +24,104 @@ pub trait RaceOk {
     /// Waits for the first successful future to complete.
     fn race_ok(self) -> Self::Future;
 }
+
+/// Wait for the first successful future to complete, where each future may
+/// have a different error type.
+///
+/// This works like [`RaceOk`], except that the futures don't need to share a
+/// common error type. If no future completes successfully, a tuple of all
+/// errors is returned, in the same order as the futures.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use std::future;
+///
+/// # futures::executor::block_on(async {
+/// let cache = future::ready(Err::<&str, _>(()));
+/// let db = future::ready(Err::<&str, _>("connection refused"));
+/// let res = (cache, db).race_ok_heterogeneous().await;
+/// assert_eq!(res, Err(((), "connection refused")));
+/// # });
+/// ```
+pub trait RaceOkHeterogeneous {
+    /// The resulting output type.
+    type Output;
+
+    /// The resulting error type.
+    type Error;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Result<Self::Output, Self::Error>>;
+
+    /// Waits for the first successful future to complete.
+    fn race_ok_heterogeneous(self) -> Self::Future;
+}
+
+/// Wait for the first successful future to complete, and report which future
+/// it was.
+///
+/// This works like [`RaceOk`], except that the output is paired with the index
+/// of the future which produced it. If no future completes successfully, the
+/// errors in the aggregate error are stored in the same order as the futures
+/// they came from, so the index of each error is the index of its future.
+pub trait RaceOkIndexed {
+    /// The resulting output type.
+    type Output;
+
+    /// The resulting error type.
+    type Error;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Result<(usize, Self::Output), Self::Error>>;
+
+    /// Waits for the first successful future to complete, returning its
+    /// output along with its index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use std::future;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let replicas = [future::ready(Err("down")), future::ready(Ok("hello"))];
+    /// assert_eq!(replicas.race_ok_indexed().await.ok(), Some((1, "hello")));
+    ///
+    /// let replicas = [future::ready(Err::<(), _>("down")), future::ready(Err("slow"))];
+    /// let errors = replicas.race_ok_indexed().await.unwrap_err();
+    /// for (index, err) in errors.iter().enumerate() {
+    ///     println!("replica {index} failed: {err}");
+    /// }
+    /// # });
+    /// ```
+    fn race_ok_indexed(self) -> Self::Future;
+}
+
+/// Wait for the first of two futures to complete successfully.
+///
+/// This is a shorthand for calling [`RaceOk::race_ok`] on a pair of futures.
+/// If neither future completes successfully, an aggregate error of both
+/// errors is returned. To race more futures, call `race_ok` on a tuple, array
+/// or vector of futures instead.
+///
+/// # Example
+///
+/// ```rust
+/// # futures::executor::block_on(async {
+/// use futures_concurrency::future;
+///
+/// let a = async { Err::<&str, _>("oops") };
+/// let b = async { Ok("hello") };
+/// assert_eq!(future::race_ok(a, b).await.ok(), Some("hello"));
+/// # })
+/// ```
+pub fn race_ok<T, E, A, B>(a: A, b: B) -> <(A, B) as RaceOk>::Future
+where
+    A: IntoFuture<Output = Result<T, E>>,
+    B: IntoFuture<Output = Result<T, E>>,
+    E: fmt::Debug,
+{
+    (a, b).race_ok()
+}
@@ -1,5 +1,7 @@
 use core::fmt;
+use core::iter::Enumerate;
 use core::ops::{Deref, DerefMut};
+use core::slice;
 #[cfg(feature = "std")]
 use std::error::Error;
 
@@ -13,6 +15,23 @@ impl<E, const N: usize> AggregateError<E, N> {
     pub(super) fn new(inner: [E; N]) -> Self {
         Self { inner }
     }
+
+    /// Consume the aggregate, returning the individual errors.
+    pub fn into_inner(self) -> [E; N] {
+        self.inner
+    }
+
+    /// Iterate over the errors together with the index of the future they
+    /// came from.
+    pub fn iter_indexed(&self) -> Enumerate<slice::Iter<'_, E>> {
+        self.inner.iter().enumerate()
+    }
+
+    /// Consume the aggregate, returning the errors together with the index of
+    /// the future they came from.
+    pub fn into_iter_indexed(self) -> Enumerate<core::array::IntoIter<E, N>> {
+        self.inner.into_iter().enumerate()
+    }
 }
 
 #[cfg(feature = "std")]
@@ -74,5 +93,28 @@ impl<E, const N: usize> DerefMut for AggregateError<E, N> {
     }
 }
 
+impl<E, const N: usize> IntoIterator for AggregateError<E, N> {
+    type Item = E;
+    type IntoIter = core::array::IntoIter<E, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, E, const N: usize> IntoIterator for &'a AggregateError<E, N> {
+    type Item = &'a E;
+    type IntoIter = slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+/// The source of the aggregate is the first error.
 #[cfg(feature = "std")]
-impl<E: Error, const N: usize> std::error::Error for AggregateError<E, N> {}
+impl<E: Error + 'static, const N: usize> Error for AggregateError<E, N> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.inner.first().map(|err| err as &(dyn Error + 'static))
+    }
+}
@@ -0,0 +1,146 @@
+use super::super::RaceOkHeterogeneous as RaceOkHeterogeneousTrait;
+use crate::utils;
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project::pin_project;
+
+macro_rules! impl_race_ok_heterogeneous_tuple {
+    ($StructName:ident $(($F:ident $E:ident))+) => {
+        /// A future which waits for the first successful future to complete,
+        /// where each future may have a different error type.
+        ///
+        /// This `struct` is created by the [`race_ok_heterogeneous`] method on the
+        /// [`RaceOkHeterogeneous`] trait. See its documentation for more.
+        ///
+        /// [`race_ok_heterogeneous`]: crate::future::RaceOkHeterogeneous::race_ok_heterogeneous
+        /// [`RaceOkHeterogeneous`]: crate::future::RaceOkHeterogeneous
+        #[must_use = "futures do nothing unless you `.await` or poll them"]
+        #[allow(non_snake_case)]
+        #[pin_project]
+        pub struct $StructName<T, $($F, $E),+>
+        where $(
+            $F: Future<Output = Result<T, $E>>,
+        )+ {
+            done: bool,
+            indexer: utils::Indexer,
+            $($E: Option<$E>,)+
+            $(#[pin] $F: $F,)+
+        }
+
+        impl<T, $($F, $E),+> fmt::Debug for $StructName<T, $($F, $E),+>
+        where $(
+            $F: Future<Output = Result<T, $E>> + fmt::Debug,
+        )+ {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("RaceOk")
+                    $(.field(&self.$F))+
+                    .finish()
+            }
+        }
+
+        impl<T, $($F, $E),+> RaceOkHeterogeneousTrait for ($($F,)+)
+        where $(
+            $F: IntoFuture<Output = Result<T, $E>>,
+        )+ {
+            type Output = T;
+            type Error = ($($E,)+);
+            type Future = $StructName<T, $($F::IntoFuture, $E),+>;
+
+            #[allow(non_snake_case)]
+            fn race_ok_heterogeneous(self) -> Self::Future {
+                let ($($F,)+) = self;
+                $StructName {
+                    done: false,
+                    indexer: utils::Indexer::new(utils::tuple_len!($($F,)+)),
+                    $($E: None,)+
+                    $($F: $F.into_future(),)+
+                }
+            }
+        }
+
+        impl<T, $($F, $E),+> Future for $StructName<T, $($F, $E),+>
+        where $(
+            $F: Future<Output = Result<T, $E>>,
+        )+ {
+            type Output = Result<T, ($($E,)+)>;
+
+            fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                let mut this = self.project();
+                assert!(!*this.done, "Futures must not be polled after completing");
+
+                #[repr(usize)]
+                enum Indexes {
+                    $($F),+
+                }
+
+                for i in this.indexer.iter() {
+                    $(
+                        // Futures which have failed are not polled again.
+                        if i == Indexes::$F as usize && this.$E.is_none() {
+                            if let Poll::Ready(output) = this.$F.as_mut().poll(cx) {
+                                match output {
+                                    Ok(output) => {
+                                        *this.done = true;
+                                        return Poll::Ready(Ok(output));
+                                    }
+                                    Err(err) => *this.$E = Some(err),
+                                }
+                            }
+                        }
+                    )+
+                }
+
+                if $(this.$E.is_some())&&+ {
+                    *this.done = true;
+                    return Poll::Ready(Err(($(this.$E.take().unwrap(),)+)));
+                }
+                Poll::Pending
+            }
+        }
+    };
+}
+
+impl_race_ok_heterogeneous_tuple! { RaceOkHeterogeneous2 (A ErrA) (B ErrB) }
+impl_race_ok_heterogeneous_tuple! { RaceOkHeterogeneous3 (A ErrA) (B ErrB) (C ErrC) }
+impl_race_ok_heterogeneous_tuple! { RaceOkHeterogeneous4 (A ErrA) (B ErrB) (C ErrC) (D ErrD) }
+impl_race_ok_heterogeneous_tuple! { RaceOkHeterogeneous5 (A ErrA) (B ErrB) (C ErrC) (D ErrD) (E ErrE) }
+impl_race_ok_heterogeneous_tuple! { RaceOkHeterogeneous6 (A ErrA) (B ErrB) (C ErrC) (D ErrD) (E ErrE) (F ErrF) }
+impl_race_ok_heterogeneous_tuple! { RaceOkHeterogeneous7 (A ErrA) (B ErrB) (C ErrC) (D ErrD) (E ErrE) (F ErrF) (G ErrG) }
+impl_race_ok_heterogeneous_tuple! { RaceOkHeterogeneous8 (A ErrA) (B ErrB) (C ErrC) (D ErrD) (E ErrE) (F ErrF) (G ErrG) (H ErrH) }
+impl_race_ok_heterogeneous_tuple! { RaceOkHeterogeneous9 (A ErrA) (B ErrB) (C ErrC) (D ErrD) (E ErrE) (F ErrF) (G ErrG) (H ErrH) (I ErrI) }
+impl_race_ok_heterogeneous_tuple! { RaceOkHeterogeneous10 (A ErrA) (B ErrB) (C ErrC) (D ErrD) (E ErrE) (F ErrF) (G ErrG) (H ErrH) (I ErrI) (J ErrJ) }
+impl_race_ok_heterogeneous_tuple! { RaceOkHeterogeneous11 (A ErrA) (B ErrB) (C ErrC) (D ErrD) (E ErrE) (F ErrF) (G ErrG) (H ErrH) (I ErrI) (J ErrJ) (K ErrK) }
+impl_race_ok_heterogeneous_tuple! { RaceOkHeterogeneous12 (A ErrA) (B ErrB) (C ErrC) (D ErrD) (E ErrE) (F ErrF) (G ErrG) (H ErrH) (I ErrI) (J ErrJ) (K ErrK) (L ErrL) }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future;
+
+    #[derive(Debug, PartialEq)]
+    struct Timeout;
+
+    #[test]
+    fn one_ok() {
+        futures_lite::future::block_on(async {
+            let a = future::ready(Err::<&str, _>(Timeout));
+            let b = future::ready(Ok::<_, &str>("hello"));
+            assert_eq!((a, b).race_ok_heterogeneous().await, Ok("hello"));
+        });
+    }
+
+    #[test]
+    fn all_err() {
+        futures_lite::future::block_on(async {
+            let a = future::ready(Err::<(), _>(Timeout));
+            let b = future::ready(Err::<(), _>("oh no"));
+            let c = future::ready(Err::<(), _>(12));
+            let res = (a, b, c).race_ok_heterogeneous().await;
+            assert_eq!(res, Err((Timeout, "oh no", 12)));
+        });
+    }
+}
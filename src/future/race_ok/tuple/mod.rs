@@ -11,6 +11,7 @@ use core::task::{Context, Poll};
 use pin_project::{pin_project, pinned_drop};
 
 mod error;
+pub(crate) mod heterogeneous;
 pub(crate) use error::AggregateError;
 
 macro_rules! impl_race_ok_tuple {
@@ -176,6 +177,30 @@ impl_race_ok_tuple! { RaceOk9 A B C D E F G H I }
 impl_race_ok_tuple! { RaceOk10 A B C D E F G H I J }
 impl_race_ok_tuple! { RaceOk11 A B C D E F G H I J K }
 impl_race_ok_tuple! { RaceOk12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk16 A B C D E F G H I J K L M N O P }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk17 A B C D E F G H I J K L M N O P Q }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk18 A B C D E F G H I J K L M N O P Q R }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk19 A B C D E F G H I J K L M N O P Q R U }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk20 A B C D E F G H I J K L M N O P Q R U V }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk21 A B C D E F G H I J K L M N O P Q R U V W }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk22 A B C D E F G H I J K L M N O P Q R U V W X }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk23 A B C D E F G H I J K L M N O P Q R U V W X Y }
+#[cfg(feature = "large-tuples")]
+impl_race_ok_tuple! { RaceOk24 A B C D E F G H I J K L M N O P Q R U V W X Y Z }
 
 #[cfg(test)]
 mod test {
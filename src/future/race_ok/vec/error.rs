@@ -2,20 +2,57 @@
 use alloc::vec::Vec;
 
 use core::fmt;
+use core::iter::{Copied, Zip};
 use core::ops::Deref;
 use core::ops::DerefMut;
+use core::slice;
 #[cfg(feature = "std")]
 use std::error::Error;
 
 /// A collection of errors.
-#[repr(transparent)]
+///
+/// The errors are stored in the same order as the futures they came from.
 pub struct AggregateError<E> {
     pub(crate) inner: Vec<E>,
+    /// The index of the future each error came from.
+    indices: Vec<usize>,
 }
 
 impl<E> AggregateError<E> {
+    /// Create an aggregate with one error for every future, in order.
     pub(crate) fn new(inner: Vec<E>) -> Self {
-        Self { inner }
+        let indices = (0..inner.len()).collect();
+        Self { inner, indices }
+    }
+
+    /// Create an aggregate from errors paired with the index of the future
+    /// they came from.
+    pub(crate) fn from_indexed(errors: Vec<(usize, E)>) -> Self {
+        let (indices, inner) = errors.into_iter().unzip();
+        Self { inner, indices }
+    }
+
+    /// Consume the aggregate, returning the individual errors.
+    pub fn into_inner(self) -> Vec<E> {
+        self.inner
+    }
+
+    /// Iterate over the errors together with the index of the future they
+    /// came from.
+    ///
+    /// The indices are recorded when the errors are collected. Errors which
+    /// are added through `DerefMut` afterwards have no index, and are skipped.
+    pub fn iter_indexed(&self) -> Zip<Copied<slice::Iter<'_, usize>>, slice::Iter<'_, E>> {
+        self.indices.iter().copied().zip(self.inner.iter())
+    }
+
+    /// Consume the aggregate, returning the errors together with the index of
+    /// the future they came from.
+    ///
+    /// See [`iter_indexed`][Self::iter_indexed] for how the indices are
+    /// recorded.
+    pub fn into_iter_indexed(self) -> Zip<alloc::vec::IntoIter<usize>, alloc::vec::IntoIter<E>> {
+        self.indices.into_iter().zip(self.inner)
     }
 }
 
@@ -51,5 +88,45 @@ impl<E> DerefMut for AggregateError<E> {
     }
 }
 
+impl<E> From<AggregateError<E>> for Vec<E> {
+    fn from(err: AggregateError<E>) -> Self {
+        err.inner
+    }
+}
+
+impl<E> IntoIterator for AggregateError<E> {
+    type Item = E;
+    type IntoIter = alloc::vec::IntoIter<E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a AggregateError<E> {
+    type Item = &'a E;
+    type IntoIter = slice::Iter<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a mut AggregateError<E> {
+    type Item = &'a mut E;
+    type IntoIter = slice::IterMut<'a, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter_mut()
+    }
+}
+
+/// The source of the aggregate is the first error, so that error reporters
+/// walking the source chain show at least one underlying cause. All errors are
+/// listed by the `Debug` implementation.
 #[cfg(feature = "std")]
-impl<E: Error> Error for AggregateError<E> {}
+impl<E: Error + 'static> Error for AggregateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.inner.first().map(|err| err as &(dyn Error + 'static))
+    }
+}
@@ -1,16 +1,19 @@
 use super::RaceOk as RaceOkTrait;
-use crate::utils::iter_pin_mut;
-use crate::utils::MaybeDone;
+use super::RaceOkIndexed as RaceOkIndexedTrait;
+use crate::utils::{FutureVec, OutputVec, PollVec, WakerVec};
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::vec::Vec;
 
 use core::fmt;
 use core::future::{Future, IntoFuture};
-use core::mem;
+use core::mem::ManuallyDrop;
+use core::ops::DerefMut;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
+use pin_project::{pin_project, pinned_drop};
+
 pub use error::AggregateError;
 
 mod error;
@@ -20,14 +23,48 @@ mod error;
 /// This `struct` is created by the [`race_ok`] method on the [`RaceOk`] trait. See
 /// its documentation for more.
 ///
+/// This future is [`Unpin`] if the futures it races are.
+///
 /// [`race_ok`]: crate::future::RaceOk::race_ok
 /// [`RaceOk`]: crate::future::RaceOk
 #[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project(PinnedDrop)]
 pub struct RaceOk<Fut, T, E>
 where
     Fut: Future<Output = Result<T, E>>,
 {
-    elems: Pin<Box<[MaybeDone<Fut>]>>,
+    /// A boolean which holds whether the future has completed
+    consumed: bool,
+    /// The number of futures which are currently still in-flight
+    pending: usize,
+    /// The errors of the futures which have failed so far
+    errors: OutputVec<E>,
+    /// A structure holding the waker passed to the future, and the various
+    /// sub-wakers passed to the contained futures.
+    wakers: WakerVec,
+    /// The individual poll state of each future.
+    state: PollVec,
+    #[pin]
+    /// The array of futures passed to the structure.
+    futures: FutureVec<Fut>,
+}
+
+impl<Fut, T, E> RaceOk<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    #[inline]
+    pub(crate) fn new(futures: Vec<Fut>) -> Self {
+        let len = futures.len();
+        Self {
+            consumed: false,
+            pending: len,
+            errors: OutputVec::uninit(len),
+            wakers: WakerVec::new(len),
+            state: PollVec::new_pending(len),
+            futures: FutureVec::new(futures),
+        }
+    }
 }
 
 impl<Fut, T, E> fmt::Debug for RaceOk<Fut, T, E>
@@ -36,7 +73,7 @@ where
     Fut::Output: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.elems.iter()).finish()
+        f.debug_list().entries(self.state.iter()).finish()
     }
 }
 
@@ -46,34 +83,134 @@ where
 {
     type Output = Result<T, AggregateError<E>>;
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mut all_done = true;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.poll_indexed(cx).map_ok(|(_, ok)| ok)
+    }
+}
+
+impl<Fut, T, E> RaceOk<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    fn poll_indexed(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(usize, T), AggregateError<E>>> {
+        let this = self.project();
+
+        assert!(
+            !*this.consumed,
+            "Futures must not be polled after completing"
+        );
+
+        let mut readiness = this.wakers.readiness();
+        readiness.set_waker(cx.waker());
+        if *this.pending != 0 && !readiness.any_ready() {
+            // Nothing is ready yet
+            return Poll::Pending;
+        }
+
+        // Poll all ready futures
+        for (i, mut fut) in this.futures.iter().enumerate() {
+            if this.state[i].is_pending() && readiness.clear_ready(i) {
+                // release readiness so we can borrow the wakers when polling
+                #[allow(clippy::drop_non_drop)]
+                drop(readiness);
+
+                // Obtain the intermediate waker.
+                let mut cx = Context::from_waker(this.wakers.get(i).unwrap());
+
+                // Poll the future
+                // SAFETY: the future's state was "pending", so it's safe to poll
+                if let Poll::Ready(value) = unsafe {
+                    fut.as_mut()
+                        .map_unchecked_mut(|t| t.deref_mut())
+                        .poll(&mut cx)
+                } {
+                    *this.pending -= 1;
 
-        for mut elem in iter_pin_mut(self.elems.as_mut()) {
-            if elem.as_mut().poll(cx).is_pending() {
-                all_done = false
-            } else if let Some(output) = elem.take_ok() {
-                return Poll::Ready(Ok(output));
+                    // Check the value, short-circuit on success.
+                    match value {
+                        Ok(value) => {
+                            // The future should no longer be polled after we're done here
+                            *this.consumed = true;
+
+                            // SAFETY: We're about to return the value from the
+                            // future, and drop the entire future. We're marking
+                            // the future as consumed, and then proceeding to
+                            // drop all other futures and errors in the
+                            // destructor.
+                            this.state[i].set_none();
+                            unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
+
+                            return Poll::Ready(Ok((i, value)));
+                        }
+                        Err(err) => {
+                            this.errors.write(i, err);
+
+                            // SAFETY: We're marking the state as "ready", which
+                            // means the future has been consumed, and its error
+                            // is now available to be consumed. The future will
+                            // no longer be used after this point so it's safe
+                            // to drop.
+                            this.state[i].set_ready();
+                            unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
+                        }
+                    }
+                }
+
+                // Reacquire readiness so we can use it again
+                readiness = this.wakers.readiness();
             }
         }
 
-        if all_done {
-            let mut elems = mem::replace(&mut self.elems, Box::pin([]));
-            let result: Vec<E> = iter_pin_mut(elems.as_mut())
-                .map(|e| match e.take_err() {
-                    Some(err) => err,
-                    // Since all futures are done without any one of them returning `Ok`, they're
-                    // all `Err`s and so `take_err` cannot fail
-                    None => unreachable!(),
-                })
-                .collect();
-            Poll::Ready(Err(AggregateError::new(result)))
+        // Check whether we're all done now or need to keep going.
+        if *this.pending == 0 {
+            // Mark all data as "consumed" before we take it
+            *this.consumed = true;
+            for state in this.state.iter_mut() {
+                debug_assert!(
+                    state.is_ready(),
+                    "Future should have reached a `Ready` state"
+                );
+                state.set_none();
+            }
+
+            // SAFETY: we've checked with the state that all of our errors have
+            // been filled, which means we're ready to take the data and assume
+            // it's initialized.
+            Poll::Ready(Err(AggregateError::new(unsafe { this.errors.take() })))
         } else {
             Poll::Pending
         }
     }
 }
 
+/// Drop the already initialized errors on cancellation.
+#[pinned_drop]
+impl<Fut, T, E> PinnedDrop for RaceOk<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    fn drop(self: Pin<&mut Self>) {
+        let mut this = self.project();
+
+        // Drop all initialized errors.
+        for i in this.state.ready_indexes() {
+            // SAFETY: we've just filtered down to *only* the initialized values.
+            // We can assume they're initialized, and this is where we drop them.
+            unsafe { this.errors.drop(i) };
+        }
+
+        // Drop all pending futures.
+        for i in this.state.pending_indexes() {
+            // SAFETY: we've just filtered down to *only* the pending futures,
+            // which have not yet been dropped.
+            unsafe { this.futures.as_mut().drop(i) };
+        }
+    }
+}
+
 impl<Fut, T, E> RaceOkTrait for Vec<Fut>
 where
     Fut: IntoFuture<Output = Result<T, E>>,
@@ -83,12 +220,62 @@ where
     type Future = RaceOk<Fut::IntoFuture, T, E>;
 
     fn race_ok(self) -> Self::Future {
-        let elems: Box<[_]> = self
-            .into_iter()
-            .map(|fut| MaybeDone::new(fut.into_future()))
-            .collect();
-        RaceOk {
-            elems: elems.into(),
+        RaceOk::new(self.into_iter().map(IntoFuture::into_future).collect())
+    }
+}
+
+/// A future which waits for the first successful future to complete, and
+/// reports its index.
+///
+/// This `struct` is created by the [`race_ok_indexed`] method on the
+/// [`RaceOkIndexed`] trait. See its documentation for more.
+///
+/// This future is [`Unpin`] if the futures it races are.
+///
+/// [`race_ok_indexed`]: crate::future::RaceOkIndexed::race_ok_indexed
+/// [`RaceOkIndexed`]: crate::future::RaceOkIndexed
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct RaceOkIndexed<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    #[pin]
+    inner: RaceOk<Fut, T, E>,
+}
+
+impl<Fut, T, E> fmt::Debug for RaceOkIndexed<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>> + fmt::Debug,
+    Fut::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<Fut, T, E> Future for RaceOkIndexed<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Result<(usize, T), AggregateError<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().inner.poll_indexed(cx)
+    }
+}
+
+impl<Fut, T, E> RaceOkIndexedTrait for Vec<Fut>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = T;
+    type Error = AggregateError<E>;
+    type Future = RaceOkIndexed<Fut::IntoFuture, T, E>;
+
+    fn race_ok_indexed(self) -> Self::Future {
+        RaceOkIndexed {
+            inner: self.race_ok(),
         }
     }
 }
@@ -121,6 +308,26 @@ mod test {
         });
     }
 
+    #[test]
+    fn unpin_for_unpin_futures() {
+        fn assert_unpin<T: Unpin>(_: &T) {}
+
+        let race = vec![future::ready(Ok::<u8, ()>(1))].race_ok();
+        assert_unpin(&race);
+        let race = vec![future::ready(Ok::<u8, ()>(1))].race_ok_indexed();
+        assert_unpin(&race);
+    }
+
+    #[test]
+    fn indexed() {
+        futures_lite::future::block_on(async {
+            let res = vec![future::ready(Err("oops")), future::ready(Ok("hello"))]
+                .race_ok_indexed()
+                .await;
+            assert_eq!(res.ok(), Some((1, "hello")));
+        });
+    }
+
     #[test]
     fn all_err() {
         futures_lite::future::block_on(async {
@@ -133,4 +340,66 @@ mod test {
             assert_eq!(errs[1], "oh no");
         });
     }
+
+    #[test]
+    fn consume_errors() {
+        futures_lite::future::block_on(async {
+            let res: Result<&str, AggregateError<_>> =
+                vec![future::ready(Err("oops")), future::ready(Err("oh no"))]
+                    .race_ok()
+                    .await;
+            let errs = res.unwrap_err();
+            assert_eq!((&errs).into_iter().count(), 2);
+            assert_eq!(errs.into_iter().collect::<Vec<_>>(), vec!["oops", "oh no"]);
+        });
+    }
+
+    #[test]
+    fn errors_keep_their_index() {
+        futures_lite::future::block_on(async {
+            let futures: Vec<_> = (0..4)
+                .map(|i| future::ready(Err::<(), _>(i * 10)))
+                .collect();
+            let errs = futures.race_ok().await.unwrap_err();
+            let indexed: Vec<_> = errs.iter_indexed().map(|(i, err)| (i, *err)).collect();
+            assert_eq!(indexed, [(0, 0), (1, 10), (2, 20), (3, 30)]);
+            assert_eq!(errs.into_iter_indexed().nth(3), Some((3, 30)));
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn first_error_is_source() {
+        use std::error::Error;
+        use std::io;
+
+        futures_lite::future::block_on(async {
+            let res: Result<(), AggregateError<io::Error>> = vec![
+                future::ready(Err(io::Error::other("oops"))),
+                future::ready(Err(io::Error::other("oh no"))),
+            ]
+            .race_ok()
+            .await;
+            let errs = res.unwrap_err();
+            assert_eq!(errs.source().unwrap().to_string(), "oops");
+            let errs = errs.into_inner();
+            assert_eq!(errs[1].to_string(), "oh no");
+        });
+    }
+
+    #[test]
+    fn cancel_after_err() {
+        use alloc::boxed::Box;
+        use alloc::string::String;
+        use futures_lite::future::Boxed;
+
+        futures_lite::future::block_on(async {
+            let futures: Vec<Boxed<Result<(), String>>> = vec![
+                Box::pin(future::ready(Err(String::from("oops")))),
+                Box::pin(future::pending()),
+            ];
+            let race = futures.race_ok();
+            assert!(futures_lite::future::poll_once(race).await.is_none());
+        });
+    }
 }
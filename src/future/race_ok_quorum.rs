@@ -0,0 +1,269 @@
+use super::join_limited::Indexed;
+use super::race_ok::vec::AggregateError;
+use crate::future::FutureGroup;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+/// Wait for a number of futures to complete successfully.
+///
+/// This is a generalization of [`RaceOk`][crate::future::RaceOk]: rather than
+/// waiting for the first success, it waits for a quorum of successes. This is
+/// useful for reading from or writing to replicated services, where a request
+/// is committed once a majority of replicas has acknowledged it.
+pub trait RaceOkQuorum {
+    /// The resulting output type.
+    type Output;
+
+    /// The resulting error type.
+    type Error;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Result<Self::Output, Self::Error>>;
+
+    /// Waits for `quorum` futures to complete successfully, and returns their
+    /// outputs.
+    ///
+    /// The outputs are returned in the same order as the futures they came
+    /// from. Once the quorum has been reached, the remaining futures are
+    /// cancelled. If so many futures fail that the quorum can no longer be
+    /// reached, the errors are returned right away, and the remaining futures
+    /// are cancelled. The errors keep track of the index of the future they
+    /// came from, see [`AggregateError::iter_indexed`].
+    ///
+    /// If `quorum` is larger than the number of futures it can never be
+    /// reached, so the future resolves to an empty error without polling any
+    /// of the futures.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # futures::executor::block_on(async {
+    /// use core::future::ready;
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// let replicas = vec![ready(Ok(1)), ready(Err("timeout")), ready(Ok(3))];
+    /// assert_eq!(replicas.race_ok_quorum(2).await.unwrap(), vec![1, 3]);
+    ///
+    /// let replicas = vec![ready(Ok(1)), ready(Err("timeout")), ready(Err("refused"))];
+    /// let errors = replicas.race_ok_quorum(2).await.unwrap_err();
+    /// let errors: Vec<_> = errors.into_iter_indexed().collect();
+    /// assert_eq!(errors, vec![(1, "timeout"), (2, "refused")]);
+    /// # })
+    /// ```
+    fn race_ok_quorum(self, quorum: usize) -> Self::Future;
+}
+
+impl<Fut, T, E> RaceOkQuorum for Vec<Fut>
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = Vec<T>;
+    type Error = AggregateError<E>;
+    type Future = RaceOkQuorumFuture<Fut::IntoFuture, T, E>;
+
+    fn race_ok_quorum(self, quorum: usize) -> Self::Future {
+        RaceOkQuorumFuture::new(self, quorum)
+    }
+}
+
+impl<Fut, T, E, const N: usize> RaceOkQuorum for [Fut; N]
+where
+    Fut: IntoFuture<Output = Result<T, E>>,
+{
+    type Output = Vec<T>;
+    type Error = AggregateError<E>;
+    type Future = RaceOkQuorumFuture<Fut::IntoFuture, T, E>;
+
+    fn race_ok_quorum(self, quorum: usize) -> Self::Future {
+        RaceOkQuorumFuture::new(self, quorum)
+    }
+}
+
+/// A future which waits for a number of futures to complete successfully.
+///
+/// This `struct` is created by the [`race_ok_quorum`] method on the
+/// [`RaceOkQuorum`] trait. See its documentation for more.
+///
+/// [`race_ok_quorum`]: crate::future::RaceOkQuorum::race_ok_quorum
+/// [`RaceOkQuorum`]: crate::future::RaceOkQuorum
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct RaceOkQuorumFuture<Fut, T, E> {
+    running: FutureGroup<Indexed<Fut>>,
+    /// The outputs of the futures which have succeeded, with their index.
+    successes: Vec<(usize, T)>,
+    /// The errors of the futures which have failed, with their index.
+    errors: Vec<(usize, E)>,
+    quorum: usize,
+    len: usize,
+    consumed: bool,
+}
+
+impl<Fut, T, E> RaceOkQuorumFuture<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    fn new<I>(futures: impl IntoIterator<Item = I>, quorum: usize) -> Self
+    where
+        I: IntoFuture<IntoFuture = Fut>,
+    {
+        let running: FutureGroup<_> = futures
+            .into_iter()
+            .enumerate()
+            .map(|(index, future)| Indexed {
+                index,
+                future: future.into_future(),
+            })
+            .collect();
+        let len = running.len();
+        Self {
+            running,
+            successes: Vec::with_capacity(quorum.min(len)),
+            errors: Vec::new(),
+            quorum,
+            len,
+            consumed: false,
+        }
+    }
+}
+
+impl<Fut, T, E> fmt::Debug for RaceOkQuorumFuture<Fut, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RaceOkQuorumFuture")
+            .field("running", &self.running.len())
+            .field("successes", &self.successes.len())
+            .field("errors", &self.errors.len())
+            .field("quorum", &self.quorum)
+            .finish()
+    }
+}
+
+impl<Fut, T, E> Future for RaceOkQuorumFuture<Fut, T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Result<Vec<T>, AggregateError<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // None of the fields are pinned: the running futures are pinned by
+        // the group.
+        let this = self.project();
+
+        assert!(
+            !*this.consumed,
+            "Futures must not be polled after completing"
+        );
+
+        loop {
+            if this.successes.len() == *this.quorum {
+                *this.consumed = true;
+                this.running.clear();
+                let mut successes = core::mem::take(this.successes);
+                successes.sort_unstable_by_key(|(index, _)| *index);
+                let outputs = successes.into_iter().map(|(_, output)| output);
+                return Poll::Ready(Ok(outputs.collect()));
+            }
+
+            // Once more futures have failed than we can afford, the quorum
+            // can't be reached anymore. This is also the case right away if
+            // the quorum is larger than the number of futures.
+            if *this.quorum > *this.len - this.errors.len() {
+                *this.consumed = true;
+                this.running.clear();
+                let mut errors = core::mem::take(this.errors);
+                errors.sort_unstable_by_key(|(index, _)| *index);
+                return Poll::Ready(Err(AggregateError::from_indexed(errors)));
+            }
+
+            match Pin::new(&mut *this.running).poll_next(cx) {
+                Poll::Ready(Some((index, Ok(output)))) => this.successes.push((index, output)),
+                Poll::Ready(Some((index, Err(err)))) => this.errors.push((index, err)),
+                Poll::Ready(None) => unreachable!("the quorum is reached or fails before"),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use core::future;
+
+    #[test]
+    fn reaches_quorum() {
+        futures_lite::future::block_on(async {
+            let futs = vec![
+                future::ready(Err("a")),
+                future::ready(Ok(1)),
+                future::ready(Ok(2)),
+                future::ready(Ok(3)),
+            ];
+            assert_eq!(futs.race_ok_quorum(2).await.ok(), Some(vec![1, 2]));
+        });
+    }
+
+    #[test]
+    fn cancels_remaining_futures() {
+        futures_lite::future::block_on(async {
+            let futs: [Box<dyn Future<Output = Result<u8, ()>> + Unpin>; 3] = [
+                Box::new(future::ready(Ok(1))),
+                Box::new(future::pending()),
+                Box::new(future::ready(Ok(3))),
+            ];
+            assert_eq!(futs.race_ok_quorum(2).await.ok(), Some(vec![1, 3]));
+        });
+    }
+
+    #[test]
+    fn fails_once_quorum_is_impossible() {
+        futures_lite::future::block_on(async {
+            let futs: [Box<dyn Future<Output = Result<(), u8>> + Unpin>; 4] = [
+                Box::new(future::ready(Err(1))),
+                Box::new(future::pending()),
+                Box::new(future::ready(Ok(()))),
+                Box::new(future::ready(Err(2))),
+            ];
+            let errors = futs.race_ok_quorum(3).await.unwrap_err();
+            let indexed: Vec<_> = errors.iter_indexed().map(|(i, err)| (i, *err)).collect();
+            assert_eq!(indexed, vec![(0, 1), (3, 2)]);
+            assert_eq!(errors.into_inner(), vec![1, 2]);
+        });
+    }
+
+    #[test]
+    fn zero_quorum() {
+        futures_lite::future::block_on(async {
+            let futs = [future::pending::<Result<(), ()>>()];
+            assert_eq!(futs.race_ok_quorum(0).await.ok(), Some(vec![]));
+        });
+    }
+
+    #[test]
+    fn quorum_too_large() {
+        futures_lite::future::block_on(async {
+            let futs = vec![future::ready(Ok::<(), ()>(()))];
+            let errors = futs.race_ok_quorum(2).await.unwrap_err();
+            assert!(errors.is_empty());
+        });
+    }
+
+    #[test]
+    fn quorum_of_usize_max() {
+        futures_lite::future::block_on(async {
+            let futs = vec![future::ready(Ok::<(), ()>(()))];
+            let errors = futs.race_ok_quorum(usize::MAX).await.unwrap_err();
+            assert!(errors.is_empty());
+        });
+    }
+}
@@ -0,0 +1,186 @@
+use super::join_some::SomeOk;
+use super::RaceOk;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project::pin_project;
+
+/// Wait for the first future to return `Some`.
+///
+/// This is the counterpart of [`RaceOk`] for futures which return an
+/// `Option`. Futures which return `None` are ignored, and once a future
+/// returns `Some` all other futures will be cancelled.
+pub trait RaceSome {
+    /// The resulting output type.
+    type Output;
+
+    /// Which kind of future are we turning this into?
+    type Future: Future<Output = Option<Self::Output>>;
+
+    /// Waits for the first future to return `Some`, or returns `None` if
+    /// all futures return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # futures::executor::block_on(async {
+    /// use core::future::ready;
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// let futs = [ready(None), ready(Some("hello"))];
+    /// assert_eq!(futs.race_some().await, Some("hello"));
+    ///
+    /// let futs = [ready(None::<u8>), ready(None)];
+    /// assert_eq!(futs.race_some().await, None);
+    /// # })
+    /// ```
+    fn race_some(self) -> Self::Future;
+}
+
+/// A future which waits for the first future to return `Some`.
+///
+/// This `struct` is created by the [`race_some`] method on the [`RaceSome`]
+/// trait. See its documentation for more.
+///
+/// [`race_some`]: crate::future::RaceSome::race_some
+/// [`RaceSome`]: crate::future::RaceSome
+#[derive(Debug)]
+#[pin_project]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RaceSomeFuture<Fut> {
+    #[pin]
+    future: Fut,
+}
+
+impl<Fut, T, E> Future for RaceSomeFuture<Fut>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().future.poll(cx).map(Result::ok)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Fut, T> RaceSome for Vec<Fut>
+where
+    Fut: IntoFuture<Output = Option<T>>,
+{
+    type Output = T;
+    type Future = RaceSomeFuture<<Vec<SomeOk<Fut::IntoFuture>> as RaceOk>::Future>;
+
+    fn race_some(self) -> Self::Future {
+        let futures: Vec<_> = self.into_iter().map(SomeOk::new).collect();
+        RaceSomeFuture {
+            future: futures.race_ok(),
+        }
+    }
+}
+
+impl<Fut, T, const N: usize> RaceSome for [Fut; N]
+where
+    Fut: IntoFuture<Output = Option<T>>,
+{
+    type Output = T;
+    type Future = RaceSomeFuture<<[SomeOk<Fut::IntoFuture>; N] as RaceOk>::Future>;
+
+    fn race_some(self) -> Self::Future {
+        RaceSomeFuture {
+            future: self.map(SomeOk::new).race_ok(),
+        }
+    }
+}
+
+macro_rules! impl_race_some_tuple {
+    ($($F:ident)+) => {
+        impl<T, $($F),+> RaceSome for ($($F,)+)
+        where
+            $($F: IntoFuture<Output = Option<T>>,)+
+        {
+            type Output = T;
+            type Future = RaceSomeFuture<<($(SomeOk<$F::IntoFuture>,)+) as RaceOk>::Future>;
+
+            #[allow(non_snake_case)]
+            fn race_some(self) -> Self::Future {
+                let ($($F,)+) = self;
+                RaceSomeFuture {
+                    future: ($(SomeOk::new($F),)+).race_ok(),
+                }
+            }
+        }
+    };
+}
+
+impl_race_some_tuple! { A }
+impl_race_some_tuple! { A B }
+impl_race_some_tuple! { A B C }
+impl_race_some_tuple! { A B C D }
+impl_race_some_tuple! { A B C D E }
+impl_race_some_tuple! { A B C D E F }
+impl_race_some_tuple! { A B C D E F G }
+impl_race_some_tuple! { A B C D E F G H }
+impl_race_some_tuple! { A B C D E F G H I }
+impl_race_some_tuple! { A B C D E F G H I J }
+impl_race_some_tuple! { A B C D E F G H I J K }
+impl_race_some_tuple! { A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M N O P }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M N O P Q }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M N O P Q R }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M N O P Q R U }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M N O P Q R U V }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M N O P Q R U V W }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M N O P Q R U V W X }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M N O P Q R U V W X Y }
+#[cfg(feature = "large-tuples")]
+impl_race_some_tuple! { A B C D E F G H I J K L M N O P Q R U V W X Y Z }
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future::{pending, ready};
+
+    #[test]
+    fn first_some() {
+        futures_lite::future::block_on(async {
+            let res = (ready(None), pending(), ready(Some(2))).race_some().await;
+            assert_eq!(res, Some(2));
+        });
+    }
+
+    #[test]
+    fn all_none() {
+        futures_lite::future::block_on(async {
+            let res = [ready(None::<u8>), ready(None)].race_some().await;
+            assert_eq!(res, None);
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn vec() {
+        futures_lite::future::block_on(async {
+            let res = alloc::vec![ready(None), ready(Some(1))].race_some().await;
+            assert_eq!(res, Some(1));
+        });
+    }
+}
@@ -0,0 +1,391 @@
+use crate::time::Timer;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use core::time::Duration;
+
+use pin_project::pin_project;
+
+/// Decides whether a failed operation should be retried, and how long to wait
+/// before trying again.
+///
+/// This is implemented for [`Backoff`], and for closures which take the number
+/// of attempts made so far and the error of the last attempt.
+pub trait RetryPolicy<E> {
+    /// Returns how long to wait before the next attempt, or `None` to give up
+    /// and return the error.
+    ///
+    /// `attempts` is the number of attempts which have been made so far, so
+    /// it's `1` after the first attempt fails.
+    fn next_delay(&mut self, attempts: u32, error: &E) -> Option<Duration>;
+}
+
+impl<E, F> RetryPolicy<E> for F
+where
+    F: FnMut(u32, &E) -> Option<Duration>,
+{
+    fn next_delay(&mut self, attempts: u32, error: &E) -> Option<Duration> {
+        (self)(attempts, error)
+    }
+}
+
+/// A retry policy which waits increasingly long between attempts.
+///
+/// Every error is retried, until the maximum number of attempts is reached.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::future::Backoff;
+/// use std::time::Duration;
+///
+/// // Wait 10ms, 20ms, 40ms, ... but never longer than a second, for up to
+/// // five attempts in total.
+/// let policy = Backoff::exponential(Duration::from_millis(10))
+///     .max_delay(Duration::from_secs(1))
+///     .max_attempts(5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Backoff {
+    initial: Duration,
+    factor: u32,
+    max_delay: Duration,
+    max_attempts: u32,
+}
+
+impl Backoff {
+    /// Wait the same amount of time between every attempt.
+    ///
+    /// By default at most three attempts are made.
+    pub fn fixed(delay: Duration) -> Self {
+        Self {
+            initial: delay,
+            factor: 1,
+            max_delay: delay,
+            max_attempts: 3,
+        }
+    }
+
+    /// Wait `initial` before the first retry, and double the delay for every
+    /// retry after that.
+    ///
+    /// By default at most three attempts are made, and the delay isn't capped.
+    pub fn exponential(initial: Duration) -> Self {
+        Self {
+            initial,
+            factor: 2,
+            max_delay: Duration::MAX,
+            max_attempts: 3,
+        }
+    }
+
+    /// Set the maximum number of attempts, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set the maximum delay between two attempts.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+}
+
+impl Backoff {
+    /// The delay after the given number of attempts, ignoring the maximum
+    /// number of attempts.
+    pub(crate) fn delay(&self, attempts: u32) -> Duration {
+        let delay = self
+            .factor
+            .checked_pow(attempts.saturating_sub(1))
+            .and_then(|factor| self.initial.checked_mul(factor))
+            .unwrap_or(Duration::MAX);
+        delay.min(self.max_delay)
+    }
+}
+
+impl<E> RetryPolicy<E> for Backoff {
+    fn next_delay(&mut self, attempts: u32, _error: &E) -> Option<Duration> {
+        if attempts >= self.max_attempts {
+            return None;
+        }
+        Some(self.delay(attempts))
+    }
+}
+
+/// Run a fallible operation, retrying it according to a policy when it fails.
+///
+/// Futures can't be restarted once they complete, so the operation is passed
+/// as a closure which creates a new future for every attempt. The `timer` is
+/// used to wait between attempts, see [`Timer`] for more.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::future::{retry, Backoff};
+/// use futures_lite::future::block_on;
+/// use std::cell::Cell;
+/// use std::time::Duration;
+///
+/// block_on(async {
+///     let calls = Cell::new(0);
+///     let connect = || async {
+///         calls.set(calls.get() + 1);
+///         if calls.get() < 3 { Err("refused") } else { Ok("connected") }
+///     };
+///
+///     let policy = Backoff::fixed(Duration::from_millis(1)).max_attempts(5);
+///     let res = retry(connect, policy, async_io::Timer::after).await;
+///     assert_eq!(res, Ok("connected"));
+///     assert_eq!(calls.get(), 3);
+/// });
+/// ```
+pub fn retry<F, Fut, T, E, P, Tm>(mut f: F, policy: P, timer: Tm) -> Retry<F, Fut, P, Tm>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    P: RetryPolicy<E>,
+    Tm: Timer,
+{
+    Retry {
+        attempts: Attempts::new(f()),
+        f,
+        policy,
+        timer,
+        done: false,
+    }
+}
+
+/// A future which retries a fallible operation according to a policy.
+///
+/// This `struct` is created by the [`retry`] function. See its documentation
+/// for more.
+///
+/// [`retry`]: crate::future::retry
+#[pin_project]
+#[must_use = "futures do nothing unless polled or .awaited"]
+pub struct Retry<F, Fut, P, Tm>
+where
+    Tm: Timer,
+{
+    f: F,
+    policy: P,
+    timer: Tm,
+    done: bool,
+    #[pin]
+    attempts: Attempts<Fut, Tm::Sleep>,
+}
+
+impl<F, Fut, P, Tm> fmt::Debug for Retry<F, Fut, P, Tm>
+where
+    Tm: Timer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Retry")
+            .field("attempts", &self.attempts.count)
+            .field("sleeping", &self.attempts.is_sleeping())
+            .finish()
+    }
+}
+
+impl<F, Fut, T, E, P, Tm> Future for Retry<F, Fut, P, Tm>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    P: RetryPolicy<E>,
+    Tm: Timer,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        assert!(!*this.done, "future polled after completing");
+
+        let out = ready!(this
+            .attempts
+            .poll_attempts(cx, this.policy, this.timer, this.f));
+        *this.done = true;
+        Poll::Ready(out)
+    }
+}
+
+/// The attempts made so far by a retried operation.
+///
+/// This is shared by [`Retry`] and the `map_retry` concurrent stream adapter,
+/// which each start new attempts in their own way.
+#[pin_project]
+pub(crate) struct Attempts<Fut, S> {
+    count: u32,
+    #[pin]
+    state: State<Fut, S>,
+}
+
+#[pin_project(project = StateProj)]
+enum State<Fut, S> {
+    Running {
+        #[pin]
+        future: Fut,
+    },
+    Sleeping {
+        #[pin]
+        sleep: S,
+    },
+}
+
+impl<Fut, S> Attempts<Fut, S> {
+    /// Start tracking attempts, beginning with `future`.
+    pub(crate) fn new(future: Fut) -> Self {
+        Self {
+            count: 0,
+            state: State::Running { future },
+        }
+    }
+
+    fn is_sleeping(&self) -> bool {
+        matches!(self.state, State::Sleeping { .. })
+    }
+
+    /// Drive the current attempt, calling `f` to start a new one whenever
+    /// the policy asks for a retry.
+    pub(crate) fn poll_attempts<F, T, E, P, Tm>(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        policy: &mut P,
+        timer: &Tm,
+        mut f: F,
+    ) -> Poll<Result<T, E>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        P: RetryPolicy<E>,
+        Tm: Timer<Sleep = S>,
+        S: Future,
+    {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Running { future } => {
+                    let err = match future.poll(cx) {
+                        Poll::Ready(Ok(value)) => return Poll::Ready(Ok(value)),
+                        Poll::Ready(Err(err)) => err,
+                        Poll::Pending => return Poll::Pending,
+                    };
+
+                    *this.count += 1;
+                    match policy.next_delay(*this.count, &err) {
+                        // Don't bother with the timer if we can retry right away.
+                        Some(delay) if delay.is_zero() => {
+                            let future = f();
+                            this.state.set(State::Running { future });
+                        }
+                        Some(delay) => {
+                            let sleep = timer.sleep(delay);
+                            this.state.set(State::Sleeping { sleep });
+                        }
+                        None => return Poll::Ready(Err(err)),
+                    }
+                }
+                StateProj::Sleeping { sleep } => {
+                    if sleep.poll(cx).is_pending() {
+                        return Poll::Pending;
+                    }
+                    let future = f();
+                    this.state.set(State::Running { future });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::rc::Rc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cell::{Cell, RefCell};
+    use core::future;
+
+    /// A timer which completes right away, and records every delay.
+    fn recording_timer() -> (impl Timer, Rc<RefCell<Vec<Duration>>>) {
+        let delays = Rc::new(RefCell::new(Vec::new()));
+        let timer = {
+            let delays = delays.clone();
+            move |delay| {
+                delays.borrow_mut().push(delay);
+                future::ready(())
+            }
+        };
+        (timer, delays)
+    }
+
+    #[test]
+    fn succeeds_after_retries() {
+        futures_lite::future::block_on(async {
+            let calls = Cell::new(0);
+            let (timer, delays) = recording_timer();
+            let op = || {
+                calls.set(calls.get() + 1);
+                future::ready(if calls.get() < 3 {
+                    Err(())
+                } else {
+                    Ok(calls.get())
+                })
+            };
+
+            let policy = Backoff::exponential(Duration::from_millis(10)).max_attempts(5);
+            assert_eq!(retry(op, policy, timer).await, Ok(3));
+            assert_eq!(
+                *delays.borrow(),
+                vec![Duration::from_millis(10), Duration::from_millis(20)]
+            );
+        });
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        futures_lite::future::block_on(async {
+            let calls = Cell::new(0);
+            let (timer, delays) = recording_timer();
+            let op = || {
+                calls.set(calls.get() + 1);
+                future::ready(Err::<(), _>(calls.get()))
+            };
+
+            let policy = Backoff::fixed(Duration::from_millis(5));
+            assert_eq!(retry(op, policy, timer).await, Err(3));
+            assert_eq!(delays.borrow().len(), 2);
+        });
+    }
+
+    #[test]
+    fn closure_policy() {
+        futures_lite::future::block_on(async {
+            let (timer, delays) = recording_timer();
+            let op = || future::ready(Err::<(), _>("fatal"));
+
+            // Never retry fatal errors, and retry others right away.
+            let policy = |_, err: &&str| (*err != "fatal").then_some(Duration::ZERO);
+            assert_eq!(retry(op, policy, timer).await, Err("fatal"));
+            assert!(delays.borrow().is_empty());
+        });
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let mut policy = Backoff::exponential(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(5))
+            .max_attempts(u32::MAX);
+        let delays: Vec<_> = (1..=40)
+            .map(|attempts| RetryPolicy::<()>::next_delay(&mut policy, attempts, &()))
+            .collect();
+        assert_eq!(delays[0], Some(Duration::from_secs(1)));
+        assert_eq!(delays[2], Some(Duration::from_secs(4)));
+        assert!(delays[3..]
+            .iter()
+            .all(|delay| *delay == Some(Duration::from_secs(5))));
+    }
+}
@@ -0,0 +1,462 @@
+use super::{Backoff, FutureMap};
+use crate::time::Timer;
+
+use alloc::collections::BTreeMap;
+use core::fmt::{self, Debug};
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+use futures_core::stream::{FusedStream, Stream};
+
+use pin_project::pin_project;
+
+/// Decides whether a supervised future should be restarted once it completes,
+/// and how long to wait before restarting it.
+///
+/// This is implemented for [`Restart`] and [`OnError`], and for closures which
+/// take the number of times the future has been restarted so far and its
+/// output.
+pub trait RestartPolicy<T> {
+    /// Returns how long to wait before restarting the future, or `None` to
+    /// stop supervising it.
+    ///
+    /// `restarts` is the number of times the future has been restarted so
+    /// far, so it's `0` the first time the future completes.
+    fn next_delay(&mut self, restarts: u32, output: &T) -> Option<Duration>;
+}
+
+impl<T, F> RestartPolicy<T> for F
+where
+    F: FnMut(u32, &T) -> Option<Duration>,
+{
+    fn next_delay(&mut self, restarts: u32, output: &T) -> Option<Duration> {
+        (self)(restarts, output)
+    }
+}
+
+/// A restart policy which restarts futures whenever they complete.
+///
+/// By default futures are restarted right away, and without limit.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::future::{Backoff, Restart};
+/// use std::time::Duration;
+///
+/// // Restart futures which fail up to five times, waiting 10ms, 20ms, 40ms,
+/// // ... in between.
+/// let policy = Restart::always()
+///     .max_restarts(5)
+///     .backoff(Backoff::exponential(Duration::from_millis(10)))
+///     .on_error();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Restart {
+    max_restarts: u32,
+    backoff: Option<Backoff>,
+}
+
+impl Restart {
+    /// Restart futures whenever they complete.
+    pub fn always() -> Self {
+        Self {
+            max_restarts: u32::MAX,
+            backoff: None,
+        }
+    }
+
+    /// Set the maximum number of times each future is restarted.
+    pub fn max_restarts(mut self, max_restarts: u32) -> Self {
+        self.max_restarts = max_restarts;
+        self
+    }
+
+    /// Wait between restarts according to `backoff`.
+    ///
+    /// Only the delays of the backoff are used. The number of restarts is
+    /// limited by [`max_restarts`][Restart::max_restarts] instead.
+    pub fn backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = Some(backoff);
+        self
+    }
+
+    /// Only restart futures which complete with an `Err`.
+    pub fn on_error(self) -> OnError<Self> {
+        OnError { policy: self }
+    }
+}
+
+impl<T> RestartPolicy<T> for Restart {
+    fn next_delay(&mut self, restarts: u32, _output: &T) -> Option<Duration> {
+        if restarts >= self.max_restarts {
+            return None;
+        }
+        let delay = match self.backoff {
+            Some(backoff) => backoff.delay(restarts + 1),
+            None => Duration::ZERO,
+        };
+        Some(delay)
+    }
+}
+
+/// A restart policy which only restarts futures which complete with an `Err`.
+///
+/// This `struct` is created by [`Restart::on_error`]. See its documentation
+/// for more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OnError<P> {
+    policy: P,
+}
+
+impl<P, T, E> RestartPolicy<Result<T, E>> for OnError<P>
+where
+    P: RestartPolicy<Result<T, E>>,
+{
+    fn next_delay(&mut self, restarts: u32, output: &Result<T, E>) -> Option<Duration> {
+        match output {
+            Ok(_) => None,
+            Err(_) => self.policy.next_delay(restarts, output),
+        }
+    }
+}
+
+/// An event yielded by a [`Supervisor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupervisorEvent<K, T> {
+    /// A future completed, and will be restarted.
+    Restarted(K, T),
+    /// A future completed, and the policy decided not to restart it. It has
+    /// been removed from the supervisor.
+    Stopped(K, T),
+}
+
+/// A group of futures which are restarted when they complete.
+///
+/// Futures can't be restarted once they complete, so every future is inserted
+/// as a closure which creates it. Whenever a future completes, the
+/// [`RestartPolicy`] decides whether to create and insert a new one, and how
+/// long to wait before doing so. The `timer` is used to wait between restarts,
+/// see [`Timer`] for more.
+///
+/// Futures are inserted under a key of the caller's choosing, which stays the
+/// same across restarts. The supervisor is a stream which yields a
+/// [`SupervisorEvent`] for every completed future, and ends once every future
+/// has stopped.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::future::{Restart, Supervisor, SupervisorEvent};
+/// use futures_lite::StreamExt;
+/// use std::cell::Cell;
+///
+/// # futures_lite::future::block_on(async {
+/// let attempts = Cell::new(0);
+/// let worker = || async {
+///     attempts.set(attempts.get() + 1);
+///     if attempts.get() < 2 { Err("crashed") } else { Ok("done") }
+/// };
+///
+/// let policy = Restart::always().on_error();
+/// let mut supervisor = Supervisor::new(policy, async_io::Timer::after);
+/// supervisor.insert("worker", worker);
+///
+/// let event = supervisor.next().await;
+/// assert_eq!(event, Some(SupervisorEvent::Restarted("worker", Err("crashed"))));
+/// let event = supervisor.next().await;
+/// assert_eq!(event, Some(SupervisorEvent::Stopped("worker", Ok("done"))));
+/// assert_eq!(supervisor.next().await, None);
+/// # });
+/// ```
+#[must_use = "`Supervisor` does nothing if not iterated over"]
+#[pin_project]
+pub struct Supervisor<K, F, Fut, P, Tm: Timer> {
+    children: FutureMap<K, Child<Fut, Tm::Sleep>>,
+    factories: BTreeMap<K, Factory<F>>,
+    policy: P,
+    timer: Tm,
+}
+
+/// The closure which creates a supervised future, and how often it has been
+/// called again.
+struct Factory<F> {
+    factory: F,
+    restarts: u32,
+}
+
+impl<K: Debug, F, Fut, P, Tm: Timer> Debug for Supervisor<K, F, Fut, P, Tm> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Supervisor")
+            .field("keys", &self.factories.keys())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K, F, Fut, P, Tm: Timer> Supervisor<K, F, Fut, P, Tm> {
+    /// Create a new, empty supervisor.
+    pub fn new(policy: P, timer: Tm) -> Self {
+        Self {
+            children: FutureMap::new(),
+            factories: BTreeMap::new(),
+            policy,
+            timer,
+        }
+    }
+
+    /// Return the number of futures being supervised.
+    ///
+    /// This includes futures which are waiting to be restarted.
+    pub fn len(&self) -> usize {
+        self.factories.len()
+    }
+
+    /// Return `true` if no futures are being supervised.
+    pub fn is_empty(&self) -> bool {
+        self.factories.is_empty()
+    }
+}
+
+impl<K: Ord, F, Fut, P, Tm: Timer> Supervisor<K, F, Fut, P, Tm> {
+    /// Stop supervising the future under `key`, dropping it. Returns whether
+    /// a future was being supervised under that key.
+    pub fn remove(&mut self, key: &K) -> bool {
+        self.children.remove(key);
+        self.factories.remove(key).is_some()
+    }
+
+    /// Returns `true` if a future is being supervised under `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.factories.contains_key(key)
+    }
+
+    /// Returns how often the future under `key` has been restarted.
+    pub fn restarts(&self, key: &K) -> Option<u32> {
+        self.factories.get(key).map(|factory| factory.restarts)
+    }
+}
+
+impl<K, F, Fut, P, Tm> Supervisor<K, F, Fut, P, Tm>
+where
+    K: Ord + Clone,
+    F: FnMut() -> Fut,
+    Fut: Future,
+    Tm: Timer,
+{
+    /// Start supervising the future created by `factory` under `key`.
+    ///
+    /// The first future is created right away. If a future was already
+    /// supervised under `key` it is dropped and replaced, and `false` is
+    /// returned. Otherwise `true` is returned.
+    pub fn insert(&mut self, key: K, mut factory: F) -> bool {
+        let future = factory();
+        self.children
+            .insert_with_key(key.clone(), Child::Running { future });
+        let factory = Factory {
+            factory,
+            restarts: 0,
+        };
+        self.factories.insert(key, factory).is_none()
+    }
+}
+
+impl<K, F, Fut, P, Tm> Stream for Supervisor<K, F, Fut, P, Tm>
+where
+    K: Ord + Clone,
+    F: FnMut() -> Fut,
+    Fut: Future,
+    P: RestartPolicy<Fut::Output>,
+    Tm: Timer,
+{
+    type Item = SupervisorEvent<K, Fut::Output>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        loop {
+            let (key, exit) = match Pin::new(&mut *this.children).poll_next(cx) {
+                Poll::Ready(Some(item)) => item,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+            let factory = this
+                .factories
+                .get_mut(&key)
+                .expect("every child has a matching factory");
+
+            let output = match exit {
+                Exit::Completed(output) => output,
+                // The backoff has passed, so it's time to restart the future.
+                Exit::Woke => {
+                    let future = (factory.factory)();
+                    this.children
+                        .insert_with_key(key, Child::Running { future });
+                    continue;
+                }
+            };
+
+            match this.policy.next_delay(factory.restarts, &output) {
+                Some(delay) => {
+                    factory.restarts += 1;
+                    // Don't bother with the timer if we can restart right away.
+                    let child = match delay.is_zero() {
+                        true => Child::Running {
+                            future: (factory.factory)(),
+                        },
+                        false => Child::Sleeping {
+                            sleep: this.timer.sleep(delay),
+                        },
+                    };
+                    this.children.insert_with_key(key.clone(), child);
+                    return Poll::Ready(Some(SupervisorEvent::Restarted(key, output)));
+                }
+                None => {
+                    this.factories.remove(&key);
+                    return Poll::Ready(Some(SupervisorEvent::Stopped(key, output)));
+                }
+            }
+        }
+    }
+}
+
+impl<K, F, Fut, P, Tm> FusedStream for Supervisor<K, F, Fut, P, Tm>
+where
+    K: Ord + Clone,
+    F: FnMut() -> Fut,
+    Fut: Future,
+    P: RestartPolicy<Fut::Output>,
+    Tm: Timer,
+{
+    fn is_terminated(&self) -> bool {
+        self.factories.is_empty()
+    }
+}
+
+/// A supervised future, or the backoff before restarting it.
+#[pin_project(project = ChildProj)]
+enum Child<Fut, S> {
+    Running {
+        #[pin]
+        future: Fut,
+    },
+    Sleeping {
+        #[pin]
+        sleep: S,
+    },
+}
+
+enum Exit<T> {
+    Completed(T),
+    Woke,
+}
+
+impl<Fut, S> Future for Child<Fut, S>
+where
+    Fut: Future,
+    S: Future,
+{
+    type Output = Exit<Fut::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project() {
+            ChildProj::Running { future } => future.poll(cx).map(Exit::Completed),
+            ChildProj::Sleeping { sleep } => sleep.poll(cx).map(|_| Exit::Woke),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::future::Backoff;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+    use core::future;
+    use futures_lite::future::block_on;
+    use futures_lite::StreamExt;
+
+    fn no_timer(_: Duration) -> future::Ready<()> {
+        panic!("the timer should not be used")
+    }
+
+    #[test]
+    fn restarts_until_limit() {
+        block_on(async {
+            let mut supervisor = Supervisor::new(Restart::always().max_restarts(2), no_timer);
+            assert!(supervisor.insert("a", || future::ready(1)));
+            let events: Vec<_> = (&mut supervisor).collect().await;
+            assert_eq!(
+                events,
+                vec![
+                    SupervisorEvent::Restarted("a", 1),
+                    SupervisorEvent::Restarted("a", 1),
+                    SupervisorEvent::Stopped("a", 1),
+                ]
+            );
+            assert!(supervisor.is_empty());
+        });
+    }
+
+    #[test]
+    fn waits_between_restarts() {
+        block_on(async {
+            let sleeps = RefCell::new(vec![]);
+            let timer = |delay: Duration| {
+                sleeps.borrow_mut().push(delay);
+                future::ready(())
+            };
+            let backoff = Backoff::exponential(Duration::from_millis(10));
+            let policy = Restart::always().max_restarts(3).backoff(backoff);
+            let mut supervisor = Supervisor::new(policy, timer);
+            supervisor.insert(0, || future::ready(()));
+
+            assert_eq!((&mut supervisor).count().await, 4);
+            let expected = [10, 20, 40].map(Duration::from_millis);
+            assert_eq!(*sleeps.borrow(), expected);
+        });
+    }
+
+    #[test]
+    fn on_error() {
+        block_on(async {
+            let mut outputs = vec![Ok(()), Err(2), Err(1)];
+            let policy = Restart::always().on_error();
+            let mut supervisor = Supervisor::new(policy, no_timer);
+            supervisor.insert("a", || future::ready(outputs.pop().unwrap()));
+
+            assert_eq!(
+                supervisor.next().await,
+                Some(SupervisorEvent::Restarted("a", Err(1)))
+            );
+            assert_eq!(supervisor.restarts(&"a"), Some(1));
+            assert_eq!(
+                supervisor.next().await,
+                Some(SupervisorEvent::Restarted("a", Err(2)))
+            );
+            assert_eq!(
+                supervisor.next().await,
+                Some(SupervisorEvent::Stopped("a", Ok(())))
+            );
+            assert_eq!(supervisor.next().await, None);
+        });
+    }
+
+    #[test]
+    fn remove() {
+        block_on(async {
+            let mut supervisor = Supervisor::new(Restart::always(), no_timer);
+            supervisor.insert("a", future::pending::<()>);
+            supervisor.insert("b", future::pending::<()>);
+            assert_eq!(supervisor.len(), 2);
+
+            assert!(supervisor.remove(&"a"));
+            assert!(!supervisor.remove(&"a"));
+            assert!(supervisor.contains_key(&"b"));
+            assert!(supervisor.remove(&"b"));
+            assert_eq!(supervisor.next().await, None);
+        });
+    }
+}
@@ -0,0 +1,148 @@
+use crate::time::TimedOut;
+use crate::utils::WakerArray;
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// The index of the waker for the future.
+const FUTURE: usize = 0;
+/// The index of the waker for the deadline.
+const DEADLINE: usize = 1;
+
+/// Resolves a future, or returns an error if a deadline expires first.
+///
+/// This `struct` is created by the [`timeout_after`] and [`deadline`] methods
+/// on [`FutureExt`]. See their documentation for more.
+///
+/// [`timeout_after`]: crate::future::FutureExt::timeout_after
+/// [`deadline`]: crate::future::FutureExt::deadline
+/// [`FutureExt`]: crate::future::FutureExt
+#[pin_project::pin_project]
+#[must_use = "futures do nothing unless polled or .awaited"]
+pub struct Timeout<F, D> {
+    #[pin]
+    future: F,
+    #[pin]
+    deadline: D,
+    // Separate wakers for the future and the deadline, so a wakeup of one
+    // doesn't cause the other to be polled too.
+    wakers: WakerArray<2>,
+    done: bool,
+}
+
+impl<F, D> Timeout<F, D> {
+    pub(super) fn new(future: F, deadline: D) -> Self {
+        Self {
+            future,
+            deadline,
+            wakers: WakerArray::new(),
+            done: false,
+        }
+    }
+}
+
+impl<F: fmt::Debug, D: fmt::Debug> fmt::Debug for Timeout<F, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timeout")
+            .field("future", &self.future)
+            .field("deadline", &self.deadline)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<F: Future, D: Future> Future for Timeout<F, D> {
+    type Output = Result<F::Output, TimedOut>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        assert!(!*this.done, "future polled after completing");
+
+        let (poll_future, poll_deadline) = {
+            let mut readiness = this.wakers.readiness();
+            readiness.set_waker(cx.waker());
+            (
+                readiness.clear_ready(FUTURE),
+                readiness.clear_ready(DEADLINE),
+            )
+        };
+
+        // Poll the future first, so that it still wins if both it and the
+        // deadline are ready.
+        if poll_future {
+            let mut cx = Context::from_waker(this.wakers.get(FUTURE).unwrap());
+            if let Poll::Ready(value) = this.future.poll(&mut cx) {
+                *this.done = true;
+                return Poll::Ready(Ok(value));
+            }
+        }
+
+        if poll_deadline {
+            let mut cx = Context::from_waker(this.wakers.get(DEADLINE).unwrap());
+            if this.deadline.poll(&mut cx).is_ready() {
+                *this.done = true;
+                return Poll::Ready(Err(TimedOut::new()));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use core::future;
+    use std::time::Duration;
+
+    #[test]
+    fn completes_in_time() {
+        futures_lite::future::block_on(async {
+            let res = future::ready(12)
+                .timeout_after(Duration::from_secs(10), async_io::Timer::after)
+                .await;
+            assert_eq!(res, Ok(12));
+        });
+    }
+
+    #[test]
+    fn times_out() {
+        futures_lite::future::block_on(async {
+            let res = future::pending::<()>()
+                .timeout_after(Duration::from_millis(10), async_io::Timer::after)
+                .await;
+            assert!(res.is_err());
+        });
+    }
+
+    #[test]
+    fn deadline_wakeup_does_not_poll_future() {
+        use core::cell::Cell;
+        use core::task::Poll;
+
+        futures_lite::future::block_on(async {
+            let polls = Cell::new(0);
+            let never = future::poll_fn(|_| {
+                polls.set(polls.get() + 1);
+                Poll::<()>::Pending
+            });
+            // Wakes itself once, and is ready the second time it's polled.
+            let res = never.deadline(futures_lite::future::yield_now()).await;
+            assert!(res.is_err());
+            assert_eq!(polls.get(), 1);
+        });
+    }
+
+    #[test]
+    fn any_future_as_deadline() {
+        futures_lite::future::block_on(async {
+            let res = future::pending::<()>().deadline(future::ready(())).await;
+            assert!(res.is_err());
+
+            let res = future::ready(1).deadline(future::ready(())).await;
+            assert_eq!(res, Ok(1));
+        });
+    }
+}
@@ -1,10 +1,9 @@
 use super::TryJoin as TryJoinTrait;
-use crate::utils::{FutureArray, OutputArray, PollArray, WakerArray};
+use crate::ops::TryError;
+use crate::utils::{MaybeDoneArray, PollArray, WakerArray};
 
 use core::fmt;
 use core::future::{Future, IntoFuture};
-use core::mem::ManuallyDrop;
-use core::ops::DerefMut;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
@@ -21,44 +20,45 @@ use pin_project::{pin_project, pinned_drop};
 #[pin_project(PinnedDrop)]
 pub struct TryJoin<Fut, T, E, const N: usize>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryError<Output = T, Error = E>,
 {
     /// A boolean which holds whether the future has completed
     consumed: bool,
     /// The number of futures which are currently still in-flight
     pending: usize,
-    /// The output data, to be returned after the future completes
-    items: OutputArray<T, N>,
     /// A structure holding the waker passed to the future, and the various
     /// sub-wakers passed to the contained futures.
     wakers: WakerArray<N>,
     /// The individual poll state of each future.
     state: PollArray<N>,
     #[pin]
-    /// The array of futures passed to the structure.
-    futures: FutureArray<Fut, N>,
+    /// The array of futures passed to the structure, which are replaced by
+    /// their outputs as they complete.
+    futures: MaybeDoneArray<Fut, T, N>,
 }
 
 impl<Fut, T, E, const N: usize> TryJoin<Fut, T, E, N>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryError<Output = T, Error = E>,
 {
     #[inline]
     pub(crate) fn new(futures: [Fut; N]) -> Self {
         Self {
             consumed: false,
             pending: N,
-            items: OutputArray::uninit(),
             wakers: WakerArray::new(),
             state: PollArray::new_pending(),
-            futures: FutureArray::new(futures),
+            futures: MaybeDoneArray::new(futures),
         }
     }
 }
 
 impl<Fut, T, E, const N: usize> TryJoinTrait for [Fut; N]
 where
-    Fut: IntoFuture<Output = Result<T, E>>,
+    Fut: IntoFuture,
+    Fut::Output: TryError<Output = T, Error = E>,
 {
     type Output = [T; N];
     type Error = E;
@@ -71,7 +71,8 @@ where
 
 impl<Fut, T, E, const N: usize> fmt::Debug for TryJoin<Fut, T, E, N>
 where
-    Fut: Future<Output = Result<T, E>> + fmt::Debug,
+    Fut: Future + fmt::Debug,
+    Fut::Output: TryError<Output = T, Error = E>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_list().entries(self.state.iter()).finish()
@@ -80,13 +81,14 @@ where
 
 impl<Fut, T, E, const N: usize> Future for TryJoin<Fut, T, E, N>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryError<Output = T, Error = E>,
 {
     type Output = Result<[T; N], E>;
 
     #[inline]
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let this = self.project();
+        let mut this = self.project();
 
         assert!(
             !*this.consumed,
@@ -101,9 +103,9 @@ where
         }
 
         // Poll all ready futures
-        for (i, mut fut) in this.futures.iter().enumerate() {
+        for (i, mut slot) in this.futures.as_mut().iter().enumerate() {
             if this.state[i].is_pending() && readiness.clear_ready(i) {
-                // unlock readiness so we don't deadlock when polling
+                // release readiness so we can borrow the wakers when polling
                 #[allow(clippy::drop_non_drop)]
                 drop(readiness);
 
@@ -112,24 +114,22 @@ where
 
                 // Poll the future
                 // SAFETY: the future's state was "pending", so it's safe to poll
-                if let Poll::Ready(value) = unsafe {
-                    fut.as_mut()
-                        .map_unchecked_mut(|t| t.deref_mut())
-                        .poll(&mut cx)
-                } {
+                if let Poll::Ready(value) = unsafe { slot.as_mut().poll(&mut cx) } {
                     *this.pending -= 1;
 
                     // Check the value, short-circuit on error.
-                    match value {
+                    match value.into_result() {
                         Ok(value) => {
-                            this.items.write(i, value);
-
                             // SAFETY: We're marking the state as "ready", which
                             // means the future has been consumed, and data is
                             // now available to be consumed. The future will no
-                            // longer be used after this point so it's safe to drop.
+                            // longer be used after this point so it's safe to
+                            // drop it and store its output in its place. It's
+                            // no longer tracked as pending by then, so it isn't
+                            // dropped a second time if its destructor panics.
+                            this.state[i].set_none();
+                            unsafe { slot.complete(value) };
                             this.state[i].set_ready();
-                            unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
                         }
                         Err(err) => {
                             // The future should no longer be polled after we're done here
@@ -141,14 +141,14 @@ where
                             // proceeding to drop all other futures and
                             // initiatlized values in the destructor.
                             this.state[i].set_none();
-                            unsafe { ManuallyDrop::drop(fut.get_unchecked_mut()) };
+                            unsafe { slot.drop_future() };
 
                             return Poll::Ready(Err(err));
                         }
                     }
                 }
 
-                // Lock readiness so we can use it again
+                // Reacquire readiness so we can use it again
                 readiness = this.wakers.readiness();
             }
         }
@@ -162,7 +162,7 @@ where
             // filled, which means we're ready to take the data and assume it's initialized.
             debug_assert!(this.state.iter().all(|entry| entry.is_ready()));
             this.state.set_all_none();
-            Poll::Ready(Ok(unsafe { this.items.take() }))
+            Poll::Ready(Ok(unsafe { this.futures.take() }))
         } else {
             Poll::Pending
         }
@@ -173,7 +173,8 @@ where
 #[pinned_drop]
 impl<Fut, T, E, const N: usize> PinnedDrop for TryJoin<Fut, T, E, N>
 where
-    Fut: Future<Output = Result<T, E>>,
+    Fut: Future,
+    Fut::Output: TryError<Output = T, Error = E>,
 {
     fn drop(self: Pin<&mut Self>) {
         let mut this = self.project();
@@ -182,14 +183,14 @@ where
         for i in this.state.ready_indexes() {
             // SAFETY: we've just filtered down to *only* the initialized values.
             // We can assume they're initialized, and this is where we drop them.
-            unsafe { this.items.drop(i) };
+            unsafe { this.futures.as_mut().drop_output(i) };
         }
 
         // Drop all pending futures.
         for i in this.state.pending_indexes() {
             // SAFETY: we've just filtered down to *only* the pending futures,
             // which have not yet been dropped.
-            unsafe { this.futures.as_mut().drop(i) };
+            unsafe { this.futures.as_mut().drop_future(i) };
         }
     }
 }
@@ -227,4 +228,22 @@ mod test {
             assert_eq!(res.unwrap_err(), "oh no");
         });
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn panicking_drop_is_not_repeated() {
+        use crate::utils::PanicOnDrop;
+        use std::cell::Cell;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let drops = Rc::new(Cell::new(0));
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            futures_lite::future::block_on(
+                [PanicOnDrop::new(Ok::<(), ()>(()), drops.clone())].try_join(),
+            )
+        }));
+        assert!(res.is_err());
+        assert_eq!(drops.get(), 1);
+    }
 }
@@ -0,0 +1,113 @@
+use super::vec::TryJoin as VecTryJoin;
+use super::TryJoin as TryJoinTrait;
+use crate::ops::TryError;
+
+use alloc::collections::BTreeMap;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+
+use pin_project::pin_project;
+
+/// A future which waits for all futures in a map to complete successfully, or
+/// abort early on error.
+///
+/// This `struct` is created by the [`try_join`] method on the [`TryJoin`] trait. See
+/// its documentation for more.
+///
+/// [`try_join`]: crate::future::TryJoin::try_join
+/// [`TryJoin`]: crate::future::TryJoin
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct TryJoin<K, Fut, T, E>
+where
+    Fut: Future,
+    Fut::Output: TryError<Output = T, Error = E>,
+{
+    keys: Vec<K>,
+    #[pin]
+    futures: VecTryJoin<Fut, T, E>,
+}
+
+impl<K, Fut, T, E> TryJoinTrait for BTreeMap<K, Fut>
+where
+    K: Ord,
+    Fut: IntoFuture,
+    Fut::Output: TryError<Output = T, Error = E>,
+{
+    type Output = BTreeMap<K, T>;
+    type Error = E;
+    type Future = TryJoin<K, Fut::IntoFuture, T, E>;
+
+    fn try_join(self) -> Self::Future {
+        let (keys, futures) = self
+            .into_iter()
+            .map(|(key, fut)| (key, fut.into_future()))
+            .unzip();
+        TryJoin {
+            keys,
+            futures: VecTryJoin::new(futures),
+        }
+    }
+}
+
+impl<K, Fut, T, E> fmt::Debug for TryJoin<K, Fut, T, E>
+where
+    K: fmt::Debug,
+    Fut: Future + fmt::Debug,
+    Fut::Output: TryError<Output = T, Error = E>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryJoin")
+            .field("keys", &self.keys)
+            .field("futures", &self.futures)
+            .finish()
+    }
+}
+
+impl<K, Fut, T, E> Future for TryJoin<K, Fut, T, E>
+where
+    K: Ord,
+    Fut: Future,
+    Fut::Output: TryError<Output = T, Error = E>,
+{
+    type Output = Result<BTreeMap<K, T>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let outputs = ready!(this.futures.poll(cx))?;
+        let keys = core::mem::take(this.keys);
+        Poll::Ready(Ok(keys.into_iter().zip(outputs).collect()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future;
+
+    #[test]
+    fn all_ok() {
+        futures_lite::future::block_on(async {
+            let futures = BTreeMap::from([
+                (1, future::ready(Ok::<_, ()>("a"))),
+                (2, future::ready(Ok("b"))),
+            ]);
+            let outputs = futures.try_join().await;
+            assert_eq!(outputs, Ok(BTreeMap::from([(1, "a"), (2, "b")])));
+        });
+    }
+
+    #[test]
+    fn one_err() {
+        futures_lite::future::block_on(async {
+            let futures =
+                BTreeMap::from([(1, future::ready(Ok(1))), (2, future::ready(Err("oops")))]);
+            assert_eq!(futures.try_join().await, Err("oops"));
+        });
+    }
+}
@@ -0,0 +1,119 @@
+use super::vec::TryJoin as VecTryJoin;
+use super::TryJoin as TryJoinTrait;
+use crate::ops::TryError;
+
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use std::collections::HashMap;
+
+use pin_project::pin_project;
+
+/// A future which waits for all futures in a map to complete successfully, or
+/// abort early on error.
+///
+/// This `struct` is created by the [`try_join`] method on the [`TryJoin`] trait. See
+/// its documentation for more.
+///
+/// [`try_join`]: crate::future::TryJoin::try_join
+/// [`TryJoin`]: crate::future::TryJoin
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct TryJoin<K, Fut, T, E, S>
+where
+    Fut: Future,
+    Fut::Output: TryError<Output = T, Error = E>,
+{
+    keys: Vec<K>,
+    #[pin]
+    futures: VecTryJoin<Fut, T, E>,
+    _hasher: PhantomData<fn() -> S>,
+}
+
+impl<K, Fut, T, E, S> TryJoinTrait for HashMap<K, Fut, S>
+where
+    K: Eq + Hash,
+    Fut: IntoFuture,
+    Fut::Output: TryError<Output = T, Error = E>,
+    S: BuildHasher + Default,
+{
+    type Output = HashMap<K, T, S>;
+    type Error = E;
+    type Future = TryJoin<K, Fut::IntoFuture, T, E, S>;
+
+    fn try_join(self) -> Self::Future {
+        let (keys, futures) = self
+            .into_iter()
+            .map(|(key, fut)| (key, fut.into_future()))
+            .unzip();
+        TryJoin {
+            keys,
+            futures: VecTryJoin::new(futures),
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<K, Fut, T, E, S> fmt::Debug for TryJoin<K, Fut, T, E, S>
+where
+    K: fmt::Debug,
+    Fut: Future + fmt::Debug,
+    Fut::Output: TryError<Output = T, Error = E>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryJoin")
+            .field("keys", &self.keys)
+            .field("futures", &self.futures)
+            .finish()
+    }
+}
+
+impl<K, Fut, T, E, S> Future for TryJoin<K, Fut, T, E, S>
+where
+    K: Eq + Hash,
+    Fut: Future,
+    Fut::Output: TryError<Output = T, Error = E>,
+    S: BuildHasher + Default,
+{
+    type Output = Result<HashMap<K, T, S>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let outputs = ready!(this.futures.poll(cx))?;
+        let keys = core::mem::take(this.keys);
+        Poll::Ready(Ok(keys.into_iter().zip(outputs).collect()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::future;
+
+    #[test]
+    fn all_ok() {
+        futures_lite::future::block_on(async {
+            let mut futures = HashMap::new();
+            futures.insert("a", future::ready(Ok::<_, ()>(1)));
+            futures.insert("b", future::ready(Ok(2)));
+
+            let outputs = futures.try_join().await.unwrap();
+            assert_eq!(outputs["a"], 1);
+            assert_eq!(outputs["b"], 2);
+        });
+    }
+
+    #[test]
+    fn one_err() {
+        futures_lite::future::block_on(async {
+            let mut futures = HashMap::new();
+            futures.insert("a", future::ready(Ok(1)));
+            futures.insert("b", future::ready(Err("oops")));
+
+            assert_eq!(futures.try_join().await, Err("oops"));
+        });
+    }
+}
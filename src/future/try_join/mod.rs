@@ -1,6 +1,11 @@
-use core::future::Future;
+use crate::ops::TryError;
+use core::future::{Future, IntoFuture};
 
 pub(crate) mod array;
+#[cfg(feature = "alloc")]
+pub(crate) mod btree_map;
+#[cfg(feature = "std")]
+pub(crate) mod hash_map;
 pub(crate) mod tuple;
 #[cfg(feature = "alloc")]
 pub(crate) mod vec;
@@ -12,6 +17,22 @@ pub(crate) mod vec;
 ///
 /// If you want to keep partial data in the case of failure, see the `merge`
 /// operation.
+///
+/// Futures may return either a `Result` or a `ControlFlow`. A `ControlFlow`
+/// short-circuits on `Break`, and its break value is returned as the error.
+///
+/// # Example
+///
+/// ```rust
+/// # futures::executor::block_on(async {
+/// use core::future::ready;
+/// use core::ops::ControlFlow;
+/// use futures_concurrency::prelude::*;
+///
+/// let futs = [ready(ControlFlow::Continue(1)), ready(ControlFlow::Break("stop"))];
+/// assert_eq!(futs.try_join().await, Err("stop"));
+/// # })
+/// ```
 pub trait TryJoin {
     /// The resulting output type.
     type Output;
@@ -27,3 +48,34 @@ pub trait TryJoin {
     /// with an error.
     fn try_join(self) -> Self::Future;
 }
+
+/// Wait for two futures to complete successfully, or abort early on error.
+///
+/// This is a shorthand for calling [`TryJoin::try_join`] on a pair of
+/// futures. To wait for more futures, call `try_join` on a tuple, array or
+/// vector of futures instead.
+///
+/// # Example
+///
+/// ```rust
+/// # futures::executor::block_on(async {
+/// use futures_concurrency::future;
+///
+/// let a = async { Ok::<_, ()>(1) };
+/// let b = async { Ok("hello") };
+/// assert_eq!(future::try_join(a, b).await, Ok((1, "hello")));
+///
+/// let a = async { Ok::<(), _>(()) };
+/// let b = async { Err::<(), _>("oops") };
+/// assert_eq!(future::try_join(a, b).await, Err("oops"));
+/// # })
+/// ```
+pub fn try_join<A, B, T1, T2, E>(a: A, b: B) -> <(A, B) as TryJoin>::Future
+where
+    A: IntoFuture,
+    A::Output: TryError<Output = T1, Error = E>,
+    B: IntoFuture,
+    B::Output: TryError<Output = T2, Error = E>,
+{
+    (a, b).try_join()
+}
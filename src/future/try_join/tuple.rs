@@ -1,4 +1,5 @@
 use super::TryJoin as TryJoinTrait;
+use crate::ops::TryError;
 use crate::utils::{PollArray, WakerArray};
 
 use core::fmt::{self, Debug};
@@ -19,7 +20,7 @@ use pin_project::{pin_project, pinned_drop};
 //
 // This is implemented as a tt-muncher of the future name `$($F:ident)`
 // and the future index `$($rest)`, taking advantage that we only support
-// tuples up to  12 elements
+// tuples up to 24 elements (or 12, without the `large-tuples` feature)
 //
 // # References
 // TT Muncher: https://veykril.github.io/tlborm/decl-macros/patterns/tt-muncher.html
@@ -36,7 +37,7 @@ macro_rules! unsafe_poll {
                 *$this.completed += 1;
 
                 // Check the value, short-circuit on error.
-                match value {
+                match value.into_result() {
                     Ok(value) => {
                         $this.outputs.$fut_idx.write(value);
 
@@ -72,7 +73,7 @@ macro_rules! unsafe_poll {
 
     // macro start
     ($iteration:ident, $this:ident, $futures:ident, $cx:ident, $LEN:ident, $($F:ident,)+) => {
-        unsafe_poll!(@inner $iteration, $this, $futures, $cx, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11);
+        unsafe_poll!(@inner $iteration, $this, $futures, $cx, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23);
     };
 }
 
@@ -94,7 +95,7 @@ macro_rules! drop_initialized_values {
 
     // macro start
     ($($outs:ident,)+ | $states:expr) => {
-        drop_initialized_values!(@drop $($outs,)+ | $states, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11,);
+        drop_initialized_values!(@drop $($outs,)+ | $states, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,);
     };
 }
 
@@ -117,7 +118,7 @@ macro_rules! drop_pending_futures {
 
     // macro start
     ($states:ident, $futures:ident, $($F:ident,)+) => {
-        drop_pending_futures!(@inner $states, $futures, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11);
+        drop_pending_futures!(@inner $states, $futures, $($F)+ | 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23);
     };
 }
 
@@ -217,7 +218,8 @@ macro_rules! impl_try_join_tuple {
         #[allow(unused_variables)]
         impl<$($F, $T,)+ Err> Future for $StructName<$($F, $T,)+ Err>
         where $(
-            $F: Future<Output = Result<$T, Err>>,
+            $F: Future,
+            $F::Output: TryError<Output = $T, Error = Err>,
         )+ {
             type Output = Result<($($T,)+), Err>;
 
@@ -244,7 +246,7 @@ macro_rules! impl_try_join_tuple {
                         continue;
                     }
 
-                    // unlock readiness so we don't deadlock when polling
+                    // release readiness so we can borrow the wakers when polling
                     #[allow(clippy::drop_non_drop)]
                     drop(readiness);
 
@@ -292,7 +294,8 @@ macro_rules! impl_try_join_tuple {
         #[allow(unused_parens)]
         impl<$($F, $T,)+ Err> TryJoinTrait for ($($F,)+)
         where $(
-            $F: IntoFuture<Output = Result<$T, Err>>,
+            $F: IntoFuture,
+            $F::Output: TryError<Output = $T, Error = Err>,
         )+ {
             type Output = ($($T,)+);
             type Error = Err;
@@ -329,6 +332,30 @@ impl_try_join_tuple! { try_join_9 TryJoin9 (A ResA) (B ResB) (C ResC) (D ResD) (
 impl_try_join_tuple! { try_join_10 TryJoin10 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) }
 impl_try_join_tuple! { try_join_11 TryJoin11 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) }
 impl_try_join_tuple! { try_join_12 TryJoin12 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_13 TryJoin13 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_14 TryJoin14 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_15 TryJoin15 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_16 TryJoin16 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_17 TryJoin17 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_18 TryJoin18 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_19 TryJoin19 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_20 TryJoin20 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) (V ResV) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_21 TryJoin21 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) (V ResV) (W ResW) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_22 TryJoin22 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) (V ResV) (W ResW) (X ResX) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_23 TryJoin23 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) (V ResV) (W ResW) (X ResX) (Y ResY) }
+#[cfg(feature = "large-tuples")]
+impl_try_join_tuple! { try_join_24 TryJoin24 (A ResA) (B ResB) (C ResC) (D ResD) (E ResE) (F ResF) (G ResG) (H ResH) (I ResI) (J ResJ) (K ResK) (L ResL) (M ResM) (N ResN) (O ResO) (P ResP) (Q ResQ) (R ResR) (U ResU) (V ResV) (W ResW) (X ResX) (Y ResY) (Z ResZ) }
 
 #[cfg(test)]
 mod test {
@@ -336,6 +363,7 @@ mod test {
 
     use core::convert::Infallible;
     use core::future;
+    use core::ops::ControlFlow;
 
     #[test]
     fn all_ok() {
@@ -359,6 +387,20 @@ mod test {
         })
     }
 
+    #[test]
+    fn control_flow() {
+        futures_lite::future::block_on(async {
+            let a = future::ready(ControlFlow::<&str, _>::Continue(1));
+            let b = future::ready(Ok::<_, &str>('z'));
+            assert_eq!((a, b).try_join().await, Ok((1, 'z')));
+
+            let a = future::ready(ControlFlow::Break("stop"));
+            let b = future::pending::<Result<(), _>>();
+            let res: Result<((), ()), _> = (a, b).try_join().await;
+            assert_eq!(res, Err("stop"));
+        })
+    }
+
     #[test]
     fn issue_135_resume_after_completion() {
         use futures_lite::future::yield_now;
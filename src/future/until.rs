@@ -0,0 +1,81 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Resolves a future, unless it's cancelled first.
+///
+/// This `struct` is created by the [`until`] method on [`FutureExt`]. See its
+/// documentation for more.
+///
+/// [`until`]: crate::future::FutureExt::until
+/// [`FutureExt`]: crate::future::FutureExt
+#[derive(Debug)]
+#[pin_project::pin_project]
+#[must_use = "futures do nothing unless polled or .awaited"]
+pub struct Until<F, D> {
+    #[pin]
+    future: F,
+    #[pin]
+    deadline: D,
+    done: bool,
+}
+
+impl<F, D> Until<F, D> {
+    pub(super) fn new(future: F, deadline: D) -> Self {
+        Self {
+            future,
+            deadline,
+            done: false,
+        }
+    }
+}
+
+impl<F: Future, D: Future> Future for Until<F, D> {
+    type Output = Option<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        assert!(!*this.done, "future polled after completing");
+
+        // Poll the future first, so that finished work isn't thrown away if
+        // both it and the deadline are ready.
+        if let Poll::Ready(value) = this.future.poll(cx) {
+            *this.done = true;
+            return Poll::Ready(Some(value));
+        }
+
+        if this.deadline.poll(cx).is_ready() {
+            *this.done = true;
+            return Poll::Ready(None);
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::sync::StopSource;
+    use core::future;
+
+    #[test]
+    fn completes() {
+        futures_lite::future::block_on(async {
+            let source = StopSource::new();
+            let res = future::ready(12).until(source.token()).await;
+            assert_eq!(res, Some(12));
+        });
+    }
+
+    #[test]
+    fn cancelled() {
+        futures_lite::future::block_on(async {
+            let source = StopSource::new();
+            let token = source.token();
+            source.stop();
+            let res = future::pending::<()>().until(token).await;
+            assert_eq!(res, None);
+        });
+    }
+}
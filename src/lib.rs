@@ -81,8 +81,18 @@
 //! | **Continue on error**      | `Future::join`           | `Future::race_ok`         |
 //! | **Short-circuit on error** | `Future::try_join`       | `Future::race`            |
 //!
+//! To wait for several futures to succeed rather than just one, use
+//! [`race_ok_quorum`][future::RaceOkQuorum].
+//!
+//! Futures which return an `Option` can use [`join_some`][future::JoinSome] and
+//! [`race_some`][future::RaceSome] instead, which treat `None` the way
+//! `try_join` and `race_ok` treat errors.
+//!
 //! The following futures implementations are provided by `futures-concurrency`:
 //! - [`FutureGroup`][future::FutureGroup]: A growable group of futures which operate as a single unit.
+//! - [`FutureMap`][future::FutureMap]: A growable group of futures indexed by caller-provided keys.
+//! - [`ArrayFutureGroup`][future::ArrayFutureGroup]: A fixed-capacity group of futures stored in-line, usable without an allocator.
+//! - [`Supervisor`][future::Supervisor]: A group of futures which are restarted according to a policy when they complete.
 //! - `tuple`: [`join`][future::Join#impl-Join-for-(A,+B)], [`try_join`][future::TryJoin#impl-TryJoin-for-(A,+B)], [`race`][future::Race#impl-Race-for-(A,+B)], [`race_ok`][future::RaceOk#impl-RaceOk-for-(A,+B)]
 //! - `array`: [`join`][future::Join#impl-Join-for-\[Fut;+N\]], [`try_join`][future::TryJoin#impl-TryJoin-for-\[Fut;+N\]], [`race`][future::Race#impl-Race-for-\[Fut;+N\]], [`race_ok`][future::RaceOk#impl-RaceOk-for-\[Fut;+N\]]
 //! - `Vec`: [`join`][future::Join#impl-Join-for-Vec<Fut>], [`try_join`][future::TryJoin#impl-TryJoin-for-Vec<Fut>], [`race`][future::Race#impl-Race-for-Vec<Fut>], [`race_ok`][future::RaceOk#impl-RaceOk-for-Vec<Fut>]
@@ -100,16 +110,14 @@
 //!
 //! |                                 | __Sequential output processing__ | __Concurrent output processing__ |
 //! | ------------------------------- | -------------------------------- | -------------------------------- |
-//! | __Sequential input evaluation__ | `Stream::chain`                  | *not yet available* ‡            |
+//! | __Sequential input evaluation__ | `Stream::chain`                  | `Stream::unzip`                  |
 //! | __Concurrent input evaluation__ | `Stream::zip`                    | `Stream::merge`                  |
-//!
-//!  ‡: _This could be addressed by a hypothetical `Stream::unzip` operation,
-//!  however because we aspire for semantic compatibility with `std::iter::Iterator`
-//!  in our operations, the path to adding it is currently unclear_.
 //!  
 //! The following streams implementations are provided by `futures-concurrency`:
 //!
 //! - [`StreamGroup`][stream::StreamGroup]: A growable group of streams which operate as a single unit.
+//! - [`ArrayStreamGroup`][stream::ArrayStreamGroup]: A fixed-capacity group of streams stored in-line, usable without an allocator.
+//! - [`ChainQueue`][stream::ChainQueue]: A growable queue of streams which are iterated over in sequence.
 //! - [`ConcurrentStream`][concurrent_stream::ConcurrentStream]: A trait for asynchronous streams which can concurrently process items.
 //! - `tuple`: [`chain`][stream::Chain#impl-Chain-for-(A,+B)], [`merge`][stream::Merge#impl-Merge-for-(A,+B)], [`zip`][stream::Zip#impl-Zip-for-(A,+B)]
 //! - `array`: [`chain`][stream::Chain#impl-Chain-for-\[Fut;+N\]], [`merge`][stream::Merge#impl-Merge-for-\[Fut;+N\]], [`zip`][stream::Zip#impl-Zip-for-\[Fut;+N\]]
@@ -137,6 +145,39 @@
 //! futures-concurrency = { version = "7.5.0", default-features = false, features = ["alloc"] }
 //! ```
 //!
+//! The `alloc` feature enables the growable collections, such as
+//! [`FutureGroup`][future::FutureGroup], [`StreamGroup`][stream::StreamGroup]
+//! and [`ConcurrentStream`][concurrent_stream::ConcurrentStream]. It also
+//! enables per-entry wakers for the fixed-size combinators, so that only the
+//! entries which were woken are polled again. Without an allocator there is
+//! nowhere for those wakers to live, so arrays and tuples poll every pending
+//! entry whenever any of them is woken. The per-entry wakers also need atomic
+//! read-modify-write operations, so on targets without those every
+//! combinator falls back to polling all pending entries.
+//!
+//! By default the tuple implementations of `Join`, `TryJoin`, `Race`,
+//! `RaceOk`, `Merge`, `Zip` and `Chain` support up to 12 elements. The
+//! `large-tuples` feature flag extends this to 24 elements, at the cost of
+//! longer compile times.
+//!
+//! The `tracing` feature flag emits [`tracing`](https://docs.rs/tracing)
+//! events at the trace level when the futures in a `join`, `FutureGroup` or
+//! `StreamGroup` are inserted, polled, completed, or cancelled. This makes it
+//! possible to tell which branch of a large `join` is stuck.
+//!
+//! The `parking_lot` feature flag backs the locks shared between handles, such
+//! as those of [`WaitGroup`][sync::WaitGroup] and
+//! [`StreamExt::unzip_buffered`][stream::StreamExt::unzip_buffered], with
+//! [`parking_lot`](https://docs.rs/parking_lot) rather than `std::sync::Mutex`.
+//!
+//! The `test-utils` feature flag enables the [`test_utils`] module, which
+//! contains deterministic futures, streams and channels for testing code built
+//! on this crate.
+//!
+//! The `sink` feature flag enables the [`sink`] module, which sends items to
+//! vectors, arrays and tuples of [`Sink`](https://docs.rs/futures/latest/futures/sink/trait.Sink.html)s
+//! concurrently.
+//!
 //! # Further Reading
 //!
 //! `futures-concurrency` has been developed over the span of several years. It is
@@ -157,47 +198,85 @@
 extern crate alloc;
 
 mod collections;
+mod macros;
 mod utils;
 
-#[doc(hidden)]
-pub use utils::private;
-
 /// The futures concurrency prelude.
 pub mod prelude {
     pub use super::future::FutureExt as _;
     pub use super::stream::StreamExt as _;
 
     pub use super::future::Join as _;
+    #[cfg(feature = "alloc")]
+    pub use super::future::JoinLimited as _;
+    pub use super::future::JoinSome as _;
+    pub use super::future::JoinStream as _;
     pub use super::future::Race as _;
+    pub use super::future::RaceBiased as _;
+    pub use super::future::RaceKeepRest as _;
     pub use super::future::RaceOk as _;
+    pub use super::future::RaceOkHeterogeneous as _;
+    pub use super::future::RaceOkIndexed as _;
+    #[cfg(feature = "alloc")]
+    pub use super::future::RaceOkQuorum as _;
+    pub use super::future::RaceSome as _;
     pub use super::future::TryJoin as _;
     pub use super::stream::Chain as _;
+    pub use super::stream::CombineLatest as _;
+    pub use super::stream::Interleave as _;
     pub use super::stream::IntoStream as _;
     pub use super::stream::Merge as _;
+    pub use super::stream::MergeHeterogeneous as _;
+    pub use super::stream::MergeIndexed as _;
+    pub use super::stream::Race as _;
+    pub use super::stream::TryMerge as _;
     pub use super::stream::Zip as _;
 
     #[cfg(feature = "alloc")]
     pub use super::concurrent_stream::{
         ConcurrentStream, FromConcurrentStream, IntoConcurrentStream,
     };
+
+    #[cfg(feature = "sink")]
+    pub use super::sink::{FanOut as _, RaceSend as _, TryBroadcast as _};
 }
 
 #[cfg(feature = "alloc")]
 pub mod concurrent_stream;
 
+#[cfg(feature = "alloc")]
+pub use collections::btree_map;
+#[cfg(feature = "std")]
+pub use collections::hash_map;
 #[cfg(feature = "alloc")]
 pub use collections::vec;
 
 pub mod future;
+pub mod ops;
+#[cfg(feature = "sink")]
+pub mod sink;
 pub mod stream;
+#[cfg(feature = "std")]
+pub mod sync;
+#[cfg(feature = "alloc")]
+pub mod task;
+#[cfg(any(feature = "test-utils", all(test, feature = "alloc")))]
+pub mod test_utils;
+pub mod time;
 
 /// Helper functions and types for fixed-length arrays.
 pub mod array {
     pub use crate::future::join::array::Join;
+    pub use crate::future::join_stream::array::JoinStream;
     pub use crate::future::race::array::Race;
-    pub use crate::future::race_ok::array::{AggregateError, RaceOk};
+    pub use crate::future::race_keep_rest::array::RaceKeepRest;
+    pub use crate::future::race_ok::array::{AggregateError, RaceOk, RaceOkIndexed};
     pub use crate::future::try_join::array::TryJoin;
     pub use crate::stream::chain::array::Chain;
-    pub use crate::stream::merge::array::Merge;
+    pub use crate::stream::combine_latest::array::CombineLatest;
+    pub use crate::stream::interleave::array::Interleave;
+    pub use crate::stream::merge::array::{Merge, MergeIndexed};
+    pub use crate::stream::race::array::Race as RaceStream;
+    pub use crate::stream::try_merge::array::TryMerge;
     pub use crate::stream::zip::array::Zip;
 }
@@ -0,0 +1,253 @@
+/// Wait for all futures to complete, and return their outputs as a tuple.
+///
+/// This macro must be used inside of an `async` context. It accepts any
+/// number of futures, which don't need to be of the same type, and expands
+/// to the [`Join`] implementation for tuples. Unlike calling `join` on a
+/// tuple directly, this isn't limited to tuples of 12 elements.
+///
+/// [`Join`]: crate::future::Join
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::join;
+///
+/// # futures::executor::block_on(async {
+/// let a = async { 1 };
+/// let b = async { "hello" };
+/// let c = async { 3u16 };
+///
+/// let (a, b, c) = join!(a, b, c);
+/// assert_eq!((a, b, c), (1, "hello", 3));
+/// # });
+/// ```
+#[macro_export]
+macro_rules! join {
+    ($($fut:expr),+ $(,)?) => {
+        $crate::__join_flatten!(@[] $crate::__join_nested!($($fut),+).await; $($fut),+)
+    };
+}
+
+/// Wait for all futures to complete successfully, or return early on error.
+///
+/// This macro must be used inside of an `async` context. It accepts any
+/// number of futures which return a `Result` with the same error type, and
+/// expands to the [`TryJoin`] implementation for tuples. On success, the
+/// outputs are returned as a tuple. Unlike calling `try_join` on a tuple
+/// directly, this isn't limited to tuples of 12 elements.
+///
+/// [`TryJoin`]: crate::future::TryJoin
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::try_join;
+///
+/// # futures::executor::block_on(async {
+/// let a = async { Ok::<_, &str>(1) };
+/// let b = async { Ok("hello") };
+/// assert_eq!(try_join!(a, b), Ok((1, "hello")));
+///
+/// let a = async { Ok::<(), _>(()) };
+/// let b = async { Err::<(), _>("oops") };
+/// assert_eq!(try_join!(a, b), Err("oops"));
+/// # });
+/// ```
+#[macro_export]
+macro_rules! try_join {
+    ($($fut:expr),+ $(,)?) => {
+        match $crate::__try_join_nested!($($fut),+).await {
+            ::core::result::Result::Ok(output) => {
+                ::core::result::Result::Ok($crate::__join_flatten!(@[] output; $($fut),+))
+            }
+            ::core::result::Result::Err(err) => ::core::result::Result::Err(err),
+        }
+    };
+}
+
+/// Wait for the first future to complete, and return its output.
+///
+/// This macro must be used inside of an `async` context. It accepts any
+/// number of futures with the same output type, and expands to the [`Race`]
+/// implementation for tuples. The other futures are cancelled. Unlike
+/// calling `race` on a tuple directly, this isn't limited to tuples of 12
+/// elements.
+///
+/// [`Race`]: crate::future::Race
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::race;
+///
+/// # futures::executor::block_on(async {
+/// let a = core::future::pending();
+/// let b = async { "hello" };
+/// assert_eq!(race!(a, b), "hello");
+/// # });
+/// ```
+#[macro_export]
+macro_rules! race {
+    ($($fut:expr),+ $(,)?) => {
+        $crate::__race_nested!($($fut),+).await
+    };
+}
+
+/// Join futures as nested pairs, so that any number of futures can be joined.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __join_nested {
+    ($a:expr) => {
+        $crate::future::Join::join(($a,))
+    };
+    ($a:expr, $b:expr) => {
+        $crate::future::Join::join(($a, $b))
+    };
+    ($a:expr, $($rest:expr),+) => {
+        $crate::future::Join::join(($a, $crate::__join_nested!($($rest),+)))
+    };
+}
+
+/// Try to join futures as nested pairs, so that any number of futures can be
+/// joined.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __try_join_nested {
+    ($a:expr) => {
+        $crate::future::TryJoin::try_join(($a,))
+    };
+    ($a:expr, $b:expr) => {
+        $crate::future::TryJoin::try_join(($a, $b))
+    };
+    ($a:expr, $($rest:expr),+) => {
+        $crate::future::TryJoin::try_join(($a, $crate::__try_join_nested!($($rest),+)))
+    };
+}
+
+/// Flatten the nested pairs created by `__join_nested` into a single tuple.
+///
+/// The futures are only passed in to count them, and are never evaluated.
+/// Every expansion binds a fresh `x`, which macro hygiene keeps distinct from
+/// the `x`s bound by the expansions before it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __join_flatten {
+    (@[$($bound:ident)*] $output:expr; $_a:expr) => {{
+        let (x,) = $output;
+        ($($bound,)* x,)
+    }};
+    (@[$($bound:ident)*] $output:expr; $_a:expr, $_b:expr) => {{
+        let (x, y) = $output;
+        ($($bound,)* x, y)
+    }};
+    (@[$($bound:ident)*] $output:expr; $_a:expr, $($rest:expr),+) => {{
+        let (x, rest) = $output;
+        $crate::__join_flatten!(@[$($bound)* x] rest; $($rest),+)
+    }};
+}
+
+/// Race futures in tuples of up to 12 elements, nesting the remaining futures
+/// in the last element.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __race_nested {
+    (
+        $a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr,
+        $g:expr, $h:expr, $i:expr, $j:expr, $k:expr, $($rest:expr),+
+    ) => {
+        $crate::future::Race::race((
+            $a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k,
+            $crate::__race_nested!($($rest),+),
+        ))
+    };
+    ($($fut:expr),+) => {
+        $crate::future::Race::race(($($fut,)+))
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use core::future::{pending, ready};
+
+    #[test]
+    fn join_many() {
+        futures_lite::future::block_on(async {
+            let (a, b, c, d, e, f, g, h, i, j, k, l, m, n) = join!(
+                ready(0u8),
+                ready(1u16),
+                ready(2u32),
+                ready(3u64),
+                ready(4u8),
+                ready(5u16),
+                ready(6u32),
+                ready(7u64),
+                ready(8u8),
+                ready(9u16),
+                ready(10u32),
+                ready(11u64),
+                ready(12u8),
+                ready("thirteen"),
+            );
+            assert_eq!(
+                (a, b, c, d, e, f, g, h, i, j, k, l),
+                (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)
+            );
+            assert_eq!((m, n), (12, "thirteen"));
+            assert_eq!(join!(ready(1)), (1,));
+        });
+    }
+
+    #[test]
+    fn try_join_many() {
+        futures_lite::future::block_on(async {
+            let ok = |n| ready(Ok::<_, &str>(n));
+            let out = try_join!(
+                ok(0),
+                ok(1),
+                ok(2),
+                ok(3),
+                ok(4),
+                ok(5),
+                ok(6),
+                ok(7),
+                ok(8),
+                ok(9),
+                ok(10),
+                ok(11),
+                ok(12),
+            );
+            let (a, b, c, d, e, f, g, h, i, j, k, l, m) = out.unwrap();
+            assert_eq!(
+                (a, b, c, d, e, f, g, h, i, j, k, l),
+                (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)
+            );
+            assert_eq!(m, 12);
+
+            let out = try_join!(ok(0), ok(1), ready(Err::<i32, _>("oops")));
+            assert_eq!(out, Err("oops"));
+        });
+    }
+
+    #[test]
+    fn race_many() {
+        futures_lite::future::block_on(async {
+            let out = race!(
+                pending(),
+                pending(),
+                pending(),
+                pending(),
+                pending(),
+                pending(),
+                pending(),
+                pending(),
+                pending(),
+                pending(),
+                pending(),
+                pending(),
+                ready(12),
+                pending(),
+            );
+            assert_eq!(out, 12);
+        });
+    }
+}
@@ -0,0 +1,252 @@
+//! Traits for fallible operations.
+//!
+//! Operations such as [`try_join`] and [`try_for_each`] short-circuit as soon
+//! as a value signals failure. Which values count as a failure is decided by
+//! the [`Try`] trait, a stable counterpart of the standard library's unstable
+//! `Try` trait. It is implemented for `Result`, `Option` and `ControlFlow`,
+//! and can be implemented for user-defined types too. Types which implement
+//! [`TryError`] as well can short-circuit `Result`-returning operations.
+//!
+//! [`try_join`]: crate::future::TryJoin::try_join
+//! [`try_for_each`]: crate::concurrent_stream::ConcurrentStream::try_for_each
+//!
+//! # Examples
+//!
+//! ```
+//! use core::convert::Infallible;
+//! use core::future::ready;
+//! use core::ops::ControlFlow;
+//! use futures_concurrency::ops::{Try, TryError};
+//! use futures_concurrency::prelude::*;
+//!
+//! #[derive(Debug, PartialEq)]
+//! enum ApiResult<T> {
+//!     Success(T),
+//!     Failure(u16),
+//! }
+//!
+//! impl<T> Try for ApiResult<T> {
+//!     type Output = T;
+//!     type Residual = ApiResult<Infallible>;
+//!
+//!     fn from_output(output: T) -> Self {
+//!         ApiResult::Success(output)
+//!     }
+//!
+//!     fn from_residual(residual: Self::Residual) -> Self {
+//!         match residual {
+//!             ApiResult::Failure(code) => ApiResult::Failure(code),
+//!             ApiResult::Success(never) => match never {},
+//!         }
+//!     }
+//!
+//!     fn branch(self) -> ControlFlow<Self::Residual, T> {
+//!         match self {
+//!             ApiResult::Success(output) => ControlFlow::Continue(output),
+//!             ApiResult::Failure(code) => ControlFlow::Break(ApiResult::Failure(code)),
+//!         }
+//!     }
+//! }
+//!
+//! impl<T> TryError for ApiResult<T> {
+//!     type Error = u16;
+//!
+//!     fn into_result(self) -> Result<T, u16> {
+//!         match self {
+//!             ApiResult::Success(output) => Ok(output),
+//!             ApiResult::Failure(code) => Err(code),
+//!         }
+//!     }
+//! }
+//!
+//! # futures_lite::future::block_on(async {
+//! let futs = [ready(ApiResult::Success(1)), ready(ApiResult::Failure(404))];
+//! assert_eq!(futs.try_join().await, Err(404));
+//!
+//! let res: ApiResult<Vec<u8>> = vec![1, 2, 3]
+//!     .into_co_stream()
+//!     .map(|n| async move { ApiResult::Success(n) })
+//!     .try_collect()
+//!     .await;
+//! assert_eq!(res, ApiResult::Success(vec![1, 2, 3]));
+//! # });
+//! ```
+
+use core::convert::Infallible;
+use core::ops::ControlFlow::{self, Break, Continue};
+use core::task::Poll;
+
+// NOTE: adapted from `rayon`
+/// A type which can short-circuit an operation.
+///
+/// This is a stable clone of `std::ops::Try`. A value is either an output,
+/// which lets the operation continue, or a residual, which makes it return
+/// early.
+pub trait Try {
+    /// The value produced when the operation continues.
+    type Output;
+
+    /// The value carried along when the operation short-circuits.
+    type Residual;
+
+    /// Construct the type from its output.
+    fn from_output(output: Self::Output) -> Self;
+
+    /// Construct the type from a residual.
+    fn from_residual(residual: Self::Residual) -> Self;
+
+    /// Decide whether the operation should continue or short-circuit.
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+impl<B, C> Try for ControlFlow<B, C> {
+    type Output = C;
+    type Residual = ControlFlow<B, Infallible>;
+
+    fn from_output(output: Self::Output) -> Self {
+        Continue(output)
+    }
+
+    fn from_residual(residual: Self::Residual) -> Self {
+        match residual {
+            Break(b) => Break(b),
+            Continue(_) => unreachable!(),
+        }
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Continue(c) => Continue(c),
+            Break(b) => Break(Break(b)),
+        }
+    }
+}
+
+impl<T> Try for Option<T> {
+    type Output = T;
+    type Residual = Option<Infallible>;
+
+    fn from_output(output: Self::Output) -> Self {
+        Some(output)
+    }
+
+    fn from_residual(residual: Self::Residual) -> Self {
+        match residual {
+            None => None,
+            Some(_) => unreachable!(),
+        }
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Some(c) => Continue(c),
+            None => Break(None),
+        }
+    }
+}
+
+impl<T, E> Try for Result<T, E> {
+    type Output = T;
+    type Residual = Result<Infallible, E>;
+
+    fn from_output(output: Self::Output) -> Self {
+        Ok(output)
+    }
+
+    fn from_residual(residual: Self::Residual) -> Self {
+        match residual {
+            Err(e) => Err(e),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Ok(c) => Continue(c),
+            Err(e) => Break(Err(e)),
+        }
+    }
+}
+
+impl<T, E> Try for Poll<Result<T, E>> {
+    type Output = Poll<T>;
+    type Residual = Result<Infallible, E>;
+
+    fn from_output(output: Self::Output) -> Self {
+        output.map(Ok)
+    }
+
+    fn from_residual(residual: Self::Residual) -> Self {
+        match residual {
+            Err(e) => Poll::Ready(Err(e)),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Poll::Pending => Continue(Poll::Pending),
+            Poll::Ready(Ok(c)) => Continue(Poll::Ready(c)),
+            Poll::Ready(Err(e)) => Break(Err(e)),
+        }
+    }
+}
+
+impl<T, E> Try for Poll<Option<Result<T, E>>> {
+    type Output = Poll<Option<T>>;
+    type Residual = Result<Infallible, E>;
+
+    fn from_output(output: Self::Output) -> Self {
+        match output {
+            Poll::Ready(o) => Poll::Ready(o.map(Ok)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn from_residual(residual: Self::Residual) -> Self {
+        match residual {
+            Err(e) => Poll::Ready(Some(Err(e))),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self {
+            Poll::Pending => Continue(Poll::Pending),
+            Poll::Ready(None) => Continue(Poll::Ready(None)),
+            Poll::Ready(Some(Ok(c))) => Continue(Poll::Ready(Some(c))),
+            Poll::Ready(Some(Err(e))) => Break(Err(e)),
+        }
+    }
+}
+
+/// A [`Try`] type which can short-circuit `Result`-returning operations, such
+/// as `try_join`.
+///
+/// The residual of the type is converted into the error of the operation.
+pub trait TryError: Try {
+    /// The error the type short-circuits with.
+    type Error;
+
+    /// Convert into a `Result`, mapping the residual to an error.
+    fn into_result(self) -> Result<Self::Output, Self::Error>;
+}
+
+impl<T, E> TryError for Result<T, E> {
+    type Error = E;
+
+    fn into_result(self) -> Result<Self::Output, Self::Error> {
+        self
+    }
+}
+
+impl<B, C> TryError for ControlFlow<B, C> {
+    type Error = B;
+
+    fn into_result(self) -> Result<Self::Output, Self::Error> {
+        match self {
+            Continue(c) => Ok(c),
+            Break(b) => Err(b),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use super::Sinks;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::vec;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project::pin_project;
+
+/// How far along sending the item to a sink is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Progress {
+    /// Waiting for the sink to be ready to accept the item.
+    Ready,
+    /// The item has been sent, and is being flushed.
+    Flush,
+    Done,
+}
+
+/// A future which sends an item to every sink in a collection.
+///
+/// This `struct` is created by the [`fan_out`] method on the [`FanOut`]
+/// trait. See its documentation for more.
+///
+/// [`fan_out`]: crate::sink::FanOut::fan_out
+/// [`FanOut`]: crate::sink::FanOut
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct FanOutFuture<'a, C, T> {
+    sinks: &'a mut C,
+    item: T,
+    progress: Vec<Progress>,
+    remaining: usize,
+}
+
+impl<'a, C, T> FanOutFuture<'a, C, T>
+where
+    C: Sinks<T>,
+{
+    pub(super) fn new(sinks: &'a mut C, item: T) -> Self {
+        let len = sinks.len();
+        Self {
+            sinks,
+            item,
+            progress: vec![Progress::Ready; len],
+            remaining: len,
+        }
+    }
+}
+
+impl<C, T> fmt::Debug for FanOutFuture<'_, C, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FanOutFuture")
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<C, T> Future for FanOutFuture<'_, C, T>
+where
+    C: Sinks<T>,
+    T: Clone,
+{
+    type Output = Result<(), C::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // None of the fields are pinned: the sinks are `Unpin`, and the item
+        // is only ever cloned.
+        let this = self.project();
+
+        for (index, progress) in this.progress.iter_mut().enumerate() {
+            if *progress == Progress::Ready {
+                match this.sinks.poll_ready(index, cx) {
+                    Poll::Ready(Ok(())) => {
+                        this.sinks.start_send(index, this.item.clone())?;
+                        *progress = Progress::Flush;
+                    }
+                    Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                    Poll::Pending => continue,
+                }
+            }
+            if *progress == Progress::Flush {
+                match this.sinks.poll_flush(index, cx) {
+                    Poll::Ready(Ok(())) => {
+                        *progress = Progress::Done;
+                        *this.remaining -= 1;
+                    }
+                    Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                    Poll::Pending => {}
+                }
+            }
+        }
+
+        match this.remaining {
+            0 => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+}
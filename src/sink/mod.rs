@@ -0,0 +1,243 @@
+//! Concurrency operations for collections of sinks.
+//!
+//! A [`Sink`](futures_sink::Sink) is the write half of an async channel: a
+//! value into which items can be sent asynchronously. The traits in this
+//! module send a single item to vectors, arrays and tuples of sinks
+//! concurrently, so that one slow sink doesn't hold up the others.
+//!
+//! - [`FanOut`]: send the item to every sink, and wait for all of them to
+//!   accept it.
+//! - [`RaceSend`]: send the item to the first sink which is ready to accept
+//!   it.
+//! - [`TryBroadcast`]: send the item to every sink which is ready to accept
+//!   it right away, skipping the others.
+//!
+//! # Examples
+//!
+//! ```
+//! use futures::channel::mpsc;
+//! use futures::StreamExt;
+//! use futures_concurrency::prelude::*;
+//!
+//! # futures::executor::block_on(async {
+//! let (a_tx, a_rx) = mpsc::unbounded();
+//! let (b_tx, b_rx) = mpsc::unbounded();
+//! let mut sinks = vec![a_tx, b_tx];
+//!
+//! sinks.fan_out("hello").await.unwrap();
+//! drop(sinks);
+//!
+//! assert_eq!(a_rx.collect::<Vec<_>>().await, vec!["hello"]);
+//! assert_eq!(b_rx.collect::<Vec<_>>().await, vec!["hello"]);
+//! # });
+//! ```
+
+mod fan_out;
+mod race_send;
+mod sinks;
+mod try_broadcast;
+
+pub use fan_out::FanOutFuture;
+pub use race_send::{RaceSendError, RaceSendFuture};
+pub use sinks::Sinks;
+pub use try_broadcast::TryBroadcastFuture;
+
+/// Send an item to every sink in a collection.
+pub trait FanOut<T>: Sinks<T> {
+    /// Send an item to every sink in the collection, and wait for all of them
+    /// to accept and flush it.
+    ///
+    /// Each sink receives its own clone of the item. The sinks are driven
+    /// concurrently, so one sink which is slow to become ready doesn't stop
+    /// the others from receiving the item. If any sink fails, the future
+    /// resolves with that error right away; sinks which had already accepted
+    /// the item keep it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let mut sinks = (Vec::new(), Vec::new());
+    /// sinks.fan_out(12).await.unwrap();
+    /// assert_eq!(sinks, (vec![12], vec![12]));
+    /// # });
+    /// ```
+    fn fan_out(&mut self, item: T) -> FanOutFuture<'_, Self, T>
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        FanOutFuture::new(self, item)
+    }
+}
+
+impl<C, T> FanOut<T> for C where C: Sinks<T> {}
+
+/// Send an item to the first sink in a collection which is ready to accept it.
+pub trait RaceSend<T>: Sinks<T> {
+    /// Send an item to the first sink in the collection which is ready to
+    /// accept it, and wait for that sink to flush it.
+    ///
+    /// Resolves with the index of the sink which accepted the item. If
+    /// multiple sinks are ready at the same time, the one earliest in the
+    /// collection wins. Sinks which fail to become ready are skipped; if every
+    /// sink fails, the future resolves with [`RaceSendError::Failed`] holding
+    /// the last error. If the collection contains no sinks, the item can't be
+    /// sent and the future resolves with [`RaceSendError::NoSinks`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::channel::mpsc;
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// // A channel without a buffer holds one message per sender.
+    /// let (mut a_tx, _a_rx) = mpsc::channel(0);
+    /// a_tx.try_send(1).unwrap();
+    /// let (b_tx, _b_rx) = mpsc::unbounded();
+    /// let mut sinks = (a_tx, b_tx);
+    ///
+    /// // The first channel is full, so the second one wins.
+    /// assert_eq!(sinks.race_send(2).await, Ok(1));
+    /// # });
+    /// ```
+    fn race_send(&mut self, item: T) -> RaceSendFuture<'_, Self, T>
+    where
+        Self: Sized,
+    {
+        RaceSendFuture::new(self, item)
+    }
+}
+
+impl<C, T> RaceSend<T> for C where C: Sinks<T> {}
+
+/// Send an item to every sink in a collection which is ready to accept it.
+pub trait TryBroadcast<T>: Sinks<T> {
+    /// Send an item to every sink in the collection which is ready to accept
+    /// it right away, and wait for those sinks to flush it.
+    ///
+    /// Sinks which aren't ready when the item is offered are skipped rather
+    /// than waited on, which keeps a full or slow sink from applying
+    /// backpressure to the others. Resolves with the number of sinks which
+    /// accepted the item. If any sink fails, the future resolves with that
+    /// error right away.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures::channel::mpsc;
+    /// use futures_concurrency::prelude::*;
+    ///
+    /// # futures::executor::block_on(async {
+    /// // A channel without a buffer holds one message per sender.
+    /// let (mut a_tx, _a_rx) = mpsc::channel(0);
+    /// a_tx.try_send(1).unwrap();
+    /// let (b_tx, _b_rx) = mpsc::unbounded();
+    /// let (c_tx, _c_rx) = mpsc::unbounded();
+    /// let mut sinks = (a_tx, b_tx, c_tx);
+    ///
+    /// // The first channel is full, so only the others receive the item.
+    /// assert_eq!(sinks.try_broadcast(2).await, Ok(2));
+    /// # });
+    /// ```
+    fn try_broadcast(&mut self, item: T) -> TryBroadcastFuture<'_, Self, T>
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        TryBroadcastFuture::new(self, item)
+    }
+}
+
+impl<C, T> TryBroadcast<T> for C where C: Sinks<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::future::Join;
+
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use futures::channel::mpsc;
+    use futures::StreamExt;
+    use futures_lite::future::block_on;
+
+    #[test]
+    fn fan_out_waits_for_every_sink() {
+        block_on(async {
+            // A channel without a buffer holds one message per sender.
+            let (mut a_tx, mut a_rx) = mpsc::channel(0);
+            a_tx.try_send(1).unwrap();
+            let (b_tx, b_rx) = mpsc::unbounded();
+            let mut sinks = (a_tx, b_tx);
+
+            let send = sinks.fan_out(2);
+            let recv = async { (a_rx.next().await, a_rx.next().await) };
+            let (res, a) = (send, recv).join().await;
+            assert_eq!(res, Ok(()));
+            assert_eq!(a, (Some(1), Some(2)));
+
+            drop(sinks);
+            assert_eq!(b_rx.collect::<Vec<_>>().await, vec![2]);
+        });
+    }
+
+    #[test]
+    fn fan_out_fails_fast() {
+        block_on(async {
+            let (a_tx, _a_rx) = mpsc::unbounded::<u8>();
+            let (b_tx, b_rx) = mpsc::unbounded();
+            drop(b_rx);
+            let mut sinks = [a_tx, b_tx];
+            assert!(sinks.fan_out(1).await.unwrap_err().is_disconnected());
+        });
+    }
+
+    #[test]
+    fn race_send_skips_failed_sinks() {
+        block_on(async {
+            let (a_tx, a_rx) = mpsc::unbounded::<u8>();
+            let (b_tx, mut b_rx) = mpsc::unbounded();
+            drop(a_rx);
+            let mut sinks = vec![a_tx, b_tx];
+            assert_eq!(sinks.race_send(1).await, Ok(1));
+            assert_eq!(b_rx.next().await, Some(1));
+
+            drop(b_rx);
+            match sinks.race_send(2).await {
+                Err(RaceSendError::Failed(err)) => assert!(err.is_disconnected()),
+                res => panic!("expected every sink to fail, got {res:?}"),
+            }
+        });
+    }
+
+    #[test]
+    fn race_send_empty() {
+        block_on(async {
+            let mut sinks: Vec<Vec<u8>> = vec![];
+            assert_eq!(sinks.race_send(1).await, Err(RaceSendError::NoSinks));
+        });
+    }
+
+    #[test]
+    fn try_broadcast_skips_full_sinks() {
+        block_on(async {
+            let (mut a_tx, a_rx) = mpsc::channel(0);
+            a_tx.try_send(1).unwrap();
+            let (b_tx, b_rx) = mpsc::unbounded();
+            let (c_tx, c_rx) = mpsc::unbounded();
+            let mut sinks = (a_tx, b_tx, c_tx);
+
+            assert_eq!(sinks.try_broadcast(2).await, Ok(2));
+            assert_eq!(sinks.try_broadcast(3).await, Ok(2));
+
+            drop(sinks);
+            assert_eq!(a_rx.collect::<Vec<_>>().await, vec![1]);
+            assert_eq!(b_rx.collect::<Vec<_>>().await, vec![2, 3]);
+            assert_eq!(c_rx.collect::<Vec<_>>().await, vec![2, 3]);
+        });
+    }
+}
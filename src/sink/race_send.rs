@@ -0,0 +1,146 @@
+use super::Sinks;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::vec;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project::pin_project;
+
+/// The error returned when [`race_send`] fails to send an item.
+///
+/// [`race_send`]: crate::sink::RaceSend::race_send
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RaceSendError<E> {
+    /// The collection contains no sinks, so the item couldn't be sent.
+    NoSinks,
+    /// Every sink failed. This holds the error of the last sink which failed.
+    Failed(E),
+}
+
+impl<E: fmt::Display> fmt::Display for RaceSendError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSinks => write!(f, "there are no sinks to send the item to"),
+            Self::Failed(err) => write!(f, "every sink failed, the last with: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for RaceSendError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NoSinks => None,
+            Self::Failed(err) => Some(err),
+        }
+    }
+}
+
+/// A future which sends an item to the first sink in a collection which is
+/// ready to accept it.
+///
+/// This `struct` is created by the [`race_send`] method on the [`RaceSend`]
+/// trait. See its documentation for more.
+///
+/// [`race_send`]: crate::sink::RaceSend::race_send
+/// [`RaceSend`]: crate::sink::RaceSend
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct RaceSendFuture<'a, C, T>
+where
+    C: Sinks<T>,
+{
+    sinks: &'a mut C,
+    item: Option<T>,
+    /// The sink which accepted the item, and is being flushed.
+    winner: Option<usize>,
+    failed: Vec<bool>,
+    /// The error of the last sink which failed.
+    error: Option<C::Error>,
+}
+
+impl<'a, C, T> RaceSendFuture<'a, C, T>
+where
+    C: Sinks<T>,
+{
+    pub(super) fn new(sinks: &'a mut C, item: T) -> Self {
+        let len = sinks.len();
+        Self {
+            sinks,
+            item: Some(item),
+            winner: None,
+            failed: vec![false; len],
+            error: None,
+        }
+    }
+}
+
+impl<C, T> fmt::Debug for RaceSendFuture<'_, C, T>
+where
+    C: Sinks<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RaceSendFuture")
+            .field("winner", &self.winner)
+            .finish()
+    }
+}
+
+impl<C, T> Future for RaceSendFuture<'_, C, T>
+where
+    C: Sinks<T>,
+{
+    type Output = Result<usize, RaceSendError<C::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // None of the fields are pinned: the sinks are `Unpin`, and the item
+        // is moved into the winning sink.
+        let this = self.project();
+
+        if this.failed.is_empty() {
+            return Poll::Ready(Err(RaceSendError::NoSinks));
+        }
+
+        if this.winner.is_none() {
+            for (index, failed) in this.failed.iter_mut().enumerate() {
+                if *failed {
+                    continue;
+                }
+                match this.sinks.poll_ready(index, cx) {
+                    Poll::Ready(Ok(())) => {
+                        let item = this.item.take().expect("polled after completion");
+                        this.sinks
+                            .start_send(index, item)
+                            .map_err(RaceSendError::Failed)?;
+                        *this.winner = Some(index);
+                        break;
+                    }
+                    // Give the other sinks a chance to accept the item.
+                    Poll::Ready(Err(err)) => {
+                        *failed = true;
+                        *this.error = Some(err);
+                    }
+                    Poll::Pending => {}
+                }
+            }
+        }
+
+        match *this.winner {
+            Some(index) => this
+                .sinks
+                .poll_flush(index, cx)
+                .map_ok(|()| index)
+                .map_err(RaceSendError::Failed),
+            None if this.failed.iter().all(|failed| *failed) => {
+                let err = this.error.take().expect("every sink has failed");
+                Poll::Ready(Err(RaceSendError::Failed(err)))
+            }
+            None => Poll::Pending,
+        }
+    }
+}
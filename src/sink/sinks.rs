@@ -0,0 +1,182 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_sink::Sink;
+
+/// A collection of sinks which accept the same items.
+///
+/// This trait is sealed: it's implemented for vectors, arrays and tuples of
+/// sinks, and can't be implemented outside of this crate.
+pub trait Sinks<T>: private::Sealed {
+    /// The error type shared by all sinks.
+    type Error;
+
+    /// The number of sinks in the collection.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the collection contains no sinks.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Call [`Sink::poll_ready`] on the sink at `index`.
+    fn poll_ready(&mut self, index: usize, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+
+    /// Call [`Sink::start_send`] on the sink at `index`.
+    fn start_send(&mut self, index: usize, item: T) -> Result<(), Self::Error>;
+
+    /// Call [`Sink::poll_flush`] on the sink at `index`.
+    fn poll_flush(&mut self, index: usize, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+impl<S> private::Sealed for Vec<S> {}
+
+impl<S, T> Sinks<T> for Vec<S>
+where
+    S: Sink<T> + Unpin,
+{
+    type Error = S::Error;
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn poll_ready(&mut self, index: usize, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self[index]).poll_ready(cx)
+    }
+
+    fn start_send(&mut self, index: usize, item: T) -> Result<(), Self::Error> {
+        Pin::new(&mut self[index]).start_send(item)
+    }
+
+    fn poll_flush(&mut self, index: usize, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self[index]).poll_flush(cx)
+    }
+}
+
+impl<S, const N: usize> private::Sealed for [S; N] {}
+
+impl<S, T, const N: usize> Sinks<T> for [S; N]
+where
+    S: Sink<T> + Unpin,
+{
+    type Error = S::Error;
+
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn poll_ready(&mut self, index: usize, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self[index]).poll_ready(cx)
+    }
+
+    fn start_send(&mut self, index: usize, item: T) -> Result<(), Self::Error> {
+        Pin::new(&mut self[index]).start_send(item)
+    }
+
+    fn poll_flush(&mut self, index: usize, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self[index]).poll_flush(cx)
+    }
+}
+
+/// Call a method on the tuple field at a runtime index.
+macro_rules! dispatch {
+    ($this:ident, $index:ident, $mod_name:ident, $method:ident $args:tt, $($S:ident)+) => {{
+        let ($($S,)+) = $this;
+        $(
+            if $index == $mod_name::Indexes::$S as usize {
+                return Pin::new($S).$method $args;
+            }
+        )+
+        panic!("sink index out of bounds")
+    }};
+}
+
+macro_rules! impl_sinks_tuple {
+    ($mod_name:ident $First:ident $($S:ident)*) => {
+        mod $mod_name {
+            #[repr(usize)]
+            pub(super) enum Indexes { $First, $($S),* }
+
+            pub(super) const LEN: usize = [Indexes::$First, $(Indexes::$S),*].len();
+        }
+
+        impl<$First, $($S),*> private::Sealed for ($First, $($S,)*) {}
+
+        impl<T, $First, $($S),*> Sinks<T> for ($First, $($S,)*)
+        where
+            $First: Sink<T> + Unpin,
+            $($S: Sink<T, Error = $First::Error> + Unpin,)*
+        {
+            type Error = $First::Error;
+
+            fn len(&self) -> usize {
+                $mod_name::LEN
+            }
+
+            fn poll_ready(
+                &mut self,
+                index: usize,
+                cx: &mut Context<'_>,
+            ) -> Poll<Result<(), Self::Error>> {
+                dispatch!(self, index, $mod_name, poll_ready(cx), $First $($S)*)
+            }
+
+            fn start_send(&mut self, index: usize, item: T) -> Result<(), Self::Error> {
+                dispatch!(self, index, $mod_name, start_send(item), $First $($S)*)
+            }
+
+            fn poll_flush(
+                &mut self,
+                index: usize,
+                cx: &mut Context<'_>,
+            ) -> Poll<Result<(), Self::Error>> {
+                dispatch!(self, index, $mod_name, poll_flush(cx), $First $($S)*)
+            }
+        }
+    };
+}
+
+impl_sinks_tuple! { sinks1 A }
+impl_sinks_tuple! { sinks2 A B }
+impl_sinks_tuple! { sinks3 A B C }
+impl_sinks_tuple! { sinks4 A B C D }
+impl_sinks_tuple! { sinks5 A B C D E }
+impl_sinks_tuple! { sinks6 A B C D E F }
+impl_sinks_tuple! { sinks7 A B C D E F G }
+impl_sinks_tuple! { sinks8 A B C D E F G H }
+impl_sinks_tuple! { sinks9 A B C D E F G H I }
+impl_sinks_tuple! { sinks10 A B C D E F G H I J }
+impl_sinks_tuple! { sinks11 A B C D E F G H I J K }
+impl_sinks_tuple! { sinks12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks16 A B C D E F G H I J K L M N O P }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks17 A B C D E F G H I J K L M N O P Q }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks18 A B C D E F G H I J K L M N O P Q R }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks19 A B C D E F G H I J K L M N O P Q R S }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks20 A B C D E F G H I J K L M N O P Q R S U }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks21 A B C D E F G H I J K L M N O P Q R S U V }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks22 A B C D E F G H I J K L M N O P Q R S U V W }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks23 A B C D E F G H I J K L M N O P Q R S U V W X }
+#[cfg(feature = "large-tuples")]
+impl_sinks_tuple! { sinks24 A B C D E F G H I J K L M N O P Q R S U V W X Y }
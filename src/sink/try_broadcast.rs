@@ -0,0 +1,105 @@
+use super::Sinks;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::vec;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use pin_project::pin_project;
+
+/// A future which sends an item to every sink in a collection which is ready
+/// to accept it right away.
+///
+/// This `struct` is created by the [`try_broadcast`] method on the
+/// [`TryBroadcast`] trait. See its documentation for more.
+///
+/// [`try_broadcast`]: crate::sink::TryBroadcast::try_broadcast
+/// [`TryBroadcast`]: crate::sink::TryBroadcast
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[pin_project]
+pub struct TryBroadcastFuture<'a, C, T> {
+    sinks: &'a mut C,
+    item: T,
+    /// Whether the item has been offered to the sinks yet.
+    offered: bool,
+    /// The sinks which accepted the item, and still need to be flushed.
+    flushing: Vec<bool>,
+    accepted: usize,
+}
+
+impl<'a, C, T> TryBroadcastFuture<'a, C, T>
+where
+    C: Sinks<T>,
+{
+    pub(super) fn new(sinks: &'a mut C, item: T) -> Self {
+        let len = sinks.len();
+        Self {
+            sinks,
+            item,
+            offered: false,
+            flushing: vec![false; len],
+            accepted: 0,
+        }
+    }
+}
+
+impl<C, T> fmt::Debug for TryBroadcastFuture<'_, C, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryBroadcastFuture")
+            .field("offered", &self.offered)
+            .field("accepted", &self.accepted)
+            .finish()
+    }
+}
+
+impl<C, T> Future for TryBroadcastFuture<'_, C, T>
+where
+    C: Sinks<T>,
+    T: Clone,
+{
+    type Output = Result<usize, C::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // None of the fields are pinned: the sinks are `Unpin`, and the item
+        // is only ever cloned.
+        let this = self.project();
+
+        // Only offer the item once, so sinks which weren't ready don't get a
+        // second chance when we're polled again to flush.
+        if !*this.offered {
+            *this.offered = true;
+            for (index, flushing) in this.flushing.iter_mut().enumerate() {
+                match this.sinks.poll_ready(index, cx) {
+                    Poll::Ready(Ok(())) => {
+                        this.sinks.start_send(index, this.item.clone())?;
+                        *flushing = true;
+                        *this.accepted += 1;
+                    }
+                    Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                    Poll::Pending => {}
+                }
+            }
+        }
+
+        let mut pending = false;
+        for (index, flushing) in this.flushing.iter_mut().enumerate() {
+            if !*flushing {
+                continue;
+            }
+            match this.sinks.poll_flush(index, cx) {
+                Poll::Ready(Ok(())) => *flushing = false,
+                Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                Poll::Pending => pending = true,
+            }
+        }
+
+        match pending {
+            true => Poll::Pending,
+            false => Poll::Ready(Ok(*this.accepted)),
+        }
+    }
+}
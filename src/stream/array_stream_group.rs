@@ -0,0 +1,315 @@
+use core::fmt::{self, Debug};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+use crate::utils::{size_hint, Indexer, WakerArray};
+
+pub use crate::future::array_future_group::CapacityError;
+
+/// A fixed-capacity group of streams which act as a single unit.
+///
+/// This is like [`StreamGroup`][super::StreamGroup], but stores up to `N`
+/// streams in-line, so it doesn't need an allocator to hold them. This makes
+/// it usable on targets without an allocator. Because streams are stored
+/// in-line, the group needs to be pinned before streams can be inserted.
+///
+/// With the `alloc` feature on targets with pointer-sized atomics, the group
+/// creates a waker for each of its `N` entries when it's created, which takes
+/// a small allocation per entry. Only the streams which were woken are polled
+/// again. Otherwise the group never allocates, but polls every stream
+/// whenever any of them is woken.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::stream::ArrayStreamGroup;
+/// use futures_lite::{stream, StreamExt};
+/// use std::pin::pin;
+///
+/// # futures_lite::future::block_on(async {
+/// let mut group = pin!(ArrayStreamGroup::<_, 4>::new());
+/// group.as_mut().try_insert(stream::once(2)).unwrap();
+/// group.as_mut().try_insert(stream::once(4)).unwrap();
+///
+/// let mut out = 0;
+/// while let Some(num) = group.next().await {
+///     out += num;
+/// }
+/// assert_eq!(out, 6);
+/// # });
+/// ```
+#[must_use = "`ArrayStreamGroup` does nothing if not iterated over"]
+pub struct ArrayStreamGroup<S, const N: usize> {
+    streams: [Option<S>; N],
+    wakers: WakerArray<N>,
+    indexer: Indexer,
+    len: usize,
+}
+
+impl<S, const N: usize> Debug for ArrayStreamGroup<S, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayStreamGroup")
+            .field("streams", &"[..]")
+            .field("len", &self.len)
+            .field("capacity", &N)
+            .finish()
+    }
+}
+
+impl<S, const N: usize> Default for ArrayStreamGroup<S, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, const N: usize> ArrayStreamGroup<S, N> {
+    /// Create a new instance of `ArrayStreamGroup`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::ArrayStreamGroup;
+    ///
+    /// let group = ArrayStreamGroup::<_, 4>::new();
+    /// # let group: ArrayStreamGroup<usize, 4> = group;
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            streams: [(); N].map(|_| None),
+            wakers: WakerArray::new(),
+            indexer: Indexer::new(N),
+            len: 0,
+        }
+    }
+
+    /// Return the number of streams currently active in the group.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return the maximum number of streams the group can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Return `true` if there are no streams currently active in the group.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return `true` if no more streams can be inserted into the group.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns `true` if the group contains a value for the specified key.
+    pub fn contains_key(&self, key: Key) -> bool {
+        self.streams.get(key.0).is_some_and(Option::is_some)
+    }
+
+    /// Removes a stream from the group. Returns whether the stream was part
+    /// of the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::ArrayStreamGroup;
+    /// use futures_lite::stream;
+    /// use std::pin::pin;
+    ///
+    /// let mut group = pin!(ArrayStreamGroup::<_, 4>::new());
+    /// let key = group.as_mut().try_insert(stream::once(4)).unwrap();
+    /// assert_eq!(group.len(), 1);
+    /// group.as_mut().remove(key);
+    /// assert_eq!(group.len(), 0);
+    /// ```
+    pub fn remove(self: Pin<&mut Self>, key: Key) -> bool {
+        // SAFETY: streams are never moved out of their slots, and assigning
+        // `None` drops the stream in place.
+        let this = unsafe { self.get_unchecked_mut() };
+        match this.streams.get_mut(key.0) {
+            Some(slot @ Some(_)) => {
+                *slot = None;
+                this.len -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<S: Stream, const N: usize> ArrayStreamGroup<S, N> {
+    /// Insert a new stream into the group, or return it in an error if the
+    /// group is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::ArrayStreamGroup;
+    /// use futures_lite::stream;
+    /// use std::pin::pin;
+    ///
+    /// let mut group = pin!(ArrayStreamGroup::<_, 1>::new());
+    /// assert!(group.as_mut().try_insert(stream::once(1)).is_ok());
+    /// assert!(group.as_mut().try_insert(stream::once(2)).is_err());
+    /// ```
+    pub fn try_insert(self: Pin<&mut Self>, stream: S) -> Result<Key, CapacityError<S>> {
+        // SAFETY: inserting a stream into a vacant slot doesn't move any of
+        // the other streams.
+        let this = unsafe { self.get_unchecked_mut() };
+        let Some(index) = this.streams.iter().position(Option::is_none) else {
+            return Err(CapacityError::new(stream));
+        };
+        this.streams[index] = Some(stream);
+        this.len += 1;
+        this.wakers.readiness().set_ready(index);
+        Ok(Key(index))
+    }
+
+    fn poll_next_inner(
+        self: Pin<&mut Self>,
+        cx: &Context<'_>,
+    ) -> Poll<Option<(Key, <S as Stream>::Item)>> {
+        // SAFETY: streams are never moved out of their slots, and are only
+        // dropped in place.
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // Short-circuit if we have no streams to iterate over
+        if this.len == 0 {
+            return Poll::Ready(None);
+        }
+
+        // Set the top-level waker and check readiness
+        let mut readiness = this.wakers.readiness();
+        readiness.set_waker(cx.waker());
+        if !readiness.any_ready() {
+            // Nothing is ready yet
+            return Poll::Pending;
+        }
+
+        // Start from a different slot every time, so a stream which is always
+        // ready can't starve the ones after it.
+        for index in this.indexer.iter() {
+            if this.streams[index].is_none() || !readiness.clear_ready(index) {
+                continue;
+            }
+
+            // release readiness so we can borrow the wakers when polling
+            #[allow(clippy::drop_non_drop)]
+            drop(readiness);
+
+            // Obtain the intermediate waker.
+            let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+
+            // SAFETY: the stream is pinned in its slot.
+            let stream = unsafe { Pin::new_unchecked(this.streams[index].as_mut().unwrap()) };
+            match stream.poll_next(&mut cx) {
+                Poll::Ready(Some(item)) => {
+                    // We just obtained an item from this index, make sure
+                    // we check it again on a next iteration
+                    this.wakers.readiness().set_ready(index);
+                    return Poll::Ready(Some((Key(index), item)));
+                }
+                // A stream has ended, keep going until we find an item
+                Poll::Ready(None) => {
+                    this.streams[index] = None;
+                    this.len -= 1;
+                }
+                Poll::Pending => {}
+            }
+
+            // Reacquire readiness so we can use it again
+            readiness = this.wakers.readiness();
+        }
+
+        // Every remaining stream is pending, and will wake us when it's ready.
+        match this.len {
+            0 => Poll::Ready(None),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+impl<S: Stream, const N: usize> Stream for ArrayStreamGroup<S, N> {
+    type Item = <S as Stream>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.poll_next_inner(cx) {
+            Poll::Ready(Some((_key, item))) => Poll::Ready(Some(item)),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::sum(self.streams.iter().flatten().map(Stream::size_hint))
+    }
+}
+
+impl<S: Stream, const N: usize> FusedStream for ArrayStreamGroup<S, N> {
+    fn is_terminated(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// A key used to index into the `ArrayStreamGroup` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key(usize);
+
+#[cfg(test)]
+mod test {
+    use super::ArrayStreamGroup;
+    use core::pin::pin;
+    use futures_lite::{prelude::*, stream};
+
+    #[test]
+    fn smoke() {
+        futures_lite::future::block_on(async {
+            let mut group = pin!(ArrayStreamGroup::<_, 2>::new());
+            group.as_mut().try_insert(stream::iter(vec![1, 2])).unwrap();
+            group.as_mut().try_insert(stream::iter(vec![3])).unwrap();
+
+            let mut out = 0;
+            while let Some(num) = group.next().await {
+                out += num;
+            }
+            assert_eq!(out, 6);
+            assert!(group.is_empty());
+        });
+    }
+
+    #[test]
+    fn insert_when_full() {
+        futures_lite::future::block_on(async {
+            let mut group = pin!(ArrayStreamGroup::<_, 1>::new());
+            group.as_mut().try_insert(stream::once(1)).unwrap();
+            assert!(group.is_full());
+            assert!(group.as_mut().try_insert(stream::once(2)).is_err());
+
+            // Slots are reused once a stream finishes.
+            assert_eq!(group.next().await, Some(1));
+            assert_eq!(group.next().await, None);
+            group.as_mut().try_insert(stream::once(3)).unwrap();
+            assert_eq!(group.next().await, Some(3));
+        });
+    }
+
+    #[test]
+    fn busy_stream_does_not_starve_others() {
+        futures_lite::future::block_on(async {
+            let mut group = pin!(ArrayStreamGroup::<_, 2>::new());
+            group
+                .as_mut()
+                .try_insert(stream::repeat(1).boxed())
+                .unwrap();
+            group.as_mut().try_insert(stream::once(2).boxed()).unwrap();
+
+            let mut out = [0; 4];
+            for slot in &mut out {
+                *slot = group.next().await.unwrap();
+            }
+            assert!(out.contains(&2));
+        });
+    }
+}
@@ -0,0 +1,161 @@
+use core::fmt;
+use core::future::IntoFuture;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+use crate::future::FutureGroup;
+
+/// Run the futures yielded by a stream concurrently, up to a limit, yielding
+/// their outputs as they complete.
+///
+/// This `struct` is created by the [`buffer_concurrent`] method on
+/// [`StreamExt`]. See its documentation for more.
+///
+/// [`buffer_concurrent`]: crate::stream::StreamExt::buffer_concurrent
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct BufferConcurrent<S>
+where
+    S: Stream,
+    S::Item: IntoFuture,
+{
+    #[pin]
+    stream: S,
+    group: FutureGroup<<S::Item as IntoFuture>::IntoFuture>,
+    limit: usize,
+    done: bool,
+}
+
+impl<S> BufferConcurrent<S>
+where
+    S: Stream,
+    S::Item: IntoFuture,
+{
+    pub(crate) fn new(stream: S, limit: usize) -> Self {
+        assert!(limit > 0, "buffer_concurrent limit must be non-zero");
+        BufferConcurrent {
+            stream,
+            group: FutureGroup::with_capacity(limit),
+            limit,
+            done: false,
+        }
+    }
+}
+
+impl<S> fmt::Debug for BufferConcurrent<S>
+where
+    S: Stream + fmt::Debug,
+    S::Item: IntoFuture,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferConcurrent")
+            .field("stream", &self.stream)
+            .field("in_flight", &self.group.len())
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<S> Stream for BufferConcurrent<S>
+where
+    S: Stream,
+    S::Item: IntoFuture,
+{
+    type Item = <S::Item as IntoFuture>::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Pull in new futures until we've reached the limit.
+        while !*this.done && this.group.len() < *this.limit {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(future)) => {
+                    this.group.insert(future.into_future());
+                }
+                Poll::Ready(None) => *this.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        match Pin::new(this.group).poll_next(cx) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(item)),
+            Poll::Ready(None) if *this.done => Poll::Ready(None),
+            // The group is empty, but the stream may still yield more futures.
+            Poll::Ready(None) | Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let in_flight = self.group.len();
+        if self.done {
+            return (in_flight, Some(in_flight));
+        }
+        let (low, high) = self.stream.size_hint();
+        let low = low.saturating_add(in_flight);
+        let high = high.and_then(|high| high.checked_add(in_flight));
+        (low, high)
+    }
+}
+
+impl<S> FusedStream for BufferConcurrent<S>
+where
+    S: Stream,
+    S::Item: IntoFuture,
+{
+    fn is_terminated(&self) -> bool {
+        self.done && self.group.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use core::cell::Cell;
+    use core::future;
+    use core::task::Poll;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn yields_all_outputs() {
+        block_on(async {
+            let s = stream::iter(1..=5).map(future::ready).buffer_concurrent(2);
+            let mut out: Vec<_> = s.collect().await;
+            out.sort();
+            assert_eq!(out, [1, 2, 3, 4, 5]);
+        })
+    }
+
+    #[test]
+    fn respects_the_limit() {
+        block_on(async {
+            let in_flight = Cell::new(0);
+            let max = Cell::new(0);
+            let s = stream::iter(0..10)
+                .map(|n| {
+                    let in_flight = &in_flight;
+                    let max = &max;
+                    let mut started = false;
+                    future::poll_fn(move |cx| {
+                        if !started {
+                            started = true;
+                            in_flight.set(in_flight.get() + 1);
+                            max.set(max.get().max(in_flight.get()));
+                            cx.waker().wake_by_ref();
+                            return Poll::Pending;
+                        }
+                        in_flight.set(in_flight.get() - 1);
+                        Poll::Ready(n)
+                    })
+                })
+                .buffer_concurrent(3);
+            assert_eq!(s.count().await, 10);
+            assert_eq!(max.get(), 3);
+        })
+    }
+}
@@ -2,7 +2,7 @@ use core::fmt;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::pin_project;
 
 use crate::utils;
@@ -51,6 +51,18 @@ impl<S: Stream, const N: usize> Stream for Chain<S, N> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Streams before the current index have already been exhausted.
+        let hints = self.streams[self.index..].iter().map(Stream::size_hint);
+        utils::size_hint::sum(hints)
+    }
+}
+
+impl<S: Stream, const N: usize> FusedStream for Chain<S, N> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
 }
 
 impl<S, const N: usize> fmt::Debug for Chain<S, N>
@@ -84,6 +96,21 @@ mod tests {
     use futures_lite::prelude::*;
     use futures_lite::stream;
 
+    #[test]
+    fn chain_size_hint() {
+        block_on(async {
+            let a = stream::iter(vec![1, 2]);
+            let b = stream::iter(vec![3]);
+            let mut s = [a, b].chain();
+
+            assert_eq!(s.size_hint(), (3, Some(3)));
+            assert_eq!(s.next().await, Some(1));
+            assert_eq!(s.next().await, Some(2));
+            assert_eq!(s.next().await, Some(3));
+            assert_eq!(s.size_hint(), (0, Some(0)));
+        })
+    }
+
     #[test]
     fn chain_3() {
         block_on(async {
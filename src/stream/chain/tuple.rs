@@ -2,9 +2,10 @@ use core::fmt;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 
 use super::Chain;
+use crate::utils;
 
 macro_rules! impl_chain_for_tuple {
     ($mod_name: ident $StructName:ident $($F:ident)+) => {
@@ -62,6 +63,18 @@ macro_rules! impl_chain_for_tuple {
                     }
                 }
             }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                // Streams before the current index have already been exhausted.
+                let hints = [$(
+                    if $mod_name::$F < self.index {
+                        utils::size_hint::EMPTY
+                    } else {
+                        self.$F.size_hint()
+                    },
+                )+];
+                utils::size_hint::sum(hints)
+            }
         }
 
         impl<$($F,)+> fmt::Debug for $StructName<$($F,)+>
@@ -75,6 +88,15 @@ macro_rules! impl_chain_for_tuple {
             }
         }
 
+        impl<T, $($F,)+> FusedStream for $StructName<$($F,)+>
+        where
+            $($F: Stream<Item = T>,)+
+        {
+            fn is_terminated(&self) -> bool {
+                self.done
+            }
+        }
+
         impl<T, $($F,)+> Chain for ($($F,)+)
         where
             $($F: Stream<Item = T>,)+
@@ -107,6 +129,30 @@ impl_chain_for_tuple! { chain_9 Chain9 A B C D E F G H I }
 impl_chain_for_tuple! { chain_10 Chain10 A B C D E F G H I J }
 impl_chain_for_tuple! { chain_11 Chain11 A B C D E F G H I J K }
 impl_chain_for_tuple! { chain_12 Chain12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_13 Chain13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_14 Chain14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_15 Chain15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_16 Chain16 A B C D E F G H I J K L M N O P }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_17 Chain17 A B C D E F G H I J K L M N O P Q }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_18 Chain18 A B C D E F G H I J K L M N O P Q R }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_19 Chain19 A B C D E F G H I J K L M N O P Q R U }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_20 Chain20 A B C D E F G H I J K L M N O P Q R U V }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_21 Chain21 A B C D E F G H I J K L M N O P Q R U V W }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_22 Chain22 A B C D E F G H I J K L M N O P Q R U V W X }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_23 Chain23 A B C D E F G H I J K L M N O P Q R U V W X Y }
+#[cfg(feature = "large-tuples")]
+impl_chain_for_tuple! { chain_24 Chain24 A B C D E F G H I J K L M N O P Q R U V W X Y Z }
 
 #[cfg(test)]
 mod tests {
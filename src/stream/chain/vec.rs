@@ -1,13 +1,15 @@
+use alloc::collections::VecDeque;
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 
 use core::fmt;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::pin_project;
 
+use crate::stream::IntoStream;
 use crate::utils;
 
 use super::Chain as ChainTrait;
@@ -54,6 +56,18 @@ impl<S: Stream> Stream for Chain<S> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Streams before the current index have already been exhausted.
+        let hints = self.streams[self.index..].iter().map(Stream::size_hint);
+        utils::size_hint::sum(hints)
+    }
+}
+
+impl<S: Stream> FusedStream for Chain<S> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
 }
 
 impl<S> fmt::Debug for Chain<S>
@@ -80,6 +94,49 @@ impl<S: Stream> ChainTrait for Vec<S> {
     }
 }
 
+impl<S> ChainTrait for Box<[S]>
+where
+    S: IntoStream,
+{
+    type Item = <Chain<S::IntoStream> as Stream>::Item;
+    type Stream = Chain<S::IntoStream>;
+
+    fn chain(self) -> Self::Stream {
+        Vec::from(self)
+            .into_iter()
+            .map(|s| s.into_stream())
+            .collect::<Vec<_>>()
+            .chain()
+    }
+}
+
+impl<S> ChainTrait for VecDeque<S>
+where
+    S: IntoStream,
+{
+    type Item = <Chain<S::IntoStream> as Stream>::Item;
+    type Stream = Chain<S::IntoStream>;
+
+    fn chain(self) -> Self::Stream {
+        self.into_iter()
+            .map(|s| s.into_stream())
+            .collect::<Vec<_>>()
+            .chain()
+    }
+}
+
+impl<S> FromIterator<S> for Chain<S::IntoStream>
+where
+    S: IntoStream,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        iter.into_iter()
+            .map(|s| s.into_stream())
+            .collect::<Vec<_>>()
+            .chain()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,6 +145,19 @@ mod tests {
     use futures_lite::prelude::*;
     use futures_lite::stream;
 
+    #[test]
+    fn chain_other_collections() {
+        block_on(async {
+            let boxed: Box<[_]> = vec![stream::once(1), stream::once(2)].into();
+            let deque: VecDeque<_> = vec![stream::once(3), stream::once(4)].into();
+            let collected: Chain<_> = [stream::once(5), stream::once(6)].into_iter().collect();
+
+            assert_eq!(boxed.chain().collect::<Vec<_>>().await, vec![1, 2]);
+            assert_eq!(deque.chain().collect::<Vec<_>>().await, vec![3, 4]);
+            assert_eq!(collected.collect::<Vec<_>>().await, vec![5, 6]);
+        })
+    }
+
     #[test]
     fn chain_3() {
         block_on(async {
@@ -0,0 +1,236 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+use crate::utils;
+
+/// A growable queue of streams which are iterated over in sequence.
+///
+/// Streams can be appended to the back of the queue while earlier streams are
+/// still being iterated over. Streams are not polled until every stream before
+/// them has been exhausted.
+///
+/// When all streams in the queue have been exhausted the queue yields `None`,
+/// but it will resume yielding items if more streams are pushed afterwards.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::stream::ChainQueue;
+/// use futures_lite::{stream, StreamExt};
+///
+/// # futures_lite::future::block_on(async {
+/// let mut queue = ChainQueue::new();
+/// queue.push_back(stream::iter(vec![1, 2]));
+///
+/// let mut out = vec![];
+/// while let Some(page) = queue.next().await {
+///     // Discover the next page while iterating over the current one.
+///     if page == 2 {
+///         queue.push_back(stream::iter(vec![3, 4]));
+///     }
+///     out.push(page);
+/// }
+/// assert_eq!(out, vec![1, 2, 3, 4]);
+/// # });
+/// ```
+#[must_use = "`ChainQueue` does nothing if not iterated over"]
+#[pin_project::pin_project]
+pub struct ChainQueue<S> {
+    #[pin]
+    current: Option<S>,
+    // Streams in the queue haven't been polled yet, so it's fine to move them.
+    queue: VecDeque<S>,
+}
+
+impl<S> ChainQueue<S> {
+    /// Create a new instance of `ChainQueue`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::ChainQueue;
+    ///
+    /// let queue = ChainQueue::new();
+    /// # let queue: ChainQueue<futures_lite::stream::Once<usize>> = queue;
+    /// ```
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create a new instance of `ChainQueue` with a given capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::ChainQueue;
+    ///
+    /// let queue = ChainQueue::with_capacity(2);
+    /// # let queue: ChainQueue<futures_lite::stream::Once<usize>> = queue;
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            current: None,
+            queue: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Return the number of streams which haven't been exhausted yet,
+    /// including the stream which is currently being iterated over.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::ChainQueue;
+    /// use futures_lite::stream;
+    ///
+    /// let mut queue = ChainQueue::new();
+    /// assert_eq!(queue.len(), 0);
+    /// queue.push_back(stream::once(12));
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.queue.len() + usize::from(self.current.is_some())
+    }
+
+    /// Returns `true` if there are no streams left to iterate over.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::ChainQueue;
+    /// use futures_lite::stream;
+    ///
+    /// let mut queue = ChainQueue::new();
+    /// assert!(queue.is_empty());
+    /// queue.push_back(stream::once(12));
+    /// assert!(!queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append a stream to the back of the queue.
+    ///
+    /// The stream will be polled once all streams before it have been
+    /// exhausted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::ChainQueue;
+    /// use futures_lite::stream;
+    ///
+    /// let mut queue = ChainQueue::new();
+    /// queue.push_back(stream::once(12));
+    /// ```
+    pub fn push_back(&mut self, stream: S) {
+        self.queue.push_back(stream);
+    }
+}
+
+impl<S> Default for ChainQueue<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for ChainQueue<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChainQueue")
+            .field("current", &self.current)
+            .field("queue", &self.queue)
+            .finish()
+    }
+}
+
+impl<S: Stream> Stream for ChainQueue<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(stream) = this.current.as_mut().as_pin_mut() {
+                match stream.poll_next(cx) {
+                    Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                    Poll::Ready(None) => this.current.set(None),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+
+            match this.queue.pop_front() {
+                Some(stream) => this.current.set(Some(stream)),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hints = self
+            .current
+            .iter()
+            .chain(&self.queue)
+            .map(Stream::size_hint);
+        utils::size_hint::sum(hints)
+    }
+}
+
+impl<S: Stream> FusedStream for ChainQueue<S> {
+    fn is_terminated(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<S> Extend<S> for ChainQueue<S> {
+    fn extend<T: IntoIterator<Item = S>>(&mut self, iter: T) {
+        self.queue.extend(iter);
+    }
+}
+
+impl<S> FromIterator<S> for ChainQueue<S> {
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        Self {
+            current: None,
+            queue: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ChainQueue;
+    use futures_lite::{stream, StreamExt};
+
+    #[test]
+    fn in_order() {
+        futures_lite::future::block_on(async {
+            let mut queue: ChainQueue<_> = [stream::iter(vec![1, 2]), stream::iter(vec![3])]
+                .into_iter()
+                .collect();
+
+            let mut out = vec![];
+            while let Some(num) = queue.next().await {
+                out.push(num);
+            }
+            assert_eq!(out, vec![1, 2, 3]);
+        });
+    }
+
+    #[test]
+    fn push_after_exhausted() {
+        futures_lite::future::block_on(async {
+            let mut queue = ChainQueue::new();
+            queue.push_back(stream::once(1));
+            assert_eq!(queue.next().await, Some(1));
+            assert_eq!(queue.next().await, None);
+            assert!(queue.is_empty());
+
+            queue.push_back(stream::once(2));
+            assert_eq!(queue.next().await, Some(2));
+            assert_eq!(queue.next().await, None);
+        });
+    }
+}
@@ -0,0 +1,181 @@
+use super::CombineLatest as CombineLatestTrait;
+use crate::stream::IntoStream;
+use crate::utils::{self, Indexer, PollArray, WakerArray};
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+/// A stream that yields the latest item of each of multiple streams.
+///
+/// This `struct` is created by the [`combine_latest`] method on the [`CombineLatest`] trait. See its
+/// documentation for more.
+///
+/// [`combine_latest`]: crate::stream::CombineLatest::combine_latest
+/// [`CombineLatest`]: crate::stream::CombineLatest
+#[pin_project::pin_project]
+pub struct CombineLatest<S, const N: usize>
+where
+    S: Stream,
+{
+    #[pin]
+    streams: [S; N],
+    latest: [Option<S::Item>; N],
+    indexer: Indexer,
+    wakers: WakerArray<N>,
+    state: PollArray<N>,
+    complete: usize,
+    done: bool,
+}
+
+impl<S, const N: usize> CombineLatest<S, N>
+where
+    S: Stream,
+{
+    pub(crate) fn new(streams: [S; N]) -> Self {
+        Self {
+            streams,
+            latest: core::array::from_fn(|_| None),
+            indexer: Indexer::new(N),
+            wakers: WakerArray::new(),
+            state: PollArray::new_pending(),
+            complete: 0,
+            done: N == 0,
+        }
+    }
+}
+
+impl<S, const N: usize> fmt::Debug for CombineLatest<S, N>
+where
+    S: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.streams.iter()).finish()
+    }
+}
+
+impl<S, const N: usize> Stream for CombineLatest<S, N>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = [S::Item; N];
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        let mut readiness = this.wakers.readiness();
+        readiness.set_waker(cx.waker());
+
+        // Iterate over our streams one-by-one. If a stream yields a value and
+        // we've seen a value from every stream, we exit early.
+        for index in this.indexer.iter() {
+            if !readiness.any_ready() {
+                // Nothing is ready yet
+                return Poll::Pending;
+            } else if !readiness.clear_ready(index) || this.state[index].is_none() {
+                continue;
+            }
+
+            // release readiness so we can borrow the wakers when polling
+            #[allow(clippy::drop_non_drop)]
+            drop(readiness);
+
+            // Obtain the intermediate waker.
+            let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+
+            let stream = utils::get_pin_mut(this.streams.as_mut(), index).unwrap();
+            match stream.poll_next(&mut cx) {
+                Poll::Ready(Some(item)) => {
+                    // Mark ourselves as ready again because we need to poll for the next item.
+                    this.wakers.readiness().set_ready(index);
+                    this.latest[index] = Some(item);
+                    if this.latest.iter().all(Option::is_some) {
+                        let latest = &*this.latest;
+                        let output = core::array::from_fn(|i| latest[i].clone().unwrap());
+                        return Poll::Ready(Some(output));
+                    }
+                }
+                Poll::Ready(None) => {
+                    *this.complete += 1;
+                    this.state[index].set_none();
+                    // If a stream ends before yielding anything, we can never
+                    // produce an output again.
+                    if *this.complete == N || this.latest[index].is_none() {
+                        *this.done = true;
+                        return Poll::Ready(None);
+                    }
+                }
+                Poll::Pending => {}
+            }
+
+            // Reacquire readiness so we can use it again
+            readiness = this.wakers.readiness();
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<S, const N: usize> FusedStream for CombineLatest<S, N>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<S, const N: usize> CombineLatestTrait for [S; N]
+where
+    S: IntoStream,
+    S::Item: Clone,
+{
+    type Item = [S::Item; N];
+    type Stream = CombineLatest<S::IntoStream, N>;
+
+    fn combine_latest(self) -> Self::Stream {
+        CombineLatest::new(self.map(|i| i.into_stream()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn combine_latest_array() {
+        block_on(async {
+            let a = stream::repeat(1).take(1);
+            let b = stream::repeat(2).take(3);
+            let mut s = [a, b].combine_latest();
+
+            let mut count = 0;
+            while let Some(item) = s.next().await {
+                assert_eq!(item, [1, 2]);
+                count += 1;
+            }
+            assert!((1..=3).contains(&count));
+        })
+    }
+
+    #[test]
+    fn ends_when_a_stream_is_empty() {
+        block_on(async {
+            let a = stream::empty().boxed();
+            let b = stream::repeat(2).boxed();
+            let mut s = [a, b].combine_latest();
+            assert_eq!(s.next().await, None);
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
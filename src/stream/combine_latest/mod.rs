@@ -0,0 +1,39 @@
+use futures_core::Stream;
+
+pub(crate) mod array;
+pub(crate) mod tuple;
+
+/// Combines multiple streams into a single stream of the most recent item of
+/// each stream.
+///
+/// Once every stream has yielded at least one item, a new output is yielded
+/// whenever any of the streams yields an item. The output holds a clone of
+/// the latest item of every stream. The stream ends once all streams have
+/// been exhausted, or when a stream is exhausted before it ever yields an item.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let a = stream::once(1);
+///     let b = stream::iter(vec!["a", "b"]);
+///     let s = (a, b).combine_latest();
+///
+///     let buf: Vec<_> = s.collect().await;
+///     assert_eq!(buf.last(), Some(&(1, "b")));
+/// })
+/// ```
+pub trait CombineLatest {
+    /// The resulting output type.
+    type Item;
+
+    /// The stream type.
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Combine multiple streams into a single stream of their latest items.
+    fn combine_latest(self) -> Self::Stream;
+}
@@ -0,0 +1,226 @@
+use super::CombineLatest as CombineLatestTrait;
+use crate::stream::IntoStream;
+use crate::utils::{self, PollArray, WakerArray};
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+macro_rules! impl_combine_latest_tuple {
+    ($mod_name:ident $StructName:ident $($F:ident)+) => {
+        mod $mod_name {
+            pub(super) struct Latest<$($F,)+>
+            where
+                $($F: super::Stream,)+
+            {
+                $(pub(super) $F: Option<<$F as super::Stream>::Item>,)+
+            }
+
+            #[repr(usize)]
+            enum Indexes {
+                $($F,)+
+            }
+
+            $(
+                pub(super) const $F: usize = Indexes::$F as usize;
+            )+
+
+            pub(super) const LEN: usize = [$(Indexes::$F,)+].len();
+        }
+
+        /// A stream that yields the latest item of each of multiple streams.
+        ///
+        /// This `struct` is created by the [`combine_latest`] method on the [`CombineLatest`] trait. See its
+        /// documentation for more.
+        ///
+        /// [`combine_latest`]: crate::stream::CombineLatest::combine_latest
+        /// [`CombineLatest`]: crate::stream::CombineLatest
+        #[pin_project::pin_project]
+        pub struct $StructName<$($F,)+>
+        where
+            $($F: Stream,)+
+        {
+            done: bool,
+            complete: usize,
+            latest: $mod_name::Latest<$($F,)+>,
+            indexer: utils::Indexer,
+            state: PollArray<{ $mod_name::LEN }>,
+            wakers: WakerArray<{ $mod_name::LEN }>,
+            $( #[pin] $F: $F,)+
+        }
+
+        impl<$($F,)+> fmt::Debug for $StructName<$($F,)+>
+        where
+            $($F: Stream + fmt::Debug,)+
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("CombineLatest")
+                    $(.field(&self.$F))+
+                    .finish()
+            }
+        }
+
+        impl<$($F,)+> Stream for $StructName<$($F,)+>
+        where
+            $($F: Stream, $F::Item: Clone,)+
+        {
+            type Item = (
+                $(<$F as Stream>::Item,)+
+            );
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                const LEN: usize = $mod_name::LEN;
+
+                if *this.done {
+                    return Poll::Ready(None);
+                }
+
+                let mut readiness = this.wakers.readiness();
+                readiness.set_waker(cx.waker());
+
+                for index in this.indexer.iter() {
+                    if !readiness.any_ready() {
+                        // Nothing is ready yet
+                        return Poll::Pending;
+                    } else if !readiness.clear_ready(index) || this.state[index].is_none() {
+                        continue;
+                    }
+
+                    // release readiness so we can borrow the wakers when polling
+                    #[allow(clippy::drop_non_drop)]
+                    drop(readiness);
+
+                    // Obtain the intermediate waker.
+                    let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
+
+                    let updated = match index {
+                        $(
+                            $mod_name::$F => {
+                                let stream = this.$F.as_mut();
+
+                                match stream.poll_next(&mut cx) {
+                                    Poll::Pending => false,
+                                    Poll::Ready(None) => {
+                                        *this.complete += 1;
+                                        this.state[index].set_none();
+                                        // If a stream ends before yielding anything, we can
+                                        // never produce an output again.
+                                        if *this.complete == LEN || this.latest.$F.is_none() {
+                                            *this.done = true;
+                                            return Poll::Ready(None);
+                                        }
+                                        false
+                                    }
+                                    Poll::Ready(Some(item)) => {
+                                        // Mark ourselves as ready again because we need to poll for the next item.
+                                        this.wakers.readiness().set_ready(index);
+                                        this.latest.$F = Some(item);
+                                        true
+                                    }
+                                }
+                            },
+                        )+
+                        _ => unreachable!(),
+                    };
+
+                    if updated $(&& this.latest.$F.is_some())+ {
+                        return Poll::Ready(Some((
+                            $(this.latest.$F.clone().unwrap(),)+
+                        )));
+                    }
+
+                    // Reacquire readiness so we can use it again
+                    readiness = this.wakers.readiness();
+                }
+
+                Poll::Pending
+            }
+        }
+
+        impl<$($F,)+> FusedStream for $StructName<$($F,)+>
+        where
+            $($F: Stream, $F::Item: Clone,)+
+        {
+            fn is_terminated(&self) -> bool {
+                self.done
+            }
+        }
+
+        impl<$($F,)+> CombineLatestTrait for ($($F,)+)
+        where
+            $($F: IntoStream, $F::Item: Clone,)+
+        {
+            type Item = (
+                $(<$F as IntoStream>::Item,)+
+            );
+
+            type Stream = $StructName<$($F::IntoStream,)+>;
+
+            fn combine_latest(self) -> Self::Stream {
+                let ($($F,)*): ($($F,)*) = self;
+                $StructName {
+                    done: false,
+                    complete: 0,
+                    latest: $mod_name::Latest { $($F: None,)+ },
+                    indexer: utils::Indexer::new(utils::tuple_len!($($F,)*)),
+                    state: PollArray::new_pending(),
+                    wakers: WakerArray::new(),
+                    $($F: $F.into_stream(),)+
+                }
+            }
+        }
+    };
+}
+
+impl_combine_latest_tuple! { combine_latest_1 CombineLatest1 A }
+impl_combine_latest_tuple! { combine_latest_2 CombineLatest2 A B }
+impl_combine_latest_tuple! { combine_latest_3 CombineLatest3 A B C }
+impl_combine_latest_tuple! { combine_latest_4 CombineLatest4 A B C D }
+impl_combine_latest_tuple! { combine_latest_5 CombineLatest5 A B C D E }
+impl_combine_latest_tuple! { combine_latest_6 CombineLatest6 A B C D E F }
+impl_combine_latest_tuple! { combine_latest_7 CombineLatest7 A B C D E F G }
+impl_combine_latest_tuple! { combine_latest_8 CombineLatest8 A B C D E F G H }
+impl_combine_latest_tuple! { combine_latest_9 CombineLatest9 A B C D E F G H I }
+impl_combine_latest_tuple! { combine_latest_10 CombineLatest10 A B C D E F G H I J }
+impl_combine_latest_tuple! { combine_latest_11 CombineLatest11 A B C D E F G H I J K }
+impl_combine_latest_tuple! { combine_latest_12 CombineLatest12 A B C D E F G H I J K L }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn combine_latest_tuple_2() {
+        block_on(async {
+            let a = stream::once(1);
+            let b = stream::once("a");
+            let mut s = (a, b).combine_latest();
+
+            assert_eq!(s.next().await, Some((1, "a")));
+            assert_eq!(s.next().await, None);
+        })
+    }
+
+    #[test]
+    fn yields_on_every_update() {
+        block_on(async {
+            let a = stream::repeat(1).take(3);
+            let b = stream::repeat("a").take(3);
+            let mut s = (a, b).combine_latest();
+
+            let mut count = 0;
+            while let Some(item) = s.next().await {
+                assert_eq!(item, (1, "a"));
+                count += 1;
+            }
+            // Every item but the very first yields an output.
+            assert_eq!(count, 5);
+        })
+    }
+}
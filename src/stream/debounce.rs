@@ -0,0 +1,186 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+use crate::time::Timer;
+use crate::utils::ITEM_BUDGET;
+
+/// A stream which only yields items once the underlying stream has been
+/// quiet for a period of time.
+///
+/// This `struct` is created by the [`debounce`] and [`debounce_leading`]
+/// methods on [`StreamExt`]. See their documentation for more.
+///
+/// [`debounce`]: crate::stream::StreamExt::debounce
+/// [`debounce_leading`]: crate::stream::StreamExt::debounce_leading
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct Debounce<S: Stream, T: Timer> {
+    #[pin]
+    stream: S,
+    // Restarted every time the underlying stream yields an item.
+    #[pin]
+    sleep: Option<T::Sleep>,
+    timer: T,
+    duration: Duration,
+    edge: Edge,
+    // The most recent item, waiting for the stream to go quiet.
+    slot: Option<S::Item>,
+    done: bool,
+}
+
+/// Whether to yield the first or the last item of a burst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Edge {
+    Leading,
+    Trailing,
+}
+
+impl<S: Stream, T: Timer> Debounce<S, T> {
+    pub(crate) fn new(stream: S, duration: Duration, timer: T, edge: Edge) -> Self {
+        Self {
+            stream,
+            sleep: None,
+            timer,
+            duration,
+            edge,
+            slot: None,
+            done: false,
+        }
+    }
+}
+
+impl<S: Stream + fmt::Debug, T: Timer> fmt::Debug for Debounce<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Debounce")
+            .field("stream", &self.stream)
+            .field("duration", &self.duration)
+            .field("edge", &self.edge)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: Stream, T: Timer> Stream for Debounce<S, T> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let mut taken = 0;
+        loop {
+            if *this.done {
+                // Yield the item we were still holding on to.
+                this.sleep.set(None);
+                return Poll::Ready(this.slot.take());
+            }
+
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let quiet = this.sleep.is_none();
+                    this.sleep.set(Some(this.timer.sleep(*this.duration)));
+                    match this.edge {
+                        Edge::Leading if quiet => return Poll::Ready(Some(item)),
+                        Edge::Leading => {}
+                        Edge::Trailing => *this.slot = Some(item),
+                    }
+                    // A stream which is always ready keeps pushing the
+                    // deadline back; don't hog the executor while it does.
+                    taken += 1;
+                    if taken == ITEM_BUDGET {
+                        cx.waker().wake_by_ref();
+                        return Poll::Pending;
+                    }
+                    continue;
+                }
+                Poll::Ready(None) => {
+                    *this.done = true;
+                    continue;
+                }
+                Poll::Pending => {}
+            }
+
+            if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                if sleep.poll(cx).is_ready() {
+                    this.sleep.set(None);
+                    if let Some(item) = this.slot.take() {
+                        return Poll::Ready(Some(item));
+                    }
+                }
+            }
+            return Poll::Pending;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            let len = self.slot.is_some() as usize;
+            return (len, Some(len));
+        }
+        let (low, high) = self.stream.size_hint();
+        let pending = self.slot.is_some() as usize;
+        (
+            low.min(1).max(pending),
+            high.and_then(|h| h.checked_add(pending)),
+        )
+    }
+}
+
+impl<S: Stream, T: Timer> FusedStream for Debounce<S, T> {
+    fn is_terminated(&self) -> bool {
+        self.done && self.slot.is_none()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stream::StreamExt as _;
+    use futures_lite::future::{block_on, poll_once};
+    use futures_lite::{stream, StreamExt};
+    use std::time::Duration;
+
+    #[test]
+    fn trailing() {
+        block_on(async {
+            let s =
+                stream::iter([1, 2, 3]).debounce(Duration::from_millis(10), async_io::Timer::after);
+            let v: Vec<_> = s.collect().await;
+            assert_eq!(v, &[3]);
+        });
+    }
+
+    #[test]
+    fn leading() {
+        block_on(async {
+            let s = stream::iter([1, 2, 3])
+                .debounce_leading(Duration::from_millis(10), async_io::Timer::after);
+            let v: Vec<_> = s.collect().await;
+            assert_eq!(v, &[1]);
+        });
+    }
+
+    #[test]
+    fn separate_bursts() {
+        block_on(async {
+            let timer = async_io::Timer::after;
+            let a = stream::iter([1, 2]);
+            let b = stream::iter([3, 4]).wait_until(timer(Duration::from_millis(100)));
+            let s = StreamExt::chain(a, b).debounce(Duration::from_millis(10), timer);
+            let v: Vec<_> = s.collect().await;
+            assert_eq!(v, &[2, 4]);
+        });
+    }
+
+    #[test]
+    fn always_ready() {
+        block_on(async {
+            let mut s =
+                stream::repeat(1).debounce(Duration::from_millis(10), async_io::Timer::after);
+            assert!(poll_once(s.next()).await.is_none());
+        });
+    }
+}
@@ -0,0 +1,33 @@
+//! Enums which hold a value of one of several types.
+//!
+//! These are yielded by [`MergeHeterogeneous`][crate::stream::MergeHeterogeneous]
+//! to tell the items of each merged stream apart. Each variant is named after
+//! the position of the stream in the tuple it came from.
+
+macro_rules! impl_either {
+    ($Name:ident $($F:ident)+) => {
+        /// A value which is one of several types.
+        ///
+        /// Each variant corresponds to the stream at the same position in
+        /// the merged tuple.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum $Name<$($F),+> {
+            $(
+                #[doc = concat!("A value of type `", stringify!($F), "`.")]
+                $F($F),
+            )+
+        }
+    };
+}
+
+impl_either! { Either2 A B }
+impl_either! { Either3 A B C }
+impl_either! { Either4 A B C D }
+impl_either! { Either5 A B C D E }
+impl_either! { Either6 A B C D E F }
+impl_either! { Either7 A B C D E F G }
+impl_either! { Either8 A B C D E F G H }
+impl_either! { Either9 A B C D E F G H I }
+impl_either! { Either10 A B C D E F G H I J }
+impl_either! { Either11 A B C D E F G H I J K }
+impl_either! { Either12 A B C D E F G H I J K L }
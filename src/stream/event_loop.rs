@@ -0,0 +1,304 @@
+use alloc::boxed::Box;
+use core::fmt;
+use core::future::{ready, Future};
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::Stream;
+use futures_lite::StreamExt;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use super::stream_group::{Fairness, StreamGroup};
+use super::IntoStream;
+use crate::utils::{trace, ITEM_BUDGET};
+
+type Source<'a, B> = Pin<Box<dyn Stream<Item = ControlFlow<B>> + Send + 'a>>;
+
+/// A loop which handles events from multiple sources as they come in.
+///
+/// Each source is a stream paired with a handler, which is called for every
+/// item the stream yields. Sources take turns in round-robin order, so a busy
+/// source can't starve the others. The loop runs until a handler returns
+/// [`ControlFlow::Break`], or until every source has been exhausted.
+///
+/// This replaces a `loop` around `futures::select!`, without having to fuse
+/// the sources or worry about which arm gets polled first. Handlers are
+/// either plain closures registered with [`on`](EventLoop::on), or async
+/// closures registered with [`on_async`](EventLoop::on_async). Sources and
+/// handlers must be `Send`, so the loop can be spawned onto a multi-threaded
+/// executor.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::stream::EventLoop;
+/// use futures_lite::stream;
+/// use std::ops::ControlFlow;
+///
+/// # futures_lite::future::block_on(async {
+/// let mut sum = 0;
+/// let mut messages = vec![];
+///
+/// let outcome = EventLoop::new()
+///     .on("numbers", stream::iter([1, 2, 3]), |num| {
+///         sum += num;
+///         ControlFlow::Continue(())
+///     })
+///     .on("messages", stream::iter(["hello", "quit"]), |msg| {
+///         if msg == "quit" {
+///             return ControlFlow::Break("quit requested");
+///         }
+///         messages.push(msg);
+///         ControlFlow::Continue(())
+///     })
+///     .run()
+///     .await;
+///
+/// assert_eq!(outcome, Some("quit requested"));
+/// assert_eq!(messages, ["hello"]);
+/// # });
+/// ```
+#[must_use = "`EventLoop` does nothing unless `run` is awaited"]
+pub struct EventLoop<'a, B> {
+    sources: StreamGroup<Source<'a, B>>,
+    names: Vec<&'static str>,
+}
+
+impl<B> fmt::Debug for EventLoop<'_, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventLoop")
+            .field("sources", &self.names)
+            .finish()
+    }
+}
+
+impl<B> Default for EventLoop<'_, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, B> EventLoop<'a, B> {
+    /// Create a new `EventLoop` without any sources.
+    pub fn new() -> Self {
+        Self {
+            sources: StreamGroup::with_fairness(Fairness::RoundRobin),
+            names: Vec::new(),
+        }
+    }
+
+    /// Register a source, along with the handler for its items.
+    ///
+    /// The name identifies the source in `tracing` events and in the `Debug`
+    /// output of the loop.
+    pub fn on<S, F>(self, name: &'static str, source: S, mut handler: F) -> Self
+    where
+        S: IntoStream,
+        S::IntoStream: Send + 'a,
+        F: FnMut(S::Item) -> ControlFlow<B> + Send + 'a,
+        B: Send + 'a,
+    {
+        self.on_async(name, source, move |item| ready(handler(item)))
+    }
+
+    /// Register a source, along with an async handler for its items.
+    ///
+    /// The source isn't polled for its next item until the handler's future
+    /// for the previous one has completed. Other sources keep making
+    /// progress in the meantime.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::EventLoop;
+    /// use futures_lite::stream;
+    /// use std::ops::ControlFlow;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let outcome = EventLoop::new()
+    ///     .on_async("jobs", stream::iter([1, 2, 3]), |job| async move {
+    ///         // Do some async work for every job.
+    ///         match job {
+    ///             3 => ControlFlow::Break(job),
+    ///             _ => ControlFlow::Continue(()),
+    ///         }
+    ///     })
+    ///     .run()
+    ///     .await;
+    ///
+    /// assert_eq!(outcome, Some(3));
+    /// # });
+    /// ```
+    pub fn on_async<S, F, Fut>(mut self, name: &'static str, source: S, mut handler: F) -> Self
+    where
+        S: IntoStream,
+        S::IntoStream: Send + 'a,
+        F: FnMut(S::Item) -> Fut + Send + 'a,
+        Fut: Future<Output = ControlFlow<B>> + Send + 'a,
+    {
+        let source = source.into_stream().then(move |item| {
+            trace!(source = name, "event loop: handling event");
+            handler(item)
+        });
+        self.sources.insert(Box::pin(source));
+        self.names.push(name);
+        self
+    }
+
+    /// Return the number of sources registered with the loop.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` if no sources have been registered with the loop.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Run the loop until a handler breaks out of it.
+    ///
+    /// Returns the value the handler broke with, or `None` once every source
+    /// has been exhausted.
+    pub fn run(self) -> EventLoopFuture<'a, B> {
+        EventLoopFuture {
+            sources: self.sources,
+        }
+    }
+}
+
+/// A future which runs an [`EventLoop`].
+///
+/// This `struct` is created by the [`run`] method on [`EventLoop`]. See its
+/// documentation for more.
+///
+/// [`run`]: EventLoop::run
+#[must_use = "futures do nothing unless polled or .awaited"]
+pub struct EventLoopFuture<'a, B> {
+    sources: StreamGroup<Source<'a, B>>,
+}
+
+impl<B> fmt::Debug for EventLoopFuture<'_, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventLoopFuture")
+            .field("sources", &self.sources.len())
+            .finish()
+    }
+}
+
+impl<B> Future for EventLoopFuture<'_, B> {
+    type Output = Option<B>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        for _ in 0..ITEM_BUDGET {
+            match self.sources.poll_next(cx) {
+                Poll::Ready(Some(ControlFlow::Continue(()))) => continue,
+                Poll::Ready(Some(ControlFlow::Break(value))) => {
+                    trace!("event loop: stopped");
+                    return Poll::Ready(Some(value));
+                }
+                Poll::Ready(None) => {
+                    trace!("event loop: sources exhausted");
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        // Don't hog the executor while the sources keep handing us events.
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn runs_until_exhausted() {
+        futures_lite::future::block_on(async {
+            let mut events = vec![];
+            let outcome: Option<()> = EventLoop::new()
+                .on("a", stream::iter([1, 2]), |n| {
+                    events.push(n);
+                    ControlFlow::Continue(())
+                })
+                .run()
+                .await;
+            assert_eq!(outcome, None);
+            assert_eq!(events, [1, 2]);
+        });
+    }
+
+    #[test]
+    fn fair_between_sources() {
+        futures_lite::future::block_on(async {
+            let seen = std::sync::Mutex::new(vec![]);
+            let outcome = EventLoop::new()
+                .on("a", stream::repeat('a'), |c| {
+                    seen.lock().unwrap().push(c);
+                    ControlFlow::Continue(())
+                })
+                .on("b", stream::repeat('b'), |c| {
+                    seen.lock().unwrap().push(c);
+                    match seen.lock().unwrap().len() > 10 {
+                        true => ControlFlow::Break(()),
+                        false => ControlFlow::Continue(()),
+                    }
+                })
+                .run()
+                .await;
+            assert_eq!(outcome, Some(()));
+            let a = seen.lock().unwrap().iter().filter(|c| **c == 'a').count();
+            assert!(a >= 5, "sources should take turns");
+        });
+    }
+
+    #[test]
+    fn async_handlers() {
+        futures_lite::future::block_on(async {
+            let mut total = 0;
+            let outcome: Option<()> = EventLoop::new()
+                .on_async("a", stream::iter([1, 2, 3]), |n| {
+                    total += n;
+                    async { ControlFlow::Continue(()) }
+                })
+                .run()
+                .await;
+            assert_eq!(outcome, None);
+            assert_eq!(total, 6);
+        });
+    }
+
+    #[test]
+    fn is_send() {
+        fn assert_send<T: Send>(_: &T) {}
+
+        let events =
+            EventLoop::<()>::new().on("a", stream::iter([1]), |_| ControlFlow::Continue(()));
+        assert_send(&events);
+        assert_send(&events.run());
+    }
+
+    #[test]
+    fn always_ready_sources() {
+        futures_lite::future::block_on(async {
+            let mut events = EventLoop::<()>::new()
+                .on("a", stream::repeat(1), |_| ControlFlow::Continue(()))
+                .run();
+            // The loop yields back to the executor instead of spinning.
+            assert!(futures_lite::future::poll_once(&mut events).await.is_none());
+        });
+    }
+
+    #[test]
+    fn empty() {
+        futures_lite::future::block_on(async {
+            let events: EventLoop<'_, ()> = EventLoop::new();
+            assert!(events.is_empty());
+            assert_eq!(events.run().await, None);
+        });
+    }
+}
@@ -0,0 +1,202 @@
+//! Strategies which decide the order in which merged streams are polled.
+//!
+//! The [`merge`][crate::stream::Merge::merge] combinators poll their streams
+//! one after the other, starting from an index picked by a
+//! [`FairnessStrategy`]. By default that index moves forward by one on every
+//! poll ([`Rotate`]), but a different strategy can be picked with
+//! `with_fairness`.
+//!
+//! These strategies only apply to `merge`. A
+//! [`StreamGroup`][crate::stream::StreamGroup] is configured with a
+//! [`Fairness`][crate::stream::stream_group::Fairness] and a
+//! [`DrainOrder`][crate::stream::stream_group::DrainOrder] instead.
+//!
+//! # Example
+//!
+//! ```
+//! use futures_concurrency::prelude::*;
+//! use futures_concurrency::stream::fairness::RoundRobin;
+//! use futures_lite::stream::{self, StreamExt};
+//! use futures_lite::future::block_on;
+//!
+//! block_on(async {
+//!     let a = stream::repeat("a").take(2);
+//!     let b = stream::repeat("b").take(2);
+//!     let s = [a, b].merge().with_fairness(RoundRobin::new());
+//!
+//!     let buf: Vec<_> = s.collect().await;
+//!     assert_eq!(buf, vec!["a", "b", "a", "b"]);
+//! })
+//! ```
+
+use crate::utils::Rng;
+
+/// Decides which stream a merge starts polling from.
+///
+/// On every poll the merge asks for a starting index, and then polls the
+/// streams from that index onwards, wrapping around at the end. Indexes which
+/// are out of bounds wrap around as well.
+///
+/// # Example
+///
+/// A strategy which always gives the last stream priority:
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_concurrency::stream::fairness::FairnessStrategy;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// struct Last;
+///
+/// impl FairnessStrategy for Last {
+///     fn start(&mut self, len: usize) -> usize {
+///         len.saturating_sub(1)
+///     }
+/// }
+///
+/// block_on(async {
+///     let a = stream::repeat("a").take(2);
+///     let b = stream::repeat("b").take(2);
+///     let s = [a, b].merge().with_fairness(Last);
+///
+///     let buf: Vec<_> = s.collect().await;
+///     assert_eq!(buf, vec!["b", "b", "a", "a"]);
+/// })
+/// ```
+pub trait FairnessStrategy {
+    /// Return the index of the stream to start polling from, given the total
+    /// number of streams.
+    fn start(&mut self, len: usize) -> usize;
+
+    /// Record that the stream at `index` yielded an item.
+    fn yielded(&mut self, index: usize, len: usize) {
+        let _ = (index, len);
+    }
+}
+
+/// Move the starting point forward by one on every poll.
+///
+/// This is the default strategy.
+#[derive(Debug, Default, Clone)]
+pub struct Rotate {
+    offset: usize,
+}
+
+impl Rotate {
+    /// Create a new instance of `Rotate`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl FairnessStrategy for Rotate {
+    fn start(&mut self, len: usize) -> usize {
+        let offset = self.offset;
+        self.offset = (offset + 1).checked_rem(len).unwrap_or(0);
+        offset
+    }
+}
+
+/// Always start polling from the first stream, so streams earlier in the
+/// collection take priority.
+///
+/// A busy high-priority stream can starve lower-priority streams.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Prioritized;
+
+impl FairnessStrategy for Prioritized {
+    fn start(&mut self, _len: usize) -> usize {
+        0
+    }
+}
+
+/// Start polling right after the stream which last yielded an item.
+///
+/// As long as other streams have items ready, no stream is picked twice in a
+/// row.
+#[derive(Debug, Default, Clone)]
+pub struct RoundRobin {
+    next: usize,
+}
+
+impl RoundRobin {
+    /// Create a new instance of `RoundRobin`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl FairnessStrategy for RoundRobin {
+    fn start(&mut self, _len: usize) -> usize {
+        self.next
+    }
+
+    fn yielded(&mut self, index: usize, len: usize) {
+        self.next = (index + 1).checked_rem(len).unwrap_or(0);
+    }
+}
+
+/// Start polling from a randomly chosen stream on every poll.
+#[derive(Debug, Default, Clone)]
+pub struct Random {
+    rng: Rng,
+}
+
+impl Random {
+    /// Create a new instance of `Random`.
+    ///
+    /// Every instance is seeded differently.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new instance of `Random` from the given seed.
+    ///
+    /// Two instances with the same seed pick the same starting points, which
+    /// makes ordering-sensitive code reproducible.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            rng: Rng::with_seed(seed),
+        }
+    }
+}
+
+impl FairnessStrategy for Random {
+    fn start(&mut self, len: usize) -> usize {
+        self.rng.gen_index(len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rotate() {
+        let mut fairness = Rotate::new();
+        let starts: [usize; 4] = core::array::from_fn(|_| fairness.start(3));
+        assert_eq!(starts, [0, 1, 2, 0]);
+    }
+
+    #[test]
+    fn round_robin() {
+        let mut fairness = RoundRobin::new();
+        assert_eq!(fairness.start(3), 0);
+        fairness.yielded(2, 3);
+        assert_eq!(fairness.start(3), 0);
+        fairness.yielded(0, 3);
+        assert_eq!(fairness.start(3), 1);
+    }
+
+    #[test]
+    fn random_with_seed() {
+        let mut a = Random::with_seed(3);
+        let mut b = Random::with_seed(3);
+        for _ in 0..10 {
+            let start = a.start(5);
+            assert!(start < 5);
+            assert_eq!(start, b.start(5));
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::utils::Mutex;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+
+/// Create `n` handles to a stream which all see every item.
+pub(crate) fn fan_out<S>(stream: S, n: usize, capacity: usize) -> Vec<FanOut<S>>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    assert!(capacity > 0, "fan out capacity must be non-zero");
+    let wakers = Arc::new(FanOutWaker {
+        wakers: Mutex::new((0..n).map(|_| None).collect()),
+    });
+    let shared = Arc::new(Mutex::new(Shared {
+        stream: Box::pin(stream),
+        buffers: (0..n).map(|_| VecDeque::new()).collect(),
+        capacity,
+        waker: Waker::from(wakers.clone()),
+        wakers,
+        dropped: alloc::vec![false; n],
+        done: false,
+    }));
+    (0..n)
+        .map(|id| FanOut {
+            shared: shared.clone(),
+            id,
+        })
+        .collect()
+}
+
+/// A waker which wakes every handle of a fanned out stream.
+#[derive(Debug)]
+struct FanOutWaker {
+    wakers: Mutex<Vec<Option<Waker>>>,
+}
+
+impl FanOutWaker {
+    fn register(&self, id: usize, waker: &Waker) {
+        let mut wakers = self.wakers.lock();
+        match &wakers[id] {
+            Some(existing) if existing.will_wake(waker) => {}
+            _ => wakers[id] = Some(waker.clone()),
+        }
+    }
+
+    fn wake_one(&self, id: usize) {
+        if let Some(waker) = self.wakers.lock()[id].take() {
+            waker.wake();
+        }
+    }
+
+    fn wake_others(&self, id: usize) {
+        let wakers: Vec<_> = self
+            .wakers
+            .lock()
+            .iter_mut()
+            .enumerate()
+            .filter(|(other, _)| *other != id)
+            .filter_map(|(_, waker)| waker.take())
+            .collect();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl Wake for FanOutWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let wakers: Vec<_> = self
+            .wakers
+            .lock()
+            .iter_mut()
+            .filter_map(Option::take)
+            .collect();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+/// The state shared between all handles of a fanned out stream.
+struct Shared<S: Stream> {
+    stream: Pin<Box<S>>,
+    buffers: Vec<VecDeque<S::Item>>,
+    capacity: usize,
+    waker: Waker,
+    wakers: Arc<FanOutWaker>,
+    dropped: Vec<bool>,
+    done: bool,
+}
+
+impl<S> Shared<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    /// Poll the inner stream until an item is available for handle `id`.
+    ///
+    /// Every item is cloned into the buffers of the other handles. If any of
+    /// those buffers is full we stop polling until that handle has made
+    /// progress.
+    fn poll_handle(&mut self, id: usize, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let was_full = self.buffers[id].len() >= self.capacity;
+        if let Some(item) = self.buffers[id].pop_front() {
+            if was_full {
+                // We've just made room in our buffer, so the others may continue.
+                self.wakers.wake_others(id);
+            }
+            return Poll::Ready(Some(item));
+        }
+
+        self.wakers.register(id, cx.waker());
+        loop {
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            let capacity = self.capacity;
+            if self.buffers.iter().any(|buffer| buffer.len() >= capacity) {
+                // Wait for the slowest handle to make room.
+                return Poll::Pending;
+            }
+
+            let mut cx = Context::from_waker(&self.waker);
+            match self.stream.as_mut().poll_next(&mut cx) {
+                Poll::Ready(Some(item)) => {
+                    for other in 0..self.buffers.len() {
+                        if other != id && !self.dropped[other] {
+                            self.buffers[other].push_back(item.clone());
+                            self.wakers.wake_one(other);
+                        }
+                    }
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(None) => {
+                    self.done = true;
+                    self.wakers.wake_others(id);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<S: Stream> Shared<S> {
+    /// Mark a handle as dropped, discarding its buffered items.
+    fn drop_handle(&mut self, id: usize) {
+        self.dropped[id] = true;
+        self.buffers[id].clear();
+        self.wakers.wake_others(id);
+    }
+}
+
+/// A handle to a stream whose items are seen by multiple consumers.
+///
+/// This `struct` is created by the [`tee`] and [`fan_out`] methods on
+/// [`StreamExt`]. See their documentation for more.
+///
+/// [`tee`]: crate::stream::StreamExt::tee
+/// [`fan_out`]: crate::stream::StreamExt::fan_out
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+pub struct FanOut<S: Stream> {
+    shared: Arc<Mutex<Shared<S>>>,
+    id: usize,
+}
+
+impl<S: Stream> fmt::Debug for FanOut<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FanOut")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> Stream for FanOut<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.shared.lock().poll_handle(self.id, cx)
+    }
+}
+
+impl<S: Stream> Drop for FanOut<S> {
+    fn drop(&mut self) {
+        self.shared.lock().drop_handle(self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::Join;
+    use crate::stream::StreamExt as _;
+    use futures_lite::future::block_on;
+    use futures_lite::stream;
+
+    #[test]
+    fn every_handle_sees_every_item() {
+        block_on(async {
+            let handles = stream::iter(0..10).fan_out(3, 2);
+            let consumers: Vec<_> = handles
+                .into_iter()
+                .map(futures_lite::StreamExt::collect::<Vec<_>>)
+                .collect();
+            for out in consumers.join().await {
+                assert_eq!(out, (0..10).collect::<Vec<_>>());
+            }
+        })
+    }
+
+    #[test]
+    fn dropped_handle() {
+        block_on(async {
+            let (mut left, right) = stream::iter(0..10).tee(1);
+            drop(right);
+
+            let mut count = 0;
+            while futures_lite::StreamExt::next(&mut left).await.is_some() {
+                count += 1;
+            }
+            assert_eq!(count, 10);
+        })
+    }
+}
@@ -0,0 +1,186 @@
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+use super::stream_group::{Fairness, StreamGroup};
+use super::IntoStream;
+use crate::utils::ITEM_BUDGET;
+
+/// Flatten a stream of streams, polling the inner streams concurrently.
+///
+/// This `struct` is created by the [`flatten_concurrent`] method on
+/// [`StreamExt`]. See its documentation for more.
+///
+/// [`flatten_concurrent`]: crate::stream::StreamExt::flatten_concurrent
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct FlattenConcurrent<S>
+where
+    S: Stream,
+    S::Item: IntoStream,
+{
+    #[pin]
+    stream: S,
+    group: StreamGroup<<S::Item as IntoStream>::IntoStream>,
+    limit: Option<usize>,
+    done: bool,
+}
+
+impl<S> FlattenConcurrent<S>
+where
+    S: Stream,
+    S::Item: IntoStream,
+{
+    pub(crate) fn new(stream: S, limit: Option<usize>) -> Self {
+        assert!(
+            limit != Some(0),
+            "flatten_concurrent limit must be non-zero"
+        );
+        // Take turns between the inner streams, so a busy stream can't
+        // starve the others.
+        let mut group = StreamGroup::with_capacity(limit.unwrap_or(0));
+        group.set_fairness(Fairness::RoundRobin);
+        FlattenConcurrent {
+            stream,
+            group,
+            limit,
+            done: false,
+        }
+    }
+}
+
+impl<S> fmt::Debug for FlattenConcurrent<S>
+where
+    S: Stream + fmt::Debug,
+    S::Item: IntoStream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlattenConcurrent")
+            .field("stream", &self.stream)
+            .field("active", &self.group.len())
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<S> Stream for FlattenConcurrent<S>
+where
+    S: Stream,
+    S::Item: IntoStream,
+{
+    type Item = <S::Item as IntoStream>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // How many inner streams we may still pull in during this call, so an
+        // outer stream which is always ready can't keep us from yielding.
+        let mut budget = ITEM_BUDGET;
+        loop {
+            // Pull in new inner streams until we've reached the limit.
+            let mut stream_pending = false;
+            while !*this.done
+                && budget > 0
+                && this.limit.map_or(true, |limit| this.group.len() < limit)
+            {
+                match this.stream.as_mut().poll_next(cx) {
+                    Poll::Ready(Some(stream)) => {
+                        this.group.insert(stream.into_stream());
+                        budget -= 1;
+                    }
+                    Poll::Ready(None) => *this.done = true,
+                    Poll::Pending => {
+                        stream_pending = true;
+                        break;
+                    }
+                }
+            }
+
+            match Pin::new(&mut *this.group).poll_next(cx) {
+                Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                Poll::Ready(None) if *this.done => return Poll::Ready(None),
+                // The outer stream may have more streams ready than we took.
+                _ if budget == 0 => {
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
+                // An inner stream may have finished and made room for the
+                // next one. The outer stream hasn't registered a waker in that
+                // case, so pull the next stream in before going to sleep.
+                _ if !*this.done
+                    && !stream_pending
+                    && this.limit.map_or(true, |limit| this.group.len() < limit) =>
+                {
+                    continue
+                }
+                _ => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<S> FusedStream for FlattenConcurrent<S>
+where
+    S: Stream,
+    S::Item: IntoStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done && self.group.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn yields_all_items() {
+        block_on(async {
+            let streams = stream::iter([stream::iter([1, 2]), stream::iter([3, 4])]);
+            let mut out: Vec<_> = streams.flatten_concurrent(None).collect().await;
+            out.sort();
+            assert_eq!(out, [1, 2, 3, 4]);
+        })
+    }
+
+    #[test]
+    fn polls_inner_streams_concurrently() {
+        block_on(async {
+            // The first inner stream never ends, so a sequential flatten would
+            // never get to the second one.
+            let streams = stream::iter([stream::repeat(1).boxed(), stream::once(2).boxed()]);
+            let mut s = streams.flatten_concurrent(2);
+            let mut seen_two = false;
+            for _ in 0..10 {
+                seen_two |= s.next().await == Some(2);
+            }
+            assert!(seen_two);
+        })
+    }
+
+    #[test]
+    fn limits_active_streams() {
+        block_on(async {
+            // With a limit of one, the inner streams are flattened in order.
+            let streams = stream::iter([stream::iter([1, 2]), stream::iter([3, 4])]);
+            let out: Vec<_> = streams.flatten_concurrent(1).collect().await;
+            assert_eq!(out, [1, 2, 3, 4]);
+        })
+    }
+
+    #[test]
+    fn always_ready_outer_stream() {
+        block_on(async {
+            let streams = stream::repeat_with(|| stream::once(1));
+            let out: Vec<_> = streams.flatten_concurrent(None).take(3).collect().await;
+            assert_eq!(out, [1, 1, 1]);
+        })
+    }
+}
@@ -0,0 +1,141 @@
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+use crate::utils;
+
+use super::Interleave as InterleaveTrait;
+
+/// A stream that takes turns yielding from multiple streams.
+///
+/// This `struct` is created by the [`interleave`] method on the
+/// [`Interleave`] trait. See its documentation for more.
+///
+/// [`interleave`]: crate::stream::Interleave::interleave
+/// [`Interleave`]: crate::stream::Interleave
+#[pin_project]
+pub struct Interleave<S, const N: usize> {
+    #[pin]
+    streams: [S; N],
+    exhausted: [bool; N],
+    index: usize,
+    remaining: usize,
+    done: bool,
+}
+
+impl<S: Stream, const N: usize> Stream for Interleave<S, N> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        assert!(!*this.done, "Stream should not be polled after completion");
+
+        loop {
+            if *this.remaining == 0 {
+                *this.done = true;
+                return Poll::Ready(None);
+            }
+            let index = *this.index;
+            if this.exhausted[index] {
+                *this.index = (index + 1) % N;
+                continue;
+            }
+            let stream = utils::get_pin_mut(this.streams.as_mut(), index).unwrap();
+            match stream.poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    *this.index = (index + 1) % N;
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(None) => {
+                    this.exhausted[index] = true;
+                    *this.remaining -= 1;
+                    *this.index = (index + 1) % N;
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hints = self
+            .streams
+            .iter()
+            .zip(self.exhausted)
+            .filter(|(_, exhausted)| !exhausted)
+            .map(|(stream, _)| stream.size_hint());
+        utils::size_hint::sum(hints)
+    }
+}
+
+impl<S: Stream, const N: usize> FusedStream for Interleave<S, N> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<S, const N: usize> fmt::Debug for Interleave<S, N>
+where
+    S: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.streams.iter()).finish()
+    }
+}
+
+impl<S: Stream, const N: usize> InterleaveTrait for [S; N] {
+    type Item = S::Item;
+
+    type Stream = Interleave<S, N>;
+
+    fn interleave(self) -> Self::Stream {
+        Interleave {
+            streams: self,
+            exhausted: [false; N],
+            index: 0,
+            remaining: N,
+            done: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn interleave_3() {
+        block_on(async {
+            let a = stream::iter(vec![1, 4]);
+            let b = stream::iter(vec![2]);
+            let c = stream::iter(vec![3, 5, 6]);
+            let mut s = [a, b, c].interleave();
+
+            assert_eq!(s.size_hint(), (6, Some(6)));
+            let items: Vec<_> = (&mut s).collect().await;
+            assert_eq!(items, [1, 2, 3, 4, 5, 6]);
+            assert_eq!(s.size_hint(), (0, Some(0)));
+        })
+    }
+
+    #[test]
+    fn waits_for_the_slow_stream() {
+        block_on(async {
+            let fast = stream::iter(vec![1, 3]).boxed();
+            let slow = stream::iter(vec![2, 4])
+                .then(|n| async move {
+                    futures_lite::future::yield_now().await;
+                    n
+                })
+                .boxed();
+            let items: Vec<_> = [fast, slow].interleave().collect().await;
+            assert_eq!(items, [1, 2, 3, 4]);
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use futures_core::Stream;
+
+pub(crate) mod array;
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Takes multiple streams and creates a new stream which takes turns
+/// yielding from each of them.
+///
+/// Unlike [`Merge`][crate::stream::Merge], which yields items in the order
+/// they become available, this yields items in strict rotation: one item
+/// from the first stream, then one from the second, and so on. If the next
+/// stream in line isn't ready, the combined stream waits for it. Streams which
+/// have been exhausted are skipped.
+pub trait Interleave {
+    /// What's the return type of our stream?
+    type Item;
+
+    /// What stream do we return?
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Combine multiple streams into a single stream, alternating between
+    /// them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::{stream, StreamExt};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let a = stream::iter([1, 3, 5, 6]);
+    /// let b = stream::iter([2, 4]);
+    ///
+    /// let items: Vec<_> = (a, b).interleave().collect().await;
+    /// assert_eq!(items, [1, 2, 3, 4, 5, 6]);
+    /// # });
+    /// ```
+    fn interleave(self) -> Self::Stream;
+}
@@ -0,0 +1,195 @@
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+
+use super::Interleave;
+use crate::utils;
+
+macro_rules! impl_interleave_for_tuple {
+    ($mod_name: ident $StructName:ident $($F:ident)+) => {
+        mod $mod_name {
+            #[repr(usize)]
+            enum Indexes {
+                $($F,)+
+            }
+
+            $(
+                pub(super) const $F: usize = Indexes::$F as usize;
+            )+
+
+            pub(super) const LEN: usize = [$(Indexes::$F,)+].len();
+        }
+
+        #[pin_project::pin_project]
+        pub struct $StructName<$($F,)+> {
+            index: usize,
+            remaining: usize,
+            exhausted: [bool; $mod_name::LEN],
+            done: bool,
+            $( #[pin] $F: $F,)+
+        }
+
+        impl<T, $($F,)+> Stream for $StructName<$($F,)+>
+        where
+            $($F: Stream<Item = T>,)+
+        {
+            type Item = T;
+
+            // With a single stream, the rotation always wraps back to `0`.
+            #[allow(clippy::modulo_one)]
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                assert!(!*this.done, "Stream should not be polled after completion");
+
+                loop {
+                    if *this.remaining == 0 {
+                        *this.done = true;
+                        return Poll::Ready(None);
+                    }
+
+                    let index = *this.index;
+                    *this.index = (index + 1) % $mod_name::LEN;
+                    if this.exhausted[index] {
+                        continue;
+                    }
+
+                    let poll = match index {
+                        $(
+                            $mod_name::$F => this.$F.as_mut().poll_next(cx),
+                        )+
+                        _ => unreachable!(),
+                    };
+                    match poll {
+                        Poll::Ready(None) => {
+                            this.exhausted[index] = true;
+                            *this.remaining -= 1;
+                        }
+                        Poll::Pending => {
+                            // Wait for this stream before moving on.
+                            *this.index = index;
+                            return Poll::Pending;
+                        }
+                        v @ Poll::Ready(Some(_)) => return v,
+                    }
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let hints = [$(
+                    if self.exhausted[$mod_name::$F] {
+                        utils::size_hint::EMPTY
+                    } else {
+                        self.$F.size_hint()
+                    },
+                )+];
+                utils::size_hint::sum(hints)
+            }
+        }
+
+        impl<$($F,)+> fmt::Debug for $StructName<$($F,)+>
+        where
+            $($F: fmt::Debug,)+
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("Interleave")
+                    $(.field(&self.$F))+
+                    .finish()
+            }
+        }
+
+        impl<T, $($F,)+> FusedStream for $StructName<$($F,)+>
+        where
+            $($F: Stream<Item = T>,)+
+        {
+            fn is_terminated(&self) -> bool {
+                self.done
+            }
+        }
+
+        impl<T, $($F,)+> Interleave for ($($F,)+)
+        where
+            $($F: Stream<Item = T>,)+
+        {
+            type Item = T;
+
+            type Stream = $StructName<$($F,)+>;
+
+            fn interleave(self) -> Self::Stream {
+                let ($($F,)*): ($($F,)*) = self;
+                Self::Stream {
+                    index: 0,
+                    remaining: $mod_name::LEN,
+                    exhausted: [false; $mod_name::LEN],
+                    done: false,
+                    $($F,)+
+                }
+            }
+        }
+    }
+}
+
+impl_interleave_for_tuple! { interleave_1 Interleave1 A }
+impl_interleave_for_tuple! { interleave_2 Interleave2 A B }
+impl_interleave_for_tuple! { interleave_3 Interleave3 A B C }
+impl_interleave_for_tuple! { interleave_4 Interleave4 A B C D }
+impl_interleave_for_tuple! { interleave_5 Interleave5 A B C D E }
+impl_interleave_for_tuple! { interleave_6 Interleave6 A B C D E F }
+impl_interleave_for_tuple! { interleave_7 Interleave7 A B C D E F G }
+impl_interleave_for_tuple! { interleave_8 Interleave8 A B C D E F G H }
+impl_interleave_for_tuple! { interleave_9 Interleave9 A B C D E F G H I }
+impl_interleave_for_tuple! { interleave_10 Interleave10 A B C D E F G H I J }
+impl_interleave_for_tuple! { interleave_11 Interleave11 A B C D E F G H I J K }
+impl_interleave_for_tuple! { interleave_12 Interleave12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_13 Interleave13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_14 Interleave14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_15 Interleave15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_16 Interleave16 A B C D E F G H I J K L M N O P }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_17 Interleave17 A B C D E F G H I J K L M N O P Q }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_18 Interleave18 A B C D E F G H I J K L M N O P Q R }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_19 Interleave19 A B C D E F G H I J K L M N O P Q R U }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_20 Interleave20 A B C D E F G H I J K L M N O P Q R U V }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_21 Interleave21 A B C D E F G H I J K L M N O P Q R U V W }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_22 Interleave22 A B C D E F G H I J K L M N O P Q R U V W X }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_23 Interleave23 A B C D E F G H I J K L M N O P Q R U V W X Y }
+#[cfg(feature = "large-tuples")]
+impl_interleave_for_tuple! { interleave_24 Interleave24 A B C D E F G H I J K L M N O P Q R U V W X Y Z }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn interleave_3() {
+        block_on(async {
+            let a = stream::iter([1, 4]);
+            let b = stream::once(2);
+            let c = stream::iter([3, 5]);
+            let mut s = (a, b, c).interleave();
+
+            assert_eq!(s.next().await, Some(1));
+            assert_eq!(s.next().await, Some(2));
+            assert_eq!(s.next().await, Some(3));
+            assert_eq!(s.next().await, Some(4));
+            assert_eq!(s.next().await, Some(5));
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
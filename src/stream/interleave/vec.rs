@@ -0,0 +1,150 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+use crate::stream::IntoStream;
+use crate::utils;
+
+use super::Interleave as InterleaveTrait;
+
+/// A stream that takes turns yielding from multiple streams.
+///
+/// This `struct` is created by the [`interleave`] method on the
+/// [`Interleave`] trait. See its documentation for more.
+///
+/// [`interleave`]: crate::stream::Interleave::interleave
+/// [`Interleave`]: crate::stream::Interleave
+#[pin_project]
+pub struct Interleave<S> {
+    #[pin]
+    streams: Vec<S>,
+    exhausted: Vec<bool>,
+    index: usize,
+    remaining: usize,
+    done: bool,
+}
+
+impl<S: Stream> Stream for Interleave<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        assert!(!*this.done, "Stream should not be polled after completion");
+
+        let len = this.exhausted.len();
+        loop {
+            if *this.remaining == 0 {
+                *this.done = true;
+                return Poll::Ready(None);
+            }
+            let index = *this.index;
+            if this.exhausted[index] {
+                *this.index = (index + 1) % len;
+                continue;
+            }
+            let stream = utils::get_pin_mut_from_vec(this.streams.as_mut(), index).unwrap();
+            match stream.poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    *this.index = (index + 1) % len;
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(None) => {
+                    this.exhausted[index] = true;
+                    *this.remaining -= 1;
+                    *this.index = (index + 1) % len;
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hints = self
+            .streams
+            .iter()
+            .zip(&self.exhausted)
+            .filter(|(_, exhausted)| !**exhausted)
+            .map(|(stream, _)| stream.size_hint());
+        utils::size_hint::sum(hints)
+    }
+}
+
+impl<S: Stream> FusedStream for Interleave<S> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<S> fmt::Debug for Interleave<S>
+where
+    S: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.streams.iter()).finish()
+    }
+}
+
+impl<S: Stream> InterleaveTrait for Vec<S> {
+    type Item = S::Item;
+
+    type Stream = Interleave<S>;
+
+    fn interleave(self) -> Self::Stream {
+        Interleave {
+            exhausted: vec![false; self.len()],
+            index: 0,
+            remaining: self.len(),
+            streams: self,
+            done: false,
+        }
+    }
+}
+
+impl<S> FromIterator<S> for Interleave<S::IntoStream>
+where
+    S: IntoStream,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        iter.into_iter()
+            .map(|s| s.into_stream())
+            .collect::<Vec<_>>()
+            .interleave()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn interleave_3() {
+        block_on(async {
+            let a = stream::iter(vec![1, 4]);
+            let b = stream::iter(vec![2]);
+            let c = stream::iter(vec![3, 5, 6]);
+            let s = vec![a, b, c].interleave();
+
+            let items: Vec<_> = s.collect().await;
+            assert_eq!(items, [1, 2, 3, 4, 5, 6]);
+        })
+    }
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let mut s: Interleave<stream::Once<u8>> = vec![].interleave();
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
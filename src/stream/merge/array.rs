@@ -1,11 +1,12 @@
-use super::Merge as MergeTrait;
+use super::{Merge as MergeTrait, MergeIndexed as MergeIndexedTrait};
+use crate::stream::fairness::{FairnessStrategy, Prioritized, Rotate, RoundRobin};
 use crate::stream::IntoStream;
-use crate::utils::{self, Indexer, PollArray, WakerArray};
+use crate::utils::{self, Budget, IndexIter, PollArray, WakerArray};
 
 use core::fmt;
 use core::pin::Pin;
 use core::task::{Context, Poll};
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 
 /// A stream that merges multiple streams into a single stream.
 ///
@@ -15,13 +16,14 @@ use futures_core::Stream;
 /// [`merge`]: trait.Merge.html#method.merge
 /// [`Merge`]: trait.Merge.html
 #[pin_project::pin_project]
-pub struct Merge<S, const N: usize>
+pub struct Merge<S, const N: usize, P = Rotate>
 where
     S: Stream,
 {
     #[pin]
     streams: [S; N],
-    indexer: Indexer,
+    fairness: P,
+    budget: Budget,
     wakers: WakerArray<N>,
     state: PollArray<N>,
     complete: usize,
@@ -35,7 +37,8 @@ where
     pub(crate) fn new(streams: [S; N]) -> Self {
         Self {
             streams,
-            indexer: Indexer::new(N),
+            fairness: Rotate::new(),
+            budget: Budget::default(),
             wakers: WakerArray::new(),
             state: PollArray::new_pending(),
             complete: 0,
@@ -44,7 +47,129 @@ where
     }
 }
 
-impl<S, const N: usize> fmt::Debug for Merge<S, N>
+impl<S, const N: usize, P> Merge<S, N, P>
+where
+    S: Stream,
+{
+    /// Poll the streams in order, so that ready items from streams earlier in
+    /// the array are always yielded before items from later streams.
+    ///
+    /// This is useful to give a stream priority over others, such as a control
+    /// channel over a data channel. Note that a busy high-priority stream can
+    /// starve lower-priority streams.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let control = stream::repeat("control").take(2);
+    ///     let data = stream::repeat("data").take(2);
+    ///     let s = [control, data].merge().prioritized();
+    ///
+    ///     let buf: Vec<_> = s.collect().await;
+    ///     assert_eq!(buf, vec!["control", "control", "data", "data"]);
+    /// })
+    /// ```
+    pub fn prioritized(self) -> Merge<S, N, Prioritized> {
+        self.with_fairness(Prioritized)
+    }
+
+    /// Poll the streams in strict rotation.
+    ///
+    /// After a stream yields an item, polling resumes from the stream right
+    /// after it. This means that as long as other streams have items ready, no
+    /// stream will be picked twice in a row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let a = stream::repeat("a").take(2);
+    ///     let b = stream::repeat("b").take(2);
+    ///     let s = [a, b].merge().round_robin();
+    ///
+    ///     let buf: Vec<_> = s.collect().await;
+    ///     assert_eq!(buf, vec!["a", "b", "a", "b"]);
+    /// })
+    /// ```
+    pub fn round_robin(self) -> Merge<S, N, RoundRobin> {
+        self.with_fairness(RoundRobin::new())
+    }
+
+    /// Poll at most `budget` streams per call to `poll_next`.
+    ///
+    /// When many streams are woken at once, polling all of them in one go can
+    /// keep the executor busy for a long time. Once the budget runs out the
+    /// merge wakes itself and returns `Poll::Pending`, giving other tasks a
+    /// chance to run. Polling resumes where it left off on the next call. At
+    /// least one stream is always polled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let a = stream::repeat(1).take(2);
+    ///     let b = stream::repeat(2).take(2);
+    ///     let s = [a, b].merge().with_budget(1);
+    ///
+    ///     let sum = s.fold(0, |a, b| a + b).await;
+    ///     assert_eq!(sum, 6);
+    /// })
+    /// ```
+    pub fn with_budget(mut self, budget: usize) -> Self {
+        self.budget.set_limit(budget);
+        self
+    }
+
+    /// Decide the order in which the streams are polled using the given
+    /// [`FairnessStrategy`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_concurrency::stream::fairness::Random;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let a = stream::repeat(1).take(2);
+    ///     let b = stream::repeat(2).take(2);
+    ///     let s = [a, b].merge().with_fairness(Random::with_seed(42));
+    ///
+    ///     let sum = s.fold(0, |a, b| a + b).await;
+    ///     assert_eq!(sum, 6);
+    /// })
+    /// ```
+    pub fn with_fairness<Fair>(self, fairness: Fair) -> Merge<S, N, Fair>
+    where
+        Fair: FairnessStrategy,
+    {
+        Merge {
+            streams: self.streams,
+            fairness,
+            budget: self.budget,
+            wakers: self.wakers,
+            state: self.state,
+            complete: self.complete,
+            done: self.done,
+        }
+    }
+}
+
+impl<S, const N: usize, P> fmt::Debug for Merge<S, N, P>
 where
     S: Stream + fmt::Debug,
 {
@@ -53,13 +178,51 @@ where
     }
 }
 
-impl<S, const N: usize> Stream for Merge<S, N>
+impl<S, const N: usize, P> Stream for Merge<S, N, P>
 where
     S: Stream,
+    P: FairnessStrategy,
 {
     type Item = S::Item;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_next_indexed(cx)
+            .map(|item| item.map(|(_, item)| item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Streams which have completed are no longer polled, so they won't
+        // yield any more items.
+        let hints = self
+            .streams
+            .iter()
+            .zip(self.state.iter())
+            .filter(|(_, state)| !state.is_none())
+            .map(|(stream, _)| stream.size_hint());
+        utils::size_hint::sum(hints)
+    }
+}
+
+impl<S, const N: usize, P> FusedStream for Merge<S, N, P>
+where
+    S: Stream,
+    P: FairnessStrategy,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<S, const N: usize, P> Merge<S, N, P>
+where
+    S: Stream,
+    P: FairnessStrategy,
+{
+    /// Poll the next item, along with the index of the stream which produced it.
+    fn poll_next_indexed(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<(usize, S::Item)>> {
         let mut this = self.project();
 
         let mut readiness = this.wakers.readiness();
@@ -68,7 +231,15 @@ where
         // Iterate over our streams one-by-one. If a stream yields a value,
         // we exit early. By default we'll return `Poll::Ready(None)`, but
         // this changes if we encounter a `Poll::Pending`.
-        for index in this.indexer.iter() {
+        let start = match this.budget.take_resume() {
+            // Resume where we left off if we ran out of budget last time.
+            Some(start) => start,
+            None => this.fairness.start(N),
+        };
+        let mut polled = 0;
+        let waker = cx.waker();
+
+        for index in IndexIter::new(start, N) {
             if !readiness.any_ready() {
                 // Nothing is ready yet
                 return Poll::Pending;
@@ -76,7 +247,7 @@ where
                 continue;
             }
 
-            // unlock readiness so we don't deadlock when polling
+            // release readiness so we can borrow the wakers when polling
             #[allow(clippy::drop_non_drop)]
             drop(readiness);
 
@@ -88,19 +259,29 @@ where
                 Poll::Ready(Some(item)) => {
                     // Mark ourselves as ready again because we need to poll for the next item.
                     this.wakers.readiness().set_ready(index);
-                    return Poll::Ready(Some(item));
+                    this.fairness.yielded(index, N);
+                    return Poll::Ready(Some((index, item)));
                 }
                 Poll::Ready(None) => {
                     *this.complete += 1;
                     this.state[index].set_none();
                     if *this.complete == this.streams.len() {
+                        *this.done = true;
                         return Poll::Ready(None);
                     }
                 }
                 Poll::Pending => {}
             }
 
-            // Lock readiness so we can use it again
+            // Yield to the executor once we've used up our budget
+            polled += 1;
+            if this.budget.is_exhausted(polled) {
+                this.budget.resume_at(index + 1);
+                waker.wake_by_ref();
+                return Poll::Pending;
+            }
+
+            // Reacquire readiness so we can use it again
             readiness = this.wakers.readiness();
         }
 
@@ -120,6 +301,70 @@ where
     }
 }
 
+/// A stream that merges multiple streams into a single stream, tagging each
+/// item with the index of the stream it came from.
+///
+/// This `struct` is created by the [`merge_indexed`] method on the [`MergeIndexed`] trait. See its
+/// documentation for more.
+///
+/// [`merge_indexed`]: trait.MergeIndexed.html#method.merge_indexed
+/// [`MergeIndexed`]: trait.MergeIndexed.html
+#[pin_project::pin_project]
+pub struct MergeIndexed<S, const N: usize>
+where
+    S: Stream,
+{
+    #[pin]
+    inner: Merge<S, N>,
+}
+
+impl<S, const N: usize> fmt::Debug for MergeIndexed<S, N>
+where
+    S: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<S, const N: usize> Stream for MergeIndexed<S, N>
+where
+    S: Stream,
+{
+    type Item = (usize, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next_indexed(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S, const N: usize> FusedStream for MergeIndexed<S, N>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S, const N: usize> MergeIndexedTrait for [S; N]
+where
+    S: IntoStream,
+{
+    type Item = <S::IntoStream as Stream>::Item;
+    type Stream = MergeIndexed<S::IntoStream, N>;
+
+    fn merge_indexed(self) -> Self::Stream {
+        MergeIndexed {
+            inner: Merge::new(self.map(|i| i.into_stream())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,6 +372,21 @@ mod tests {
     use futures_lite::prelude::*;
     use futures_lite::stream;
 
+    #[test]
+    fn merge_size_hint() {
+        block_on(async {
+            let a = stream::iter(vec![1, 2]);
+            let b = stream::iter(vec![3]);
+            let mut s = [a, b].merge();
+
+            assert_eq!(s.size_hint(), (3, Some(3)));
+            s.next().await;
+            assert_eq!(s.size_hint(), (2, Some(2)));
+            while s.next().await.is_some() {}
+            assert_eq!(s.size_hint(), (0, Some(0)));
+        })
+    }
+
     #[test]
     fn merge_array_4() {
         block_on(async {
@@ -159,6 +419,34 @@ mod tests {
         })
     }
 
+    #[test]
+    fn merge_indexed_array() {
+        block_on(async {
+            let a = stream::repeat(1).take(2);
+            let b = stream::repeat(2).take(3);
+            let mut s = [a, b].merge_indexed();
+
+            let mut counts = [0; 2];
+            while let Some((index, n)) = s.next().await {
+                assert_eq!(n, index + 1);
+                counts[index] += 1;
+            }
+            assert_eq!(counts, [2, 3]);
+        })
+    }
+
+    #[test]
+    fn merge_is_terminated() {
+        use futures_core::stream::FusedStream;
+
+        block_on(async {
+            let mut s = [stream::once(1), stream::once(2)].merge();
+            assert!(!s.is_terminated());
+            while s.next().await.is_some() {}
+            assert!(s.is_terminated());
+        })
+    }
+
     /// This test case uses channels so we'll have streams that return Pending from time to time.
     ///
     /// The purpose of this test is to make sure we have the waking logic working.
@@ -171,7 +459,7 @@ mod tests {
         use futures::task::LocalSpawnExt;
 
         use crate::future::join::Join;
-        use crate::utils::channel::local_channel;
+        use crate::test_utils::local_channel;
 
         let mut pool = LocalPool::new();
 
@@ -0,0 +1,163 @@
+use super::tuple::*;
+use super::{Merge as MergeTrait, MergeHeterogeneous as MergeHeterogeneousTrait};
+use crate::stream::either::*;
+use crate::stream::IntoStream;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+/// A stream which wraps every item of the inner stream in an enum variant.
+#[pin_project::pin_project]
+pub(crate) struct Tagged<S, T>
+where
+    S: Stream,
+{
+    #[pin]
+    stream: S,
+    tag: fn(S::Item) -> T,
+}
+
+impl<S, T> fmt::Debug for Tagged<S, T>
+where
+    S: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.stream.fmt(f)
+    }
+}
+
+impl<S, T> Stream for Tagged<S, T>
+where
+    S: Stream,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream.poll_next(cx).map(|item| item.map(*this.tag))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+macro_rules! impl_merge_heterogeneous_tuple {
+    ($StructName:ident $MergeName:ident $Either:ident $($F:ident)+) => {
+        impl_merge_heterogeneous_tuple! {
+            @impl $StructName $MergeName $Either ($Either<$($F::Item),+>) $($F)+
+        }
+    };
+    (@impl $StructName:ident $MergeName:ident $Either:ident ($Item:ty) $($F:ident)+) => {
+        /// A stream that merges multiple streams with different item types
+        /// into a single stream.
+        ///
+        /// This `struct` is created by the [`merge_heterogeneous`] method on the
+        /// [`MergeHeterogeneous`] trait. See its documentation for more.
+        ///
+        /// [`merge_heterogeneous`]: crate::stream::MergeHeterogeneous::merge_heterogeneous
+        /// [`MergeHeterogeneous`]: crate::stream::MergeHeterogeneous
+        #[pin_project::pin_project]
+        pub struct $StructName<$($F),+>
+        where $(
+            $F: Stream,
+        )+ {
+            #[pin]
+            inner: $MergeName<$Item, $(Tagged<$F, $Item>),+>,
+        }
+
+        impl<$($F),+> fmt::Debug for $StructName<$($F),+>
+        where $(
+            $F: Stream + fmt::Debug,
+        )+ {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.inner.fmt(f)
+            }
+        }
+
+        impl<$($F),+> Stream for $StructName<$($F),+>
+        where $(
+            $F: Stream,
+        )+ {
+            type Item = $Item;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                self.project().inner.poll_next(cx)
+            }
+        }
+
+        impl<$($F),+> FusedStream for $StructName<$($F),+>
+        where $(
+            $F: Stream,
+        )+ {
+            fn is_terminated(&self) -> bool {
+                self.inner.is_terminated()
+            }
+        }
+
+        impl<$($F),+> MergeHeterogeneousTrait for ($($F,)+)
+        where $(
+            $F: IntoStream,
+        )+ {
+            type Item = $Either<$($F::Item),+>;
+            type Stream = $StructName<$($F::IntoStream),+>;
+
+            #[allow(non_snake_case)]
+            fn merge_heterogeneous(self) -> Self::Stream {
+                let ($($F,)+) = self;
+                let streams = ($(
+                    Tagged {
+                        stream: $F.into_stream(),
+                        tag: $Either::$F as fn(_) -> _,
+                    },
+                )+);
+                $StructName { inner: streams.merge() }
+            }
+        }
+    };
+}
+
+impl_merge_heterogeneous_tuple! { MergeHeterogeneous2 Merge2 Either2 A B }
+impl_merge_heterogeneous_tuple! { MergeHeterogeneous3 Merge3 Either3 A B C }
+impl_merge_heterogeneous_tuple! { MergeHeterogeneous4 Merge4 Either4 A B C D }
+impl_merge_heterogeneous_tuple! { MergeHeterogeneous5 Merge5 Either5 A B C D E }
+impl_merge_heterogeneous_tuple! { MergeHeterogeneous6 Merge6 Either6 A B C D E F }
+impl_merge_heterogeneous_tuple! { MergeHeterogeneous7 Merge7 Either7 A B C D E F G }
+impl_merge_heterogeneous_tuple! { MergeHeterogeneous8 Merge8 Either8 A B C D E F G H }
+impl_merge_heterogeneous_tuple! { MergeHeterogeneous9 Merge9 Either9 A B C D E F G H I }
+impl_merge_heterogeneous_tuple! { MergeHeterogeneous10 Merge10 Either10 A B C D E F G H I J }
+impl_merge_heterogeneous_tuple! { MergeHeterogeneous11 Merge11 Either11 A B C D E F G H I J K }
+impl_merge_heterogeneous_tuple! { MergeHeterogeneous12 Merge12 Either12 A B C D E F G H I J K L }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn merge_heterogeneous_2() {
+        block_on(async {
+            let a = stream::repeat(1u8).take(2);
+            let b = stream::once("hello");
+            let mut s = (a, b).merge_heterogeneous();
+
+            let mut sum = 0;
+            let mut strings = 0;
+            while let Some(item) = s.next().await {
+                match item {
+                    Either2::A(n) => sum += n,
+                    Either2::B(s) => {
+                        assert_eq!(s, "hello");
+                        strings += 1;
+                    }
+                }
+            }
+            assert_eq!(sum, 2);
+            assert_eq!(strings, 1);
+        })
+    }
+}
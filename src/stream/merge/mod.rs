@@ -1,6 +1,7 @@
 use futures_core::Stream;
 
 pub(crate) mod array;
+pub(crate) mod heterogeneous;
 pub(crate) mod tuple;
 #[cfg(feature = "alloc")]
 pub(crate) mod vec;
@@ -9,7 +10,8 @@ pub(crate) mod vec;
 ///
 /// Items are yielded as soon as they're received, and the stream continues
 /// yield until both streams have been exhausted. The output ordering
-/// between streams is not guaranteed.
+/// between streams is not guaranteed, but the order in which they're polled
+/// can be picked with a [`FairnessStrategy`][crate::stream::fairness::FairnessStrategy].
 ///
 /// # Examples
 ///
@@ -30,6 +32,25 @@ pub(crate) mod vec;
 ///     assert_eq!(&buf, &[1, 2, 3]);
 /// })
 /// ```
+///
+/// Streams from any iterator can be merged by collecting them:
+///
+/// ```
+/// use futures_concurrency::vec::Merge;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+/// use std::collections::HashMap;
+///
+/// block_on(async {
+///     let mut streams = HashMap::new();
+///     streams.insert("a", stream::once(1));
+///     streams.insert("b", stream::once(2));
+///
+///     let s: Merge<_> = streams.into_values().collect();
+///     let sum = s.fold(0, |a, b| a + b).await;
+///     assert_eq!(sum, 3);
+/// })
+/// ```
 pub trait Merge {
     /// The resulting output type.
     type Item;
@@ -40,3 +61,78 @@ pub trait Merge {
     /// Combine multiple streams into a single stream.
     fn merge(self) -> Self::Stream;
 }
+
+/// Combines multiple streams into a single stream of all their outputs,
+/// tagging each item with the index of the stream which produced it.
+///
+/// This works like [`Merge`], but yields `(index, item)` pairs so the
+/// source of every item can be told apart without mapping each stream first.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let a = stream::once("a");
+///     let b = stream::once("b");
+///     let mut s = [a, b].merge_indexed();
+///
+///     let mut buf = vec![];
+///     s.for_each(|n| buf.push(n)).await;
+///     buf.sort_unstable();
+///     assert_eq!(&buf, &[(0, "a"), (1, "b")]);
+/// })
+/// ```
+pub trait MergeIndexed {
+    /// The resulting output type.
+    type Item;
+
+    /// The stream type.
+    type Stream: Stream<Item = (usize, Self::Item)>;
+
+    /// Combine multiple streams into a single stream, tagging each item with
+    /// the index of its source stream.
+    fn merge_indexed(self) -> Self::Stream;
+}
+
+/// Combines multiple streams with different item types into a single stream.
+///
+/// Each item is wrapped in an [`Either`][crate::stream::either] enum whose
+/// variant identifies the stream it came from. Items are yielded as soon as
+/// they're received, and the stream continues until all streams have been
+/// exhausted.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_concurrency::stream::either::Either2;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let ticks = stream::once(1u64);
+///     let messages = stream::once("hello");
+///     let mut s = (ticks, messages).merge_heterogeneous();
+///
+///     while let Some(item) = s.next().await {
+///         match item {
+///             Either2::A(tick) => assert_eq!(tick, 1),
+///             Either2::B(msg) => assert_eq!(msg, "hello"),
+///         }
+///     }
+/// })
+/// ```
+pub trait MergeHeterogeneous {
+    /// The resulting output type.
+    type Item;
+
+    /// The stream type.
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Combine multiple streams with different item types into a single stream.
+    fn merge_heterogeneous(self) -> Self::Stream;
+}
@@ -1,11 +1,12 @@
-use super::Merge as MergeTrait;
+use super::{Merge as MergeTrait, MergeIndexed as MergeIndexedTrait};
+use crate::stream::fairness::{FairnessStrategy, Prioritized, Rotate, RoundRobin};
 use crate::stream::IntoStream;
-use crate::utils::{self, PollArray, WakerArray};
+use crate::utils::{self, IndexIter, PollArray, WakerArray};
 
 use core::fmt;
 use core::pin::Pin;
 use core::task::{Context, Poll};
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 
 macro_rules! poll_stream {
     ($stream_idx:tt, $iteration:ident, $this:ident, $streams:ident . $stream_member:ident, $cx:ident, $len_streams:ident) => {
@@ -14,7 +15,8 @@ macro_rules! poll_stream {
                 Poll::Ready(Some(item)) => {
                     // Mark ourselves as ready again because we need to poll for the next item.
                     $this.wakers.readiness().set_ready($stream_idx);
-                    return Poll::Ready(Some(item));
+                    $this.fairness.yielded($stream_idx, $len_streams as usize);
+                    return Poll::Ready(Some(($stream_idx, item)));
                 }
                 Poll::Ready(None) => {
                     *$this.completed += 1;
@@ -30,7 +32,7 @@ macro_rules! poll_stream {
 }
 
 macro_rules! impl_merge_tuple {
-    ($ignore:ident $StructName:ident) => {
+    ($ignore:ident $StructName:ident $IndexedName:ident) => {
         /// A stream that merges multiple streams into a single stream.
         ///
         /// This `struct` is created by the [`merge`] method on the [`Merge`] trait. See its
@@ -52,6 +54,16 @@ macro_rules! impl_merge_tuple {
             fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
                 Poll::Ready(None)
             }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                utils::size_hint::EMPTY
+            }
+        }
+
+        impl FusedStream for $StructName {
+            fn is_terminated(&self) -> bool {
+                true
+            }
         }
 
         impl MergeTrait for () {
@@ -62,8 +74,51 @@ macro_rules! impl_merge_tuple {
                 $StructName { }
             }
         }
+
+        /// A stream that merges multiple streams into a single stream, tagging
+        /// each item with the index of the stream it came from.
+        ///
+        /// This `struct` is created by the [`merge_indexed`] method on the [`MergeIndexed`] trait. See its
+        /// documentation for more.
+        ///
+        /// [`merge_indexed`]: trait.MergeIndexed.html#method.merge_indexed
+        /// [`MergeIndexed`]: trait.MergeIndexed.html
+        pub struct $IndexedName {}
+
+        impl fmt::Debug for $IndexedName {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("MergeIndexed").finish()
+            }
+        }
+
+        impl Stream for $IndexedName {
+            type Item = (usize, core::convert::Infallible);
+
+            fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                Poll::Ready(None)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                utils::size_hint::EMPTY
+            }
+        }
+
+        impl FusedStream for $IndexedName {
+            fn is_terminated(&self) -> bool {
+                true
+            }
+        }
+
+        impl MergeIndexedTrait for () {
+            type Item = core::convert::Infallible;
+            type Stream = $IndexedName;
+
+            fn merge_indexed(self) -> Self::Stream {
+                $IndexedName { }
+            }
+        }
     };
-    ($mod_name:ident $StructName:ident $($F:ident)+) => {
+    ($mod_name:ident $StructName:ident $IndexedName:ident $($F:ident)+) => {
         mod $mod_name {
             #[pin_project::pin_project]
             pub(super) struct Streams<$($F,)+> { $(#[pin] pub(super) $F: $F),+ }
@@ -82,18 +137,18 @@ macro_rules! impl_merge_tuple {
         /// [`merge`]: trait.Merge.html#method.merge
         /// [`Merge`]: trait.Merge.html
         #[pin_project::pin_project]
-        pub struct $StructName<T, $($F),*>
+        pub struct $StructName<T, $($F,)* Fairness = Rotate>
         where $(
             $F: Stream<Item = T>,
         )* {
             #[pin] streams: $mod_name::Streams<$($F,)+>,
-            indexer: utils::Indexer,
+            fairness: Fairness,
             wakers: WakerArray<{$mod_name::LEN}>,
             state: PollArray<{$mod_name::LEN}>,
             completed: u8,
         }
 
-        impl<T, $($F),*> fmt::Debug for $StructName<T, $($F),*>
+        impl<T, $($F,)* Fairness> fmt::Debug for $StructName<T, $($F,)* Fairness>
         where
             $( $F: Stream<Item = T> + fmt::Debug, )*
         {
@@ -104,13 +159,79 @@ macro_rules! impl_merge_tuple {
             }
         }
 
-        impl<T, $($F),*> Stream for $StructName<T, $($F),*>
+        impl<T, $($F,)* Fairness> Stream for $StructName<T, $($F,)* Fairness>
         where $(
             $F: Stream<Item = T>,
-        )* {
+        )*
+            Fairness: FairnessStrategy,
+        {
             type Item = T;
 
             fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                self.poll_next_indexed(cx).map(|item| item.map(|(_, item)| item))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                // Streams which have completed are no longer polled, so they won't
+                // yield any more items.
+                let hints = [$(
+                    if self.state[$mod_name::Indexes::$F as usize].is_none() {
+                        utils::size_hint::EMPTY
+                    } else {
+                        self.streams.$F.size_hint()
+                    },
+                )+];
+                utils::size_hint::sum(hints)
+            }
+        }
+
+        impl<T, $($F,)* Fairness> $StructName<T, $($F,)* Fairness>
+        where $(
+            $F: Stream<Item = T>,
+        )* {
+            /// Poll the streams in order, so that ready items from streams earlier in
+            /// the tuple are always yielded before items from later streams.
+            ///
+            /// This is useful to give a stream priority over others, such as a control
+            /// channel over a data channel. Note that a busy high-priority stream can
+            /// starve lower-priority streams.
+            pub fn prioritized(self) -> $StructName<T, $($F,)* Prioritized> {
+                self.with_fairness(Prioritized)
+            }
+
+            /// Poll the streams in strict rotation.
+            ///
+            /// After a stream yields an item, polling resumes from the stream right
+            /// after it. This means that as long as other streams have items ready, no
+            /// stream will be picked twice in a row.
+            pub fn round_robin(self) -> $StructName<T, $($F,)* RoundRobin> {
+                self.with_fairness(RoundRobin::new())
+            }
+
+            /// Decide the order in which the streams are polled using the given
+            /// [`FairnessStrategy`].
+            pub fn with_fairness<Fair>(self, fairness: Fair) -> $StructName<T, $($F,)* Fair>
+            where
+                Fair: FairnessStrategy,
+            {
+                $StructName {
+                    streams: self.streams,
+                    fairness,
+                    wakers: self.wakers,
+                    state: self.state,
+                    completed: self.completed,
+                }
+            }
+        }
+
+        impl<T, $($F,)* Fairness> $StructName<T, $($F,)* Fairness>
+        where $(
+            $F: Stream<Item = T>,
+        )*
+            Fairness: FairnessStrategy,
+        {
+            /// Poll the next item, along with the index of the stream which produced it.
+            fn poll_next_indexed(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<(usize, T)>> {
                 let this = self.project();
 
                 let mut readiness = this.wakers.readiness();
@@ -123,7 +244,8 @@ macro_rules! impl_merge_tuple {
                 // Iterate over our streams one-by-one. If a stream yields a value,
                 // we exit early. By default we'll return `Poll::Ready(None)`, but
                 // this changes if we encounter a `Poll::Pending`.
-                for index in this.indexer.iter() {
+                let start = this.fairness.start($mod_name::LEN);
+                for index in IndexIter::new(start, $mod_name::LEN) {
                     if !readiness.any_ready() {
                         // Nothing is ready yet
                         return Poll::Pending;
@@ -131,7 +253,7 @@ macro_rules! impl_merge_tuple {
                         continue;
                     }
 
-                    // unlock readiness so we don't deadlock when polling
+                    // release readiness so we can borrow the wakers when polling
                     #[allow(clippy::drop_non_drop)]
                     drop(readiness);
 
@@ -150,7 +272,7 @@ macro_rules! impl_merge_tuple {
                         );
                     )+
 
-                    // Lock readiness so we can use it again
+                    // Reacquire readiness so we can use it again
                     readiness = this.wakers.readiness();
                 }
 
@@ -158,6 +280,17 @@ macro_rules! impl_merge_tuple {
             }
         }
 
+        impl<T, $($F,)* Fairness> FusedStream for $StructName<T, $($F,)* Fairness>
+        where $(
+            $F: Stream<Item = T>,
+        )*
+            Fairness: FairnessStrategy,
+        {
+            fn is_terminated(&self) -> bool {
+                self.completed as usize == $mod_name::LEN
+            }
+        }
+
         impl<T, $($F),*> MergeTrait for ($($F,)*)
         where $(
             $F: IntoStream<Item = T>,
@@ -169,29 +302,116 @@ macro_rules! impl_merge_tuple {
                 let ($($F,)*): ($($F,)*) = self;
                 $StructName {
                     streams: $mod_name::Streams { $($F: $F.into_stream()),+ },
-                    indexer: utils::Indexer::new(utils::tuple_len!($($F,)*)),
+                    fairness: Rotate::new(),
                     wakers: WakerArray::new(),
                     state: PollArray::new_pending(),
                     completed: 0,
                 }
             }
         }
+
+        /// A stream that merges multiple streams into a single stream, tagging
+        /// each item with the index of the stream it came from.
+        ///
+        /// This `struct` is created by the [`merge_indexed`] method on the [`MergeIndexed`] trait. See its
+        /// documentation for more.
+        ///
+        /// [`merge_indexed`]: trait.MergeIndexed.html#method.merge_indexed
+        /// [`MergeIndexed`]: trait.MergeIndexed.html
+        #[pin_project::pin_project]
+        pub struct $IndexedName<T, $($F),*>
+        where $(
+            $F: Stream<Item = T>,
+        )* {
+            #[pin] inner: $StructName<T, $($F),*>,
+        }
+
+        impl<T, $($F),*> fmt::Debug for $IndexedName<T, $($F),*>
+        where
+            $( $F: Stream<Item = T> + fmt::Debug, )*
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("MergeIndexed")
+                    $( .field(&self.inner.streams.$F) )* // Hides implementation detail of Streams struct
+                    .finish()
+            }
+        }
+
+        impl<T, $($F),*> Stream for $IndexedName<T, $($F),*>
+        where $(
+            $F: Stream<Item = T>,
+        )* {
+            type Item = (usize, T);
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                self.project().inner.poll_next_indexed(cx)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<T, $($F),*> FusedStream for $IndexedName<T, $($F),*>
+        where $(
+            $F: Stream<Item = T>,
+        )* {
+            fn is_terminated(&self) -> bool {
+                self.inner.is_terminated()
+            }
+        }
+
+        impl<T, $($F),*> MergeIndexedTrait for ($($F,)*)
+        where $(
+            $F: IntoStream<Item = T>,
+        )* {
+            type Item = T;
+            type Stream = $IndexedName<T, $($F::IntoStream),*>;
+
+            fn merge_indexed(self) -> Self::Stream {
+                $IndexedName { inner: MergeTrait::merge(self) }
+            }
+        }
     };
 }
 
-impl_merge_tuple! { merge0 Merge0  }
-impl_merge_tuple! { merge1 Merge1  A }
-impl_merge_tuple! { merge2 Merge2  A B }
-impl_merge_tuple! { merge3 Merge3  A B C }
-impl_merge_tuple! { merge4 Merge4  A B C D }
-impl_merge_tuple! { merge5 Merge5  A B C D E }
-impl_merge_tuple! { merge6 Merge6  A B C D E F }
-impl_merge_tuple! { merge7 Merge7  A B C D E F G }
-impl_merge_tuple! { merge8 Merge8  A B C D E F G H }
-impl_merge_tuple! { merge9 Merge9  A B C D E F G H I }
-impl_merge_tuple! { merge10 Merge10 A B C D E F G H I J }
-impl_merge_tuple! { merge11 Merge11 A B C D E F G H I J K }
-impl_merge_tuple! { merge12 Merge12 A B C D E F G H I J K L }
+impl_merge_tuple! { merge0 Merge0 MergeIndexed0  }
+impl_merge_tuple! { merge1 Merge1 MergeIndexed1  A }
+impl_merge_tuple! { merge2 Merge2 MergeIndexed2  A B }
+impl_merge_tuple! { merge3 Merge3 MergeIndexed3  A B C }
+impl_merge_tuple! { merge4 Merge4 MergeIndexed4  A B C D }
+impl_merge_tuple! { merge5 Merge5 MergeIndexed5  A B C D E }
+impl_merge_tuple! { merge6 Merge6 MergeIndexed6  A B C D E F }
+impl_merge_tuple! { merge7 Merge7 MergeIndexed7  A B C D E F G }
+impl_merge_tuple! { merge8 Merge8 MergeIndexed8  A B C D E F G H }
+impl_merge_tuple! { merge9 Merge9 MergeIndexed9  A B C D E F G H I }
+impl_merge_tuple! { merge10 Merge10 MergeIndexed10 A B C D E F G H I J }
+impl_merge_tuple! { merge11 Merge11 MergeIndexed11 A B C D E F G H I J K }
+impl_merge_tuple! { merge12 Merge12 MergeIndexed12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge13 Merge13 MergeIndexed13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge14 Merge14 MergeIndexed14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge15 Merge15 MergeIndexed15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge16 Merge16 MergeIndexed16 A B C D E F G H I J K L M N O P }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge17 Merge17 MergeIndexed17 A B C D E F G H I J K L M N O P Q }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge18 Merge18 MergeIndexed18 A B C D E F G H I J K L M N O P Q R }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge19 Merge19 MergeIndexed19 A B C D E F G H I J K L M N O P Q R U }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge20 Merge20 MergeIndexed20 A B C D E F G H I J K L M N O P Q R U V }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge21 Merge21 MergeIndexed21 A B C D E F G H I J K L M N O P Q R U V W }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge22 Merge22 MergeIndexed22 A B C D E F G H I J K L M N O P Q R U V W X }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge23 Merge23 MergeIndexed23 A B C D E F G H I J K L M N O P Q R U V W X Y }
+#[cfg(feature = "large-tuples")]
+impl_merge_tuple! { merge24 Merge24 MergeIndexed24 A B C D E F G H I J K L M N O P Q R U V W X Y Z }
 
 #[cfg(test)]
 mod tests {
@@ -275,6 +495,56 @@ mod tests {
         })
     }
 
+    #[test]
+    fn merge_indexed_tuple_3() {
+        block_on(async {
+            let a = stream::once("a");
+            let b = stream::repeat("b").take(2);
+            let c = stream::once("c");
+            let mut s = (a, b, c).merge_indexed();
+
+            let mut counts = [0; 3];
+            while let Some((index, item)) = s.next().await {
+                assert_eq!(item, ["a", "b", "c"][index]);
+                counts[index] += 1;
+            }
+            assert_eq!(counts, [1, 2, 1]);
+        })
+    }
+
+    #[test]
+    fn merge_tuple_prioritized() {
+        block_on(async {
+            let a = stream::repeat(1).take(2);
+            let b = stream::repeat(2).take(2);
+            let mut s = (a, b).merge().prioritized();
+
+            let mut out = [0; 4];
+            for slot in out.iter_mut() {
+                *slot = s.next().await.unwrap();
+            }
+            assert_eq!(out, [1, 1, 2, 2]);
+            assert_eq!(s.next().await, None);
+        })
+    }
+
+    #[test]
+    fn merge_tuple_round_robin() {
+        block_on(async {
+            let a = stream::repeat(1).take(2);
+            let b = stream::repeat(2).take(2);
+            let c = stream::repeat(3).take(2);
+            let mut s = (a, b, c).merge().round_robin();
+
+            let mut out = [0; 6];
+            for slot in out.iter_mut() {
+                *slot = s.next().await.unwrap();
+            }
+            assert_eq!(out, [1, 2, 3, 1, 2, 3]);
+            assert_eq!(s.next().await, None);
+        })
+    }
+
     /// This test case uses channels so we'll have streams that return Pending from time to time.
     ///
     /// The purpose of this test is to make sure we have the waking logic working.
@@ -288,7 +558,7 @@ mod tests {
         use futures::task::LocalSpawnExt;
 
         use crate::future::Join;
-        use crate::utils::channel::local_channel;
+        use crate::test_utils::local_channel;
 
         let mut pool = LocalPool::new();
 
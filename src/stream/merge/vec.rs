@@ -1,14 +1,17 @@
-use super::Merge as MergeTrait;
-use crate::stream::IntoStream;
-use crate::utils::{self, Indexer, PollVec, WakerVec};
+use super::{Merge as MergeTrait, MergeIndexed as MergeIndexedTrait};
+use crate::stream::fairness::{FairnessStrategy, Prioritized, Rotate, RoundRobin};
+use crate::stream::stream_group::Fairness;
+use crate::stream::{IntoStream, StreamGroup};
+use crate::utils::{self, Budget, IndexIter, PollVec, WakerVec};
 
+use alloc::collections::VecDeque;
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 
 use core::fmt;
 use core::pin::Pin;
 use core::task::{Context, Poll};
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 
 /// A stream that merges multiple streams into a single stream.
 ///
@@ -18,13 +21,14 @@ use futures_core::Stream;
 /// [`merge`]: trait.Merge.html#method.merge
 /// [`Merge`]: trait.Merge.html
 #[pin_project::pin_project]
-pub struct Merge<S>
+pub struct Merge<S, P = Rotate>
 where
     S: Stream,
 {
     #[pin]
     streams: Vec<S>,
-    indexer: Indexer,
+    fairness: P,
+    budget: Budget,
     complete: usize,
     wakers: WakerVec,
     state: PollVec,
@@ -40,7 +44,8 @@ where
         Self {
             wakers: WakerVec::new(len),
             state: PollVec::new_pending(len),
-            indexer: Indexer::new(len),
+            fairness: Rotate::new(),
+            budget: Budget::default(),
             streams,
             complete: 0,
             done: false,
@@ -48,7 +53,176 @@ where
     }
 }
 
-impl<S> fmt::Debug for Merge<S>
+impl<S, P> Merge<S, P>
+where
+    S: Stream,
+{
+    /// Poll the streams in order, so that ready items from streams earlier in
+    /// the `Vec` are always yielded before items from later streams.
+    ///
+    /// This is useful to give a stream priority over others, such as a control
+    /// channel over a data channel. Note that a busy high-priority stream can
+    /// starve lower-priority streams.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let control = stream::repeat("control").take(2);
+    ///     let data = stream::repeat("data").take(2);
+    ///     let s = vec![control, data].merge().prioritized();
+    ///
+    ///     let buf: Vec<_> = s.collect().await;
+    ///     assert_eq!(buf, vec!["control", "control", "data", "data"]);
+    /// })
+    /// ```
+    pub fn prioritized(self) -> Merge<S, Prioritized> {
+        self.with_fairness(Prioritized)
+    }
+
+    /// Poll the streams in strict rotation.
+    ///
+    /// After a stream yields an item, polling resumes from the stream right
+    /// after it. This means that as long as other streams have items ready, no
+    /// stream will be picked twice in a row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let a = stream::repeat("a").take(2);
+    ///     let b = stream::repeat("b").take(2);
+    ///     let s = vec![a, b].merge().round_robin();
+    ///
+    ///     let buf: Vec<_> = s.collect().await;
+    ///     assert_eq!(buf, vec!["a", "b", "a", "b"]);
+    /// })
+    /// ```
+    pub fn round_robin(self) -> Merge<S, RoundRobin> {
+        self.with_fairness(RoundRobin::new())
+    }
+
+    /// Poll at most `budget` streams per call to `poll_next`.
+    ///
+    /// When many streams are woken at once, polling all of them in one go can
+    /// keep the executor busy for a long time. Once the budget runs out the
+    /// merge wakes itself and returns `Poll::Pending`, giving other tasks a
+    /// chance to run. Polling resumes where it left off on the next call. At
+    /// least one stream is always polled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let a = stream::repeat(1).take(2);
+    ///     let b = stream::repeat(2).take(2);
+    ///     let s = vec![a, b].merge().with_budget(1);
+    ///
+    ///     let sum = s.fold(0, |a, b| a + b).await;
+    ///     assert_eq!(sum, 6);
+    /// })
+    /// ```
+    pub fn with_budget(mut self, budget: usize) -> Self {
+        self.budget.set_limit(budget);
+        self
+    }
+
+    /// Decide the order in which the streams are polled using the given
+    /// [`FairnessStrategy`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_concurrency::stream::fairness::Random;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let a = stream::repeat(1).take(2);
+    ///     let b = stream::repeat(2).take(2);
+    ///     let s = vec![a, b].merge().with_fairness(Random::with_seed(42));
+    ///
+    ///     let sum = s.fold(0, |a, b| a + b).await;
+    ///     assert_eq!(sum, 6);
+    /// })
+    /// ```
+    pub fn with_fairness<Fair>(self, fairness: Fair) -> Merge<S, Fair>
+    where
+        Fair: FairnessStrategy,
+    {
+        Merge {
+            streams: self.streams,
+            fairness,
+            budget: self.budget,
+            complete: self.complete,
+            wakers: self.wakers,
+            state: self.state,
+            done: self.done,
+        }
+    }
+}
+
+impl<S, P> Merge<S, P>
+where
+    S: Stream + Unpin,
+{
+    /// Convert the merge into a [`StreamGroup`], so that more streams can be
+    /// inserted later on.
+    ///
+    /// Streams which have already completed are not included in the group.
+    /// The group takes turns between its streams, like the merge does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let mut group = vec![stream::once(1), stream::once(2)].merge().into_group();
+    ///     group.insert(stream::once(3));
+    ///
+    ///     let mut out: Vec<_> = group.collect().await;
+    ///     out.sort();
+    ///     assert_eq!(out, vec![1, 2, 3]);
+    /// })
+    /// ```
+    pub fn into_group(self) -> StreamGroup<S> {
+        let mut group = StreamGroup::with_capacity(self.streams.len() - self.complete);
+        group.set_fairness(Fairness::RoundRobin);
+        for (stream, state) in self.streams.into_iter().zip(self.state.iter()) {
+            if !state.is_none() {
+                group.insert(stream);
+            }
+        }
+        group
+    }
+}
+
+impl<S, P> From<Merge<S, P>> for StreamGroup<S>
+where
+    S: Stream + Unpin,
+{
+    fn from(merge: Merge<S, P>) -> Self {
+        merge.into_group()
+    }
+}
+
+impl<S, P> fmt::Debug for Merge<S, P>
 where
     S: Stream + fmt::Debug,
 {
@@ -57,13 +231,51 @@ where
     }
 }
 
-impl<S> Stream for Merge<S>
+impl<S, P> Stream for Merge<S, P>
 where
     S: Stream,
+    P: FairnessStrategy,
 {
     type Item = S::Item;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_next_indexed(cx)
+            .map(|item| item.map(|(_, item)| item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Streams which have completed are no longer polled, so they won't
+        // yield any more items.
+        let hints = self
+            .streams
+            .iter()
+            .zip(self.state.iter())
+            .filter(|(_, state)| !state.is_none())
+            .map(|(stream, _)| stream.size_hint());
+        utils::size_hint::sum(hints)
+    }
+}
+
+impl<S, P> FusedStream for Merge<S, P>
+where
+    S: Stream,
+    P: FairnessStrategy,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+impl<S, P> Merge<S, P>
+where
+    S: Stream,
+    P: FairnessStrategy,
+{
+    /// Poll the next item, along with the index of the stream which produced it.
+    fn poll_next_indexed(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<(usize, S::Item)>> {
         let mut this = self.project();
 
         let mut readiness = this.wakers.readiness();
@@ -72,7 +284,16 @@ where
         // Iterate over our streams one-by-one. If a stream yields a value,
         // we exit early. By default we'll return `Poll::Ready(None)`, but
         // this changes if we encounter a `Poll::Pending`.
-        for index in this.indexer.iter() {
+        let len = this.streams.len();
+        let start = match this.budget.take_resume() {
+            // Resume where we left off if we ran out of budget last time.
+            Some(start) => start,
+            None => this.fairness.start(len),
+        };
+        let mut polled = 0;
+        let waker = cx.waker();
+
+        for index in IndexIter::new(start, len) {
             if !readiness.any_ready() {
                 // Nothing is ready yet
                 return Poll::Pending;
@@ -80,7 +301,7 @@ where
                 continue;
             }
 
-            // unlock readiness so we don't deadlock when polling
+            // release readiness so we can borrow the wakers when polling
             #[allow(clippy::drop_non_drop)]
             drop(readiness);
 
@@ -92,19 +313,29 @@ where
                 Poll::Ready(Some(item)) => {
                     // Mark ourselves as ready again because we need to poll for the next item.
                     this.wakers.readiness().set_ready(index);
-                    return Poll::Ready(Some(item));
+                    this.fairness.yielded(index, len);
+                    return Poll::Ready(Some((index, item)));
                 }
                 Poll::Ready(None) => {
                     *this.complete += 1;
                     this.state[index].set_none();
-                    if *this.complete == this.streams.len() {
+                    if *this.complete == len {
+                        *this.done = true;
                         return Poll::Ready(None);
                     }
                 }
                 Poll::Pending => {}
             }
 
-            // Lock readiness so we can use it again
+            // Yield to the executor once we've used up our budget
+            polled += 1;
+            if this.budget.is_exhausted(polled) {
+                this.budget.resume_at(index + 1);
+                waker.wake_by_ref();
+                return Poll::Pending;
+            }
+
+            // Reacquire readiness so we can use it again
             readiness = this.wakers.readiness();
         }
 
@@ -124,6 +355,103 @@ where
     }
 }
 
+/// A stream that merges multiple streams into a single stream, tagging each
+/// item with the index of the stream it came from.
+///
+/// This `struct` is created by the [`merge_indexed`] method on the [`MergeIndexed`] trait. See its
+/// documentation for more.
+///
+/// [`merge_indexed`]: trait.MergeIndexed.html#method.merge_indexed
+/// [`MergeIndexed`]: trait.MergeIndexed.html
+#[pin_project::pin_project]
+pub struct MergeIndexed<S>
+where
+    S: Stream,
+{
+    #[pin]
+    inner: Merge<S>,
+}
+
+impl<S> fmt::Debug for MergeIndexed<S>
+where
+    S: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<S> Stream for MergeIndexed<S>
+where
+    S: Stream,
+{
+    type Item = (usize, S::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_next_indexed(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> FusedStream for MergeIndexed<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<S> MergeIndexedTrait for Vec<S>
+where
+    S: IntoStream,
+{
+    type Item = <S::IntoStream as Stream>::Item;
+    type Stream = MergeIndexed<S::IntoStream>;
+
+    fn merge_indexed(self) -> Self::Stream {
+        MergeIndexed {
+            inner: Merge::new(self.into_iter().map(|i| i.into_stream()).collect()),
+        }
+    }
+}
+
+impl<S> MergeTrait for Box<[S]>
+where
+    S: IntoStream,
+{
+    type Item = <Merge<S::IntoStream> as Stream>::Item;
+    type Stream = Merge<S::IntoStream>;
+
+    fn merge(self) -> Self::Stream {
+        Vec::from(self).merge()
+    }
+}
+
+impl<S> MergeTrait for VecDeque<S>
+where
+    S: IntoStream,
+{
+    type Item = <Merge<S::IntoStream> as Stream>::Item;
+    type Stream = Merge<S::IntoStream>;
+
+    fn merge(self) -> Self::Stream {
+        Vec::from(self).merge()
+    }
+}
+
+impl<S> FromIterator<S> for Merge<S::IntoStream>
+where
+    S: IntoStream,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().merge()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::rc::Rc;
@@ -131,7 +459,7 @@ mod tests {
     use core::cell::RefCell;
 
     use super::*;
-    use crate::utils::channel::local_channel;
+    use crate::test_utils::local_channel;
     use futures::executor::LocalPool;
     use futures::task::LocalSpawnExt;
     use futures_lite::future::block_on;
@@ -157,6 +485,56 @@ mod tests {
         })
     }
 
+    #[test]
+    fn merge_indexed_vec() {
+        block_on(async {
+            let a = stream::repeat(1).take(2);
+            let b = stream::repeat(2).take(3);
+            let mut s = vec![a, b].merge_indexed();
+
+            let mut counts = [0; 2];
+            while let Some((index, n)) = s.next().await {
+                assert_eq!(n, index + 1);
+                counts[index] += 1;
+            }
+            assert_eq!(counts, [2, 3]);
+        })
+    }
+
+    #[test]
+    fn into_group_skips_completed_streams() {
+        block_on(async {
+            // Polling in order means the empty stream completes before the
+            // second stream yields its first item.
+            let a = stream::empty().boxed();
+            let b = stream::repeat(2).take(2).boxed();
+            let mut s = vec![a, b].merge().prioritized();
+            assert_eq!(s.next().await, Some(2));
+
+            let mut group = StreamGroup::from(s);
+            assert_eq!(group.len(), 1);
+            group.insert(stream::once(3).boxed());
+            let sum = group.fold(0, |a, b| a + b).await;
+            assert_eq!(sum, 5);
+        })
+    }
+
+    #[test]
+    fn merge_other_collections() {
+        block_on(async {
+            let boxed: Box<[_]> = vec![stream::once(1), stream::once(2)].into();
+            let deque: VecDeque<_> = vec![stream::once(3), stream::once(4)].into();
+            let collected: Merge<_> = [stream::once(5), stream::once(6)].into_iter().collect();
+
+            let counter = boxed.merge().fold(0, |a, b| a + b).await;
+            assert_eq!(counter, 3);
+            let counter = deque.merge().fold(0, |a, b| a + b).await;
+            assert_eq!(counter, 7);
+            let counter = collected.fold(0, |a, b| a + b).await;
+            assert_eq!(counter, 11);
+        })
+    }
+
     #[test]
     fn merge_vec_2x2() {
         block_on(async {
@@ -172,6 +550,34 @@ mod tests {
         })
     }
 
+    #[test]
+    fn budget() {
+        use core::cell::Cell;
+
+        block_on(async {
+            // Streams which never yield, but wake themselves every time.
+            let polls: Rc<[Cell<usize>]> = (0..10).map(|_| Cell::new(0)).collect();
+            let streams = (0..10).map(|index| {
+                let polls = polls.clone();
+                stream::poll_fn(move |cx| {
+                    polls[index].set(polls[index].get() + 1);
+                    cx.waker().wake_by_ref();
+                    Poll::<Option<()>>::Pending
+                })
+            });
+            let mut s = streams.collect::<Vec<_>>().merge().with_budget(4);
+
+            let total = || polls.iter().map(Cell::get).sum::<usize>();
+            assert!(futures_lite::future::poll_once(s.next()).await.is_none());
+            assert_eq!(total(), 4);
+
+            // Polling resumes where it left off, so every stream gets a turn.
+            futures_lite::future::poll_once(s.next()).await;
+            futures_lite::future::poll_once(s.next()).await;
+            assert!(polls.iter().all(|polls| polls.get() > 0));
+        })
+    }
+
     /// This test case uses channels so we'll have streams that return Pending from time to time.
     ///
     /// The purpose of this test is to make sure we have the waking logic working.
@@ -47,23 +47,77 @@
 //!
 //! See the [future concurrency][crate::future#concurrency] documentation for
 //! more on futures concurrency.
+pub use array_stream_group::ArrayStreamGroup;
+#[cfg(feature = "alloc")]
+pub use buffer_concurrent::BufferConcurrent;
 pub use chain::Chain;
+#[cfg(feature = "alloc")]
+pub use chain_queue::ChainQueue;
+pub use combine_latest::CombineLatest;
+pub use debounce::Debounce;
+#[cfg(feature = "alloc")]
+pub use event_loop::{EventLoop, EventLoopFuture};
+#[cfg(feature = "std")]
+pub use fan_out::FanOut;
+#[cfg(feature = "alloc")]
+pub use flatten_concurrent::FlattenConcurrent;
+pub use interleave::Interleave;
 pub use into_stream::IntoStream;
-pub use merge::Merge;
+pub use merge::{Merge, MergeHeterogeneous, MergeIndexed};
+#[cfg(feature = "std")]
+pub use partition::{PartitionLeft, PartitionRight};
+pub use race::Race;
+pub use sample::Sample;
 pub use stream_ext::StreamExt;
 #[doc(inline)]
 #[cfg(feature = "alloc")]
-pub use stream_group::StreamGroup;
+pub use stream_group::{StreamEvent, StreamGroup};
+pub use switch::Switch;
+pub use throttle::Throttle;
+pub use timeout::Timeout;
+pub use try_merge::TryMerge;
+pub use until::Until;
+#[cfg(feature = "std")]
+pub use unzip::{UnzipLeft, UnzipRight};
 pub use wait_until::WaitUntil;
 pub use zip::Zip;
 
+/// A fixed-capacity group of streams which act as a single unit.
+pub mod array_stream_group;
+
 /// A growable group of streams which act as a single unit.
 #[cfg(feature = "alloc")]
 pub mod stream_group;
 
+#[cfg(feature = "alloc")]
+pub(crate) mod buffer_concurrent;
 pub(crate) mod chain;
+#[cfg(feature = "alloc")]
+pub(crate) mod chain_queue;
+pub(crate) mod combine_latest;
+pub(crate) mod debounce;
+pub mod either;
+#[cfg(feature = "alloc")]
+pub(crate) mod event_loop;
+pub mod fairness;
+#[cfg(feature = "std")]
+pub(crate) mod fan_out;
+#[cfg(feature = "alloc")]
+pub(crate) mod flatten_concurrent;
+pub(crate) mod interleave;
 mod into_stream;
 pub(crate) mod merge;
+#[cfg(feature = "std")]
+pub(crate) mod partition;
+pub(crate) mod race;
+pub(crate) mod sample;
 mod stream_ext;
+pub(crate) mod switch;
+pub(crate) mod throttle;
+pub(crate) mod timeout;
+pub(crate) mod try_merge;
+pub(crate) mod until;
+#[cfg(feature = "std")]
+pub(crate) mod unzip;
 pub(crate) mod wait_until;
 pub(crate) mod zip;
@@ -0,0 +1,271 @@
+use crate::utils::Mutex;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+
+const LEFT: usize = 0;
+const RIGHT: usize = 1;
+
+/// Create the two halves of a partitioned stream.
+pub(crate) fn partition<S, P>(
+    stream: S,
+    capacity: usize,
+    predicate: P,
+) -> (PartitionLeft<S, P>, PartitionRight<S, P>)
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    assert!(capacity > 0, "partition capacity must be non-zero");
+    let wakers = Arc::new(PartitionWaker::default());
+    let shared = Arc::new(Mutex::new(Shared {
+        stream: Box::pin(stream),
+        predicate,
+        buffers: [VecDeque::new(), VecDeque::new()],
+        capacity,
+        waker: Waker::from(wakers.clone()),
+        wakers,
+        dropped: [false; 2],
+        done: false,
+    }));
+    let left = PartitionLeft {
+        shared: shared.clone(),
+    };
+    let right = PartitionRight { shared };
+    (left, right)
+}
+
+/// A waker which wakes both halves of a partitioned stream.
+#[derive(Debug, Default)]
+struct PartitionWaker {
+    wakers: Mutex<[Option<Waker>; 2]>,
+}
+
+impl PartitionWaker {
+    fn register(&self, side: usize, waker: &Waker) {
+        let mut wakers = self.wakers.lock();
+        match &wakers[side] {
+            Some(existing) if existing.will_wake(waker) => {}
+            _ => wakers[side] = Some(waker.clone()),
+        }
+    }
+
+    fn wake_side(&self, side: usize) {
+        if let Some(waker) = self.wakers.lock()[side].take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Wake for PartitionWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let wakers = core::mem::take(&mut *self.wakers.lock());
+        for waker in wakers.into_iter().flatten() {
+            waker.wake();
+        }
+    }
+}
+
+/// The state shared between both halves of a partitioned stream.
+struct Shared<S: Stream, P> {
+    stream: Pin<Box<S>>,
+    predicate: P,
+    buffers: [VecDeque<S::Item>; 2],
+    capacity: usize,
+    waker: Waker,
+    wakers: Arc<PartitionWaker>,
+    dropped: [bool; 2],
+    done: bool,
+}
+
+impl<S, P> Shared<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    /// Poll the inner stream until an item is available for `side`.
+    ///
+    /// Items for the other half are buffered. If the other half's buffer is
+    /// full we stop polling until it has made progress.
+    fn poll_side(&mut self, side: usize, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        if let Some(item) = self.buffers[side].pop_front() {
+            // We've just made room in our buffer, so the other half may continue.
+            self.wakers.wake_side(1 - side);
+            return Poll::Ready(Some(item));
+        }
+
+        self.wakers.register(side, cx.waker());
+        loop {
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            if self.buffers[1 - side].len() >= self.capacity {
+                // Wait for the other half to make room.
+                return Poll::Pending;
+            }
+
+            let mut cx = Context::from_waker(&self.waker);
+            match self.stream.as_mut().poll_next(&mut cx) {
+                Poll::Ready(Some(item)) => {
+                    let target = match (self.predicate)(&item) {
+                        true => LEFT,
+                        false => RIGHT,
+                    };
+                    if target == side {
+                        return Poll::Ready(Some(item));
+                    }
+                    if !self.dropped[target] {
+                        self.buffers[target].push_back(item);
+                        self.wakers.wake_side(target);
+                    }
+                }
+                Poll::Ready(None) => {
+                    self.done = true;
+                    self.wakers.wake_side(1 - side);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<S: Stream, P> Shared<S, P> {
+    /// Mark one half as dropped, discarding its buffered items.
+    fn drop_side(&mut self, side: usize) {
+        self.dropped[side] = true;
+        self.buffers[side].clear();
+        self.wakers.wake_side(1 - side);
+    }
+}
+
+/// The half of a partitioned stream with the items matching the predicate.
+///
+/// This `struct` is created by the [`partition`] method on [`StreamExt`].
+/// See its documentation for more.
+///
+/// [`partition`]: crate::stream::StreamExt::partition
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+pub struct PartitionLeft<S: Stream, P> {
+    shared: Arc<Mutex<Shared<S, P>>>,
+}
+
+/// The half of a partitioned stream with the items not matching the
+/// predicate.
+///
+/// This `struct` is created by the [`partition`] method on [`StreamExt`].
+/// See its documentation for more.
+///
+/// [`partition`]: crate::stream::StreamExt::partition
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+pub struct PartitionRight<S: Stream, P> {
+    shared: Arc<Mutex<Shared<S, P>>>,
+}
+
+impl<S: Stream, P> fmt::Debug for PartitionLeft<S, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PartitionLeft").finish_non_exhaustive()
+    }
+}
+
+impl<S: Stream, P> fmt::Debug for PartitionRight<S, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PartitionRight").finish_non_exhaustive()
+    }
+}
+
+impl<S, P> Stream for PartitionLeft<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.shared.lock().poll_side(LEFT, cx)
+    }
+}
+
+impl<S, P> Stream for PartitionRight<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.shared.lock().poll_side(RIGHT, cx)
+    }
+}
+
+impl<S: Stream, P> Drop for PartitionLeft<S, P> {
+    fn drop(&mut self) {
+        self.shared.lock().drop_side(LEFT);
+    }
+}
+
+impl<S: Stream, P> Drop for PartitionRight<S, P> {
+    fn drop(&mut self) {
+        self.shared.lock().drop_side(RIGHT);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::Join;
+    use crate::stream::StreamExt as _;
+    use futures_lite::future::block_on;
+    use futures_lite::stream;
+
+    #[test]
+    fn partition_concurrently() {
+        block_on(async {
+            let s = stream::iter(0..10);
+            let (mut even, mut odd) = s.partition(2, |n| n % 2 == 0);
+
+            let even = async {
+                let mut out = vec![];
+                while let Some(n) = futures_lite::StreamExt::next(&mut even).await {
+                    out.push(n);
+                }
+                out
+            };
+            let odd = async {
+                let mut out = vec![];
+                while let Some(n) = futures_lite::StreamExt::next(&mut odd).await {
+                    out.push(n);
+                }
+                out
+            };
+            let (even, odd) = (even, odd).join().await;
+            assert_eq!(even, [0, 2, 4, 6, 8]);
+            assert_eq!(odd, [1, 3, 5, 7, 9]);
+        })
+    }
+
+    #[test]
+    fn dropped_half() {
+        block_on(async {
+            let s = stream::iter(0..10);
+            let (mut small, large) = s.partition(1, |n| *n < 3);
+            drop(large);
+
+            let mut out = vec![];
+            while let Some(n) = futures_lite::StreamExt::next(&mut small).await {
+                out.push(n);
+            }
+            assert_eq!(out, [0, 1, 2]);
+        })
+    }
+}
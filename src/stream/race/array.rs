@@ -0,0 +1,146 @@
+use super::Race as RaceTrait;
+use crate::stream::IntoStream;
+use crate::utils::{self, Indexer};
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+/// A stream which yields items from whichever stream produces an item first.
+///
+/// This `struct` is created by the [`race`] method on the [`Race`] trait. See its
+/// documentation for more.
+///
+/// [`race`]: crate::stream::Race::race
+/// [`Race`]: crate::stream::Race
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project::pin_project]
+pub struct Race<S, const N: usize>
+where
+    S: Stream,
+{
+    #[pin]
+    streams: [Option<S>; N],
+    indexer: Indexer,
+    winner: Option<usize>,
+}
+
+impl<S, const N: usize> fmt::Debug for Race<S, N>
+where
+    S: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.streams.iter().flatten())
+            .finish()
+    }
+}
+
+impl<S, const N: usize> Stream for Race<S, N>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if let Some(winner) = *this.winner {
+            let mut slot = utils::get_pin_mut(this.streams.as_mut(), winner).unwrap();
+            let Some(stream) = slot.as_mut().as_pin_mut() else {
+                return Poll::Ready(None);
+            };
+            let item = ready!(stream.poll_next(cx));
+            if item.is_none() {
+                slot.set(None);
+            }
+            return Poll::Ready(item);
+        }
+
+        for index in this.indexer.iter() {
+            let mut slot = utils::get_pin_mut(this.streams.as_mut(), index).unwrap();
+            let Some(stream) = slot.as_mut().as_pin_mut() else {
+                continue;
+            };
+            match stream.poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    // We have a winner, cancel all other streams.
+                    *this.winner = Some(index);
+                    for i in (0..N).filter(|i| *i != index) {
+                        utils::get_pin_mut(this.streams.as_mut(), i)
+                            .unwrap()
+                            .set(None);
+                    }
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(None) => slot.set(None),
+                Poll::Pending => {}
+            }
+        }
+
+        match this.streams.iter().all(Option::is_none) {
+            true => Poll::Ready(None),
+            false => Poll::Pending,
+        }
+    }
+}
+
+impl<S, const N: usize> FusedStream for Race<S, N>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.streams.iter().all(Option::is_none)
+    }
+}
+
+impl<S, const N: usize> RaceTrait for [S; N]
+where
+    S: IntoStream,
+{
+    type Item = S::Item;
+    type Stream = Race<S::IntoStream, N>;
+
+    fn race(self) -> Self::Stream {
+        Race {
+            streams: self.map(|s| Some(s.into_stream())),
+            indexer: Indexer::new(N),
+            winner: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn winner_takes_all() {
+        block_on(async {
+            let a = stream::pending().boxed();
+            let b = stream::repeat(2).take(3).boxed();
+            let mut s = [a, b].race();
+
+            let mut counter = 0;
+            while let Some(n) = s.next().await {
+                counter += n;
+            }
+            assert_eq!(counter, 6);
+        })
+    }
+
+    #[test]
+    fn empty_streams_drop_out() {
+        block_on(async {
+            let a = stream::empty().boxed();
+            let b = stream::once(1).boxed();
+            let mut s = [a, b].race();
+            assert_eq!(s.next().await, Some(1));
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use futures_core::Stream;
+
+pub(crate) mod array;
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Yield items from whichever stream produces an item first.
+///
+/// All streams are polled concurrently until one of them yields an item. That
+/// stream wins the race: the other streams are dropped, and the remaining
+/// items of the winning stream are forwarded. Streams which are exhausted
+/// before yielding an item drop out of the race.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::stream::Race;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let slow = stream::pending();
+///     let fast = stream::iter(vec![1, 2, 3]).boxed();
+///     let s = [slow.boxed(), fast].race();
+///
+///     let buf: Vec<u8> = s.collect().await;
+///     assert_eq!(buf, vec![1, 2, 3]);
+/// })
+/// ```
+pub trait Race {
+    /// The resulting output type.
+    type Item;
+
+    /// The stream type.
+    type Stream: Stream<Item = Self::Item>;
+
+    /// Yield items from whichever stream produces an item first.
+    fn race(self) -> Self::Stream;
+}
@@ -0,0 +1,180 @@
+use super::Race as RaceTrait;
+use crate::stream::IntoStream;
+use crate::utils;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+macro_rules! impl_race_tuple {
+    ($mod_name:ident $StructName:ident $($F:ident)+) => {
+        mod $mod_name {
+            #[repr(usize)]
+            enum Indexes {
+                $($F,)+
+            }
+
+            $(
+                pub(super) const $F: usize = Indexes::$F as usize;
+            )+
+
+            pub(super) const LEN: usize = [$(Indexes::$F,)+].len();
+        }
+
+        /// A stream which yields items from whichever stream produces an item first.
+        ///
+        /// This `struct` is created by the [`race`] method on the [`Race`] trait. See its
+        /// documentation for more.
+        ///
+        /// [`race`]: crate::stream::Race::race
+        /// [`Race`]: crate::stream::Race
+        #[must_use = "streams do nothing unless polled or .awaited"]
+        #[pin_project::pin_project]
+        pub struct $StructName<T, $($F,)+>
+        where
+            $($F: Stream<Item = T>,)+
+        {
+            indexer: utils::Indexer,
+            winner: Option<usize>,
+            $( #[pin] $F: Option<$F>,)+
+        }
+
+        impl<T, $($F,)+> fmt::Debug for $StructName<T, $($F,)+>
+        where
+            $($F: Stream<Item = T> + fmt::Debug,)+
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("Race")
+                    $(.field(&self.$F))+
+                    .finish()
+            }
+        }
+
+        impl<T, $($F,)+> Stream for $StructName<T, $($F,)+>
+        where
+            $($F: Stream<Item = T>,)+
+        {
+            type Item = T;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                let mut this = self.project();
+
+                if let Some(winner) = *this.winner {
+                    return match winner {
+                        $(
+                            $mod_name::$F => {
+                                let Some(stream) = this.$F.as_mut().as_pin_mut() else {
+                                    return Poll::Ready(None);
+                                };
+                                let item = ready!(stream.poll_next(cx));
+                                if item.is_none() {
+                                    this.$F.set(None);
+                                }
+                                Poll::Ready(item)
+                            }
+                        )+
+                        _ => unreachable!(),
+                    };
+                }
+
+                for index in this.indexer.iter() {
+                    let item = match index {
+                        $(
+                            $mod_name::$F => match this.$F.as_mut().as_pin_mut() {
+                                None => None,
+                                Some(stream) => match stream.poll_next(cx) {
+                                    Poll::Ready(Some(item)) => Some(item),
+                                    Poll::Ready(None) => {
+                                        this.$F.set(None);
+                                        None
+                                    }
+                                    Poll::Pending => None,
+                                },
+                            },
+                        )+
+                        _ => unreachable!(),
+                    };
+
+                    if let Some(item) = item {
+                        // We have a winner, cancel all other streams.
+                        *this.winner = Some(index);
+                        $(
+                            if index != $mod_name::$F {
+                                this.$F.set(None);
+                            }
+                        )+
+                        return Poll::Ready(Some(item));
+                    }
+                }
+
+                if $(this.$F.is_none())&&+ {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Pending
+                }
+            }
+        }
+
+        impl<T, $($F,)+> FusedStream for $StructName<T, $($F,)+>
+        where
+            $($F: Stream<Item = T>,)+
+        {
+            fn is_terminated(&self) -> bool {
+                $(self.$F.is_none())&&+
+            }
+        }
+
+        impl<T, $($F,)+> RaceTrait for ($($F,)+)
+        where
+            $($F: IntoStream<Item = T>,)+
+        {
+            type Item = T;
+            type Stream = $StructName<T, $($F::IntoStream,)+>;
+
+            fn race(self) -> Self::Stream {
+                let ($($F,)+): ($($F,)+) = self;
+                $StructName {
+                    indexer: utils::Indexer::new($mod_name::LEN),
+                    winner: None,
+                    $($F: Some($F.into_stream()),)+
+                }
+            }
+        }
+    };
+}
+
+impl_race_tuple! { race_1 Race1 A }
+impl_race_tuple! { race_2 Race2 A B }
+impl_race_tuple! { race_3 Race3 A B C }
+impl_race_tuple! { race_4 Race4 A B C D }
+impl_race_tuple! { race_5 Race5 A B C D E }
+impl_race_tuple! { race_6 Race6 A B C D E F }
+impl_race_tuple! { race_7 Race7 A B C D E F G }
+impl_race_tuple! { race_8 Race8 A B C D E F G H }
+impl_race_tuple! { race_9 Race9 A B C D E F G H I }
+impl_race_tuple! { race_10 Race10 A B C D E F G H I J }
+impl_race_tuple! { race_11 Race11 A B C D E F G H I J K }
+impl_race_tuple! { race_12 Race12 A B C D E F G H I J K L }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn race_tuple_3() {
+        block_on(async {
+            let a = stream::pending();
+            let b = stream::empty();
+            let c = stream::repeat(3).take(2);
+            let mut s = (a, b, c).race();
+
+            assert_eq!(s.next().await, Some(3));
+            assert_eq!(s.next().await, Some(3));
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
@@ -0,0 +1,151 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+use crate::time::Timer;
+use crate::utils::ITEM_BUDGET;
+
+/// A stream which periodically yields the most recent item of the underlying
+/// stream.
+///
+/// This `struct` is created by the [`sample`] method on [`StreamExt`]. See its
+/// documentation for more.
+///
+/// [`sample`]: crate::stream::StreamExt::sample
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct Sample<S: Stream, T: Timer> {
+    #[pin]
+    stream: S,
+    // The next tick of the interval.
+    #[pin]
+    sleep: Option<T::Sleep>,
+    timer: T,
+    interval: Duration,
+    // The most recent item since the last tick.
+    slot: Option<S::Item>,
+    done: bool,
+}
+
+impl<S: Stream, T: Timer> Sample<S, T> {
+    pub(crate) fn new(stream: S, interval: Duration, timer: T) -> Self {
+        Self {
+            stream,
+            sleep: None,
+            timer,
+            interval,
+            slot: None,
+            done: false,
+        }
+    }
+}
+
+impl<S: Stream + fmt::Debug, T: Timer> fmt::Debug for Sample<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sample")
+            .field("stream", &self.stream)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: Stream, T: Timer> Stream for Sample<S, T> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let mut taken = 0;
+        loop {
+            if *this.done {
+                // Yield the item we were still holding on to.
+                this.sleep.set(None);
+                return Poll::Ready(this.slot.take());
+            }
+
+            // Check the interval before every item, so a stream which is
+            // always ready can't keep us from yielding.
+            if this.sleep.is_none() {
+                this.sleep.set(Some(this.timer.sleep(*this.interval)));
+            }
+            let sleep = this.sleep.as_mut().as_pin_mut().unwrap();
+            if sleep.poll(cx).is_ready() {
+                // The interval has elapsed; start the next one.
+                this.sleep.set(None);
+                if let Some(item) = this.slot.take() {
+                    return Poll::Ready(Some(item));
+                }
+
+                // Nothing to yield this interval. Schedule another poll rather
+                // than looping, in case the timer completes immediately.
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    *this.slot = Some(item);
+                    // Don't hog the executor while we wait for the interval.
+                    taken += 1;
+                    if taken == ITEM_BUDGET {
+                        cx.waker().wake_by_ref();
+                        return Poll::Pending;
+                    }
+                }
+                Poll::Ready(None) => *this.done = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.slot.is_some() as usize;
+        if self.done {
+            return (pending, Some(pending));
+        }
+        let (low, high) = self.stream.size_hint();
+        (
+            low.min(1).max(pending),
+            high.and_then(|h| h.checked_add(pending)),
+        )
+    }
+}
+
+impl<S: Stream, T: Timer> FusedStream for Sample<S, T> {
+    fn is_terminated(&self) -> bool {
+        self.done && self.slot.is_none()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stream::StreamExt as _;
+    use futures_lite::{future::block_on, stream, StreamExt};
+    use std::time::Duration;
+
+    #[test]
+    fn sample() {
+        block_on(async {
+            let timer = async_io::Timer::after;
+            let a = stream::iter([1, 2]);
+            let b = stream::iter([3, 4]).wait_until(timer(Duration::from_millis(100)));
+            let s = StreamExt::chain(a, b).sample(Duration::from_millis(10), timer);
+            let v: Vec<_> = s.collect().await;
+            assert_eq!(v, &[2, 4]);
+        });
+    }
+
+    #[test]
+    fn always_ready() {
+        block_on(async {
+            let s = stream::repeat(1).sample(Duration::from_millis(10), async_io::Timer::after);
+            let v: Vec<_> = s.take(2).collect().await;
+            assert_eq!(v, &[1, 1]);
+        });
+    }
+}
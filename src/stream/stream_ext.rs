@@ -1,12 +1,21 @@
 use core::future::IntoFuture;
+use core::time::Duration;
 
 use crate::stream::{IntoStream, Merge};
+use crate::time::Timer;
 use futures_core::Stream;
 
 #[cfg(feature = "alloc")]
 use crate::concurrent_stream::FromStream;
+#[cfg(feature = "alloc")]
+use crate::stream::{BufferConcurrent, FlattenConcurrent};
+#[cfg(feature = "std")]
+use crate::stream::{FanOut, PartitionLeft, PartitionRight, UnzipLeft, UnzipRight};
 
-use super::{chain::tuple::Chain2, merge::tuple::Merge2, zip::tuple::Zip2, Chain, WaitUntil, Zip};
+use super::{
+    chain::tuple::Chain2, debounce::Edge, merge::tuple::Merge2, zip::tuple::Zip2, Chain, Debounce,
+    Sample, Switch, Throttle, Timeout, Until, WaitUntil, Zip,
+};
 
 /// An extension trait for the `Stream` trait.
 pub trait StreamExt: Stream {
@@ -16,18 +25,93 @@ pub trait StreamExt: Stream {
         Self: Stream<Item = T> + Sized,
         S2: IntoStream<Item = T>;
 
+    /// Combines two streams into a single stream of all their outputs.
+    ///
+    /// This is the same as [`merge`][StreamExt::merge], but doesn't clash
+    /// with the `merge` methods of other stream extension traits, such as
+    /// the one in `futures-lite`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::stream::StreamExt as _;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let a = stream::once(1);
+    ///     let b = stream::once(2);
+    ///     let sum = a.merge_with(b).fold(0, |a, b| a + b).await;
+    ///     assert_eq!(sum, 3);
+    /// })
+    /// ```
+    fn merge_with<T, S2>(self, other: S2) -> Merge2<T, Self, S2::IntoStream>
+    where
+        Self: Stream<Item = T> + Sized,
+        S2: IntoStream<Item = T>;
+
     /// Takes two streams and creates a new stream over all in sequence
     fn chain<T, S2>(self, other: S2) -> Chain2<Self, S2::IntoStream>
     where
         Self: Stream<Item = T> + Sized,
         S2: IntoStream<Item = T>;
 
+    /// Takes two streams and creates a new stream over all in sequence.
+    ///
+    /// This is the same as [`chain`][StreamExt::chain], but doesn't clash
+    /// with the `chain` methods of other stream extension traits, such as
+    /// the one in `futures-lite`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::stream::StreamExt as _;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let a = stream::once(1);
+    ///     let b = stream::once(2);
+    ///     let out: Vec<_> = a.chain_with(b).collect().await;
+    ///     assert_eq!(out, vec![1, 2]);
+    /// })
+    /// ```
+    fn chain_with<T, S2>(self, other: S2) -> Chain2<Self, S2::IntoStream>
+    where
+        Self: Stream<Item = T> + Sized,
+        S2: IntoStream<Item = T>;
+
     /// ‘Zips up’ multiple streams into a single stream of pairs.
     fn zip<T, S2>(self, other: S2) -> Zip2<Self, S2::IntoStream>
     where
         Self: Stream<Item = T> + Sized,
         S2: IntoStream<Item = T>;
 
+    /// ‘Zips up’ two streams into a single stream of pairs.
+    ///
+    /// This is the same as [`zip`][StreamExt::zip], but doesn't clash with
+    /// the `zip` methods of other stream extension traits, such as the one in
+    /// `futures-lite`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::stream::StreamExt as _;
+    /// use futures_lite::stream::{self, StreamExt};
+    /// use futures_lite::future::block_on;
+    ///
+    /// block_on(async {
+    ///     let a = stream::iter(vec![1, 2]);
+    ///     let b = stream::iter(vec![3, 4]);
+    ///     let out: Vec<_> = a.zip_with(b).collect().await;
+    ///     assert_eq!(out, vec![(1, 3), (2, 4)]);
+    /// })
+    /// ```
+    fn zip_with<T, S2>(self, other: S2) -> Zip2<Self, S2::IntoStream>
+    where
+        Self: Stream<Item = T> + Sized,
+        S2: IntoStream<Item = T>;
+
     /// Convert into a concurrent stream.
     #[cfg(feature = "alloc")]
     fn co(self) -> FromStream<Self>
@@ -75,6 +159,457 @@ pub trait StreamExt: Stream {
     {
         WaitUntil::new(self, deadline.into_future())
     }
+
+    /// End the stream once the deadline resolves.
+    ///
+    /// Any future can be used as a deadline, such as a timer or a
+    /// [`StopToken`]. Because groups are streams too, this can be used to
+    /// stop a [`FutureGroup`] or [`StreamGroup`] from outside while keeping
+    /// the items which were already yielded.
+    ///
+    /// [`StopToken`]: crate::sync::StopToken
+    /// [`FutureGroup`]: crate::future::FutureGroup
+    /// [`StreamGroup`]: crate::stream::StreamGroup
+    ///
+    /// # Example
+    /// ```
+    /// use futures_concurrency::stream::StreamExt;
+    /// use futures_concurrency::sync::StopSource;
+    /// use futures_lite::{future::block_on, stream};
+    ///
+    /// block_on(async {
+    ///     let source = StopSource::new();
+    ///     let mut s = stream::repeat(1).until(source.token());
+    ///
+    ///     assert_eq!(futures_lite::StreamExt::next(&mut s).await, Some(1));
+    ///     source.stop();
+    ///     assert_eq!(futures_lite::StreamExt::next(&mut s).await, None);
+    /// });
+    /// ```
+    fn until<D>(self, deadline: D) -> Until<Self, D::IntoFuture>
+    where
+        Self: Sized,
+        D: IntoFuture,
+    {
+        Until::new(self, deadline.into_future())
+    }
+
+    /// Return an error whenever the stream takes longer than `duration` to
+    /// yield an item.
+    ///
+    /// The deadline is reset every time an item is yielded. If it expires, a
+    /// [`TimedOut`] error is returned and a new deadline is started, so this
+    /// can be used to detect missing heartbeats without ending the stream.
+    ///
+    /// [`TimedOut`]: crate::time::TimedOut
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(miri)] fn main() {}
+    /// # #[cfg(not(miri))]
+    /// # fn main() {
+    /// use async_io::Timer;
+    /// use futures_concurrency::stream::StreamExt;
+    /// use futures_lite::{future::block_on, stream};
+    /// use std::time::Duration;
+    ///
+    /// block_on(async {
+    ///     let heartbeats = stream::pending::<()>();
+    ///     let mut heartbeats = heartbeats.timeout(Duration::from_millis(10), Timer::after);
+    ///
+    ///     let res = futures_lite::StreamExt::next(&mut heartbeats).await;
+    ///     assert!(matches!(res, Some(Err(_))));
+    /// });
+    /// # }
+    /// ```
+    fn timeout<T>(self, duration: Duration, timer: T) -> Timeout<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        Timeout::new(self, duration, timer)
+    }
+
+    /// Yield the last item of every burst of items, once the stream has been
+    /// quiet for `duration`.
+    ///
+    /// Every item restarts the period, and replaces the item which was
+    /// waiting to be yielded. When the underlying stream ends, the item which
+    /// was waiting is yielded right away.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(miri)] fn main() {}
+    /// # #[cfg(not(miri))]
+    /// # fn main() {
+    /// use async_io::Timer;
+    /// use futures_concurrency::stream::StreamExt;
+    /// use futures_lite::{future::block_on, stream};
+    /// use std::time::Duration;
+    ///
+    /// block_on(async {
+    ///     let s = stream::iter([1, 2, 3]).debounce(Duration::from_millis(10), Timer::after);
+    ///     let v: Vec<_> = futures_lite::StreamExt::collect(s).await;
+    ///     assert_eq!(v, &[3]);
+    /// });
+    /// # }
+    /// ```
+    fn debounce<T>(self, duration: Duration, timer: T) -> Debounce<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        Debounce::new(self, duration, timer, Edge::Trailing)
+    }
+
+    /// Yield the first item of every burst of items, ignoring further items
+    /// until the stream has been quiet for `duration`.
+    ///
+    /// Every item restarts the period, including the items which are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(miri)] fn main() {}
+    /// # #[cfg(not(miri))]
+    /// # fn main() {
+    /// use async_io::Timer;
+    /// use futures_concurrency::stream::StreamExt;
+    /// use futures_lite::{future::block_on, stream};
+    /// use std::time::Duration;
+    ///
+    /// block_on(async {
+    ///     let s = stream::iter([1, 2, 3]).debounce_leading(Duration::from_millis(10), Timer::after);
+    ///     let v: Vec<_> = futures_lite::StreamExt::collect(s).await;
+    ///     assert_eq!(v, &[1]);
+    /// });
+    /// # }
+    /// ```
+    fn debounce_leading<T>(self, duration: Duration, timer: T) -> Debounce<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        Debounce::new(self, duration, timer, Edge::Leading)
+    }
+
+    /// Yield at most one item per `duration`.
+    ///
+    /// After an item is yielded, all items from the underlying stream are
+    /// dropped until `duration` has passed.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(miri)] fn main() {}
+    /// # #[cfg(not(miri))]
+    /// # fn main() {
+    /// use async_io::Timer;
+    /// use futures_concurrency::stream::StreamExt;
+    /// use futures_lite::{future::block_on, stream};
+    /// use std::time::Duration;
+    ///
+    /// block_on(async {
+    ///     let s = stream::iter([1, 2, 3]).throttle(Duration::from_millis(10), Timer::after);
+    ///     let v: Vec<_> = futures_lite::StreamExt::collect(s).await;
+    ///     assert_eq!(v, &[1]);
+    /// });
+    /// # }
+    /// ```
+    fn throttle<T>(self, duration: Duration, timer: T) -> Throttle<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        Throttle::new(self, duration, timer)
+    }
+
+    /// Yield the most recent item of the stream once every `interval`.
+    ///
+    /// Nothing is yielded for intervals in which the underlying stream didn't
+    /// yield an item. When the underlying stream ends, the item which was
+    /// waiting for the next interval is yielded right away.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(miri)] fn main() {}
+    /// # #[cfg(not(miri))]
+    /// # fn main() {
+    /// use async_io::Timer;
+    /// use futures_concurrency::stream::StreamExt;
+    /// use futures_lite::{future::block_on, stream};
+    /// use std::time::Duration;
+    ///
+    /// block_on(async {
+    ///     let s = stream::iter([1, 2, 3]).sample(Duration::from_millis(10), Timer::after);
+    ///     let v: Vec<_> = futures_lite::StreamExt::collect(s).await;
+    ///     assert_eq!(v, &[3]);
+    /// });
+    /// # }
+    /// ```
+    fn sample<T>(self, interval: Duration, timer: T) -> Sample<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        Sample::new(self, interval, timer)
+    }
+
+    /// Flatten a stream of streams, only yielding items from the most
+    /// recently received inner stream.
+    ///
+    /// Whenever this stream yields a new inner stream, the previous inner
+    /// stream is dropped and its remaining items are discarded. The resulting
+    /// stream ends once both this stream and the current inner stream have
+    /// been exhausted.
+    ///
+    /// # Example
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::{future::block_on, stream};
+    /// use futures_lite::prelude::*;
+    ///
+    /// block_on(async {
+    ///     let requests = stream::iter(vec![stream::iter(vec!["stale"]), stream::iter(vec!["fresh"])]);
+    ///     let responses: Vec<_> = requests.switch().collect().await;
+    ///     assert_eq!(responses, vec!["fresh"]);
+    /// });
+    /// ```
+    fn switch(self) -> Switch<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoStream,
+    {
+        Switch::new(self)
+    }
+
+    /// Run the futures yielded by this stream concurrently, yielding their
+    /// outputs in the order they complete.
+    ///
+    /// At most `limit` futures are in flight at any time. New futures are
+    /// only pulled from this stream once there is room for them. The futures
+    /// are driven by a [`FutureGroup`][crate::future::FutureGroup], so only
+    /// the futures which have been woken are polled again.
+    ///
+    /// This is like the `buffer_unordered` method of `futures::StreamExt`,
+    /// but has a different name so it doesn't clash with it.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `limit` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::{future::block_on, stream};
+    /// use futures_lite::prelude::*;
+    ///
+    /// block_on(async {
+    ///     let requests = stream::iter(1..=3).map(|n| async move { n * 2 });
+    ///     let mut responses: Vec<_> = requests.buffer_concurrent(2).collect().await;
+    ///     responses.sort();
+    ///     assert_eq!(responses, vec![2, 4, 6]);
+    /// });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn buffer_concurrent(self, limit: usize) -> BufferConcurrent<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoFuture,
+    {
+        BufferConcurrent::new(self, limit)
+    }
+
+    /// Flatten a stream of streams, polling the inner streams concurrently.
+    ///
+    /// Items are yielded as soon as any of the inner streams yields one. If
+    /// `limit` is set, at most that many inner streams are active at any
+    /// time, and new inner streams are only pulled from this stream once one
+    /// of the active streams has been exhausted. The inner streams are driven
+    /// by a [`StreamGroup`][crate::stream::StreamGroup].
+    ///
+    /// This is like the `flatten_unordered` method of `futures::StreamExt`,
+    /// but has a different name so it doesn't clash with it.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `limit` is `Some(0)`.
+    ///
+    /// # Example
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::{future::block_on, stream};
+    /// use futures_lite::prelude::*;
+    ///
+    /// block_on(async {
+    ///     let pages = stream::iter(vec![stream::iter(vec![1, 2]), stream::iter(vec![3])]);
+    ///     let mut items: Vec<_> = pages.flatten_concurrent(None).collect().await;
+    ///     items.sort();
+    ///     assert_eq!(items, vec![1, 2, 3]);
+    /// });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn flatten_concurrent<L>(self, limit: L) -> FlattenConcurrent<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoStream,
+        L: Into<Option<usize>>,
+    {
+        FlattenConcurrent::new(self, limit.into())
+    }
+
+    /// Split a stream of pairs into two streams which can be consumed
+    /// concurrently.
+    ///
+    /// Items are pulled from the underlying stream by whichever half is
+    /// polled, and the other half's value is buffered until it is read. Once
+    /// a half has `capacity` items buffered, the underlying stream is no
+    /// longer polled until that half catches up. If a half is dropped, its
+    /// values are discarded.
+    ///
+    /// Unlike the `unzip` method of `futures::StreamExt`, this doesn't collect
+    /// the stream, and has a different name so it doesn't clash with it.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `capacity` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::{future::block_on, stream};
+    ///
+    /// block_on(async {
+    ///     let pairs = stream::iter(vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+    ///     let (numbers, letters) = pairs.unzip_buffered(2);
+    ///
+    ///     let numbers = futures_lite::StreamExt::collect::<Vec<_>>(numbers);
+    ///     let letters = futures_lite::StreamExt::collect::<String>(letters);
+    ///     let (numbers, letters) = (numbers, letters).join().await;
+    ///     assert_eq!(numbers, vec![1, 2, 3]);
+    ///     assert_eq!(letters, "abc");
+    /// });
+    /// ```
+    #[cfg(feature = "std")]
+    fn unzip_buffered<A, B>(
+        self,
+        capacity: usize,
+    ) -> (UnzipLeft<Self, A, B>, UnzipRight<Self, A, B>)
+    where
+        Self: Stream<Item = (A, B)> + Sized,
+    {
+        super::unzip::unzip(self, capacity)
+    }
+
+    /// Split a stream into two streams based on a predicate, which can be
+    /// consumed concurrently.
+    ///
+    /// Items for which the predicate returns `true` are yielded by the first
+    /// stream, and all other items by the second. Items are pulled from the
+    /// underlying stream by whichever half is polled, and items for the other
+    /// half are buffered until they are read. Once a half has `capacity` items
+    /// buffered, the underlying stream is no longer polled until that half
+    /// catches up. If a half is dropped, its items are discarded.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `capacity` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::{future::block_on, stream};
+    ///
+    /// block_on(async {
+    ///     let numbers = stream::iter(1..=6);
+    ///     let (even, odd) = numbers.partition(2, |n| n % 2 == 0);
+    ///
+    ///     let even = futures_lite::StreamExt::collect::<Vec<_>>(even);
+    ///     let odd = futures_lite::StreamExt::collect::<Vec<_>>(odd);
+    ///     let (even, odd) = (even, odd).join().await;
+    ///     assert_eq!(even, vec![2, 4, 6]);
+    ///     assert_eq!(odd, vec![1, 3, 5]);
+    /// });
+    /// ```
+    #[cfg(feature = "std")]
+    fn partition<P>(
+        self,
+        capacity: usize,
+        predicate: P,
+    ) -> (PartitionLeft<Self, P>, PartitionRight<Self, P>)
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        super::partition::partition(self, capacity, predicate)
+    }
+
+    /// Split a stream into two streams which both yield every item.
+    ///
+    /// This is a shorthand for [`fan_out`][StreamExt::fan_out] with two
+    /// handles. Items are cloned into the buffer of the other handle when
+    /// either of them pulls a new item.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `capacity` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::{future::block_on, stream};
+    ///
+    /// block_on(async {
+    ///     let (a, b) = stream::iter(1..=3).tee(2);
+    ///
+    ///     let a = futures_lite::StreamExt::collect::<Vec<_>>(a);
+    ///     let b = futures_lite::StreamExt::collect::<Vec<_>>(b);
+    ///     assert_eq!((a, b).join().await, (vec![1, 2, 3], vec![1, 2, 3]));
+    /// });
+    /// ```
+    #[cfg(feature = "std")]
+    fn tee(self, capacity: usize) -> (FanOut<Self>, FanOut<Self>)
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        let mut handles = super::fan_out::fan_out(self, 2, capacity).into_iter();
+        (handles.next().unwrap(), handles.next().unwrap())
+    }
+
+    /// Split a stream into `n` streams which all yield every item.
+    ///
+    /// Items are pulled from the underlying stream by whichever handle is
+    /// polled, and cloned into the buffers of the other handles until they
+    /// are read. Once any handle has `capacity` items buffered, the
+    /// underlying stream is no longer polled until that handle catches up, so
+    /// the slowest consumer sets the pace. If a handle is dropped, it no
+    /// longer receives items.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `capacity` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use futures_concurrency::prelude::*;
+    /// use futures_lite::{future::block_on, stream};
+    ///
+    /// block_on(async {
+    ///     let handles = stream::iter(1..=3).fan_out(3, 2);
+    ///     let sums: Vec<_> = handles
+    ///         .into_iter()
+    ///         .map(|handle| futures_lite::StreamExt::fold(handle, 0, |acc, n| acc + n))
+    ///         .collect();
+    ///     assert_eq!(sums.join().await, vec![6, 6, 6]);
+    /// });
+    /// ```
+    #[cfg(feature = "std")]
+    fn fan_out(self, n: usize, capacity: usize) -> Vec<FanOut<Self>>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        super::fan_out::fan_out(self, n, capacity)
+    }
 }
 
 impl<S1> StreamExt for S1
@@ -89,6 +624,14 @@ where
         Merge::merge((self, other))
     }
 
+    fn merge_with<T, S2>(self, other: S2) -> Merge2<T, S1, S2::IntoStream>
+    where
+        S1: Stream<Item = T>,
+        S2: IntoStream<Item = T>,
+    {
+        Merge::merge((self, other))
+    }
+
     fn chain<T, S2>(self, other: S2) -> Chain2<Self, S2::IntoStream>
     where
         Self: Stream<Item = T> + Sized,
@@ -98,6 +641,14 @@ where
         Chain::chain((self, other.into_stream()))
     }
 
+    fn chain_with<T, S2>(self, other: S2) -> Chain2<Self, S2::IntoStream>
+    where
+        Self: Stream<Item = T> + Sized,
+        S2: IntoStream<Item = T>,
+    {
+        Chain::chain((self, other.into_stream()))
+    }
+
     fn zip<T, S2>(self, other: S2) -> Zip2<Self, S2::IntoStream>
     where
         Self: Stream<Item = T> + Sized,
@@ -106,4 +657,12 @@ where
         // TODO(yosh): fix the bounds on the tuple impl
         Zip::zip((self, other.into_stream()))
     }
+
+    fn zip_with<T, S2>(self, other: S2) -> Zip2<Self, S2::IntoStream>
+    where
+        Self: Stream<Item = T> + Sized,
+        S2: IntoStream<Item = T>,
+    {
+        Zip::zip((self, other.into_stream()))
+    }
 }
@@ -1,16 +1,34 @@
 use alloc::collections::BTreeSet;
 use core::fmt::{self, Debug};
+use core::iter;
 use core::ops::{Deref, DerefMut};
 use core::pin::Pin;
 use core::task::{Context, Poll};
-use futures_core::Stream;
-use slab::Slab;
+use core::time::Duration;
+use futures_core::stream::{FusedStream, Stream};
 use smallvec::{smallvec, SmallVec};
 
-use crate::utils::{PollState, PollVec, WakerVec};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::concurrent_stream::{FromStream, IntoConcurrentStream};
+use crate::time::watchdog;
+use crate::time::{Timer, Watchdog, WatchdogEvent, WatchdogGroup};
+use crate::utils::{
+    size_hint, trace, Budget, ChunkedVec, PollState, PollVec, ReadyQueue, Rng, WakerVec,
+};
+
+pub use crate::future::future_group::DrainOrder;
 
 /// A growable group of streams which act as a single unit.
 ///
+/// The group allocates when it's created, and as streams are inserted: the
+/// streams themselves are stored on the heap, and every entry gets a waker of
+/// its own. Small groups keep their bookkeeping in-line, which saves some
+/// allocations, but not all of them.
+/// [`ArrayStreamGroup`](crate::stream::ArrayStreamGroup) stores a fixed number of streams
+/// in-line instead.
+///
 /// # Example
 ///
 /// **Basic example**
@@ -60,19 +78,105 @@ use crate::utils::{PollState, PollVec, WakerVec};
 #[derive(Default)]
 #[pin_project::pin_project]
 pub struct StreamGroup<S> {
-    #[pin]
-    streams: Slab<S>,
+    streams: ChunkedVec<S>,
     wakers: WakerVec,
     states: PollVec,
     keys: BTreeSet<usize>,
     key_removal_queue: SmallVec<[usize; 10]>,
     capacity: usize,
+    fairness: Fairness,
+    cursor: usize,
+    rng: Rng,
+    budget: Budget,
+    completed: usize,
+    shares: Vec<Share>,
+    order: DrainOrder,
+    /// The streams which are ready, in the order they were woken. Only kept
+    /// while the streams aren't polled by key.
+    ready: ReadyQueue,
+}
+
+/// The order in which a [`StreamGroup`] polls its streams.
+///
+/// The fairness decides which stream a poll starts from while the group
+/// polls its streams by key, which is the default [`DrainOrder::Keys`]. With
+/// [`DrainOrder::Fifo`] or [`DrainOrder::Lifo`] the group polls its streams in
+/// the order they were woken instead, and the fairness is ignored.
+///
+/// [`merge`](crate::stream::Merge::merge) has no drain order, and takes a
+/// [`FairnessStrategy`](crate::stream::fairness::FairnessStrategy) instead.
+///
+/// # Example
+///
+/// ```rust
+/// use futures_concurrency::stream::stream_group::{Fairness, StreamGroup};
+///
+/// let group = StreamGroup::with_fairness(Fairness::RoundRobin);
+/// # let group: StreamGroup<futures_lite::stream::Once<usize>> = group;
+/// assert_eq!(group.fairness(), Fairness::RoundRobin);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Fairness {
+    /// Always start polling from the stream with the lowest key. This favors
+    /// streams which were inserted earlier.
+    #[default]
+    Ordered,
+    /// Start polling right after the stream which last yielded an item, so
+    /// every stream gets a turn.
+    RoundRobin,
+    /// Start polling from a randomly chosen stream on every call.
+    Random,
+    /// Start polling from the ready stream which is furthest behind its
+    /// share, so that under contention streams yield items roughly in
+    /// proportion to their weights. Weights are set with
+    /// [`StreamGroup::insert_weighted`] and default to `1`.
+    Weighted,
+}
+
+/// The weight of a stream, and how far ahead or behind its share it is.
+#[derive(Debug, Clone, Copy)]
+struct Share {
+    weight: u32,
+    credit: i64,
+}
+
+impl Default for Share {
+    fn default() -> Self {
+        Self {
+            weight: 1,
+            credit: 0,
+        }
+    }
+}
+
+/// Pick the ready stream to start polling from using smooth weighted
+/// round-robin: every ready stream earns its weight in credit, and the stream
+/// with the most credit is picked and pays for it with the total weight.
+fn pick_weighted(shares: &mut [Share], ready: impl Iterator<Item = usize>) -> usize {
+    let mut total = 0;
+    let mut best: Option<(usize, i64)> = None;
+    for index in ready {
+        let share = &mut shares[index];
+        share.credit += i64::from(share.weight);
+        total += i64::from(share.weight);
+        if best.map_or(true, |(_, credit)| share.credit > credit) {
+            best = Some((index, share.credit));
+        }
+    }
+    match best {
+        Some((index, _)) => {
+            shares[index].credit -= total;
+            index
+        }
+        None => 0,
+    }
 }
 
 impl<T: Debug> Debug for StreamGroup<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("StreamGroup")
-            .field("slab", &"[..]")
+            .field("streams", &"[..]")
             .finish()
     }
 }
@@ -104,13 +208,167 @@ impl<S> StreamGroup<S> {
     /// ```
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            streams: Slab::with_capacity(capacity),
+            streams: ChunkedVec::with_capacity(capacity),
             wakers: WakerVec::new(capacity),
             states: PollVec::new(capacity),
             keys: BTreeSet::new(),
             key_removal_queue: smallvec![],
             capacity,
+            fairness: Fairness::default(),
+            cursor: 0,
+            rng: Rng::new(),
+            budget: Budget::default(),
+            completed: 0,
+            shares: (0..capacity).map(|_| Share::default()).collect(),
+            order: DrainOrder::default(),
+            ready: ReadyQueue::default(),
+        }
+    }
+
+    /// Create a new instance of `StreamGroup` which polls its streams in the
+    /// given order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::stream_group::{Fairness, StreamGroup};
+    ///
+    /// let group = StreamGroup::with_fairness(Fairness::Random);
+    /// # let group: StreamGroup<usize> = group;
+    /// ```
+    pub fn with_fairness(fairness: Fairness) -> Self {
+        let mut this = Self::new();
+        this.fairness = fairness;
+        this
+    }
+
+    /// Return the order in which the `StreamGroup` polls its streams.
+    pub fn fairness(&self) -> Fairness {
+        self.fairness
+    }
+
+    /// Change the order in which the `StreamGroup` polls its streams.
+    ///
+    /// This only applies while the drain order is [`DrainOrder::Keys`], see
+    /// [`Fairness`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::stream_group::{Fairness, StreamGroup};
+    ///
+    /// let mut group = StreamGroup::with_capacity(10);
+    /// group.set_fairness(Fairness::RoundRobin);
+    /// # let group: StreamGroup<usize> = group;
+    /// ```
+    pub fn set_fairness(&mut self, fairness: Fairness) {
+        self.fairness = fairness;
+    }
+
+    /// Seed the generator used by [`Fairness::Random`].
+    ///
+    /// Every group is seeded differently by default. Two groups which are
+    /// given the same seed, streams, and wakeups poll their streams in the
+    /// same order, which makes ordering-sensitive code reproducible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::stream_group::{Fairness, StreamGroup};
+    ///
+    /// let mut group = StreamGroup::with_fairness(Fairness::Random);
+    /// group.set_seed(42);
+    /// # let group: StreamGroup<usize> = group;
+    /// ```
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = Rng::with_seed(seed);
+    }
+
+    /// Change the order in which streams which are ready at the same time are
+    /// polled.
+    ///
+    /// With [`DrainOrder::Fifo`] and [`DrainOrder::Lifo`] the group keeps a
+    /// queue of the streams in the order they were woken, and polls them in
+    /// that order instead of following its [`Fairness`]. A stream which
+    /// yields an item counts as woken again right away, so with
+    /// [`DrainOrder::Lifo`] a stream which is always ready keeps being polled
+    /// before the others.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::stream_group::{DrainOrder, StreamGroup};
+    /// use futures_lite::{stream, StreamExt};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = StreamGroup::new();
+    /// group.set_drain_order(DrainOrder::Lifo);
+    /// group.insert(stream::once(1));
+    /// group.insert(stream::once(2));
+    ///
+    /// assert_eq!(group.next().await, Some(2));
+    /// assert_eq!(group.next().await, Some(1));
+    /// # });
+    /// ```
+    pub fn set_drain_order(&mut self, order: DrainOrder) {
+        let was_tracking = self.tracks_wakes();
+        self.order = order;
+        self.ready.set_order(order.into());
+        let tracking = self.tracks_wakes();
+        if tracking == was_tracking {
+            return;
         }
+        let mut readiness = self.wakers.readiness();
+        readiness.track_wakes(tracking);
+        self.ready.clear();
+        if tracking {
+            // Streams which are ready already were woken before any others.
+            for &index in &self.keys {
+                if readiness.is_ready(index) {
+                    self.ready.push(index, 0);
+                }
+            }
+        }
+    }
+
+    /// Return the order in which streams which are ready at the same time are
+    /// polled.
+    pub fn drain_order(&self) -> DrainOrder {
+        self.order
+    }
+
+    /// Whether streams are polled in the order they were woken, rather than
+    /// by key.
+    fn tracks_wakes(&self) -> bool {
+        self.order != DrainOrder::Keys
+    }
+
+    /// Poll at most `budget` streams per call to `poll_next`.
+    ///
+    /// When many streams are woken at once, polling all of them in one go can
+    /// keep the executor busy for a long time. Once the budget runs out the
+    /// group wakes itself and returns `Poll::Pending`, giving other tasks a
+    /// chance to run. Polling resumes where it left off on the next call. At
+    /// least one stream is always polled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::StreamGroup;
+    ///
+    /// let mut group = StreamGroup::new();
+    /// group.set_budget(32);
+    /// assert_eq!(group.budget(), Some(32));
+    /// # let group: StreamGroup<futures_lite::stream::Once<usize>> = group;
+    /// ```
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget.set_limit(budget);
+    }
+
+    /// Return the maximum number of streams polled per call to `poll_next`,
+    /// if any.
+    pub fn budget(&self) -> Option<usize> {
+        self.budget.limit()
     }
 
     /// Return the number of futures currently active in the group.
@@ -147,6 +405,45 @@ impl<S> StreamGroup<S> {
         self.capacity
     }
 
+    /// Take a snapshot of the number of streams in each state.
+    ///
+    /// This is cheap enough to call on every iteration, for example to export
+    /// gauge metrics from a long-lived group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::StreamGroup;
+    /// use futures_lite::{stream, StreamExt};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = StreamGroup::with_capacity(4);
+    /// group.insert(stream::pending().boxed());
+    /// group.insert(stream::once(1).boxed());
+    /// assert_eq!(group.next().await, Some(1));
+    ///
+    /// // The second stream will be polled again, to see whether it has ended.
+    /// let stats = group.stats();
+    /// assert_eq!(stats.pending(), 1);
+    /// assert_eq!(stats.ready(), 1);
+    /// assert_eq!(stats.completed(), 0);
+    /// assert_eq!(stats.capacity(), 4);
+    /// # });
+    /// ```
+    pub fn stats(&self) -> Stats {
+        let ready = self
+            .keys
+            .iter()
+            .filter(|index| self.wakers.is_ready(**index))
+            .count();
+        Stats {
+            pending: self.len() - ready,
+            ready,
+            completed: self.completed,
+            capacity: self.capacity,
+        }
+    }
+
     /// Returns true if there are no futures currently active in the group.
     ///
     /// # Example
@@ -187,10 +484,123 @@ impl<S> StreamGroup<S> {
         if is_present {
             self.states[key.0].set_none();
             self.streams.remove(key.0);
+            // Don't hand the wake of this stream to the next one using its key.
+            if self.wakers.readiness().clear_ready(key.0) && self.tracks_wakes() {
+                self.ready.remove(key.0);
+            }
+            trace!(key = key.0, "stream group: stream removed");
         }
         is_present
     }
 
+    /// Removes all streams from the group, keeping the allocated capacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::stream::StreamGroup;
+    /// use futures_lite::stream;
+    ///
+    /// let mut group = StreamGroup::with_capacity(2);
+    /// group.insert(stream::once(1));
+    /// group.insert(stream::once(2));
+    /// group.clear();
+    /// assert!(group.is_empty());
+    /// assert_eq!(group.capacity(), 2);
+    /// ```
+    pub fn clear(&mut self) {
+        for index in core::mem::take(&mut self.keys) {
+            self.states[index].set_none();
+        }
+        self.streams.clear();
+        self.cursor = 0;
+        self.ready.clear();
+        trace!("stream group: cleared");
+    }
+
+    /// Removes all streams from the group and returns them, keeping the
+    /// allocated capacity.
+    ///
+    /// The streams are returned in key order. Values are pinned once they're
+    /// inserted into the group, so this requires the streams to be `Unpin`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::stream::StreamGroup;
+    /// use futures_lite::stream;
+    ///
+    /// let mut group = StreamGroup::with_capacity(2);
+    /// group.insert(stream::once(1));
+    /// group.insert(stream::once(2));
+    ///
+    /// let other: StreamGroup<_> = group.drain().collect();
+    /// assert!(group.is_empty());
+    /// assert_eq!(group.capacity(), 2);
+    /// assert_eq!(other.len(), 2);
+    /// ```
+    pub fn drain(&mut self) -> impl Iterator<Item = S>
+    where
+        S: Unpin,
+    {
+        let streams: Vec<_> = core::mem::take(&mut self.keys)
+            .into_iter()
+            .filter_map(|index| {
+                self.states[index].set_none();
+                self.streams.take(index)
+            })
+            .collect();
+        // Reset the vacant list, so keys are handed out from the start again.
+        self.streams.clear();
+        self.cursor = 0;
+        self.ready.clear();
+        trace!(count = streams.len(), "stream group: drained");
+        streams.into_iter()
+    }
+
+    /// Retains only the streams specified by the predicate.
+    ///
+    /// Removes every stream for which `predicate` returns `false`. The
+    /// predicate is called once for each stream, along with its key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_concurrency::stream::StreamGroup;
+    /// use futures_lite::stream;
+    ///
+    /// let mut group = StreamGroup::new();
+    /// let keep = group.insert(stream::once(1));
+    /// let discard = group.insert(stream::once(2));
+    ///
+    /// group.retain(|key, _stream| key == keep);
+    /// assert!(group.contains_key(keep));
+    /// assert!(!group.contains_key(discard));
+    /// ```
+    pub fn retain<P>(&mut self, mut predicate: P)
+    where
+        P: FnMut(Key, Pin<&mut S>) -> bool,
+    {
+        let tracking = self.tracks_wakes();
+        let streams = &mut self.streams;
+        let states = &mut self.states;
+        let mut readiness = self.wakers.readiness();
+        let ready = &mut self.ready;
+        self.keys.retain(|&index| {
+            let stream = streams.get_pin_mut(index).unwrap();
+            let keep = predicate(Key(index), stream);
+            if !keep {
+                states[index].set_none();
+                streams.remove(index);
+                if readiness.clear_ready(index) && tracking {
+                    ready.remove(index);
+                }
+                trace!(key = index, "stream group: stream removed");
+            }
+            keep
+        });
+    }
+
     /// Returns `true` if the `StreamGroup` contains a value for the specified key.
     ///
     /// # Example
@@ -211,6 +621,90 @@ impl<S> StreamGroup<S> {
         self.keys.contains(&key.0)
     }
 
+    /// Returns a reference to the stream corresponding to the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_lite::stream;
+    /// use futures_concurrency::stream::StreamGroup;
+    ///
+    /// let mut group = StreamGroup::new();
+    /// let key = group.insert(stream::once(4));
+    /// assert!(group.get(key).is_some());
+    /// group.remove(key);
+    /// assert!(group.get(key).is_none());
+    /// ```
+    pub fn get(&self, key: Key) -> Option<&S> {
+        self.streams.get(key.0)
+    }
+
+    /// Returns a pinned mutable reference to the stream corresponding to the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_lite::{stream, StreamExt};
+    /// use futures_concurrency::stream::StreamGroup;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = StreamGroup::new();
+    /// group.insert(stream::iter(vec![1, 2]));
+    ///
+    /// let mut group = group.keyed();
+    /// let (key, num) = group.next().await.unwrap();
+    /// assert_eq!(num, 1);
+    ///
+    /// // Access the stream which just yielded an item.
+    /// let mut stream = group.get_mut(key).unwrap();
+    /// assert_eq!(stream.next().await, Some(2));
+    /// # })
+    /// ```
+    pub fn get_mut(&mut self, key: Key) -> Option<Pin<&mut S>> {
+        self.streams.get_pin_mut(key.0)
+    }
+
+    /// Returns an iterator over the streams in the group, along with their keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_lite::stream;
+    /// use futures_concurrency::stream::StreamGroup;
+    ///
+    /// let mut group = StreamGroup::new();
+    /// let key = group.insert(stream::once(4));
+    ///
+    /// let keys: Vec<_> = group.iter().map(|(key, _stream)| key).collect();
+    /// assert_eq!(keys, [key]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (Key, &S)> + '_ {
+        self.streams
+            .iter()
+            .map(|(index, stream)| (Key(index), stream))
+    }
+
+    /// Returns an iterator over pinned mutable references to the streams in
+    /// the group, along with their keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_lite::stream;
+    /// use futures_concurrency::stream::StreamGroup;
+    ///
+    /// let mut group = StreamGroup::new();
+    /// group.insert(stream::once(4));
+    /// group.insert(stream::once(8));
+    ///
+    /// assert_eq!(group.iter_mut().count(), 2);
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Key, Pin<&mut S>)> + '_ {
+        self.streams
+            .iter_pin_mut()
+            .map(|(index, stream)| (Key(index), stream))
+    }
+
     /// Reserves capacity for `additional` more streams to be inserted.
     /// Does nothing if the capacity is already sufficient.
     ///
@@ -237,8 +731,85 @@ impl<S> StreamGroup<S> {
         let new_cap = self.capacity + additional;
         self.wakers.resize(new_cap);
         self.states.resize(new_cap);
-        self.streams.reserve_exact(additional);
+        self.shares.resize_with(new_cap, Share::default);
+        self.streams.reserve(additional);
+        self.capacity = new_cap;
+        trace!(capacity = new_cap, "stream group: resized");
+    }
+
+    /// Shrinks the capacity of the group as much as possible.
+    ///
+    /// Streams never move once they're inserted, so they're stored in chunks
+    /// which can only be released as a whole, and only once none of their
+    /// keys are in use. The group keeps the capacity of every chunk up to the
+    /// one holding the highest key still in use, so [`StreamGroup::capacity`]
+    /// may remain larger than the number of streams in the group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::StreamGroup;
+    /// use futures_lite::stream;
+    ///
+    /// let mut group = StreamGroup::with_capacity(1);
+    /// group.insert(stream::once(0));
+    /// let keys: Vec<_> = (1..10).map(|n| group.insert(stream::once(n))).collect();
+    /// for key in keys {
+    ///     group.remove(key);
+    /// }
+    ///
+    /// group.shrink_to_fit();
+    /// assert_eq!(group.capacity(), 1);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    /// Shrinks the capacity of the group with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both `min_capacity`
+    /// and the highest key still in use. Capacity is released a chunk at a
+    /// time, so it may remain larger than that, see
+    /// [`StreamGroup::shrink_to_fit`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::StreamGroup;
+    /// use futures_lite::stream;
+    ///
+    /// let mut group = StreamGroup::with_capacity(1);
+    /// group.insert(stream::once(0));
+    /// let keys: Vec<_> = (1..10).map(|n| group.insert(stream::once(n))).collect();
+    /// for key in keys {
+    ///     group.remove(key);
+    /// }
+    ///
+    /// group.shrink_to(4);
+    /// assert_eq!(group.capacity(), 4);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let needed = self.keys.last().map_or(0, |key| key + 1);
+        let new_cap = needed.max(min_capacity);
+        if new_cap >= self.capacity {
+            return;
+        }
+        self.streams.shrink_to(new_cap);
+        // Chunks which still hold streams can't be released, so report the
+        // capacity which is actually left.
+        let new_cap = self.streams.capacity().clamp(new_cap, self.capacity);
+        if new_cap == self.capacity {
+            return;
+        }
+        self.wakers.resize(new_cap);
+        self.wakers.shrink_to_fit();
+        self.states.resize(new_cap);
+        self.states.shrink_to_fit();
+        self.shares.truncate(new_cap);
+        self.shares.shrink_to_fit();
+        self.ready.truncate(new_cap);
         self.capacity = new_cap;
+        trace!(capacity = new_cap, "stream group: resized");
     }
 }
 
@@ -264,24 +835,99 @@ impl<S: Stream> StreamGroup<S> {
 
         let index = self.streams.insert(stream);
         self.keys.insert(index);
+        trace!(key = index, "stream group: stream inserted");
 
         // Set the corresponding state
         self.states[index].set_pending();
+        self.shares[index] = Share::default();
+        // A new stream counts as woken when it's inserted.
         self.wakers.readiness().set_ready(index);
+        if self.tracks_wakes() {
+            self.ready.push(index, 0);
+        }
 
         Key(index)
     }
 
-    /// Create a stream which also yields the key of each item.
+    /// Insert a new stream into the group with the given weight.
+    ///
+    /// With [`Fairness::Weighted`], streams which are ready at the same time
+    /// yield items roughly in proportion to their weights. Streams inserted
+    /// with [`StreamGroup::insert`] have a weight of `1`. Other fairness
+    /// strategies ignore the weights.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `weight` is zero.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use futures_concurrency::stream::StreamGroup;
+    /// use futures_concurrency::stream::stream_group::{Fairness, StreamGroup};
     /// use futures_lite::{stream, StreamExt};
     ///
     /// # futures_lite::future::block_on(async {
-    /// let mut group = StreamGroup::new();
+    /// let mut group = StreamGroup::with_fairness(Fairness::Weighted);
+    /// group.insert_weighted(stream::repeat("gold"), 3);
+    /// group.insert_weighted(stream::repeat("bronze"), 1);
+    ///
+    /// let items: Vec<_> = group.take(8).collect().await;
+    /// let gold = items.iter().filter(|tier| **tier == "gold").count();
+    /// assert_eq!(gold, 6);
+    /// # });
+    /// ```
+    pub fn insert_weighted(&mut self, stream: S, weight: u32) -> Key {
+        assert!(weight > 0, "stream weight must be non-zero");
+        let key = self.insert(stream);
+        self.shares[key.0].weight = weight;
+        key
+    }
+
+    /// Change the weight of a stream in the group. Returns whether the stream
+    /// was present in the group.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `weight` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::StreamGroup;
+    /// use futures_lite::stream;
+    ///
+    /// let mut group = StreamGroup::new();
+    /// let key = group.insert(stream::once(1));
+    /// assert_eq!(group.weight(key), Some(1));
+    /// assert!(group.set_weight(key, 4));
+    /// assert_eq!(group.weight(key), Some(4));
+    /// ```
+    pub fn set_weight(&mut self, key: Key, weight: u32) -> bool {
+        assert!(weight > 0, "stream weight must be non-zero");
+        let is_present = self.keys.contains(&key.0);
+        if is_present {
+            self.shares[key.0].weight = weight;
+        }
+        is_present
+    }
+
+    /// Return the weight of a stream in the group, if it's present.
+    pub fn weight(&self, key: Key) -> Option<u32> {
+        self.keys
+            .contains(&key.0)
+            .then(|| self.shares[key.0].weight)
+    }
+
+    /// Create a stream which also yields the key of each item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::StreamGroup;
+    /// use futures_lite::{stream, StreamExt};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = StreamGroup::new();
     /// group.insert(stream::once(2));
     /// group.insert(stream::once(4));
     ///
@@ -296,6 +942,69 @@ impl<S: Stream> StreamGroup<S> {
     pub fn keyed(self) -> Keyed<S> {
         Keyed { group: self }
     }
+
+    /// Create a stream which yields the items of each stream along with their
+    /// key, and notifies when a stream has finished.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures_concurrency::stream::{StreamEvent, StreamGroup};
+    /// use futures_lite::{stream, StreamExt};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = StreamGroup::new();
+    /// let key = group.insert(stream::once(2));
+    ///
+    /// let mut group = group.events();
+    /// assert_eq!(group.next().await, Some(StreamEvent::Item(key, 2)));
+    /// assert_eq!(group.next().await, Some(StreamEvent::Finished(key)));
+    /// assert_eq!(group.next().await, None);
+    /// # });
+    /// ```
+    pub fn events(self) -> Events<S> {
+        Events { group: self }
+    }
+
+    /// Create a stream which reports when the group stops making progress.
+    ///
+    /// The stream yields the items of each stream along with their key, and
+    /// notifies when a stream has finished. In addition, it yields
+    /// [`WatchdogEvent::Stalled`] whenever no stream has made progress for
+    /// `stall_after`, and [`WatchdogEvent::Overdue`] when a single stream
+    /// hasn't yielded an item for longer than `overdue_after`. Streams
+    /// inserted through [`Watchdog::insert`] are tracked as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(miri)] fn main() {}
+    /// # #[cfg(not(miri))]
+    /// # fn main() {
+    /// use futures_concurrency::stream::StreamGroup;
+    /// use futures_concurrency::time::WatchdogEvent;
+    /// use futures_lite::{stream, StreamExt};
+    /// use std::time::Duration;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut group = StreamGroup::new();
+    /// let key = group.insert(stream::pending::<()>());
+    ///
+    /// let stall = Duration::from_secs(60);
+    /// let overdue = Duration::from_millis(10);
+    /// let mut group = group.watchdog(stall, overdue, async_io::Timer::after);
+    /// assert_eq!(group.next().await, Some(WatchdogEvent::Overdue(key)));
+    /// # });
+    /// # }
+    /// ```
+    pub fn watchdog<T: Timer>(
+        self,
+        stall_after: Duration,
+        overdue_after: Duration,
+        timer: T,
+    ) -> Watchdog<Self, T> {
+        Watchdog::new(self, stall_after, overdue_after, timer)
+    }
 }
 
 impl<S: Stream> StreamGroup<S> {
@@ -303,7 +1012,24 @@ impl<S: Stream> StreamGroup<S> {
         mut self: Pin<&mut Self>,
         cx: &Context<'_>,
     ) -> Poll<Option<(Key, <S as Stream>::Item)>> {
-        let mut this = self.as_mut().project();
+        loop {
+            match self.as_mut().poll_next_event(cx) {
+                Poll::Ready(Some(StreamEvent::Item(key, item))) => {
+                    return Poll::Ready(Some((key, item)))
+                }
+                // A stream ended, keep going until we find an item
+                Poll::Ready(Some(StreamEvent::Finished(_))) => continue,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn poll_next_event(
+        mut self: Pin<&mut Self>,
+        cx: &Context<'_>,
+    ) -> Poll<Option<StreamEvent<<S as Stream>::Item>>> {
+        let this = self.as_mut().project();
 
         // Short-circuit if we have no streams to iterate over
         if this.streams.is_empty() {
@@ -311,63 +1037,112 @@ impl<S: Stream> StreamGroup<S> {
         }
 
         // Set the top-level waker and check readiness
+        let tracking = *this.order != DrainOrder::Keys;
         let mut readiness = this.wakers.readiness();
         readiness.set_waker(cx.waker());
         if !readiness.any_ready() {
             // Nothing is ready yet
             return Poll::Pending;
         }
+        if tracking {
+            let ready = &mut *this.ready;
+            readiness.take_woken(|index| ready.push(index, 0));
+        }
 
         // Setup our stream state
         let mut ret = Poll::Pending;
-        let mut done_count = 0;
-        let stream_count = this.streams.len();
         let states = this.states;
 
-        // SAFETY: We unpin the stream set so we can later individually access
-        // single streams. Either to read from them or to drop them.
-        let streams = unsafe { this.streams.as_mut().get_unchecked_mut() };
+        let streams = this.streams;
 
-        for index in this.keys.iter().cloned() {
+        // Pick which stream we start polling from, and wrap around from there.
+        // Streams polled in the order they were woken are taken off the ready
+        // queue instead, which keeps whatever we didn't get to.
+        let start = match this.budget.take_resume() {
+            // Resume where we left off if we ran out of budget last time.
+            Some(start) => start,
+            None if tracking => 0,
+            None => match this.fairness {
+                Fairness::Ordered => 0,
+                Fairness::RoundRobin => *this.cursor,
+                Fairness::Random => {
+                    let nth = this.rng.gen_index(this.keys.len());
+                    this.keys.iter().nth(nth).copied().unwrap_or(0)
+                }
+                Fairness::Weighted => {
+                    let ready =
+                        this.keys.iter().copied().filter(|index| {
+                            states[*index].is_pending() && readiness.is_ready(*index)
+                        });
+                    pick_weighted(this.shares, ready)
+                }
+            },
+        };
+        let mut rotated =
+            (!tracking).then(|| this.keys.range(start..).chain(this.keys.range(..start)));
+        let ready = &mut *this.ready;
+        let keys = iter::from_fn(|| match &mut rotated {
+            Some(rotated) => rotated.next().copied(),
+            None => ready.pop(),
+        });
+        let mut polled = 0;
+        let waker = cx.waker();
+
+        for index in keys {
             if states[index].is_pending() && readiness.clear_ready(index) {
-                // unlock readiness so we don't deadlock when polling
+                // release readiness so we can borrow the wakers when polling
                 #[allow(clippy::drop_non_drop)]
                 drop(readiness);
 
                 // Obtain the intermediate waker.
                 let mut cx = Context::from_waker(this.wakers.get(index).unwrap());
 
-                // SAFETY: this stream here is a projection from the streams
-                // vec, which we're reading from.
-                let stream = unsafe { Pin::new_unchecked(&mut streams[index]) };
+                trace!(key = index, "stream group: polling stream");
+                let stream = streams.get_pin_mut(index).unwrap();
                 match stream.poll_next(&mut cx) {
                     Poll::Ready(Some(item)) => {
                         // Set the return type for the function
-                        ret = Poll::Ready(Some((Key(index), item)));
+                        ret = Poll::Ready(Some(StreamEvent::Item(Key(index), item)));
+                        *this.cursor = index + 1;
 
                         // We just obtained an item from this index, make sure
                         // we check it again on a next iteration
                         states[index] = PollState::Pending;
                         let mut readiness = this.wakers.readiness();
-                        readiness.set_ready(index);
+                        if !readiness.set_ready(index) && tracking {
+                            ready.push(index, 0);
+                        }
 
                         break;
                     }
                     Poll::Ready(None) => {
                         // A stream has ended, make note of that
-                        done_count += 1;
+                        trace!(key = index, "stream group: stream finished");
+                        ret = Poll::Ready(Some(StreamEvent::Finished(Key(index))));
+                        *this.cursor = index + 1;
 
                         // Remove all associated data about the stream.
                         // The only data we can't remove directly is the key entry.
                         states[index] = PollState::None;
                         streams.remove(index);
+                        *this.completed += 1;
                         this.key_removal_queue.push(index);
+
+                        break;
                     }
                     // Keep looping if there is nothing for us to do
                     Poll::Pending => {}
                 };
 
-                // Lock readiness so we can use it again
+                // Yield to the executor once we've used up our budget
+                polled += 1;
+                if this.budget.is_exhausted(polled) {
+                    this.budget.resume_at(index + 1);
+                    waker.wake_by_ref();
+                    break;
+                }
+
+                // Reacquire readiness so we can use it again
                 readiness = this.wakers.readiness();
             }
         }
@@ -381,12 +1156,6 @@ impl<S: Stream> StreamGroup<S> {
             this.key_removal_queue.clear();
         }
 
-        // If all streams turned up with `Poll::Ready(None)` our
-        // stream should return that
-        if done_count == stream_count {
-            ret = Poll::Ready(None);
-        }
-
         ret
     }
 }
@@ -401,6 +1170,25 @@ impl<S: Stream> Stream for StreamGroup<S> {
             Poll::Pending => Poll::Pending,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::sum(self.streams.iter().map(|(_, stream)| stream.size_hint()))
+    }
+}
+
+impl<S: Stream> IntoConcurrentStream for StreamGroup<S> {
+    type Item = <S as Stream>::Item;
+    type IntoConcurrentStream = FromStream<Self>;
+
+    fn into_co_stream(self) -> Self::IntoConcurrentStream {
+        FromStream::new(self)
+    }
+}
+
+impl<S: Stream> FusedStream for StreamGroup<S> {
+    fn is_terminated(&self) -> bool {
+        self.is_empty()
+    }
 }
 
 impl<S: Stream> FromIterator<S> for StreamGroup<S> {
@@ -419,7 +1207,74 @@ impl<S: Stream> FromIterator<S> for StreamGroup<S> {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Key(usize);
 
+/// A snapshot of the state of a [`StreamGroup`].
+///
+/// This `struct` is created by the [`StreamGroup::stats`] method. The counts are
+/// taken when the snapshot is created, and aren't updated afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Stats {
+    pending: usize,
+    ready: usize,
+    completed: usize,
+    capacity: usize,
+}
+
+impl Stats {
+    /// The number of streams which are waiting to be woken.
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+
+    /// The number of streams which have been woken, and will be polled the next
+    /// time the group is polled.
+    pub fn ready(&self) -> usize {
+        self.ready
+    }
+
+    /// The number of streams which have ended since the group was created.
+    ///
+    /// Streams which were removed from the group aren't counted.
+    pub fn completed(&self) -> usize {
+        self.completed
+    }
+
+    /// The capacity of the group.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
 /// Iterate over items in the stream group with their associated keys.
+///
+/// `Keyed` dereferences to the group, so streams can be inserted and removed
+/// while iterating. Combined with a lending stream, this makes it possible to
+/// feed the group from its own outputs while keeping per-key bookkeeping.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::stream::StreamGroup;
+/// use futures_lite::stream;
+/// use lending_stream::prelude::*;
+/// use std::collections::HashMap;
+///
+/// # futures_lite::future::block_on(async {
+/// let mut group = StreamGroup::new();
+/// let mut counts = HashMap::new();
+/// counts.insert(group.insert(stream::iter(vec![2, 2])), 0);
+///
+/// let mut group = group.keyed().lend_mut();
+/// while let Some((group, (key, num))) = group.next().await {
+///     *counts.get_mut(&key).unwrap() += 1;
+///     if num > 1 {
+///         counts.insert(group.insert(stream::iter(vec![num - 1])), 0);
+///     }
+/// }
+/// let mut counts: Vec<_> = counts.into_values().collect();
+/// counts.sort_unstable();
+/// assert_eq!(counts, [1, 1, 2]);
+/// # });
+/// ```
 #[derive(Debug)]
 #[pin_project::pin_project]
 pub struct Keyed<S: Stream> {
@@ -427,6 +1282,13 @@ pub struct Keyed<S: Stream> {
     group: StreamGroup<S>,
 }
 
+impl<S: Stream> Keyed<S> {
+    /// Consume the `Keyed` stream, returning the underlying group.
+    pub fn into_inner(self) -> StreamGroup<S> {
+        self.group
+    }
+}
+
 impl<S: Stream> Deref for Keyed<S> {
     type Target = StreamGroup<S>;
 
@@ -450,9 +1312,94 @@ impl<S: Stream> Stream for Keyed<S> {
     }
 }
 
+impl<S: Stream> FusedStream for Keyed<S> {
+    fn is_terminated(&self) -> bool {
+        self.group.is_terminated()
+    }
+}
+
+/// An event emitted by the [`Events`] stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StreamEvent<T> {
+    /// The stream with the given key yielded an item.
+    Item(Key, T),
+    /// The stream with the given key has finished, and has been removed from
+    /// the group.
+    Finished(Key),
+}
+
+/// Iterate over items in the stream group with their associated keys, and
+/// be notified when streams finish.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct Events<S: Stream> {
+    #[pin]
+    group: StreamGroup<S>,
+}
+
+impl<S: Stream> Deref for Events<S> {
+    type Target = StreamGroup<S>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.group
+    }
+}
+
+impl<S: Stream> DerefMut for Events<S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.group
+    }
+}
+
+impl<S: Stream> Stream for Events<S> {
+    type Item = StreamEvent<<S as Stream>::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        this.group.as_mut().poll_next_event(cx)
+    }
+}
+
+impl<S: Stream> FusedStream for Events<S> {
+    fn is_terminated(&self) -> bool {
+        self.group.is_terminated()
+    }
+}
+
+impl<S: Stream> watchdog::private::Sealed for StreamGroup<S> {}
+
+impl<S: Stream> WatchdogGroup for StreamGroup<S> {
+    type Key = Key;
+    type Item = S::Item;
+
+    fn keys(&self) -> impl Iterator<Item = Key> + '_ {
+        self.keys.iter().map(|index| Key(*index))
+    }
+
+    fn contains_entry(&self, key: Key) -> bool {
+        self.keys.contains(&key.0)
+    }
+
+    fn remove_entry(&mut self, key: Key) -> bool {
+        self.remove(key)
+    }
+
+    fn poll_progress(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<WatchdogEvent<Key, S::Item>>> {
+        self.poll_next_event(cx).map(|event| {
+            event.map(|event| match event {
+                StreamEvent::Item(key, item) => WatchdogEvent::Item(key, item),
+                StreamEvent::Finished(key) => WatchdogEvent::Finished(key),
+            })
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::StreamGroup;
+    use super::{DrainOrder, Fairness, StreamEvent, StreamGroup};
     use futures_lite::{prelude::*, stream};
 
     #[test]
@@ -472,6 +1419,227 @@ mod test {
         });
     }
 
+    #[test]
+    fn is_terminated() {
+        use futures_core::stream::FusedStream;
+
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::new();
+            group.insert(stream::once(2));
+            assert!(!group.is_terminated());
+            while group.next().await.is_some() {}
+            assert!(group.is_terminated());
+        });
+    }
+
+    #[test]
+    fn iter_skips_completed_streams() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::new();
+            let a = group.insert(stream::once(2));
+            let b = group.insert(stream::once(4));
+            assert_eq!(group.iter().count(), 2);
+
+            group.remove(a);
+            let keys: Vec<_> = group.iter().map(|(key, _)| key).collect();
+            assert_eq!(keys, [b]);
+            let keys: Vec<_> = group.iter_mut().map(|(key, _)| key).collect();
+            assert_eq!(keys, [b]);
+
+            while group.next().await.is_some() {}
+            assert_eq!(group.iter().count(), 0);
+        });
+    }
+
+    #[test]
+    fn get_after_completion() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::new().keyed();
+            group.insert(stream::once(2));
+
+            let (key, _) = group.next().await.unwrap();
+            assert!(group.get(key).is_some());
+            assert!(group.get_mut(key).is_some());
+
+            assert!(group.next().await.is_none());
+            assert!(group.get(key).is_none());
+            assert!(group.get_mut(key).is_none());
+        });
+    }
+
+    #[test]
+    fn events() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::new();
+            let a = group.insert(stream::iter(vec![1, 2]));
+            let b = group.insert(stream::iter(vec![]));
+
+            let mut items = 0;
+            let mut finished = vec![];
+            let mut group = group.events();
+            while let Some(event) = group.next().await {
+                match event {
+                    StreamEvent::Item(key, _) => {
+                        assert_eq!(key, a);
+                        items += 1;
+                    }
+                    StreamEvent::Finished(key) => finished.push(key),
+                }
+            }
+            assert_eq!(items, 2);
+            finished.sort();
+            assert_eq!(finished, [a, b]);
+            assert!(group.is_empty());
+        });
+    }
+
+    #[test]
+    fn round_robin() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::with_fairness(Fairness::RoundRobin);
+            group.insert(stream::repeat(0).take(3));
+            group.insert(stream::repeat(1).take(3));
+            group.insert(stream::repeat(2).take(3));
+
+            let mut out = vec![];
+            while let Some(num) = group.next().await {
+                out.push(num);
+            }
+            assert_eq!(out, [0, 1, 2, 0, 1, 2, 0, 1, 2]);
+        });
+    }
+
+    #[test]
+    fn weighted() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::with_fairness(Fairness::Weighted);
+            group.insert_weighted(stream::repeat(0), 2);
+            group.insert(stream::repeat(1));
+            let key = group.insert_weighted(stream::repeat(2), 3);
+
+            let out: Vec<_> = (&mut group).take(12).collect().await;
+            let count = |n| out.iter().filter(|m| **m == n).count();
+            assert_eq!((count(0), count(1), count(2)), (4, 2, 6));
+
+            // Once the heaviest stream is gone, the others share the rest.
+            group.remove(key);
+            let out: Vec<_> = group.take(6).collect().await;
+            let count = |n| out.iter().filter(|m| **m == n).count();
+            assert_eq!((count(0), count(1)), (4, 2));
+        });
+    }
+
+    #[test]
+    fn ordered() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::with_fairness(Fairness::Ordered);
+            group.insert(stream::repeat(0).take(2));
+            group.insert(stream::repeat(1).take(2));
+
+            let mut out = vec![];
+            while let Some(num) = group.next().await {
+                out.push(num);
+            }
+            assert_eq!(out, [0, 0, 1, 1]);
+        });
+    }
+
+    #[test]
+    fn random() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::with_fairness(Fairness::Random);
+            group.insert(stream::repeat(0).take(10));
+            group.insert(stream::repeat(1).take(10));
+
+            let mut out = 0;
+            while let Some(num) = group.next().await {
+                out += num;
+            }
+            assert_eq!(out, 10);
+        });
+    }
+
+    #[test]
+    fn random_with_seed() {
+        futures_lite::future::block_on(async {
+            let run = || async {
+                let mut group = StreamGroup::with_fairness(Fairness::Random);
+                group.set_seed(7);
+                for n in 0..4 {
+                    group.insert(stream::repeat(n).take(4));
+                }
+                group.collect::<Vec<_>>().await
+            };
+            let out = run().await;
+            assert_eq!(out.len(), 16);
+            assert_eq!(out, run().await);
+        });
+    }
+
+    #[test]
+    fn random_with_spare_capacity() {
+        futures_lite::future::block_on(async {
+            // Unused capacity shouldn't make the first stream more likely to
+            // be picked.
+            let mut group = StreamGroup::with_capacity(64);
+            group.set_fairness(Fairness::Random);
+            group.set_seed(7);
+            group.insert(stream::repeat(0));
+            group.insert(stream::repeat(1));
+
+            let mut ones = 0;
+            for _ in 0..200 {
+                ones += group.next().await.unwrap();
+            }
+            assert!((50..150).contains(&ones), "{ones} out of 200");
+        });
+    }
+
+    #[test]
+    fn drain_order() {
+        futures_lite::future::block_on(async {
+            let run = |order| async move {
+                let mut group = StreamGroup::new();
+                group.set_drain_order(order);
+                for n in 0..3 {
+                    group.insert(stream::repeat(n).take(2));
+                }
+                group.collect::<Vec<_>>().await
+            };
+            assert_eq!(run(DrainOrder::Keys).await, [0, 0, 1, 1, 2, 2]);
+            assert_eq!(run(DrainOrder::Fifo).await, [0, 1, 2, 0, 1, 2]);
+            assert_eq!(run(DrainOrder::Lifo).await, [2, 2, 1, 1, 0, 0]);
+        });
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "ptr")]
+    fn drain_order_follows_wakes() {
+        use futures::channel::mpsc;
+
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::new();
+            group.set_drain_order(DrainOrder::Fifo);
+            let mut senders = Vec::new();
+            for _ in 0..3 {
+                let (sender, receiver) = mpsc::unbounded();
+                group.insert(receiver);
+                senders.push(sender);
+            }
+            assert!(futures_lite::future::poll_once(group.next())
+                .await
+                .is_none());
+
+            // Wake the streams in a different order than their keys.
+            for n in [2, 0, 1] {
+                senders[n].unbounded_send(n).unwrap();
+            }
+            drop(senders);
+            let out: Vec<_> = group.collect().await;
+            assert_eq!(out, [2, 0, 1]);
+        });
+    }
+
     #[test]
     fn capacity_grow_on_insert() {
         futures_lite::future::block_on(async {
@@ -483,4 +1651,174 @@ mod test {
             assert!(group.capacity() > cap);
         });
     }
+
+    #[test]
+    fn into_co_stream() {
+        use crate::concurrent_stream::{ConcurrentStream, IntoConcurrentStream};
+
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::new();
+            group.insert(stream::iter(vec![1, 2]));
+            group.insert(stream::iter(vec![3]));
+            assert_eq!(group.size_hint(), (3, Some(3)));
+
+            let mut v: Vec<_> = group
+                .into_co_stream()
+                .map(|n| async move { n * 2 })
+                .collect()
+                .await;
+            v.sort();
+            assert_eq!(v, &[2, 4, 6]);
+        });
+    }
+
+    #[test]
+    fn watchdog() {
+        use crate::time::WatchdogEvent;
+        use std::pin::Pin;
+        use std::time::Duration;
+
+        futures_lite::future::block_on(async {
+            let mut group: StreamGroup<Pin<Box<dyn Stream<Item = u8>>>> = StreamGroup::new();
+            let pending = group.insert(Box::pin(stream::pending()));
+            let once = group.insert(Box::pin(stream::once(1)));
+
+            let stall = Duration::from_millis(50);
+            let overdue = Duration::from_millis(10);
+            let mut group = group.watchdog(stall, overdue, async_io::Timer::after);
+            assert_eq!(group.next().await, Some(WatchdogEvent::Item(once, 1)));
+            assert_eq!(group.next().await, Some(WatchdogEvent::Finished(once)));
+            assert_eq!(group.next().await, Some(WatchdogEvent::Overdue(pending)));
+            assert_eq!(group.next().await, Some(WatchdogEvent::Stalled));
+
+            assert!(group.remove(pending));
+            assert_eq!(group.next().await, None);
+        });
+    }
+
+    #[test]
+    fn keyed_into_inner() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::new();
+            let a = group.insert(stream::once(1));
+            let b = group.insert(stream::once(2));
+
+            let mut keyed = group.keyed();
+            assert_eq!(keyed.next().await, Some((a, 1)));
+            let mut group = keyed.into_inner();
+            assert!(group.contains_key(b));
+        });
+    }
+
+    #[test]
+    fn clear() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::with_capacity(4);
+            let key = group.insert(stream::once(1));
+            group.insert(stream::once(2));
+            assert_eq!(group.next().await, Some(1));
+
+            group.clear();
+            assert!(group.is_empty());
+            assert!(!group.contains_key(key));
+            assert_eq!(group.capacity(), 4);
+            assert_eq!(group.next().await, None);
+
+            group.insert(stream::once(3));
+            assert_eq!(group.next().await, Some(3));
+        });
+    }
+
+    #[test]
+    fn drain() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::with_capacity(4);
+            let key = group.insert(stream::once(1));
+            group.insert(stream::once(2));
+
+            let other: StreamGroup<_> = group.drain().collect();
+            assert!(group.is_empty());
+            assert!(!group.contains_key(key));
+            assert_eq!(group.capacity(), 4);
+            assert_eq!(group.next().await, None);
+
+            let out: Vec<_> = other.collect().await;
+            assert_eq!(out, [1, 2]);
+
+            // The group keeps working after draining.
+            assert_eq!(group.insert(stream::once(3)), key);
+            assert_eq!(group.next().await, Some(3));
+        });
+    }
+
+    #[test]
+    fn retain() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::new();
+            for n in 0..6 {
+                group.insert(stream::once(n));
+            }
+            group.retain(|_key, _stream| true);
+            assert_eq!(group.len(), 6);
+
+            let mut count = 0;
+            group.retain(|_key, _stream| {
+                count += 1;
+                count % 2 == 0
+            });
+            assert_eq!(group.len(), 3);
+
+            let mut out: Vec<_> = group.collect().await;
+            out.sort();
+            assert_eq!(out, [1, 3, 5]);
+        });
+    }
+
+    #[test]
+    fn shrink_keeps_occupied_chunks() {
+        futures_lite::future::block_on(async {
+            // All entries share a single chunk, which is still in use.
+            let mut group = StreamGroup::with_capacity(10);
+            group.insert(stream::once(1));
+            group.shrink_to_fit();
+            assert_eq!(group.capacity(), 10);
+
+            for n in 2..=10 {
+                group.insert(stream::once(n));
+            }
+            assert_eq!(group.capacity(), 10);
+            let mut out = 0;
+            while let Some(num) = group.next().await {
+                out += num;
+            }
+            assert_eq!(out, 55);
+        });
+    }
+
+    #[test]
+    fn shrink_after_burst() {
+        futures_lite::future::block_on(async {
+            let mut group = StreamGroup::new();
+            let first = group.insert(stream::once(1).boxed());
+            let keys: Vec<_> = (0..100)
+                .map(|_| group.insert(stream::pending().boxed()))
+                .collect();
+            assert!(group.capacity() >= 101);
+            for key in keys {
+                group.remove(key);
+            }
+
+            group.shrink_to_fit();
+            assert_eq!(group.capacity(), 1);
+            assert!(group.contains_key(first));
+
+            // The group keeps working after shrinking.
+            group.insert(stream::once(2).boxed());
+            let mut out = 0;
+            while let Some(num) = group.next().await {
+                out += num;
+            }
+            assert_eq!(out, 3);
+        });
+    }
 }
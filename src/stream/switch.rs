@@ -0,0 +1,133 @@
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+use super::IntoStream;
+
+/// Flatten a stream of streams, only yielding items from the most recent
+/// inner stream.
+///
+/// This `struct` is created by the [`switch`] method on [`StreamExt`]. See its
+/// documentation for more.
+///
+/// [`switch`]: crate::stream::StreamExt::switch
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct Switch<S>
+where
+    S: Stream,
+    S::Item: IntoStream,
+{
+    #[pin]
+    stream: S,
+    #[pin]
+    current: Option<<S::Item as IntoStream>::IntoStream>,
+    done: bool,
+}
+
+impl<S> Switch<S>
+where
+    S: Stream,
+    S::Item: IntoStream,
+{
+    pub(crate) fn new(stream: S) -> Self {
+        Switch {
+            stream,
+            current: None,
+            done: false,
+        }
+    }
+}
+
+impl<S> fmt::Debug for Switch<S>
+where
+    S: Stream + fmt::Debug,
+    S::Item: IntoStream,
+    <S::Item as IntoStream>::IntoStream: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Switch")
+            .field("stream", &self.stream)
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+impl<S> Stream for Switch<S>
+where
+    S: Stream,
+    S::Item: IntoStream,
+{
+    type Item = <S::Item as IntoStream>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        // Pull in the most recent inner stream, dropping the previous one.
+        while !*this.done {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(stream)) => this.current.set(Some(stream.into_stream())),
+                Poll::Ready(None) => *this.done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if let Some(current) = this.current.as_mut().as_pin_mut() {
+            match current.poll_next(cx) {
+                Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                Poll::Ready(None) => this.current.set(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        match *this.done {
+            true => Poll::Ready(None),
+            false => Poll::Pending,
+        }
+    }
+}
+
+impl<S> FusedStream for Switch<S>
+where
+    S: Stream,
+    S::Item: IntoStream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done && self.current.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn only_yields_from_latest() {
+        block_on(async {
+            let streams = stream::iter([stream::repeat(1).take(3), stream::repeat(2).take(3)]);
+            let mut s = streams.switch();
+
+            let mut out = [0; 3];
+            for slot in out.iter_mut() {
+                *slot = s.next().await.unwrap();
+            }
+            assert_eq!(out, [2, 2, 2]);
+            assert_eq!(s.next().await, None);
+        })
+    }
+
+    #[test]
+    fn empty() {
+        block_on(async {
+            let mut s = stream::empty::<stream::Once<u8>>().switch();
+            assert_eq!(s.next().await, None);
+        })
+    }
+}
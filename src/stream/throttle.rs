@@ -0,0 +1,143 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+use crate::time::Timer;
+use crate::utils::ITEM_BUDGET;
+
+/// A stream which yields at most one item per period of time.
+///
+/// This `struct` is created by the [`throttle`] method on [`StreamExt`]. See its
+/// documentation for more.
+///
+/// [`throttle`]: crate::stream::StreamExt::throttle
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct Throttle<S, T: Timer> {
+    #[pin]
+    stream: S,
+    // Started when an item is yielded; items are dropped until it completes.
+    #[pin]
+    sleep: Option<T::Sleep>,
+    timer: T,
+    duration: Duration,
+    done: bool,
+}
+
+impl<S, T: Timer> Throttle<S, T> {
+    pub(crate) fn new(stream: S, duration: Duration, timer: T) -> Self {
+        Self {
+            stream,
+            sleep: None,
+            timer,
+            duration,
+            done: false,
+        }
+    }
+}
+
+impl<S: fmt::Debug, T: Timer> fmt::Debug for Throttle<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Throttle")
+            .field("stream", &self.stream)
+            .field("duration", &self.duration)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: Stream, T: Timer> Stream for Throttle<S, T> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        let mut taken = 0;
+        loop {
+            // Check whether the period has passed before looking at the next
+            // item, so we don't drop items which should be let through.
+            if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                if sleep.poll(cx).is_ready() {
+                    this.sleep.set(None);
+                }
+            }
+
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) if this.sleep.is_none() => {
+                    this.sleep.set(Some(this.timer.sleep(*this.duration)));
+                    return Poll::Ready(Some(item));
+                }
+                Poll::Ready(Some(_)) => {
+                    // Don't hog the executor while we drop items.
+                    taken += 1;
+                    if taken == ITEM_BUDGET {
+                        cx.waker().wake_by_ref();
+                        return Poll::Pending;
+                    }
+                }
+                Poll::Ready(None) => {
+                    *this.done = true;
+                    this.sleep.set(None);
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (low, high) = self.stream.size_hint();
+        let low = match self.sleep {
+            Some(_) => 0,
+            None => low.min(1),
+        };
+        (low, high)
+    }
+}
+
+impl<S: Stream, T: Timer> FusedStream for Throttle<S, T> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stream::StreamExt as _;
+    use futures_lite::future::{block_on, poll_once};
+    use futures_lite::{stream, StreamExt};
+    use std::time::Duration;
+
+    #[test]
+    fn throttle() {
+        block_on(async {
+            let timer = async_io::Timer::after;
+            let a = stream::iter([1, 2]);
+            let b = stream::iter([3, 4]).wait_until(timer(Duration::from_millis(100)));
+            let s = StreamExt::chain(a, b).throttle(Duration::from_millis(10), timer);
+            let v: Vec<_> = s.collect().await;
+            assert_eq!(v, &[1, 3]);
+        });
+    }
+
+    #[test]
+    fn always_ready() {
+        block_on(async {
+            let mut s =
+                stream::repeat(1).throttle(Duration::from_millis(100), async_io::Timer::after);
+            assert_eq!(s.next().await, Some(1));
+            assert!(poll_once(s.next()).await.is_none());
+        });
+    }
+}
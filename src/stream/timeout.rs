@@ -0,0 +1,138 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+use crate::time::{TimedOut, Timer};
+
+/// A stream which returns an error whenever the underlying stream takes too
+/// long to yield an item.
+///
+/// This `struct` is created by the [`timeout`] method on [`StreamExt`]. See its
+/// documentation for more.
+///
+/// [`timeout`]: crate::stream::StreamExt::timeout
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct Timeout<S, T: Timer> {
+    #[pin]
+    stream: S,
+    // The deadline for the next item. It's created once we start waiting for
+    // an item, and reset every time an item is yielded.
+    #[pin]
+    sleep: Option<T::Sleep>,
+    timer: T,
+    duration: Duration,
+    done: bool,
+}
+
+impl<S, T: Timer> Timeout<S, T> {
+    pub(crate) fn new(stream: S, duration: Duration, timer: T) -> Self {
+        Self {
+            stream,
+            sleep: None,
+            timer,
+            duration,
+            done: false,
+        }
+    }
+}
+
+impl<S: fmt::Debug, T: Timer> fmt::Debug for Timeout<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timeout")
+            .field("stream", &self.stream)
+            .field("duration", &self.duration)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: Stream, T: Timer> Stream for Timeout<S, T> {
+    type Item = Result<S::Item, TimedOut>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                this.sleep.set(None);
+                return Poll::Ready(Some(Ok(item)));
+            }
+            Poll::Ready(None) => {
+                *this.done = true;
+                this.sleep.set(None);
+                return Poll::Ready(None);
+            }
+            Poll::Pending => {}
+        }
+
+        if this.sleep.is_none() {
+            this.sleep.set(Some(this.timer.sleep(*this.duration)));
+        }
+
+        let sleep = this.sleep.as_mut().as_pin_mut().unwrap();
+        if sleep.poll(cx).is_ready() {
+            // Start a new deadline the next time we're polled, so that an
+            // error is returned for every period without an item.
+            this.sleep.set(None);
+            return Poll::Ready(Some(Err(TimedOut::new())));
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, _) = self.stream.size_hint();
+        (low, None)
+    }
+}
+
+impl<S: Stream, T: Timer> FusedStream for Timeout<S, T> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+    use std::time::Duration;
+
+    #[test]
+    fn yields_items() {
+        futures_lite::future::block_on(async {
+            let s = stream::iter([1, 2, 3]);
+            let s = crate::stream::StreamExt::timeout(
+                s,
+                Duration::from_secs(10),
+                async_io::Timer::after,
+            );
+            let v: Vec<_> = s.collect().await;
+            assert_eq!(v, &[Ok(1), Ok(2), Ok(3)]);
+        });
+    }
+
+    #[test]
+    fn times_out_between_items() {
+        futures_lite::future::block_on(async {
+            let s = stream::pending::<()>();
+            let s = crate::stream::StreamExt::timeout(
+                s,
+                Duration::from_millis(10),
+                async_io::Timer::after,
+            );
+            let v: Vec<_> = s.take(2).collect().await;
+            assert!(v.iter().all(|res| res.is_err()));
+            assert_eq!(v.len(), 2);
+        });
+    }
+}
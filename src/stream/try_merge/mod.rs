@@ -0,0 +1,73 @@
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::Stream;
+
+pub(crate) mod array;
+pub(crate) mod tuple;
+#[cfg(feature = "alloc")]
+pub(crate) mod vec;
+
+/// Combines multiple streams of `Result` into a single stream of all their
+/// outputs, ending early on the first error.
+///
+/// Items are yielded as soon as they're received. Once any stream yields an
+/// `Err`, that error is yielded and all other streams are cancelled, after
+/// which the merged stream ends. The output ordering between streams is not
+/// guaranteed.
+///
+/// # Examples
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_lite::stream::{self, StreamExt};
+/// use futures_lite::future::block_on;
+///
+/// block_on(async {
+///     let a = stream::iter(vec![Ok(1), Ok(2)]);
+///     let b = stream::iter(vec![Err("oh no"), Ok(3)]);
+///     let s = [a, b].try_merge();
+///
+///     let buf: Vec<Result<u8, &str>> = s.collect().await;
+///     assert!(buf.contains(&Err("oh no")));
+///     assert!(!buf.contains(&Ok(3)));
+///     assert_eq!(buf.last(), Some(&Err("oh no")));
+/// })
+/// ```
+pub trait TryMerge {
+    /// The resulting output type.
+    type Output;
+
+    /// The resulting error type.
+    type Error;
+
+    /// The stream type.
+    type Stream: Stream<Item = Result<Self::Output, Self::Error>>;
+
+    /// Combine multiple streams into a single stream, ending the stream and
+    /// cancelling all other streams when any stream yields an error.
+    fn try_merge(self) -> Self::Stream;
+}
+
+/// Poll the merged stream, dropping it once it yields an error or is exhausted.
+pub(crate) fn poll_try_merge<S, T, E>(
+    mut inner: Pin<&mut Option<S>>,
+    cx: &mut Context<'_>,
+) -> Poll<Option<Result<T, E>>>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    let Some(stream) = inner.as_mut().as_pin_mut() else {
+        return Poll::Ready(None);
+    };
+    match ready!(stream.poll_next(cx)) {
+        Some(Ok(item)) => Poll::Ready(Some(Ok(item))),
+        Some(Err(err)) => {
+            inner.set(None);
+            Poll::Ready(Some(Err(err)))
+        }
+        None => {
+            inner.set(None);
+            Poll::Ready(None)
+        }
+    }
+}
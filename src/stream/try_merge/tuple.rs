@@ -0,0 +1,112 @@
+use super::TryMerge as TryMergeTrait;
+use crate::stream::merge::tuple::*;
+use crate::stream::{IntoStream, Merge as MergeTrait};
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+macro_rules! impl_try_merge_tuple {
+    ($StructName:ident $MergeName:ident $($F:ident)*) => {
+        /// A stream that merges multiple streams of `Result` into a single
+        /// stream, ending at the first error.
+        ///
+        /// This `struct` is created by the [`try_merge`] method on the [`TryMerge`] trait. See its
+        /// documentation for more.
+        ///
+        /// [`try_merge`]: crate::stream::TryMerge::try_merge
+        /// [`TryMerge`]: crate::stream::TryMerge
+        #[pin_project::pin_project]
+        pub struct $StructName<T, Err, $($F),*>
+        where $(
+            $F: Stream<Item = Result<T, Err>>,
+        )* {
+            #[pin] inner: Option<$MergeName<Result<T, Err>, $($F),*>>,
+        }
+
+        impl<T, Err, $($F),*> fmt::Debug for $StructName<T, Err, $($F),*>
+        where
+            $( $F: Stream<Item = Result<T, Err>> + fmt::Debug, )*
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("TryMerge").field(&self.inner).finish()
+            }
+        }
+
+        impl<T, Err, $($F),*> Stream for $StructName<T, Err, $($F),*>
+        where $(
+            $F: Stream<Item = Result<T, Err>>,
+        )* {
+            type Item = Result<T, Err>;
+
+            fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+                super::poll_try_merge(self.project().inner, cx)
+            }
+        }
+
+        impl<T, Err, $($F),*> FusedStream for $StructName<T, Err, $($F),*>
+        where $(
+            $F: Stream<Item = Result<T, Err>>,
+        )* {
+            fn is_terminated(&self) -> bool {
+                self.inner.is_none()
+            }
+        }
+
+        impl<T, Err, $($F),*> TryMergeTrait for ($($F,)*)
+        where $(
+            $F: IntoStream<Item = Result<T, Err>>,
+        )* {
+            type Output = T;
+            type Error = Err;
+            type Stream = $StructName<T, Err, $($F::IntoStream),*>;
+
+            fn try_merge(self) -> Self::Stream {
+                $StructName { inner: Some(MergeTrait::merge(self)) }
+            }
+        }
+    };
+}
+
+impl_try_merge_tuple! { TryMerge1 Merge1 A }
+impl_try_merge_tuple! { TryMerge2 Merge2 A B }
+impl_try_merge_tuple! { TryMerge3 Merge3 A B C }
+impl_try_merge_tuple! { TryMerge4 Merge4 A B C D }
+impl_try_merge_tuple! { TryMerge5 Merge5 A B C D E }
+impl_try_merge_tuple! { TryMerge6 Merge6 A B C D E F }
+impl_try_merge_tuple! { TryMerge7 Merge7 A B C D E F G }
+impl_try_merge_tuple! { TryMerge8 Merge8 A B C D E F G H }
+impl_try_merge_tuple! { TryMerge9 Merge9 A B C D E F G H I }
+impl_try_merge_tuple! { TryMerge10 Merge10 A B C D E F G H I J }
+impl_try_merge_tuple! { TryMerge11 Merge11 A B C D E F G H I J K }
+impl_try_merge_tuple! { TryMerge12 Merge12 A B C D E F G H I J K L }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn try_merge_tuple_3() {
+        block_on(async {
+            let a = stream::once(Ok(1));
+            let b = stream::once(Ok(2));
+            let c = stream::once(Err("oh no"));
+            let mut s = (a, b, c).try_merge();
+
+            let mut counter = 0;
+            let mut errors = 0;
+            while let Some(item) = s.next().await {
+                match item {
+                    Ok(n) => counter += n,
+                    Err(_) => errors += 1,
+                }
+            }
+            assert_eq!(errors, 1);
+            assert!(counter <= 3);
+        })
+    }
+}
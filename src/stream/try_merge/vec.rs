@@ -0,0 +1,118 @@
+use super::TryMerge as TryMergeTrait;
+use crate::stream::merge::vec::Merge;
+use crate::stream::IntoStream;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+/// A stream that merges multiple streams of `Result` into a single stream,
+/// ending at the first error.
+///
+/// This `struct` is created by the [`try_merge`] method on the [`TryMerge`] trait. See its
+/// documentation for more.
+///
+/// [`try_merge`]: crate::stream::TryMerge::try_merge
+/// [`TryMerge`]: crate::stream::TryMerge
+#[pin_project::pin_project]
+pub struct TryMerge<S>
+where
+    S: Stream,
+{
+    #[pin]
+    inner: Option<Merge<S>>,
+}
+
+impl<S> fmt::Debug for TryMerge<S>
+where
+    S: Stream + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TryMerge").field(&self.inner).finish()
+    }
+}
+
+impl<S, T, E> Stream for TryMerge<S>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        super::poll_try_merge(self.project().inner, cx)
+    }
+}
+
+impl<S, T, E> FusedStream for TryMerge<S>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_none()
+    }
+}
+
+impl<S, T, E> TryMergeTrait for Vec<S>
+where
+    S: IntoStream<Item = Result<T, E>>,
+{
+    type Output = T;
+    type Error = E;
+    type Stream = TryMerge<S::IntoStream>;
+
+    fn try_merge(self) -> Self::Stream {
+        TryMerge {
+            inner: Some(Merge::new(
+                self.into_iter().map(|i| i.into_stream()).collect(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use futures_lite::future::block_on;
+    use futures_lite::prelude::*;
+    use futures_lite::stream;
+
+    #[test]
+    fn all_ok() {
+        block_on(async {
+            let a = stream::repeat(Ok::<_, ()>(1)).take(2);
+            let b = stream::repeat(Ok(2)).take(2);
+            let mut s = vec![a, b].try_merge();
+
+            let mut counter = 0;
+            while let Some(n) = s.next().await {
+                counter += n.unwrap();
+            }
+            assert_eq!(counter, 6);
+        })
+    }
+
+    #[test]
+    fn ends_on_error() {
+        block_on(async {
+            let a = stream::repeat(Ok(1));
+            let b = stream::once(Err("oh no"));
+            let mut s = vec![a.boxed(), b.boxed()].try_merge();
+
+            loop {
+                match s.next().await {
+                    Some(Ok(n)) => assert_eq!(n, 1),
+                    Some(Err(err)) => break assert_eq!(err, "oh no"),
+                    None => panic!("stream ended before the error"),
+                }
+            }
+            assert!(s.next().await.is_none());
+            assert!(s.next().await.is_none());
+        })
+    }
+}
@@ -0,0 +1,119 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+use pin_project::pin_project;
+
+/// End a stream once a deadline resolves.
+///
+/// This `struct` is created by the [`until`] method on [`StreamExt`]. See its
+/// documentation for more.
+///
+/// [`until`]: crate::stream::StreamExt::until
+/// [`StreamExt`]: crate::stream::StreamExt
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[pin_project]
+pub struct Until<S, D> {
+    #[pin]
+    stream: S,
+    #[pin]
+    deadline: D,
+    done: bool,
+}
+
+impl<S, D> Until<S, D> {
+    pub(crate) fn new(stream: S, deadline: D) -> Self {
+        Self {
+            stream,
+            deadline,
+            done: false,
+        }
+    }
+}
+
+impl<S, D> Stream for Until<S, D>
+where
+    S: Stream,
+    D: Future,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        // Check the deadline first, so that a stream which is always ready
+        // can still be stopped.
+        if this.deadline.poll(cx).is_ready() {
+            *this.done = true;
+            return Poll::Ready(None);
+        }
+
+        match this.stream.poll_next(cx) {
+            Poll::Ready(None) => {
+                *this.done = true;
+                Poll::Ready(None)
+            }
+            other => other,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        (0, self.stream.size_hint().1)
+    }
+}
+
+impl<S, D> FusedStream for Until<S, D>
+where
+    S: Stream,
+    D: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::future::FutureGroup;
+    use crate::sync::StopSource;
+    use core::future::Future;
+    use core::pin::Pin;
+    use futures_lite::{stream, StreamExt};
+
+    #[test]
+    fn stops_early() {
+        futures_lite::future::block_on(async {
+            let source = StopSource::new();
+            let token = source.token();
+            let mut s = crate::stream::StreamExt::until(stream::repeat(1), token);
+
+            assert_eq!(s.next().await, Some(1));
+            source.stop();
+            assert_eq!(s.next().await, None);
+            assert_eq!(s.next().await, None);
+        });
+    }
+
+    #[test]
+    fn groups() {
+        futures_lite::future::block_on(async {
+            let source = StopSource::new();
+            let mut group: FutureGroup<Pin<Box<dyn Future<Output = u8>>>> = FutureGroup::new();
+            group.insert(Box::pin(async { 1 }));
+            group.insert(Box::pin(core::future::pending()));
+
+            let mut s = crate::stream::StreamExt::until(group, source.token());
+            assert_eq!(s.next().await, Some(1));
+            source.stop();
+            assert_eq!(s.next().await, None);
+        });
+    }
+}
@@ -0,0 +1,280 @@
+use crate::utils::Mutex;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+
+const LEFT: usize = 0;
+const RIGHT: usize = 1;
+
+/// Create the two halves of an unzipped stream.
+pub(crate) fn unzip<S, A, B>(
+    stream: S,
+    capacity: usize,
+) -> (UnzipLeft<S, A, B>, UnzipRight<S, A, B>)
+where
+    S: Stream<Item = (A, B)>,
+{
+    assert!(capacity > 0, "unzip capacity must be non-zero");
+    let wakers = Arc::new(UnzipWaker::default());
+    let shared = Arc::new(Mutex::new(Shared {
+        stream: Box::pin(stream),
+        left: VecDeque::new(),
+        right: VecDeque::new(),
+        capacity,
+        waker: Waker::from(wakers.clone()),
+        wakers,
+        dropped: [false; 2],
+        done: false,
+    }));
+    let left = UnzipLeft {
+        shared: shared.clone(),
+    };
+    let right = UnzipRight { shared };
+    (left, right)
+}
+
+/// A waker which wakes both halves of an unzipped stream.
+#[derive(Debug, Default)]
+struct UnzipWaker {
+    wakers: Mutex<[Option<Waker>; 2]>,
+}
+
+impl UnzipWaker {
+    fn register(&self, side: usize, waker: &Waker) {
+        let mut wakers = self.wakers.lock();
+        match &wakers[side] {
+            Some(existing) if existing.will_wake(waker) => {}
+            _ => wakers[side] = Some(waker.clone()),
+        }
+    }
+
+    fn wake_side(&self, side: usize) {
+        if let Some(waker) = self.wakers.lock()[side].take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Wake for UnzipWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let wakers = core::mem::take(&mut *self.wakers.lock());
+        for waker in wakers.into_iter().flatten() {
+            waker.wake();
+        }
+    }
+}
+
+/// The state shared between both halves of an unzipped stream.
+struct Shared<S, A, B> {
+    stream: Pin<Box<S>>,
+    left: VecDeque<A>,
+    right: VecDeque<B>,
+    capacity: usize,
+    waker: Waker,
+    wakers: Arc<UnzipWaker>,
+    dropped: [bool; 2],
+    done: bool,
+}
+
+impl<S, A, B> Shared<S, A, B>
+where
+    S: Stream<Item = (A, B)>,
+{
+    /// Poll the inner stream until an item is available for `side`.
+    ///
+    /// Items for the other half are buffered. If the other half's buffer is
+    /// full we stop polling until it has made progress.
+    fn poll_side<T>(
+        &mut self,
+        side: usize,
+        cx: &mut Context<'_>,
+        pop: impl Fn(&mut Self) -> Option<T>,
+    ) -> Poll<Option<T>> {
+        if let Some(item) = pop(self) {
+            // We've just made room in our buffer, so the other half may continue.
+            self.wakers.wake_side(1 - side);
+            return Poll::Ready(Some(item));
+        }
+
+        self.wakers.register(side, cx.waker());
+        loop {
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            let other_len = match side {
+                LEFT => self.right.len(),
+                _ => self.left.len(),
+            };
+            if other_len >= self.capacity {
+                // Wait for the other half to make room.
+                return Poll::Pending;
+            }
+
+            let mut cx = Context::from_waker(&self.waker);
+            match self.stream.as_mut().poll_next(&mut cx) {
+                Poll::Ready(Some((a, b))) => {
+                    if !self.dropped[LEFT] {
+                        self.left.push_back(a);
+                    }
+                    if !self.dropped[RIGHT] {
+                        self.right.push_back(b);
+                    }
+                    self.wakers.wake_side(1 - side);
+                    if let Some(item) = pop(self) {
+                        return Poll::Ready(Some(item));
+                    }
+                }
+                Poll::Ready(None) => {
+                    self.done = true;
+                    self.wakers.wake_side(1 - side);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<S, A, B> Shared<S, A, B> {
+    /// Mark one half as dropped, discarding its buffered items.
+    fn drop_side(&mut self, side: usize) {
+        self.dropped[side] = true;
+        match side {
+            LEFT => self.left.clear(),
+            _ => self.right.clear(),
+        }
+        self.wakers.wake_side(1 - side);
+    }
+}
+
+/// The first half of an unzipped stream.
+///
+/// This `struct` is created by the [`unzip_buffered`] method on [`StreamExt`]. See its
+/// documentation for more.
+///
+/// [`unzip_buffered`]: crate::stream::StreamExt::unzip_buffered
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+pub struct UnzipLeft<S, A, B> {
+    shared: Arc<Mutex<Shared<S, A, B>>>,
+}
+
+/// The second half of an unzipped stream.
+///
+/// This `struct` is created by the [`unzip_buffered`] method on [`StreamExt`]. See its
+/// documentation for more.
+///
+/// [`unzip_buffered`]: crate::stream::StreamExt::unzip_buffered
+/// [`StreamExt`]: crate::stream::StreamExt
+#[must_use = "streams do nothing unless polled or .awaited"]
+pub struct UnzipRight<S, A, B> {
+    shared: Arc<Mutex<Shared<S, A, B>>>,
+}
+
+impl<S, A, B> fmt::Debug for UnzipLeft<S, A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnzipLeft").finish_non_exhaustive()
+    }
+}
+
+impl<S, A, B> fmt::Debug for UnzipRight<S, A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnzipRight").finish_non_exhaustive()
+    }
+}
+
+impl<S, A, B> Stream for UnzipLeft<S, A, B>
+where
+    S: Stream<Item = (A, B)>,
+{
+    type Item = A;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.shared
+            .lock()
+            .poll_side(LEFT, cx, |shared| shared.left.pop_front())
+    }
+}
+
+impl<S, A, B> Stream for UnzipRight<S, A, B>
+where
+    S: Stream<Item = (A, B)>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.shared
+            .lock()
+            .poll_side(RIGHT, cx, |shared| shared.right.pop_front())
+    }
+}
+
+impl<S, A, B> Drop for UnzipLeft<S, A, B> {
+    fn drop(&mut self) {
+        self.shared.lock().drop_side(LEFT);
+    }
+}
+
+impl<S, A, B> Drop for UnzipRight<S, A, B> {
+    fn drop(&mut self) {
+        self.shared.lock().drop_side(RIGHT);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::future::Join;
+    use crate::stream::StreamExt as _;
+    use futures_lite::future::block_on;
+    use futures_lite::stream;
+
+    #[test]
+    fn unzip_concurrently() {
+        block_on(async {
+            let s = stream::iter((0..10).map(|n| (n, n * 2)));
+            let (mut left, mut right) = s.unzip_buffered(2);
+
+            let left = async {
+                let mut out = vec![];
+                while let Some(n) = futures_lite::StreamExt::next(&mut left).await {
+                    out.push(n);
+                }
+                out
+            };
+            let right = async {
+                let mut out = vec![];
+                while let Some(n) = futures_lite::StreamExt::next(&mut right).await {
+                    out.push(n);
+                }
+                out
+            };
+            let (left, right) = (left, right).join().await;
+            assert_eq!(left, (0..10).collect::<Vec<_>>());
+            assert_eq!(right, (0..10).map(|n| n * 2).collect::<Vec<_>>());
+        })
+    }
+
+    #[test]
+    fn dropped_half() {
+        block_on(async {
+            let s = stream::iter((0..10).map(|n| (n, n)));
+            let (mut left, right) = s.unzip_buffered(1);
+            drop(right);
+
+            let mut count = 0;
+            while futures_lite::StreamExt::next(&mut left).await.is_some() {
+                count += 1;
+            }
+            assert_eq!(count, 10);
+        })
+    }
+}
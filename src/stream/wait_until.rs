@@ -2,7 +2,7 @@ use core::future::Future;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::stream::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::pin_project;
 
 /// Delay execution of a stream once for the specified duration.
@@ -61,3 +61,13 @@ where
         }
     }
 }
+
+impl<S, D> FusedStream for WaitUntil<S, D>
+where
+    S: FusedStream,
+    D: Future,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
@@ -8,7 +8,7 @@ use core::mem::{self, MaybeUninit};
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::{pin_project, pinned_drop};
 
 /// A stream that ‘zips up’ multiple streams into a single stream of pairs.
@@ -46,6 +46,15 @@ where
     }
 }
 
+impl<S, const N: usize> FusedStream for Zip<S, N>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
 impl<S, const N: usize> fmt::Debug for Zip<S, N>
 where
     S: Stream + fmt::Debug,
@@ -78,7 +87,7 @@ where
                 continue;
             }
 
-            // unlock readiness so we don't deadlock when polling
+            // release readiness so we can borrow the wakers when polling
             #[allow(clippy::drop_non_drop)]
             drop(readiness);
 
@@ -117,11 +126,28 @@ where
                 Poll::Pending => {}
             }
 
-            // Lock readiness so we can use it again
+            // Reacquire readiness so we can use it again
             readiness = this.wakers.readiness();
         }
         Poll::Pending
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return utils::size_hint::EMPTY;
+        }
+
+        // Items which have already been taken out of a stream are still
+        // waiting to be yielded as part of the next set.
+        let hints = self
+            .streams
+            .iter()
+            .zip(self.state.iter())
+            .map(|(stream, state)| {
+                utils::size_hint::add(stream.size_hint(), usize::from(state.is_ready()))
+            });
+        utils::size_hint::min(hints)
+    }
 }
 
 /// Drop the already initialized values on cancellation.
@@ -176,6 +202,31 @@ mod tests {
     use futures_lite::prelude::*;
     use futures_lite::stream;
 
+    #[test]
+    fn zip_size_hint() {
+        block_on(async {
+            let a = stream::iter(vec![1, 2, 3]);
+            let b = stream::iter(vec![4, 5]);
+            let mut s = [a, b].zip();
+
+            assert_eq!(s.size_hint(), (2, Some(2)));
+            assert_eq!(s.next().await, Some([1, 4]));
+            assert_eq!(s.size_hint(), (1, Some(1)));
+        })
+    }
+
+    #[test]
+    fn zip_is_terminated() {
+        use futures_core::stream::FusedStream;
+
+        block_on(async {
+            let mut s = [stream::once(1), stream::once(2)].zip();
+            assert!(!s.is_terminated());
+            while s.next().await.is_some() {}
+            assert!(s.is_terminated());
+        })
+    }
+
     #[test]
     fn zip_array_3() {
         block_on(async {
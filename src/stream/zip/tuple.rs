@@ -3,10 +3,10 @@ use core::mem::MaybeUninit;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 
 use super::Zip;
-use crate::utils::{PollArray, WakerArray};
+use crate::utils::{self, PollArray, WakerArray};
 
 macro_rules! impl_zip_for_tuple {
     ($mod_name: ident $StructName: ident $($F: ident)+) => {
@@ -93,7 +93,7 @@ macro_rules! impl_zip_for_tuple {
                         continue;
                     }
 
-                    // unlock readiness so we don't deadlock when polling
+                    // release readiness so we can borrow the wakers when polling
                     #[allow(clippy::drop_non_drop)]
                     drop(readiness);
 
@@ -147,12 +147,37 @@ macro_rules! impl_zip_for_tuple {
                         }
                     }
 
-                    // Lock readiness so we can use it again
+                    // Reacquire readiness so we can use it again
                     readiness = this.wakers.readiness();
                 }
 
                 Poll::Pending
             }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.done {
+                    return utils::size_hint::EMPTY;
+                }
+
+                // Items which have already been taken out of a stream are still
+                // waiting to be yielded as part of the next set.
+                let hints = [$(
+                    utils::size_hint::add(
+                        self.$F.size_hint(),
+                        usize::from(self.state[$mod_name::$F].is_ready()),
+                    ),
+                )+];
+                utils::size_hint::min(hints)
+            }
+        }
+
+        impl<$($F,)+> FusedStream for $StructName<$($F,)+>
+        where
+            $($F: Stream,)+
+        {
+            fn is_terminated(&self) -> bool {
+                self.done
+            }
         }
 
         impl<$($F,)+> Zip for ($($F,)+)
@@ -208,6 +233,30 @@ impl_zip_for_tuple! { zip_9 Zip9 A B C D E F G H I }
 impl_zip_for_tuple! { zip_10 Zip10 A B C D E F G H I J }
 impl_zip_for_tuple! { zip_11 Zip11 A B C D E F G H I J K }
 impl_zip_for_tuple! { zip_12 Zip12 A B C D E F G H I J K L }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_13 Zip13 A B C D E F G H I J K L M }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_14 Zip14 A B C D E F G H I J K L M N }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_15 Zip15 A B C D E F G H I J K L M N O }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_16 Zip16 A B C D E F G H I J K L M N O P }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_17 Zip17 A B C D E F G H I J K L M N O P Q }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_18 Zip18 A B C D E F G H I J K L M N O P Q R }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_19 Zip19 A B C D E F G H I J K L M N O P Q R U }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_20 Zip20 A B C D E F G H I J K L M N O P Q R U V }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_21 Zip21 A B C D E F G H I J K L M N O P Q R U V W }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_22 Zip22 A B C D E F G H I J K L M N O P Q R U V W X }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_23 Zip23 A B C D E F G H I J K L M N O P Q R U V W X Y }
+#[cfg(feature = "large-tuples")]
+impl_zip_for_tuple! { zip_24 Zip24 A B C D E F G H I J K L M N O P Q R U V W X Y Z }
 
 #[cfg(test)]
 mod tests {
@@ -2,6 +2,8 @@ use super::Zip as ZipTrait;
 use crate::stream::IntoStream;
 use crate::utils::{self, PollVec, WakerVec};
 #[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
 use alloc::vec::Vec;
 
 use core::fmt;
@@ -10,7 +12,7 @@ use core::mem::MaybeUninit;
 use core::pin::Pin;
 use core::task::{Context, Poll};
 
-use futures_core::Stream;
+use futures_core::stream::{FusedStream, Stream};
 use pin_project::{pin_project, pinned_drop};
 
 /// A stream that ‘zips up’ multiple streams into a single stream of pairs.
@@ -51,6 +53,15 @@ where
     }
 }
 
+impl<S> FusedStream for Zip<S>
+where
+    S: Stream,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
 impl<S> fmt::Debug for Zip<S>
 where
     S: Stream + fmt::Debug,
@@ -83,7 +94,7 @@ where
                 continue;
             }
 
-            // unlock readiness so we don't deadlock when polling
+            // release readiness so we can borrow the wakers when polling
             #[allow(clippy::drop_non_drop)]
             drop(readiness);
 
@@ -122,11 +133,28 @@ where
                 Poll::Pending => {}
             }
 
-            // Lock readiness so we can use it again
+            // Reacquire readiness so we can use it again
             readiness = this.wakers.readiness();
         }
         Poll::Pending
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return utils::size_hint::EMPTY;
+        }
+
+        // Items which have already been taken out of a stream are still
+        // waiting to be yielded as part of the next set.
+        let hints = self
+            .streams
+            .iter()
+            .zip(self.state.iter())
+            .map(|(stream, state)| {
+                utils::size_hint::add(stream.size_hint(), usize::from(state.is_ready()))
+            });
+        utils::size_hint::min(hints)
+    }
 }
 
 /// Drop the already initialized values on cancellation.
@@ -173,6 +201,39 @@ unsafe fn vec_assume_init<T>(vec: Vec<MaybeUninit<T>>) -> Vec<T> {
     ret
 }
 
+impl<S> ZipTrait for Box<[S]>
+where
+    S: IntoStream,
+{
+    type Item = <Zip<S::IntoStream> as Stream>::Item;
+    type Stream = Zip<S::IntoStream>;
+
+    fn zip(self) -> Self::Stream {
+        Vec::from(self).zip()
+    }
+}
+
+impl<S> ZipTrait for VecDeque<S>
+where
+    S: IntoStream,
+{
+    type Item = <Zip<S::IntoStream> as Stream>::Item;
+    type Stream = Zip<S::IntoStream>;
+
+    fn zip(self) -> Self::Stream {
+        Vec::from(self).zip()
+    }
+}
+
+impl<S> FromIterator<S> for Zip<S::IntoStream>
+where
+    S: IntoStream,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().zip()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec;
@@ -182,6 +243,19 @@ mod tests {
     use futures_lite::prelude::*;
     use futures_lite::stream;
 
+    #[test]
+    fn zip_other_collections() {
+        block_on(async {
+            let boxed: Box<[_]> = vec![stream::once(1), stream::once(2)].into();
+            let deque: super::VecDeque<_> = vec![stream::once(3), stream::once(4)].into();
+            let collected: super::Zip<_> = [stream::once(5), stream::once(6)].into_iter().collect();
+
+            assert_eq!(boxed.zip().next().await, Some(vec![1, 2]));
+            assert_eq!(deque.zip().next().await, Some(vec![3, 4]));
+            assert_eq!(collected.collect::<Vec<_>>().await, vec![vec![5, 6]]);
+        })
+    }
+
     #[test]
     fn zip_array_3() {
         block_on(async {
@@ -0,0 +1,10 @@
+//! Synchronization primitives for coordinating concurrent work.
+//!
+//! These primitives don't depend on any particular runtime, and are designed
+//! to be used together with the concurrency operations in this crate.
+
+pub use stop_token::{StopSource, StopToken};
+pub use wait_group::{Wait, WaitGroup, WaitGuard};
+
+mod stop_token;
+mod wait_group;
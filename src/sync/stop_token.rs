@@ -0,0 +1,192 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use slab::Slab;
+use std::sync::Arc;
+
+use crate::utils::Mutex;
+
+/// The shared state between a [`StopSource`] and its tokens.
+#[derive(Debug, Default)]
+struct Inner {
+    stopped: bool,
+    wakers: Slab<Waker>,
+}
+
+/// Signals cancellation to all of its [`StopToken`]s.
+///
+/// Dropping the source stops its tokens as well, so work which depends on a
+/// source can't outlive it.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_concurrency::sync::StopSource;
+/// use futures_lite::{stream, StreamExt};
+///
+/// # futures_lite::future::block_on(async {
+/// let source = StopSource::new();
+/// let token = source.token();
+///
+/// source.stop();
+/// let items: Vec<_> = stream::repeat(1).until(token).collect().await;
+/// assert!(items.is_empty());
+/// # });
+/// ```
+pub struct StopSource {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl StopSource {
+    /// Create a new `StopSource`.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Inner::default())),
+        }
+    }
+
+    /// Create a new token which completes once this source is stopped.
+    pub fn token(&self) -> StopToken {
+        StopToken {
+            inner: self.inner.clone(),
+            key: None,
+        }
+    }
+
+    /// Stop all tokens created from this source.
+    pub fn stop(&self) {
+        let mut inner = self.inner.lock();
+        inner.stopped = true;
+        for waker in inner.wakers.drain() {
+            waker.wake();
+        }
+    }
+
+    /// Returns `true` if this source has been stopped.
+    pub fn is_stopped(&self) -> bool {
+        self.inner.lock().stopped
+    }
+}
+
+impl Default for StopSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for StopSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StopSource")
+            .field("stopped", &self.is_stopped())
+            .finish()
+    }
+}
+
+impl Drop for StopSource {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// A future which completes once its [`StopSource`] has been stopped.
+///
+/// Because it's a future, a token can be passed as the deadline to any
+/// operation which takes one, such as [`FutureExt::until`],
+/// [`StreamExt::until`] or [`ConcurrentStream::until`].
+///
+/// [`FutureExt::until`]: crate::future::FutureExt::until
+/// [`StreamExt::until`]: crate::stream::StreamExt::until
+/// [`ConcurrentStream::until`]: crate::concurrent_stream::ConcurrentStream::until
+#[must_use = "futures do nothing unless polled or .awaited"]
+pub struct StopToken {
+    inner: Arc<Mutex<Inner>>,
+    // The slot our waker is registered under, if any.
+    key: Option<usize>,
+}
+
+impl StopToken {
+    /// Returns `true` if the source of this token has been stopped.
+    pub fn is_stopped(&self) -> bool {
+        self.inner.lock().stopped
+    }
+}
+
+impl Clone for StopToken {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            key: None,
+        }
+    }
+}
+
+impl fmt::Debug for StopToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StopToken")
+            .field("stopped", &self.is_stopped())
+            .finish()
+    }
+}
+
+impl Future for StopToken {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let mut inner = this.inner.lock();
+        if inner.stopped {
+            // Our waker was removed when the source was stopped.
+            this.key = None;
+            return Poll::Ready(());
+        }
+
+        match this.key.and_then(|key| inner.wakers.get_mut(key)) {
+            Some(waker) => waker.clone_from(cx.waker()),
+            None => this.key = Some(inner.wakers.insert(cx.waker().clone())),
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for StopToken {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            let mut inner = self.inner.lock();
+            if !inner.stopped {
+                inner.wakers.try_remove(key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::future::Join;
+
+    #[test]
+    fn stop_wakes_tokens() {
+        futures_lite::future::block_on(async {
+            let source = StopSource::new();
+            let a = source.token();
+            let b = a.clone();
+            assert!(!a.is_stopped());
+
+            let stop = async { source.stop() };
+            (a, b, stop).join().await;
+        });
+    }
+
+    #[test]
+    fn drop_stops_tokens() {
+        futures_lite::future::block_on(async {
+            let source = StopSource::new();
+            let token = source.token();
+            drop(source);
+            assert!(token.is_stopped());
+            token.await;
+        });
+    }
+}
@@ -0,0 +1,224 @@
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use slab::Slab;
+use std::sync::Arc;
+
+use crate::utils::Mutex;
+
+/// The shared state between a [`WaitGroup`], its guards and its waiters.
+#[derive(Debug, Default)]
+struct Inner {
+    guards: usize,
+    wakers: Slab<Waker>,
+}
+
+/// Wait for a set of futures to drop their guards.
+///
+/// Each future which needs to be waited on holds a [`WaitGuard`]. Once all
+/// guards have been dropped, the futures returned by [`WaitGroup::wait`]
+/// complete. This pairs well with a [`FutureGroup`] whose items are polled
+/// without observing their completion one by one.
+///
+/// [`FutureGroup`]: crate::future::FutureGroup
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_concurrency::sync::WaitGroup;
+///
+/// # futures_lite::future::block_on(async {
+/// let wg = WaitGroup::new();
+///
+/// let a = {
+///     let guard = wg.guard();
+///     async move {
+///         // do some work while holding the guard
+///         drop(guard);
+///     }
+/// };
+/// let b = {
+///     let guard = wg.guard();
+///     async move { drop(guard) }
+/// };
+///
+/// (a, b, wg.wait()).join().await;
+/// assert_eq!(wg.count(), 0);
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct WaitGroup {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl WaitGroup {
+    /// Create a new `WaitGroup`.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Inner::default())),
+        }
+    }
+
+    /// Create a new guard which the group will wait for.
+    pub fn guard(&self) -> WaitGuard {
+        self.inner.lock().guards += 1;
+        WaitGuard {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Returns the number of guards which are still alive.
+    pub fn count(&self) -> usize {
+        self.inner.lock().guards
+    }
+
+    /// Wait until all guards have been dropped.
+    ///
+    /// This completes immediately if there are no guards. Guards created
+    /// while waiting are waited for too.
+    pub fn wait(&self) -> Wait {
+        Wait {
+            inner: self.inner.clone(),
+            key: None,
+        }
+    }
+}
+
+impl Default for WaitGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for WaitGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WaitGroup")
+            .field("count", &self.count())
+            .finish()
+    }
+}
+
+/// A guard which keeps its [`WaitGroup`] waiting until it is dropped.
+///
+/// This `struct` is created by the [`guard`] method on [`WaitGroup`]. Cloning
+/// a guard adds another guard to the same group.
+///
+/// [`guard`]: WaitGroup::guard
+pub struct WaitGuard {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Clone for WaitGuard {
+    fn clone(&self) -> Self {
+        self.inner.lock().guards += 1;
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for WaitGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WaitGuard").finish_non_exhaustive()
+    }
+}
+
+impl Drop for WaitGuard {
+    fn drop(&mut self) {
+        let mut inner = self.inner.lock();
+        inner.guards -= 1;
+        if inner.guards == 0 {
+            // Waiters remove their own wakers once they complete.
+            for (_, waker) in inner.wakers.iter() {
+                waker.wake_by_ref();
+            }
+        }
+    }
+}
+
+/// A future which completes once all guards of a [`WaitGroup`] have been
+/// dropped.
+///
+/// This `struct` is created by the [`wait`] method on [`WaitGroup`]. See its
+/// documentation for more.
+///
+/// [`wait`]: WaitGroup::wait
+#[must_use = "futures do nothing unless polled or .awaited"]
+pub struct Wait {
+    inner: Arc<Mutex<Inner>>,
+    // The slot our waker is registered under, if any.
+    key: Option<usize>,
+}
+
+impl fmt::Debug for Wait {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Wait").finish_non_exhaustive()
+    }
+}
+
+impl Future for Wait {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let mut inner = this.inner.lock();
+        if inner.guards == 0 {
+            if let Some(key) = this.key.take() {
+                inner.wakers.remove(key);
+            }
+            return Poll::Ready(());
+        }
+
+        match this.key {
+            Some(key) => inner.wakers[key].clone_from(cx.waker()),
+            None => this.key = Some(inner.wakers.insert(cx.waker().clone())),
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Wait {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.inner.lock().wakers.remove(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::future::{FutureGroup, Join};
+
+    #[test]
+    fn no_guards() {
+        futures_lite::future::block_on(async {
+            WaitGroup::new().wait().await;
+        });
+    }
+
+    #[test]
+    fn waits_for_guards() {
+        futures_lite::future::block_on(async {
+            let wg = WaitGroup::new();
+            let mut group = FutureGroup::new();
+            for _ in 0..3 {
+                let guard = wg.guard();
+                group.insert(async move {
+                    futures_lite::future::yield_now().await;
+                    let _inner = guard.clone();
+                    drop(guard);
+                });
+            }
+            assert_eq!(wg.count(), 3);
+
+            let drive = async {
+                futures_lite::StreamExt::for_each(group, |_| {}).await;
+            };
+            (drive, wg.wait()).join().await;
+            assert_eq!(wg.count(), 0);
+        });
+    }
+}
@@ -0,0 +1,12 @@
+//! Structured, runtime-agnostic tasks.
+//!
+//! Unlike tasks spawned on an executor, the tasks in this module run as part
+//! of the future which created them. This means they can borrow data from
+//! the stack, and they are always awaited or cancelled together with their
+//! parent. They also all run on the thread which polls their parent, so
+//! tasks don't need to be `Send`, but the futures in this module aren't
+//! `Send` either.
+
+pub use scope::{scope, Scope, Scoped};
+
+mod scope;
@@ -0,0 +1,237 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use futures_core::Stream;
+use pin_project::pin_project;
+
+use crate::future::FutureGroup;
+
+type Task<'env> = Pin<Box<dyn Future<Output = ()> + 'env>>;
+
+/// The state shared between a [`Scoped`] future and its [`Scope`] handles.
+struct Shared<'env> {
+    // Tasks which have been spawned, but not yet picked up by the scope.
+    queue: Vec<Task<'env>>,
+    waker: Option<Waker>,
+}
+
+/// Create a scope in which tasks can be spawned.
+///
+/// The closure is passed a [`Scope`] handle which can be used to spawn tasks,
+/// and returns the body of the scope. The scope completes once its body and
+/// all of its tasks have completed, and resolves to the output of the body.
+/// Dropping the scope cancels its body and all of its tasks.
+///
+/// Tasks may borrow data from outside the scope, and may spawn further tasks
+/// using a clone of the handle. All tasks run concurrently within the task
+/// which awaits the scope, so they don't need to be `Send` or `'static`.
+///
+/// # `Send`
+///
+/// Because tasks don't need to be `Send`, neither [`Scoped`] nor [`Scope`]
+/// is `Send`. A scope can't be passed to `tokio::spawn` or similar
+/// functions of multi-threaded executors. Instead await it from within a
+/// task, or use a single-threaded executor such as `spawn_local`. The tasks
+/// of a scope always run on the thread which polls it, so no further
+/// synchronization is needed between them.
+///
+/// ```compile_fail
+/// use futures_concurrency::task;
+///
+/// fn assert_send<T: Send>(_: T) {}
+/// assert_send(task::scope(|s| async move { s.spawn(async {}) }));
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::task;
+/// use std::cell::Cell;
+///
+/// # futures::executor::block_on(async {
+/// let count = Cell::new(0);
+/// let count = &count;
+///
+/// let out = task::scope(|s| async move {
+///     for _ in 0..3 {
+///         let inner = s.clone();
+///         s.spawn(async move {
+///             count.set(count.get() + 1);
+///             inner.spawn(async move { count.set(count.get() + 1) });
+///         });
+///     }
+///     "done"
+/// })
+/// .await;
+///
+/// assert_eq!(out, "done");
+/// assert_eq!(count.get(), 6);
+/// # });
+/// ```
+pub fn scope<'env, F, Fut>(f: F) -> Scoped<'env, Fut::IntoFuture>
+where
+    F: FnOnce(Scope<'env>) -> Fut,
+    Fut: IntoFuture,
+{
+    let shared = Rc::new(RefCell::new(Shared {
+        queue: Vec::new(),
+        waker: None,
+    }));
+    let body = f(Scope {
+        shared: shared.clone(),
+    });
+    Scoped {
+        body: body.into_future(),
+        output: None,
+        tasks: FutureGroup::new(),
+        shared,
+    }
+}
+
+/// A handle used to spawn tasks within a scope.
+///
+/// This `struct` is created by the [`scope`] function. See its documentation
+/// for more. It is not `Send`, see [`scope`] for why.
+#[derive(Clone)]
+pub struct Scope<'env> {
+    shared: Rc<RefCell<Shared<'env>>>,
+}
+
+impl<'env> Scope<'env> {
+    /// Spawn a task within the scope.
+    ///
+    /// The scope will not complete until the task has completed. If the
+    /// scope has already completed, the task is dropped without being
+    /// polled.
+    pub fn spawn<F>(&self, future: F)
+    where
+        F: IntoFuture<Output = ()>,
+        F::IntoFuture: 'env,
+    {
+        let mut shared = self.shared.borrow_mut();
+        shared.queue.push(Box::pin(future.into_future()));
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl fmt::Debug for Scope<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scope").finish_non_exhaustive()
+    }
+}
+
+/// A future which waits for the body of a scope and all of its tasks.
+///
+/// This `struct` is created by the [`scope`] function. See its documentation
+/// for more. It is not `Send`, see [`scope`] for why.
+#[pin_project]
+#[must_use = "futures do nothing unless polled or .awaited"]
+pub struct Scoped<'env, Fut: Future> {
+    #[pin]
+    body: Fut,
+    output: Option<Fut::Output>,
+    tasks: FutureGroup<Task<'env>>,
+    shared: Rc<RefCell<Shared<'env>>>,
+}
+
+impl<Fut: Future> fmt::Debug for Scoped<'_, Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scoped")
+            .field("tasks", &self.tasks.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Fut: Future> Future for Scoped<'_, Fut> {
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        let mut body_done = this.output.is_some();
+
+        loop {
+            if !body_done {
+                if let Poll::Ready(output) = this.body.as_mut().poll(cx) {
+                    *this.output = Some(output);
+                    body_done = true;
+                }
+            }
+
+            // Pick up any tasks which were spawned since we last checked.
+            // We must not hold on to the borrow while polling, since tasks
+            // may spawn further tasks.
+            let queue = core::mem::take(&mut this.shared.borrow_mut().queue);
+            for task in queue {
+                this.tasks.insert(task);
+            }
+
+            while let Poll::Ready(Some(())) = Pin::new(&mut *this.tasks).poll_next(cx) {}
+
+            // Polling may have spawned new tasks, which need to be polled
+            // before we can go to sleep.
+            let mut shared = this.shared.borrow_mut();
+            if !shared.queue.is_empty() {
+                continue;
+            }
+
+            if body_done && this.tasks.is_empty() {
+                shared.waker = None;
+                let output = this.output.take();
+                return Poll::Ready(output.expect("future polled after completing"));
+            }
+
+            shared.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn waits_for_tasks() {
+        futures_lite::future::block_on(async {
+            let data = vec![1, 2, 3];
+            let sum = Cell::new(0);
+            let (data, sum) = (&data, &sum);
+
+            scope(|s| async move {
+                for n in data {
+                    s.spawn(async move {
+                        futures_lite::future::yield_now().await;
+                        sum.set(sum.get() + n);
+                    });
+                }
+            })
+            .await;
+            assert_eq!(sum.get(), 6);
+        });
+    }
+
+    #[test]
+    fn drop_cancels_tasks() {
+        futures_lite::future::block_on(async {
+            let finished = Cell::new(false);
+            let finished = &finished;
+
+            let scoped = scope(|s| async move {
+                s.spawn(async move {
+                    core::future::pending::<()>().await;
+                    finished.set(true);
+                });
+            });
+            assert!(futures_lite::future::poll_once(scoped).await.is_none());
+            assert!(!finished.get());
+        });
+    }
+}
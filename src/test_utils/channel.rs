@@ -1,22 +1,33 @@
 use alloc::{collections::VecDeque, rc::Rc};
 use core::{
     cell::RefCell,
+    fmt,
     pin::Pin,
     task::{Context, Poll, Waker},
 };
 
-use futures::Stream;
+use futures_core::Stream;
 
-pub(crate) struct LocalChannel<T> {
+struct LocalChannel<T> {
     queue: VecDeque<T>,
     waker: Option<Waker>,
     closed: bool,
 }
 
-pub(crate) struct LocalReceiver<T> {
+/// The receiving half of a [`local_channel`].
+///
+/// Items are yielded in the order they were sent. The stream ends once the
+/// sender has been dropped and all items have been received.
+pub struct LocalReceiver<T> {
     channel: Rc<RefCell<LocalChannel<T>>>,
 }
 
+impl<T> fmt::Debug for LocalReceiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalReceiver").finish_non_exhaustive()
+    }
+}
+
 impl<T> Stream for LocalReceiver<T> {
     type Item = T;
 
@@ -40,12 +51,24 @@ impl<T> Stream for LocalReceiver<T> {
     }
 }
 
-pub(crate) struct LocalSender<T> {
+/// The sending half of a [`local_channel`].
+///
+/// Dropping the sender closes the channel.
+pub struct LocalSender<T> {
     channel: Rc<RefCell<LocalChannel<T>>>,
 }
 
+impl<T> fmt::Debug for LocalSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalSender").finish_non_exhaustive()
+    }
+}
+
 impl<T> LocalSender<T> {
-    pub(crate) fn send(&self, item: T) {
+    /// Send an item, waking the receiver if it's waiting for one.
+    ///
+    /// The channel is unbounded, so this never waits.
+    pub fn send(&self, item: T) {
         let mut channel = self.channel.borrow_mut();
 
         channel.queue.push_back(item);
@@ -62,7 +85,29 @@ impl<T> Drop for LocalSender<T> {
     }
 }
 
-pub(crate) fn local_channel<T>() -> (LocalSender<T>, LocalReceiver<T>) {
+/// Create an unbounded, single-threaded channel.
+///
+/// The receiver is only woken when an item is sent or the sender is dropped,
+/// which makes it possible to control exactly when a stream makes progress.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::test_utils::local_channel;
+/// use futures_lite::StreamExt;
+///
+/// # futures_lite::future::block_on(async {
+/// let (sender, mut receiver) = local_channel();
+/// sender.send(1);
+/// sender.send(2);
+/// drop(sender);
+///
+/// assert_eq!(receiver.next().await, Some(1));
+/// assert_eq!(receiver.next().await, Some(2));
+/// assert_eq!(receiver.next().await, None);
+/// # });
+/// ```
+pub fn local_channel<T>() -> (LocalSender<T>, LocalReceiver<T>) {
     let channel = Rc::new(RefCell::new(LocalChannel {
         queue: VecDeque::new(),
         waker: None,
@@ -0,0 +1,232 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::collections::BinaryHeap;
+use alloc::rc::Rc;
+use core::cell::{Cell, RefCell};
+use core::cmp::Ordering;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+
+/// Create `len` futures which complete in order of their index.
+///
+/// Each future yields its index. No matter in which order the futures are
+/// polled, the future with index `n` only completes after the futures with
+/// lower indexes have, and every future has to be woken and polled several
+/// times before it does. This makes them a good worst case for combinators
+/// which need to track which of their futures are ready.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_concurrency::test_utils::countdown_futures;
+///
+/// # futures_lite::future::block_on(async {
+/// let mut futures = countdown_futures(4);
+/// futures.reverse();
+/// assert_eq!(futures.join().await, [3, 2, 1, 0]);
+/// # });
+/// ```
+pub fn countdown_futures(len: usize) -> Vec<CountdownFuture> {
+    let shared = Shared::new(len);
+    (0..len)
+        .map(|index| CountdownFuture {
+            inner: Countdown::new(index, shared.clone()),
+        })
+        .collect()
+}
+
+/// Create `len` streams which each yield a single item, in order of their
+/// index.
+///
+/// Each stream yields its index, and then ends. This works like
+/// [`countdown_futures`], but for streams.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::prelude::*;
+/// use futures_concurrency::test_utils::countdown_streams;
+/// use futures_lite::StreamExt;
+///
+/// # futures_lite::future::block_on(async {
+/// let mut streams = countdown_streams(4);
+/// streams.reverse();
+/// let items: Vec<_> = streams.merge().collect().await;
+/// assert_eq!(items, [0, 1, 2, 3]);
+/// # });
+/// ```
+pub fn countdown_streams(len: usize) -> Vec<CountdownStream> {
+    let shared = Shared::new(len);
+    (0..len)
+        .map(|index| CountdownStream {
+            inner: Countdown::new(index, shared.clone()),
+            done: false,
+        })
+        .collect()
+}
+
+/// A future which will _eventually_ be ready, but needs to be polled several
+/// times before it is.
+///
+/// This `struct` is created by the [`countdown_futures`] function. See its
+/// documentation for more.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Debug)]
+pub struct CountdownFuture {
+    inner: Countdown,
+}
+
+impl Future for CountdownFuture {
+    type Output = usize;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.poll(cx)
+    }
+}
+
+/// A stream which will _eventually_ yield an item, but needs to be polled
+/// several times before it does.
+///
+/// This `struct` is created by the [`countdown_streams`] function. See its
+/// documentation for more.
+#[must_use = "streams do nothing unless polled or .awaited"]
+#[derive(Debug)]
+pub struct CountdownStream {
+    inner: Countdown,
+    done: bool,
+}
+
+impl Stream for CountdownStream {
+    type Item = usize;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+        let index = core::task::ready!(self.inner.poll(cx));
+        self.done = true;
+        Poll::Ready(Some(index))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    Init,
+    Polled,
+    Done,
+}
+
+/// The state shared between all countdowns created together.
+#[derive(Clone)]
+struct Shared {
+    wakers: Rc<RefCell<BinaryHeap<PrioritizedWaker>>>,
+    completed: Rc<Cell<usize>>,
+    len: usize,
+}
+
+impl Shared {
+    fn new(len: usize) -> Self {
+        Self {
+            wakers: Rc::new(RefCell::new(BinaryHeap::new())),
+            completed: Rc::new(Cell::new(0)),
+            len,
+        }
+    }
+}
+
+/// The state machine shared by `CountdownFuture` and `CountdownStream`.
+struct Countdown {
+    state: State,
+    index: usize,
+    shared: Shared,
+}
+
+impl fmt::Debug for Countdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Countdown")
+            .field("state", &self.state)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl Countdown {
+    fn new(index: usize, shared: Shared) -> Self {
+        Self {
+            state: State::Init,
+            index,
+            shared,
+        }
+    }
+
+    fn poll(&mut self, cx: &mut Context<'_>) -> Poll<usize> {
+        let wakers = &self.shared.wakers;
+
+        // If we are the last one to be polled, skip straight to the Polled state.
+        if wakers.borrow().len() + 1 == self.shared.len {
+            self.state = State::Polled;
+        }
+
+        match self.state {
+            State::Init => {
+                // Push our waker onto the stack so we get woken again someday.
+                wakers
+                    .borrow_mut()
+                    .push(PrioritizedWaker(self.index, cx.waker().clone()));
+                self.state = State::Polled;
+                Poll::Pending
+            }
+            State::Polled => {
+                // Wake up the next one
+                let _ = wakers
+                    .borrow_mut()
+                    .pop()
+                    .map(|PrioritizedWaker(_, waker)| waker.wake());
+
+                let completed = &self.shared.completed;
+                if completed.get() == self.index {
+                    self.state = State::Done;
+                    completed.set(completed.get() + 1);
+                    Poll::Ready(self.index)
+                } else {
+                    // We're not done yet, so schedule another wakeup
+                    wakers
+                        .borrow_mut()
+                        .push(PrioritizedWaker(self.index, cx.waker().clone()));
+                    Poll::Pending
+                }
+            }
+            State::Done => Poll::Ready(self.index),
+        }
+    }
+}
+
+/// A waker paired with the index of its countdown. The lowest index gets
+/// popped off the `BinaryHeap` first.
+struct PrioritizedWaker(usize, Waker);
+
+impl PartialEq for PrioritizedWaker {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for PrioritizedWaker {}
+
+impl PartialOrd for PrioritizedWaker {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PrioritizedWaker {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0).reverse()
+    }
+}
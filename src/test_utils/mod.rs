@@ -0,0 +1,14 @@
+//! Futures and streams for testing concurrent code.
+//!
+//! These are the building blocks this crate uses for its own tests and
+//! benchmarks. They're single-threaded and fully deterministic: they only
+//! make progress when they're polled or when their counterpart acts, so the
+//! same test always polls in the same order.
+//!
+//! This module is only available with the `test-utils` feature flag enabled.
+
+mod channel;
+mod countdown;
+
+pub use channel::{local_channel, LocalReceiver, LocalSender};
+pub use countdown::{countdown_futures, countdown_streams, CountdownFuture, CountdownStream};
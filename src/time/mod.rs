@@ -0,0 +1,110 @@
+//! Runtime-agnostic time primitives.
+//!
+//! This crate doesn't depend on any particular async runtime. Instead,
+//! operations which need to wait for time to pass, such as timeouts or rate
+//! limiting, are generic over the [`Timer`] trait. Runtimes each provide their
+//! own sleep function, and any function which takes a [`Duration`] and returns
+//! a future implements [`Timer`], so it can be passed in directly.
+//!
+//! # Examples
+//!
+//! ```
+//! use futures_concurrency::time::Timer;
+//! use std::time::Duration;
+//!
+//! # futures_lite::future::block_on(async {
+//! // `async_io::Timer::after` is a function from a `Duration` to a future.
+//! let timer = async_io::Timer::after;
+//! timer.sleep(Duration::from_millis(10)).await;
+//! # });
+//! ```
+
+use core::fmt;
+use core::future::Future;
+use core::time::Duration;
+
+#[cfg(feature = "alloc")]
+pub(crate) mod watchdog;
+
+#[cfg(feature = "alloc")]
+pub use watchdog::{Watchdog, WatchdogEvent, WatchdogGroup};
+
+/// A source of futures which complete after a duration of time has passed.
+///
+/// This is implemented for all functions which take a [`Duration`] and
+/// return a future, such as `async_io::Timer::after` or
+/// `tokio::time::sleep`. The output of the future is ignored.
+///
+/// # Example
+///
+/// ```
+/// use futures_concurrency::time::Timer;
+/// use std::time::Duration;
+///
+/// #[derive(Clone, Copy)]
+/// struct AsyncIo;
+///
+/// impl Timer for AsyncIo {
+///     type Sleep = async_io::Timer;
+///
+///     fn sleep(&self, duration: Duration) -> Self::Sleep {
+///         async_io::Timer::after(duration)
+///     }
+/// }
+/// ```
+pub trait Timer {
+    /// The future returned by [`Timer::sleep`].
+    type Sleep: Future;
+
+    /// Create a future which completes once `duration` has passed.
+    fn sleep(&self, duration: Duration) -> Self::Sleep;
+}
+
+impl<F, Fut> Timer for F
+where
+    F: Fn(Duration) -> Fut,
+    Fut: Future,
+{
+    type Sleep = Fut;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        (self)(duration)
+    }
+}
+
+/// An error returned when an operation didn't complete before its deadline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimedOut {
+    _priv: (),
+}
+
+impl TimedOut {
+    pub(crate) fn new() -> Self {
+        Self { _priv: () }
+    }
+}
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "deadline has elapsed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TimedOut {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn function_timer() {
+        futures_lite::future::block_on(async {
+            let timer = async_io::Timer::after;
+            let start = Instant::now();
+            timer.sleep(Duration::from_millis(10)).await;
+            assert!(start.elapsed() >= Duration::from_millis(10));
+        });
+    }
+}
@@ -0,0 +1,203 @@
+use core::fmt::{self, Debug};
+use core::future::Future;
+use core::ops::Deref;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+use futures_core::stream::{FusedStream, Stream};
+
+use super::Timer;
+use crate::future::{future_group, FutureGroup, FutureMap};
+use crate::stream::{stream_group, StreamGroup};
+
+/// An event yielded by a [`Watchdog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchdogEvent<K, T> {
+    /// An entry yielded an item. For a group of futures this means the
+    /// future has completed.
+    Item(K, T),
+    /// A stream has finished. This is only yielded by groups of streams.
+    Finished(K),
+    /// An entry hasn't yielded an item for longer than the overdue
+    /// threshold. This is yielded at most once until the entry yields its
+    /// next item.
+    Overdue(K),
+    /// No entry has made progress for the stall period.
+    Stalled,
+}
+
+/// A group which can be wrapped in a [`Watchdog`].
+///
+/// This trait is sealed: it's implemented for [`FutureGroup`] and
+/// [`StreamGroup`], and can't be implemented outside of this crate.
+pub trait WatchdogGroup: private::Sealed {
+    /// The key which identifies an entry in the group.
+    type Key: Copy + Ord;
+
+    /// The type of item yielded by the entries in the group.
+    type Item;
+
+    /// The keys of all entries currently in the group.
+    fn keys(&self) -> impl Iterator<Item = Self::Key> + '_;
+
+    /// Returns `true` if the group contains an entry for `key`.
+    fn contains_entry(&self, key: Self::Key) -> bool;
+
+    /// Removes the entry for `key` from the group. Returns whether the entry
+    /// was part of the group.
+    fn remove_entry(&mut self, key: Self::Key) -> bool;
+
+    /// Poll the group for its next item, or for an entry which finished.
+    ///
+    /// This only ever yields [`WatchdogEvent::Item`] and
+    /// [`WatchdogEvent::Finished`].
+    fn poll_progress(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<WatchdogEvent<Self::Key, Self::Item>>>;
+}
+
+pub(crate) mod private {
+    pub trait Sealed {}
+}
+
+/// A group of futures or streams which reports when it stops making progress.
+///
+/// This `struct` is created by the [`watchdog`][FutureGroup::watchdog] method
+/// on [`FutureGroup`], and the [`watchdog`][StreamGroup::watchdog] method on
+/// [`StreamGroup`]. See their documentation for more.
+#[must_use = "`Watchdog` does nothing if not iterated over"]
+#[pin_project::pin_project]
+pub struct Watchdog<G: WatchdogGroup, T: Timer> {
+    #[pin]
+    group: G,
+    // A deadline for every entry in the group. The group never moves its
+    // futures, so the sleeps can be stored inline and re-armed in place.
+    overdue: FutureMap<G::Key, T::Sleep>,
+    // Reset every time an entry makes progress.
+    #[pin]
+    stall: Option<T::Sleep>,
+    timer: T,
+    stall_after: Duration,
+    overdue_after: Duration,
+}
+
+impl<G: WatchdogGroup, T: Timer> Watchdog<G, T> {
+    pub(crate) fn new(group: G, stall_after: Duration, overdue_after: Duration, timer: T) -> Self {
+        let mut overdue = FutureMap::new();
+        for key in group.keys() {
+            overdue.insert_with_key(key, timer.sleep(overdue_after));
+        }
+        Self {
+            group,
+            overdue,
+            stall: None,
+            timer,
+            stall_after,
+            overdue_after,
+        }
+    }
+
+    /// Removes an entry from the group. Returns whether the entry was part
+    /// of the group.
+    pub fn remove(&mut self, key: G::Key) -> bool {
+        self.overdue.remove(&key);
+        self.group.remove_entry(key)
+    }
+
+    /// Start tracking a newly inserted entry.
+    fn track(&mut self, key: G::Key) -> G::Key {
+        let sleep = self.timer.sleep(self.overdue_after);
+        self.overdue.insert_with_key(key, sleep);
+        key
+    }
+}
+
+impl<F: Future, T: Timer> Watchdog<FutureGroup<F>, T> {
+    /// Insert a new future into the group, and start tracking how long it
+    /// has been pending.
+    pub fn insert(&mut self, future: F) -> future_group::Key {
+        let key = self.group.insert(future);
+        self.track(key)
+    }
+}
+
+impl<S: Stream, T: Timer> Watchdog<StreamGroup<S>, T> {
+    /// Insert a new stream into the group, and start tracking how long it
+    /// goes without yielding an item.
+    pub fn insert(&mut self, stream: S) -> stream_group::Key {
+        let key = self.group.insert(stream);
+        self.track(key)
+    }
+}
+
+impl<G: WatchdogGroup + Debug, T: Timer> Debug for Watchdog<G, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Watchdog")
+            .field("group", &self.group)
+            .field("stall_after", &self.stall_after)
+            .field("overdue_after", &self.overdue_after)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<G: WatchdogGroup, T: Timer> Deref for Watchdog<G, T> {
+    type Target = G;
+
+    fn deref(&self) -> &Self::Target {
+        &self.group
+    }
+}
+
+impl<G: WatchdogGroup, T: Timer> Stream for Watchdog<G, T> {
+    type Item = WatchdogEvent<G::Key, G::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        match this.group.as_mut().poll_progress(cx) {
+            Poll::Ready(Some(event)) => {
+                match event {
+                    // Futures leave the group once they yield their item, but
+                    // streams keep going: start a new overdue period.
+                    WatchdogEvent::Item(key, _) if this.group.contains_entry(key) => {
+                        let sleep = this.timer.sleep(*this.overdue_after);
+                        this.overdue.insert_with_key(key, sleep);
+                    }
+                    WatchdogEvent::Item(key, _) | WatchdogEvent::Finished(key) => {
+                        this.overdue.remove(&key);
+                    }
+                    WatchdogEvent::Overdue(_) | WatchdogEvent::Stalled => {}
+                }
+                this.stall.set(None);
+                return Poll::Ready(Some(event));
+            }
+            Poll::Ready(None) => {
+                this.stall.set(None);
+                return Poll::Ready(None);
+            }
+            Poll::Pending => {}
+        }
+
+        if let Poll::Ready(Some((key, _))) = Pin::new(&mut *this.overdue).poll_next(cx) {
+            return Poll::Ready(Some(WatchdogEvent::Overdue(key)));
+        }
+
+        if this.stall.is_none() {
+            this.stall.set(Some(this.timer.sleep(*this.stall_after)));
+        }
+        let stall = this.stall.as_mut().as_pin_mut().unwrap();
+        if stall.poll(cx).is_ready() {
+            // Start a new stall period the next time we're polled.
+            this.stall.set(None);
+            return Poll::Ready(Some(WatchdogEvent::Stalled));
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<G: WatchdogGroup + FusedStream, T: Timer> FusedStream for Watchdog<G, T> {
+    fn is_terminated(&self) -> bool {
+        self.group.is_terminated()
+    }
+}
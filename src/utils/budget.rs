@@ -0,0 +1,45 @@
+/// How many items an adapter takes from its stream in a single call without
+/// yielding any, before it wakes itself and returns `Poll::Pending`.
+pub(crate) const ITEM_BUDGET: usize = 32;
+
+/// Limits how many entries are polled in a single call, so that a large
+/// number of ready entries can't hog the executor.
+///
+/// Once the budget runs out, the caller wakes itself and returns
+/// `Poll::Pending`. The next call resumes right where the last one stopped,
+/// so every entry eventually gets polled.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Budget {
+    limit: Option<usize>,
+    /// Where to resume polling after the budget ran out.
+    resume: Option<usize>,
+}
+
+impl Budget {
+    /// Poll at most `limit` entries per call. At least one entry is always
+    /// polled.
+    pub(crate) fn set_limit(&mut self, limit: usize) {
+        self.limit = Some(limit.max(1));
+    }
+
+    /// The maximum number of entries polled per call, if any.
+    pub(crate) fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Returns `true` if `polled` entries use up the budget.
+    pub(crate) fn is_exhausted(&self, polled: usize) -> bool {
+        self.limit.is_some_and(|limit| polled >= limit)
+    }
+
+    /// Resume polling from `index` on the next call.
+    pub(crate) fn resume_at(&mut self, index: usize) {
+        self.resume = Some(index);
+    }
+
+    /// Take the index to resume polling from, if the last call ran out of
+    /// budget.
+    pub(crate) fn take_resume(&mut self) -> Option<usize> {
+        self.resume.take()
+    }
+}
@@ -0,0 +1,378 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, vec::Vec};
+
+use core::mem;
+use core::pin::Pin;
+use core::ptr;
+
+/// A slot in a chunk, which is either vacant or holds a value.
+enum Entry<T> {
+    /// The index of the next vacant entry.
+    Vacant(usize),
+    Occupied(T),
+}
+
+/// A slab which never moves its values.
+///
+/// Values are stored in chunks which are never reallocated, so growing the
+/// storage leaves existing values where they are. Values are only handed out
+/// as pinned references and are dropped in place, which means every value is
+/// pinned from the moment it's inserted. This makes it sound to insert values
+/// while other values are being polled.
+pub(crate) struct ChunkedVec<T> {
+    chunks: Vec<Box<[Entry<T>]>>,
+    len: usize,
+    /// The total number of entries across all chunks.
+    capacity: usize,
+    /// The first vacant entry. Equal to `capacity` if there are none.
+    next: usize,
+}
+
+impl<T> Default for ChunkedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> ChunkedVec<T> {
+    /// Create a new, empty `ChunkedVec`.
+    pub(crate) fn new() -> Self {
+        Self {
+            chunks: Vec::new(),
+            len: 0,
+            capacity: 0,
+            next: 0,
+        }
+    }
+
+    /// Create a new `ChunkedVec` which fits `capacity` values.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        let mut this = Self::new();
+        this.reserve(capacity);
+        this
+    }
+
+    /// The number of values stored.
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no values are stored.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of values which can be stored without allocating.
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Make sure at least `additional` more values fit without allocating.
+    ///
+    /// This allocates a new chunk rather than growing an existing one, so the
+    /// values which are already stored don't move.
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        let needed = (self.len + additional).saturating_sub(self.capacity);
+        if needed == 0 {
+            return;
+        }
+        // Grow by at least the current capacity, so the number of chunks
+        // stays logarithmic in the number of values.
+        let size = needed.max(self.capacity);
+        let start = self.capacity;
+        let chunk = (start..start + size)
+            .map(|index| Entry::Vacant(index + 1))
+            .collect();
+        self.chunks.push(chunk);
+        // The last vacant entry points at the old capacity, which is where
+        // the new chunk starts. So the new entries extend the vacant list.
+        self.capacity += size;
+    }
+
+    /// Release trailing chunks which don't hold any values, while keeping at
+    /// least `min_capacity` entries. Chunks which hold values can't be
+    /// released, because the values can't be moved.
+    pub(crate) fn shrink_to(&mut self, min_capacity: usize) {
+        while let Some(chunk) = self.chunks.last() {
+            let remaining = self.capacity - chunk.len();
+            let occupied = chunk
+                .iter()
+                .any(|entry| matches!(entry, Entry::Occupied(_)));
+            if remaining < min_capacity || occupied {
+                break;
+            }
+            self.chunks.pop();
+            self.capacity = remaining;
+        }
+
+        // The vacant list may point into the chunks we just released, so we
+        // rebuild it in order.
+        let mut index = self.capacity;
+        self.next = self.capacity;
+        let entries = self.chunks.iter_mut().flat_map(|chunk| chunk.iter_mut());
+        for entry in entries.rev() {
+            index -= 1;
+            if let Entry::Vacant(next) = entry {
+                *next = self.next;
+                self.next = index;
+            }
+        }
+    }
+
+    /// Find the entry for the given index.
+    fn entry(&self, index: usize) -> Option<&Entry<T>> {
+        let mut offset = index;
+        for chunk in &self.chunks {
+            match chunk.get(offset) {
+                Some(entry) => return Some(entry),
+                None => offset -= chunk.len(),
+            }
+        }
+        None
+    }
+
+    /// Find the entry for the given index.
+    fn entry_mut(&mut self, index: usize) -> Option<&mut Entry<T>> {
+        let mut offset = index;
+        for chunk in &mut self.chunks {
+            if offset < chunk.len() {
+                return Some(&mut chunk[offset]);
+            }
+            offset -= chunk.len();
+        }
+        None
+    }
+
+    /// Insert a value, and return the index it was stored at.
+    pub(crate) fn insert(&mut self, value: T) -> usize {
+        if self.next == self.capacity {
+            self.reserve(1);
+        }
+        let index = self.next;
+        let entry = self.entry_mut(index).unwrap();
+        let Entry::Vacant(next) = *entry else {
+            unreachable!("the vacant list points at an occupied entry");
+        };
+        // Vacant entries don't need to be dropped, so overwriting is fine.
+        *entry = Entry::Occupied(value);
+        self.next = next;
+        self.len += 1;
+        index
+    }
+
+    /// Drop the value at the given index in place. Returns whether a value
+    /// was present.
+    pub(crate) fn remove(&mut self, index: usize) -> bool {
+        if !self.contains(index) {
+            return false;
+        }
+        let next = mem::replace(&mut self.next, index);
+        self.len -= 1;
+        let entry: *mut Entry<T> = self.entry_mut(index).unwrap();
+
+        /// Marks the entry as vacant once the value has been dropped, even if
+        /// dropping it panics.
+        struct SetVacant<T>(*mut Entry<T>, usize);
+        impl<T> Drop for SetVacant<T> {
+            fn drop(&mut self) {
+                // SAFETY: the value in the entry has been dropped, so we must
+                // overwrite it without dropping it again.
+                unsafe { ptr::write(self.0, Entry::Vacant(self.1)) };
+            }
+        }
+
+        let _guard = SetVacant(entry, next);
+        // SAFETY: values are pinned, so they must be dropped in place. The
+        // guard makes sure the entry isn't dropped a second time.
+        if let Entry::Occupied(value) = unsafe { &mut *entry } {
+            unsafe { ptr::drop_in_place(value) };
+        }
+        true
+    }
+
+    /// Move the value at the given index out.
+    ///
+    /// Values are pinned once they're inserted, so only `Unpin` values can
+    /// be moved out again.
+    pub(crate) fn take(&mut self, index: usize) -> Option<T>
+    where
+        T: Unpin,
+    {
+        if !self.contains(index) {
+            return None;
+        }
+        let next = mem::replace(&mut self.next, index);
+        self.len -= 1;
+        let entry = self.entry_mut(index).unwrap();
+        match mem::replace(entry, Entry::Vacant(next)) {
+            Entry::Occupied(value) => Some(value),
+            Entry::Vacant(_) => unreachable!("checked the entry is occupied"),
+        }
+    }
+
+    /// Drop all values in place, keeping the allocated capacity.
+    pub(crate) fn clear(&mut self) {
+        for index in 0..self.capacity {
+            self.remove(index);
+        }
+        // Every entry is vacant now, so we can rebuild the vacant list in
+        // order. That way indexes are handed out from the start again.
+        let entries = self.chunks.iter_mut().flat_map(|chunk| chunk.iter_mut());
+        for (index, entry) in entries.enumerate() {
+            *entry = Entry::Vacant(index + 1);
+        }
+        self.next = 0;
+    }
+
+    /// Returns `true` if a value is stored at the given index.
+    pub(crate) fn contains(&self, index: usize) -> bool {
+        self.get(index).is_some()
+    }
+
+    /// Get a reference to the value at the given index.
+    pub(crate) fn get(&self, index: usize) -> Option<&T> {
+        match self.entry(index)? {
+            Entry::Occupied(value) => Some(value),
+            Entry::Vacant(_) => None,
+        }
+    }
+
+    /// Get a pinned mutable reference to the value at the given index.
+    pub(crate) fn get_pin_mut(&mut self, index: usize) -> Option<Pin<&mut T>> {
+        match self.entry_mut(index)? {
+            // SAFETY: values are never moved, and are dropped in place.
+            Entry::Occupied(value) => Some(unsafe { Pin::new_unchecked(value) }),
+            Entry::Vacant(_) => None,
+        }
+    }
+
+    /// Iterate over all values, along with their indexes.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.chunks
+            .iter()
+            .flat_map(|chunk| chunk.iter())
+            .enumerate()
+            .filter_map(|(index, entry)| match entry {
+                Entry::Occupied(value) => Some((index, value)),
+                Entry::Vacant(_) => None,
+            })
+    }
+
+    /// Iterate over pinned mutable references to all values, along with their
+    /// indexes.
+    pub(crate) fn iter_pin_mut(&mut self) -> impl Iterator<Item = (usize, Pin<&mut T>)> + '_ {
+        self.chunks
+            .iter_mut()
+            .flat_map(|chunk| chunk.iter_mut())
+            .enumerate()
+            .filter_map(|(index, entry)| match entry {
+                // SAFETY: values are never moved, and are dropped in place.
+                Entry::Occupied(value) => Some((index, unsafe { Pin::new_unchecked(value) })),
+                Entry::Vacant(_) => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    #[test]
+    fn insert_remove() {
+        let mut vec = ChunkedVec::new();
+        let a = vec.insert("a");
+        let b = vec.insert("b");
+        assert_eq!(vec.len(), 2);
+        assert!(vec.remove(a));
+        assert!(!vec.remove(a));
+        assert!(!vec.contains(a));
+        assert_eq!(vec.get(b), Some(&"b"));
+        assert_eq!(vec.take(b), Some("b"));
+        assert_eq!(vec.take(b), None);
+        assert_eq!(vec.insert("b"), b);
+
+        // Vacant entries are reused before growing.
+        let capacity = vec.capacity();
+        assert_eq!(vec.insert("c"), a);
+        assert_eq!(vec.capacity(), capacity);
+        let items: Vec<_> = vec.iter().collect();
+        assert_eq!(items, [(a, &"c"), (b, &"b")]);
+    }
+
+    #[test]
+    fn growing_never_moves() {
+        let mut vec = ChunkedVec::with_capacity(1);
+        let index = vec.insert(0usize);
+        let addr = vec.get(index).unwrap() as *const usize;
+        for n in 1..100 {
+            vec.insert(n);
+        }
+        assert!(vec.capacity() >= 100);
+        assert_eq!(vec.get(index).unwrap() as *const usize, addr);
+        assert_eq!(vec.iter_pin_mut().count(), 100);
+    }
+
+    #[test]
+    fn drops_values() {
+        struct Counted(Rc<Cell<usize>>);
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut vec = ChunkedVec::new();
+        let index = vec.insert(Counted(drops.clone()));
+        vec.insert(Counted(drops.clone()));
+        vec.remove(index);
+        assert_eq!(drops.get(), 1);
+        drop(vec);
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn shrink_to() {
+        let mut vec = ChunkedVec::with_capacity(1);
+        let first = vec.insert(0);
+        for n in 1..100 {
+            vec.insert(n);
+        }
+        let capacity = vec.capacity();
+
+        // Chunks holding values are kept.
+        vec.shrink_to(0);
+        assert_eq!(vec.capacity(), capacity);
+
+        for index in 1..100 {
+            vec.remove(index);
+        }
+        vec.shrink_to(0);
+        assert_eq!(vec.capacity(), 1);
+        assert_eq!(vec.get(first), Some(&0));
+
+        // Vacant entries are handed out in order again.
+        assert_eq!(vec.insert(1), 1);
+        assert_eq!(vec.insert(2), 2);
+        assert_eq!(vec.len(), 3);
+    }
+
+    #[test]
+    fn clear() {
+        let mut vec = ChunkedVec::new();
+        for n in 0..10 {
+            vec.insert(n);
+        }
+        vec.remove(3);
+        let capacity = vec.capacity();
+
+        vec.clear();
+        assert!(vec.is_empty());
+        assert_eq!(vec.iter().count(), 0);
+        assert_eq!(vec.capacity(), capacity);
+        assert_eq!(vec.insert(10), 0);
+        assert_eq!(vec.insert(11), 1);
+    }
+}
@@ -5,11 +5,31 @@ use core::ops;
 pub(crate) struct Indexer {
     offset: usize,
     max: usize,
+    mode: Mode,
+}
+
+/// How the starting point of the iteration changes between iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// Move the starting point forward by one on every iteration.
+    Rotate,
+    /// Always start from `0`.
+    Ordered,
 }
 
 impl Indexer {
     pub(crate) fn new(max: usize) -> Self {
-        Self { offset: 0, max }
+        Self {
+            offset: 0,
+            max,
+            mode: Mode::Rotate,
+        }
+    }
+
+    /// Always start iterating from `0`, so lower indexes take priority.
+    pub(crate) fn set_ordered(&mut self) {
+        self.mode = Mode::Ordered;
+        self.offset = 0;
     }
 
     /// Generate a range between `0..max`, incrementing the starting point
@@ -17,12 +37,11 @@ impl Indexer {
     pub(crate) fn iter(&mut self) -> IndexIter {
         // Increment the starting point for next time.
         let offset = self.offset;
-        self.offset = (self.offset + 1).wrapping_rem(self.max);
-
-        IndexIter {
-            iter: (0..self.max),
-            offset,
+        if self.mode == Mode::Rotate {
+            self.offset = (self.offset + 1).wrapping_rem(self.max);
         }
+
+        IndexIter::new(offset, self.max)
     }
 }
 
@@ -31,6 +50,17 @@ pub(crate) struct IndexIter {
     offset: usize,
 }
 
+impl IndexIter {
+    /// Generate a range between `0..max`, starting from `offset`. Offsets
+    /// which are out of bounds wrap around.
+    pub(crate) fn new(offset: usize, max: usize) -> Self {
+        Self {
+            iter: (0..max),
+            offset: offset.checked_rem(max).unwrap_or(0),
+        }
+    }
+}
+
 impl Iterator for IndexIter {
     type Item = usize;
 
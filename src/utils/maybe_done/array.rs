@@ -0,0 +1,64 @@
+use core::array;
+use core::pin::Pin;
+
+use super::Slot;
+
+/// An array of futures which are replaced by their outputs in place.
+pub(crate) struct MaybeDoneArray<F, T, const N: usize> {
+    slots: [Slot<F, T>; N],
+}
+
+impl<F, T, const N: usize> MaybeDoneArray<F, T, N> {
+    /// Create a new instance of `MaybeDoneArray`.
+    pub(crate) fn new(futures: [F; N]) -> Self {
+        Self {
+            slots: futures.map(Slot::new),
+        }
+    }
+
+    /// Create an iterator of pinned slots.
+    pub(crate) fn iter(self: Pin<&mut Self>) -> impl Iterator<Item = Pin<&mut Slot<F, T>>> {
+        // SAFETY: `std` _could_ make this unsound if it were to decide Pin's
+        // invariants aren't required to transmit through slices. Otherwise this has
+        // the same safety as a normal field pin projection.
+        unsafe { self.get_unchecked_mut() }
+            .slots
+            .iter_mut()
+            .map(|t| unsafe { Pin::new_unchecked(t) })
+    }
+
+    /// Drop the future at the given index.
+    ///
+    /// # Safety
+    ///
+    /// The slot at the index must hold a future.
+    pub(crate) unsafe fn drop_future(self: Pin<&mut Self>, idx: usize) {
+        // SAFETY: the future is dropped in place, and the caller guarantees
+        // the slot holds a future.
+        unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().slots[idx]).drop_future() }
+    }
+
+    /// Drop the output at the given index.
+    ///
+    /// # Safety
+    ///
+    /// The slot at the index must hold an output.
+    pub(crate) unsafe fn drop_output(self: Pin<&mut Self>, idx: usize) {
+        // SAFETY: outputs aren't pinned, and the caller guarantees the slot
+        // holds an output.
+        unsafe { self.get_unchecked_mut().slots[idx].drop_output() }
+    }
+
+    /// Take the outputs out of all slots.
+    ///
+    /// # Safety
+    ///
+    /// Every slot must hold an output, and the slots may not be used again
+    /// afterwards.
+    pub(crate) unsafe fn take(self: Pin<&mut Self>) -> [T; N] {
+        // SAFETY: outputs aren't pinned, and the caller guarantees every slot
+        // holds an output.
+        let slots = unsafe { &mut self.get_unchecked_mut().slots };
+        array::from_fn(|i| unsafe { slots[i].take_output() })
+    }
+}
@@ -0,0 +1,109 @@
+//! Storage for futures which is reused for their outputs once they complete.
+//!
+//! Joining keeps every future around until all of them have completed, and
+//! every output around until the last future completes. Rather than storing
+//! the futures and their outputs side-by-side, each slot holds either the
+//! future or its output. This means a join only takes up as much space as the
+//! larger of the two, rather than both combined.
+
+mod array;
+#[cfg(feature = "alloc")]
+mod vec;
+
+pub(crate) use array::MaybeDoneArray;
+#[cfg(feature = "alloc")]
+pub(crate) use vec::MaybeDoneVec;
+
+use core::future::Future;
+use core::mem::ManuallyDrop;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// A single slot, holding either a future or its output.
+///
+/// Which of the two is held is tracked externally, usually through a
+/// `PollState`. Unions never drop their fields, so the owner is responsible for
+/// dropping whichever is held.
+pub(crate) union Slot<F, T> {
+    future: ManuallyDrop<F>,
+    output: ManuallyDrop<T>,
+}
+
+impl<F, T> Slot<F, T> {
+    fn new(future: F) -> Self {
+        Self {
+            future: ManuallyDrop::new(future),
+        }
+    }
+
+    /// Poll the future in the slot.
+    ///
+    /// # Safety
+    ///
+    /// The slot must hold a future.
+    pub(crate) unsafe fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output>
+    where
+        F: Future,
+    {
+        // SAFETY: the caller guarantees the slot holds the future, and the
+        // future is never moved out of the slot.
+        unsafe { self.map_unchecked_mut(|slot| &mut *slot.future).poll(cx) }
+    }
+
+    /// Drop the future in place, and store its output in the slot.
+    ///
+    /// # Safety
+    ///
+    /// The slot must hold a future, and the caller must already have stopped
+    /// tracking it as one. If the future's destructor panics the slot holds
+    /// neither a future nor an output, so it must not be dropped again while
+    /// unwinding.
+    pub(crate) unsafe fn complete(mut self: Pin<&mut Self>, output: T) {
+        // SAFETY: the future is dropped in place before its memory is reused,
+        // which upholds the pinning guarantees.
+        unsafe {
+            self.as_mut().drop_future();
+            self.get_unchecked_mut().output = ManuallyDrop::new(output);
+        }
+    }
+
+    /// Drop the future in place.
+    ///
+    /// # Safety
+    ///
+    /// The slot must hold a future, and it may not be used again afterwards.
+    pub(crate) unsafe fn drop_future(self: Pin<&mut Self>) {
+        // SAFETY: the caller guarantees the slot holds the future.
+        unsafe { ManuallyDrop::drop(&mut self.get_unchecked_mut().future) }
+    }
+
+    /// Move the future out of the slot.
+    ///
+    /// # Safety
+    ///
+    /// The slot must hold a future, and it may not be used again afterwards.
+    unsafe fn take_future(&mut self) -> F {
+        // SAFETY: the caller guarantees the slot holds a future.
+        unsafe { ManuallyDrop::take(&mut self.future) }
+    }
+
+    /// Drop the output in place.
+    ///
+    /// # Safety
+    ///
+    /// The slot must hold an output, and it may not be used again afterwards.
+    unsafe fn drop_output(&mut self) {
+        // SAFETY: the caller guarantees the slot holds an output.
+        unsafe { ManuallyDrop::drop(&mut self.output) }
+    }
+
+    /// Move the output out of the slot.
+    ///
+    /// # Safety
+    ///
+    /// The slot must hold an output, and it may not be used again afterwards.
+    unsafe fn take_output(&mut self) -> T {
+        // SAFETY: the caller guarantees the slot holds an output.
+        unsafe { ManuallyDrop::take(&mut self.output) }
+    }
+}
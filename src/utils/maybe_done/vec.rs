@@ -0,0 +1,100 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use core::pin::Pin;
+
+use super::Slot;
+
+/// A vector of futures which are replaced by their outputs in place.
+pub(crate) struct MaybeDoneVec<F, T> {
+    slots: Vec<Slot<F, T>>,
+}
+
+impl<F, T> MaybeDoneVec<F, T> {
+    /// Create a new instance of `MaybeDoneVec`.
+    pub(crate) fn new(futures: Vec<F>) -> Self {
+        Self {
+            slots: futures.into_iter().map(Slot::new).collect(),
+        }
+    }
+
+    /// Create an iterator of pinned slots.
+    pub(crate) fn iter(self: Pin<&mut Self>) -> impl Iterator<Item = Pin<&mut Slot<F, T>>> {
+        // SAFETY: `std` _could_ make this unsound if it were to decide Pin's
+        // invariants aren't required to transmit through slices. Otherwise this has
+        // the same safety as a normal field pin projection.
+        unsafe { self.get_unchecked_mut() }
+            .slots
+            .iter_mut()
+            .map(|t| unsafe { Pin::new_unchecked(t) })
+    }
+
+    /// Drop the future at the given index.
+    ///
+    /// # Safety
+    ///
+    /// The slot at the index must hold a future.
+    pub(crate) unsafe fn drop_future(self: Pin<&mut Self>, idx: usize) {
+        // SAFETY: the future is dropped in place, and the caller guarantees
+        // the slot holds a future.
+        unsafe { Pin::new_unchecked(&mut self.get_unchecked_mut().slots[idx]).drop_future() }
+    }
+
+    /// Move the future at the given index out of its slot.
+    ///
+    /// # Safety
+    ///
+    /// The slot at the index must hold a future, and it may not be used again
+    /// afterwards.
+    pub(crate) unsafe fn take_future(self: Pin<&mut Self>, idx: usize) -> F
+    where
+        F: Unpin,
+    {
+        // SAFETY: the future is `Unpin`, so it's fine to move it out of the
+        // pinned slot. The caller guarantees the slot holds a future.
+        unsafe { self.get_unchecked_mut().slots[idx].take_future() }
+    }
+
+    /// Drop the output at the given index.
+    ///
+    /// # Safety
+    ///
+    /// The slot at the index must hold an output.
+    pub(crate) unsafe fn drop_output(self: Pin<&mut Self>, idx: usize) {
+        // SAFETY: outputs aren't pinned, and the caller guarantees the slot
+        // holds an output.
+        unsafe { self.get_unchecked_mut().slots[idx].drop_output() }
+    }
+
+    /// Move the output at the given index out of its slot.
+    ///
+    /// # Safety
+    ///
+    /// The slot at the index must hold an output, and it may not be used again
+    /// afterwards.
+    pub(crate) unsafe fn take_output(self: Pin<&mut Self>, idx: usize) -> T {
+        // SAFETY: outputs aren't pinned, and the caller guarantees the slot
+        // holds an output.
+        unsafe { self.get_unchecked_mut().slots[idx].take_output() }
+    }
+
+    /// Take the outputs out of all slots, releasing the slots' memory.
+    ///
+    /// When the layouts are compatible, the standard library reuses the
+    /// allocation of the slots for the outputs.
+    ///
+    /// # Safety
+    ///
+    /// Every slot must hold an output, and the slots may not be used again
+    /// afterwards.
+    pub(crate) unsafe fn take(self: Pin<&mut Self>) -> Vec<T> {
+        // SAFETY: outputs aren't pinned, and the caller guarantees every slot
+        // holds an output. Taking the vector is fine too: the slots no longer
+        // hold any pinned futures.
+        let slots = core::mem::take(unsafe { &mut self.get_unchecked_mut().slots });
+        slots
+            .into_iter()
+            .map(|mut slot| unsafe { slot.take_output() })
+            .collect()
+    }
+}
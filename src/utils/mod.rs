@@ -3,24 +3,41 @@
 //! Utilities to implement the different futures of this crate.
 
 mod array;
+mod budget;
+#[cfg(feature = "alloc")]
+mod chunked_vec;
 mod futures;
 mod indexer;
+mod maybe_done;
+#[cfg(feature = "std")]
+mod mutex;
 mod output;
+#[cfg(all(test, feature = "std"))]
+mod panic_on_drop;
 mod pin;
 mod poll_state;
+#[cfg(feature = "alloc")]
+mod ready_queue;
+mod rng;
+pub(crate) mod size_hint;
 mod stream;
+mod trace;
 mod tuple;
 mod wakers;
 
-#[doc(hidden)]
-pub mod private;
-
 pub(crate) use self::futures::FutureArray;
 #[cfg(feature = "alloc")]
 pub(crate) use self::futures::FutureVec;
 pub(crate) use array::array_assume_init;
-pub(crate) use indexer::Indexer;
-pub(crate) use output::OutputArray;
+pub(crate) use budget::{Budget, ITEM_BUDGET};
+#[cfg(feature = "alloc")]
+pub(crate) use chunked_vec::ChunkedVec;
+pub(crate) use indexer::{IndexIter, Indexer};
+pub(crate) use maybe_done::MaybeDoneArray;
+#[cfg(feature = "alloc")]
+pub(crate) use maybe_done::MaybeDoneVec;
+#[cfg(feature = "std")]
+pub(crate) use mutex::Mutex;
 #[cfg(feature = "alloc")]
 pub(crate) use output::OutputVec;
 pub(crate) use pin::{get_pin_mut, iter_pin_mut};
@@ -28,17 +45,20 @@ pub(crate) use pin::{get_pin_mut, iter_pin_mut};
 pub(crate) use pin::{get_pin_mut_from_vec, iter_pin_mut_vec};
 pub(crate) use poll_state::PollArray;
 #[cfg(feature = "alloc")]
-pub(crate) use poll_state::{MaybeDone, PollState, PollVec};
+pub(crate) use poll_state::{PollState, PollVec};
+#[cfg(feature = "alloc")]
+pub(crate) use ready_queue::{ReadyOrder, ReadyQueue};
+pub(crate) use rng::Rng;
+pub(crate) use trace::trace;
 pub(crate) use tuple::{gen_conditions, tuple_len};
 pub(crate) use wakers::WakerArray;
 #[cfg(feature = "alloc")]
 pub(crate) use wakers::WakerVec;
 
+#[cfg(all(test, feature = "std"))]
+pub(crate) use panic_on_drop::PanicOnDrop;
 #[cfg(all(test, feature = "alloc"))]
 pub(crate) use wakers::DummyWaker;
 
-#[cfg(all(test, feature = "alloc"))]
-pub(crate) mod channel;
-
 #[cfg(feature = "alloc")]
 pub(crate) use stream::{from_iter, FromIter};
@@ -0,0 +1,50 @@
+//! A mutex which doesn't poison.
+//!
+//! None of the state we guard can be left in an inconsistent state by a panic,
+//! so there's nothing to be gained by propagating poisoning. With the
+//! `parking_lot` feature enabled this is `parking_lot::Mutex`, which doesn't
+//! poison in the first place and copes better with contended wakeups.
+
+#[cfg(feature = "parking_lot")]
+pub(crate) use parking_lot::Mutex;
+
+#[cfg(not(feature = "parking_lot"))]
+use std::sync::{MutexGuard, PoisonError};
+
+/// A wrapper around `std::sync::Mutex` which ignores poisoning.
+#[cfg(not(feature = "parking_lot"))]
+#[derive(Debug, Default)]
+pub(crate) struct Mutex<T: ?Sized>(std::sync::Mutex<T>);
+
+#[cfg(not(feature = "parking_lot"))]
+impl<T> Mutex<T> {
+    /// Create a new instance of `Mutex`.
+    pub(crate) const fn new(value: T) -> Self {
+        Self(std::sync::Mutex::new(value))
+    }
+}
+
+#[cfg(not(feature = "parking_lot"))]
+impl<T: ?Sized> Mutex<T> {
+    /// Acquire the lock, even if a previous holder panicked.
+    pub(crate) fn lock(&self) -> MutexGuard<'_, T> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn lock_after_panic() {
+        let mutex = Mutex::new(1);
+        let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let _guard = mutex.lock();
+            panic!("poison the lock");
+        }));
+        *mutex.lock() += 1;
+        assert_eq!(*mutex.lock(), 2);
+    }
+}
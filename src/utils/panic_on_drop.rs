@@ -0,0 +1,38 @@
+use core::cell::Cell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::rc::Rc;
+
+/// A future which is ready right away, and panics when it's dropped.
+///
+/// Every drop is counted, so tests can check that a combinator doesn't drop
+/// the future a second time while unwinding.
+pub(crate) struct PanicOnDrop<T> {
+    output: Option<T>,
+    drops: Rc<Cell<usize>>,
+}
+
+impl<T> PanicOnDrop<T> {
+    pub(crate) fn new(output: T, drops: Rc<Cell<usize>>) -> Self {
+        Self {
+            output: Some(output),
+            drops,
+        }
+    }
+}
+
+impl<T: Unpin> Future for PanicOnDrop<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<T> {
+        Poll::Ready(self.output.take().expect("polled after completion"))
+    }
+}
+
+impl<T> Drop for PanicOnDrop<T> {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+        panic!("PanicOnDrop was dropped");
+    }
+}
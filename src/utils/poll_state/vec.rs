@@ -84,6 +84,11 @@ impl PollVec {
     pub(crate) fn resize(&mut self, len: usize) {
         self.0.resize_with(len, || PollState::None)
     }
+
+    /// Release the memory of states which were removed by shrinking.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
 }
 
 impl Deref for PollVec {
@@ -0,0 +1,190 @@
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use core::cmp::Reverse;
+use core::mem;
+
+/// The order in which a [`ReadyQueue`] hands out entries with the same
+/// priority.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReadyOrder {
+    /// By index, lowest first.
+    #[default]
+    Index,
+    /// By when they became ready, oldest first.
+    Oldest,
+    /// By when they became ready, newest first.
+    Newest,
+}
+
+/// The entries of a group which are ready to be polled, by priority.
+///
+/// Entries are pushed as their wakers are woken, so handing them out only
+/// touches the entries which are actually ready.
+#[derive(Debug, Default)]
+pub(crate) struct ReadyQueue {
+    order: ReadyOrder,
+    /// The ready entries per priority, highest priority first.
+    lanes: BTreeMap<Reverse<u8>, Lane>,
+}
+
+/// The ready entries with the same priority.
+#[derive(Debug)]
+enum Lane {
+    Index(BTreeSet<usize>),
+    Woken(VecDeque<usize>),
+}
+
+impl Lane {
+    fn new(order: ReadyOrder) -> Self {
+        match order {
+            ReadyOrder::Index => Self::Index(BTreeSet::new()),
+            ReadyOrder::Oldest | ReadyOrder::Newest => Self::Woken(VecDeque::new()),
+        }
+    }
+
+    fn push(&mut self, index: usize) {
+        match self {
+            Self::Index(set) => {
+                set.insert(index);
+            }
+            Self::Woken(queue) => queue.push_back(index),
+        }
+    }
+
+    fn pop(&mut self, order: ReadyOrder) -> Option<usize> {
+        match self {
+            Self::Index(set) => set.pop_first(),
+            Self::Woken(queue) if order == ReadyOrder::Newest => queue.pop_back(),
+            Self::Woken(queue) => queue.pop_front(),
+        }
+    }
+
+    fn remove(&mut self, index: usize) {
+        match self {
+            Self::Index(set) => {
+                set.remove(&index);
+            }
+            Self::Woken(queue) => queue.retain(|&queued| queued != index),
+        }
+    }
+
+    fn retain(&mut self, f: impl FnMut(&usize) -> bool) {
+        match self {
+            Self::Index(set) => set.retain(f),
+            Self::Woken(queue) => queue.retain(f),
+        }
+    }
+}
+
+impl ReadyQueue {
+    /// Change the order in which entries with the same priority are handed
+    /// out. Entries which are already queued are kept.
+    pub(crate) fn set_order(&mut self, order: ReadyOrder) {
+        let was_by_index = self.order == ReadyOrder::Index;
+        self.order = order;
+        if was_by_index == (order == ReadyOrder::Index) {
+            return;
+        }
+        for lane in self.lanes.values_mut() {
+            let old = mem::replace(lane, Lane::new(order));
+            match old {
+                Lane::Index(set) => set.into_iter().for_each(|index| lane.push(index)),
+                Lane::Woken(queue) => queue.into_iter().for_each(|index| lane.push(index)),
+            }
+        }
+    }
+
+    /// Queue an entry which became ready.
+    pub(crate) fn push(&mut self, index: usize, priority: u8) {
+        let order = self.order;
+        self.lanes
+            .entry(Reverse(priority))
+            .or_insert_with(|| Lane::new(order))
+            .push(index);
+    }
+
+    /// Take the next entry to poll: the entry with the highest priority,
+    /// picked by the queue's order among entries with the same priority.
+    pub(crate) fn pop(&mut self) -> Option<usize> {
+        let order = self.order;
+        self.lanes.values_mut().find_map(|lane| lane.pop(order))
+    }
+
+    /// Remove the entry with the given index, if it's queued.
+    pub(crate) fn remove(&mut self, index: usize) {
+        for lane in self.lanes.values_mut() {
+            lane.remove(index);
+        }
+    }
+
+    /// Remove the entries with an index of `len` or higher.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        for lane in self.lanes.values_mut() {
+            lane.retain(|&index| index < len);
+        }
+    }
+
+    /// Remove all entries.
+    pub(crate) fn clear(&mut self) {
+        self.lanes.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn drain(queue: &mut ReadyQueue) -> Vec<usize> {
+        core::iter::from_fn(|| queue.pop()).collect()
+    }
+
+    #[test]
+    fn orders_within_priority() {
+        let mut queue = ReadyQueue::default();
+        for index in [2, 0, 1] {
+            queue.push(index, 0);
+        }
+        assert_eq!(drain(&mut queue), [0, 1, 2]);
+
+        for (order, expected) in [
+            (ReadyOrder::Oldest, [2, 0, 1]),
+            (ReadyOrder::Newest, [1, 0, 2]),
+        ] {
+            queue.set_order(order);
+            for index in [2, 0, 1] {
+                queue.push(index, 0);
+            }
+            assert_eq!(drain(&mut queue), expected);
+        }
+    }
+
+    #[test]
+    fn highest_priority_first() {
+        let mut queue = ReadyQueue::default();
+        queue.set_order(ReadyOrder::Oldest);
+        queue.push(0, 0);
+        queue.push(1, 2);
+        queue.push(2, 1);
+        queue.push(3, 2);
+        assert_eq!(drain(&mut queue), [1, 3, 2, 0]);
+
+        queue.set_order(ReadyOrder::Index);
+        queue.push(3, 2);
+        queue.push(0, 0);
+        queue.push(1, 2);
+        assert_eq!(drain(&mut queue), [1, 3, 0]);
+    }
+
+    #[test]
+    fn keeps_entries_when_reordered() {
+        let mut queue = ReadyQueue::default();
+        queue.set_order(ReadyOrder::Newest);
+        for index in [2, 0, 1] {
+            queue.push(index, 0);
+        }
+        queue.set_order(ReadyOrder::Index);
+        queue.truncate(3);
+        queue.remove(2);
+        assert_eq!(drain(&mut queue), [0, 1]);
+    }
+}
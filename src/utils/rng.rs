@@ -0,0 +1,95 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A counter used to give every generator a distinct default seed.
+static SEED: AtomicUsize = AtomicUsize::new(0);
+
+/// Take the next default seed.
+#[cfg(target_has_atomic = "ptr")]
+fn next_seed() -> usize {
+    SEED.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Take the next default seed.
+///
+/// This target can't atomically increment the counter, so concurrent callers
+/// may get the same seed. That only makes their polling order correlated.
+#[cfg(not(target_has_atomic = "ptr"))]
+fn next_seed() -> usize {
+    let seed = SEED.load(Ordering::Relaxed);
+    SEED.store(seed.wrapping_add(1), Ordering::Relaxed);
+    seed
+}
+
+/// A small, non-cryptographic pseudo-random number generator (xorshift64*).
+///
+/// This is used to pick starting points when polling, which only needs to be
+/// cheap and roughly uniform. It is not suitable for anything else.
+#[derive(Debug, Clone)]
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rng {
+    /// Create a new generator with a unique seed.
+    pub(crate) fn new() -> Self {
+        Self::with_seed(next_seed() as u64)
+    }
+
+    /// Create a new generator from the given seed.
+    pub(crate) fn with_seed(seed: u64) -> Self {
+        // Scramble the seed with the SplitMix64 finalizer so that similar
+        // seeds produce unrelated sequences, and make sure it's never zero.
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        Self { state: z | 1 }
+    }
+
+    /// Generate the next number in the sequence.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Generate a number in the range `0..max`. Returns `0` if `max` is `0`.
+    pub(crate) fn gen_index(&mut self, max: usize) -> usize {
+        if max == 0 {
+            return 0;
+        }
+        (self.next_u64() % max as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Rng;
+
+    #[test]
+    fn in_range() {
+        let mut rng = Rng::with_seed(12);
+        for max in 1..100 {
+            assert!(rng.gen_index(max) < max);
+        }
+        assert_eq!(rng.gen_index(0), 0);
+    }
+
+    #[test]
+    fn deterministic() {
+        let mut a = Rng::with_seed(42);
+        let mut b = Rng::with_seed(42);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+}
@@ -0,0 +1,65 @@
+/// The size hint of a stream which won't yield any more items.
+pub(crate) const EMPTY: (usize, Option<usize>) = (0, Some(0));
+
+/// Combine the size hints of streams whose items are all yielded, such as
+/// when streams are chained or merged.
+pub(crate) fn sum<I>(hints: I) -> (usize, Option<usize>)
+where
+    I: IntoIterator<Item = (usize, Option<usize>)>,
+{
+    hints.into_iter().fold(EMPTY, |(low, high), (l, h)| {
+        let low = low.saturating_add(l);
+        let high = high.zip(h).and_then(|(a, b)| a.checked_add(b));
+        (low, high)
+    })
+}
+
+/// Combine the size hints of streams which yield their items in lockstep,
+/// such as when streams are zipped.
+pub(crate) fn min<I>(hints: I) -> (usize, Option<usize>)
+where
+    I: IntoIterator<Item = (usize, Option<usize>)>,
+{
+    hints
+        .into_iter()
+        .reduce(|(low, high), (l, h)| {
+            let low = low.min(l);
+            let high = match (high, h) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (Some(a), None) | (None, Some(a)) => Some(a),
+                (None, None) => None,
+            };
+            (low, high)
+        })
+        .unwrap_or(EMPTY)
+}
+
+/// Account for `n` items which have already been taken out of a stream, but
+/// haven't been yielded yet.
+pub(crate) fn add((low, high): (usize, Option<usize>), n: usize) -> (usize, Option<usize>) {
+    (low.saturating_add(n), high.and_then(|h| h.checked_add(n)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sum_hints() {
+        assert_eq!(sum([]), EMPTY);
+        assert_eq!(sum([(1, Some(2)), (3, Some(4))]), (4, Some(6)));
+        assert_eq!(sum([(1, Some(2)), (3, None)]), (4, None));
+        assert_eq!(
+            sum([(usize::MAX, Some(usize::MAX)), (1, Some(1))]),
+            (usize::MAX, None)
+        );
+    }
+
+    #[test]
+    fn min_hints() {
+        assert_eq!(min([]), EMPTY);
+        assert_eq!(min([(1, Some(2)), (3, Some(4))]), (1, Some(2)));
+        assert_eq!(min([(1, None), (3, Some(4))]), (1, Some(4)));
+        assert_eq!(min([(1, None), (3, None)]), (1, None));
+    }
+}
@@ -0,0 +1,12 @@
+/// Emit a `tracing` event at the trace level.
+///
+/// This compiles to nothing unless the `tracing` feature is enabled, so it
+/// must only be used as a statement.
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::trace!($($arg)*);
+    };
+}
+
+pub(crate) use trace;
@@ -1,17 +1,9 @@
-#[cfg(not(feature = "std"))]
+#[cfg(not(all(feature = "alloc", target_has_atomic = "ptr")))]
 mod no_std;
-#[cfg(feature = "std")]
-mod readiness_array;
-#[cfg(feature = "std")]
-mod waker;
-#[cfg(feature = "std")]
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
 mod waker_array;
 
-#[cfg(not(feature = "std"))]
+#[cfg(not(all(feature = "alloc", target_has_atomic = "ptr")))]
 pub(crate) use no_std::WakerArray;
-#[cfg(feature = "std")]
-pub(crate) use readiness_array::ReadinessArray;
-#[cfg(feature = "std")]
-pub(crate) use waker::InlineWakerArray;
-#[cfg(feature = "std")]
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
 pub(crate) use waker_array::WakerArray;
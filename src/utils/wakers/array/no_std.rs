@@ -1,32 +1,63 @@
 use core::ops::{Deref, DerefMut};
 use core::task::Waker;
 
+/// Tracks which entries are "ready" and should be polled.
+///
+/// The entries share the parent waker, so all we learn from being polled is
+/// that one of them was woken, not which one. Every entry is marked as ready
+/// again whenever a new parent waker is set.
 #[derive(Debug)]
 pub(crate) struct ReadinessArray<const N: usize> {
+    ready: [bool; N],
+    count: usize,
     parent_waker: Option<Waker>,
 }
 
 impl<const N: usize> ReadinessArray<N> {
+    /// Create a new instance of readiness, with all markers set to ready.
     pub(crate) fn new() -> Self {
-        Self { parent_waker: None }
+        Self {
+            ready: [true; N],
+            count: N,
+            parent_waker: None,
+        }
     }
 
+    /// Set the ready state to `true` for the given index
+    ///
     /// Returns the old ready state for this id
-    pub(crate) fn set_ready(&mut self, _id: usize) -> bool {
-        false
+    pub(crate) fn set_ready(&mut self, index: usize) -> bool {
+        if self.ready[index] {
+            true
+        } else {
+            self.ready[index] = true;
+            self.count += 1;
+            false
+        }
     }
 
     /// Set all markers to ready.
-    pub(crate) fn set_all_ready(&mut self) {}
+    pub(crate) fn set_all_ready(&mut self) {
+        self.ready = [true; N];
+        self.count = N;
+    }
 
+    /// Set the ready state to `false` for the given index
+    ///
     /// Returns whether the task id was previously ready
-    pub(crate) fn clear_ready(&mut self, _id: usize) -> bool {
-        true
+    pub(crate) fn clear_ready(&mut self, index: usize) -> bool {
+        if self.ready[index] {
+            self.ready[index] = false;
+            self.count -= 1;
+            true
+        } else {
+            false
+        }
     }
 
     /// Returns `true` if any of the wakers are ready.
     pub(crate) fn any_ready(&self) -> bool {
-        true
+        self.count != 0
     }
 
     /// Access the parent waker.
@@ -37,11 +68,15 @@ impl<const N: usize> ReadinessArray<N> {
 
     /// Set the parent `Waker`. This needs to be called at the start of every
     /// `poll` function.
+    ///
+    /// We're only polled once one of the entries was woken, so every entry is
+    /// marked as ready again.
     pub(crate) fn set_waker(&mut self, parent_waker: &Waker) {
         match &mut self.parent_waker {
             Some(prev) => prev.clone_from(parent_waker),
             None => self.parent_waker = Some(parent_waker.clone()),
         }
+        self.set_all_ready();
     }
 }
 
@@ -63,7 +98,12 @@ impl<'a, const N: usize> DerefMut for ReadinessArrayRef<'a, N> {
     }
 }
 
-/// A collection of wakers which delegate to an in-line waker.
+/// A collection of wakers which all delegate to the parent waker.
+///
+/// Telling the entries apart when they're woken takes a waker per entry which
+/// can outlive the array, and so an allocator and atomic reference counts.
+/// Without those the entries are handed the parent waker, and readiness is
+/// tracked per entry only between two calls to `poll`.
 pub(crate) struct WakerArray<const N: usize> {
     readiness: ReadinessArray<N>,
 }
@@ -1,24 +1,20 @@
-use alloc::sync::Arc;
 use core::array;
 use core::task::Waker;
-use std::sync::{Mutex, MutexGuard};
 
-use super::{InlineWakerArray, ReadinessArray};
+use super::super::{Readiness, ReadinessRef};
 
 /// A collection of wakers which delegate to an in-line waker.
 pub(crate) struct WakerArray<const N: usize> {
     wakers: [Waker; N],
-    readiness: Arc<Mutex<ReadinessArray<N>>>,
+    readiness: Readiness,
 }
 
 impl<const N: usize> WakerArray<N> {
     /// Create a new instance of `WakerArray`.
     pub(crate) fn new() -> Self {
-        let readiness = Arc::new(Mutex::new(ReadinessArray::new()));
+        let readiness = Readiness::new(N);
         Self {
-            wakers: array::from_fn(|i| {
-                Arc::new(InlineWakerArray::new(i, readiness.clone())).into()
-            }),
+            wakers: array::from_fn(|i| readiness.waker(i)),
             readiness,
         }
     }
@@ -28,7 +24,7 @@ impl<const N: usize> WakerArray<N> {
     }
 
     /// Access the `Readiness`.
-    pub(crate) fn readiness(&mut self) -> MutexGuard<'_, ReadinessArray<N>> {
-        self.readiness.as_ref().lock().unwrap()
+    pub(crate) fn readiness(&mut self) -> ReadinessRef<'_> {
+        ReadinessRef::new(&mut self.readiness)
     }
 }
@@ -1,11 +1,15 @@
 mod array;
 #[cfg(all(test, feature = "alloc"))]
 mod dummy;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod readiness;
 #[cfg(feature = "alloc")]
 mod vec;
 
 #[cfg(all(test, feature = "alloc"))]
 pub(crate) use dummy::DummyWaker;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub(crate) use readiness::{Readiness, ReadinessRef};
 
 pub(crate) use array::*;
 #[cfg(feature = "alloc")]
@@ -0,0 +1,491 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::sync::atomic::{self, AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use core::task::Waker;
+use diatomic_waker::DiatomicWaker;
+
+const BITS: usize = usize::BITS as usize;
+
+/// The number of words stored in-line in the shared state.
+///
+/// Most groups are small, so their markers fit in the allocation we need for
+/// the shared state anyway, without allocating a separate chunk.
+const INLINE_WORDS: usize = 1;
+
+/// A chunk of readiness bits.
+///
+/// Chunks are allocated once the in-line words are exhausted, and every chunk
+/// at least doubles the capacity. Chunks never move once they're allocated, so
+/// wakers hold on to the chunk containing their bit directly.
+type Chunk = Arc<[AtomicUsize]>;
+
+/// Marks the log of woken wakers as closed, once the `Readiness` is dropped.
+static CLOSED: u8 = 0;
+
+/// The head of a closed log. No waker is ever stored at this address.
+fn closed() -> *mut InlineWaker {
+    ptr::addr_of!(CLOSED).cast_mut().cast()
+}
+
+/// The state shared between the wakers and the task which polls them.
+#[derive(Default)]
+struct Shared {
+    /// The number of bits which are set. This may briefly wrap around while a
+    /// bit is set and cleared concurrently, which only causes a spurious poll.
+    count: AtomicUsize,
+    parent_waker: DiatomicWaker,
+    /// The first words of readiness bits.
+    inline: [AtomicUsize; INLINE_WORDS],
+    /// Whether wakers record the order in which they're woken.
+    track_wakes: AtomicBool,
+    /// The wakers which were woken since the log was last taken, as a linked
+    /// list with the most recently woken waker first. Every entry owns a
+    /// reference to its waker.
+    woken: AtomicPtr<InlineWaker>,
+}
+
+impl fmt::Debug for Shared {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shared")
+            .field("count", &self.count)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Tracks which wakers are "ready" and should be polled.
+///
+/// Wakers may be called from any thread. Readiness is tracked with atomic
+/// bitsets rather than behind a lock, so waking a future never contends with
+/// the task polling it.
+#[derive(Debug)]
+pub(crate) struct Readiness {
+    len: usize,
+    /// The number of words across the in-line words and all chunks.
+    capacity: usize,
+    chunks: Vec<Chunk>,
+    shared: Arc<Shared>,
+}
+
+impl Readiness {
+    /// Create a new instance of readiness, with all markers set to ready.
+    pub(crate) fn new(len: usize) -> Self {
+        let mut readiness = Self {
+            len: 0,
+            capacity: INLINE_WORDS,
+            chunks: Vec::new(),
+            shared: Arc::new(Shared::default()),
+        };
+        readiness.resize(len);
+        readiness
+    }
+
+    /// Find the chunk, word and mask for the given index. Markers stored
+    /// in-line in the shared state don't have a chunk.
+    #[inline]
+    fn locate(&self, index: usize) -> (Option<&Chunk>, usize, usize) {
+        debug_assert!(index < self.len, "index out of bounds");
+        let mask = 1 << (index % BITS);
+        let mut offset = index / BITS;
+        if offset < INLINE_WORDS {
+            return (None, offset, mask);
+        }
+        offset -= INLINE_WORDS;
+        for chunk in &self.chunks {
+            if offset < chunk.len() {
+                return (Some(chunk), offset, mask);
+            }
+            offset -= chunk.len();
+        }
+        unreachable!("index out of bounds")
+    }
+
+    /// Find the word and mask for the given index.
+    #[inline]
+    fn word(&self, index: usize) -> (&AtomicUsize, usize) {
+        match self.locate(index) {
+            (Some(chunk), offset, mask) => (&chunk[offset], mask),
+            (None, offset, mask) => (&self.shared.inline[offset], mask),
+        }
+    }
+
+    /// Create a waker which marks the given index as ready.
+    pub(crate) fn waker(&self, index: usize) -> Waker {
+        let (chunk, offset, mask) = self.locate(index);
+        Arc::new(InlineWaker {
+            chunk: chunk.cloned(),
+            offset,
+            mask,
+            shared: self.shared.clone(),
+            index,
+            logged: AtomicBool::new(false),
+            next: AtomicPtr::new(ptr::null_mut()),
+        })
+        .into()
+    }
+
+    /// Returns `true` if the given index is ready.
+    #[inline]
+    pub(crate) fn is_ready(&self, index: usize) -> bool {
+        let (word, mask) = self.word(index);
+        word.load(Ordering::Acquire) & mask != 0
+    }
+
+    /// Set the ready state to `true` for the given index
+    ///
+    /// Returns the old ready state for this id
+    #[inline]
+    pub(crate) fn set_ready(&mut self, index: usize) -> bool {
+        let (word, mask) = self.word(index);
+        if word.fetch_or(mask, Ordering::AcqRel) & mask == 0 {
+            self.shared.count.fetch_add(1, Ordering::AcqRel);
+            false
+        } else {
+            true
+        }
+    }
+
+    /// Set all markers to ready.
+    pub(crate) fn set_all_ready(&mut self) {
+        for index in 0..self.len {
+            self.set_ready(index);
+        }
+    }
+
+    /// Set the ready state to `false` for the given index
+    ///
+    /// Returns whether the task id was previously ready
+    #[inline]
+    pub(crate) fn clear_ready(&mut self, index: usize) -> bool {
+        let (word, mask) = self.word(index);
+        // Most markers aren't set when polling, so check before writing.
+        if word.load(Ordering::Relaxed) & mask == 0 {
+            return false;
+        }
+        if word.fetch_and(!mask, Ordering::AcqRel) & mask != 0 {
+            self.shared.count.fetch_sub(1, Ordering::AcqRel);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Start or stop recording the order in which the wakers are woken.
+    ///
+    /// Markers which are already set aren't recorded, so the caller needs to
+    /// check them after enabling this.
+    pub(crate) fn track_wakes(&mut self, enabled: bool) {
+        self.shared.track_wakes.store(enabled, Ordering::SeqCst);
+        // Pairs with the wakers setting their marker before checking whether
+        // to record it: either they see this store, or the caller sees their
+        // marker.
+        atomic::fence(Ordering::SeqCst);
+        if !enabled {
+            self.take_woken(|_| {});
+        }
+    }
+
+    /// Call `f` with the index of every waker which was woken since the last
+    /// call, in the order they were woken.
+    ///
+    /// Only wakers woken while [`Readiness::track_wakes`] is enabled are
+    /// recorded, and only the first time their marker is set.
+    pub(crate) fn take_woken(&mut self, mut f: impl FnMut(usize)) {
+        let head = self.shared.woken.swap(ptr::null_mut(), Ordering::Acquire);
+        // SAFETY: we just took the log, so nothing else can access it.
+        unsafe {
+            drain_log(reverse_log(head), |index| {
+                if index < self.len {
+                    f(index)
+                }
+            })
+        };
+    }
+
+    /// Set all markers to not ready.
+    pub(crate) fn clear_all_ready(&mut self) {
+        for index in 0..self.len {
+            self.clear_ready(index);
+        }
+    }
+
+    /// Returns `true` if any of the wakers are ready.
+    #[inline]
+    pub(crate) fn any_ready(&self) -> bool {
+        self.shared.count.load(Ordering::Acquire) != 0
+    }
+
+    /// Set the parent `Waker`. This needs to be called at the start of every
+    /// `poll` function.
+    pub(crate) fn set_waker(&mut self, parent_waker: &Waker) {
+        // SAFETY: wakers are only registered through a mutable reference to
+        // the `Readiness`, so this can never be called concurrently.
+        unsafe { self.shared.parent_waker.register(parent_waker) };
+    }
+
+    /// Resize `readiness` to the new length.
+    ///
+    /// If new entries are created, they will be marked as 'ready'.
+    pub(crate) fn resize(&mut self, len: usize) {
+        // Clear the markers we're dropping, so they're no longer counted.
+        for index in len..self.len {
+            self.clear_ready(index);
+        }
+
+        // Allocate chunks until all words fit. Existing chunks stay where
+        // they are, so the wakers pointing into them remain valid.
+        let words = len.div_ceil(BITS);
+        if words > self.capacity {
+            let size = (words - self.capacity).max(self.capacity);
+            self.chunks.push(new_chunk(size));
+            self.capacity += size;
+        }
+
+        let old_len = self.len;
+        self.len = len;
+        for index in old_len..len {
+            self.set_ready(index);
+        }
+    }
+}
+
+impl Drop for Readiness {
+    fn drop(&mut self) {
+        // Close the log, so that wakers which outlive us no longer record
+        // themselves in it. Otherwise they'd keep each other alive through the
+        // shared state.
+        let head = self.shared.woken.swap(closed(), Ordering::Acquire);
+        // SAFETY: we just took the log, so nothing else can access it.
+        unsafe { drain_log(head, |_| {}) };
+    }
+}
+
+/// Reverse a log of woken wakers, so that it starts with the oldest entry.
+///
+/// # Safety
+///
+/// The log must have been taken from the shared state.
+unsafe fn reverse_log(mut head: *mut InlineWaker) -> *mut InlineWaker {
+    let mut prev = ptr::null_mut();
+    while !head.is_null() {
+        // SAFETY: every entry in the log owns a reference to its waker, and
+        // the waker won't record itself again until we release it.
+        let waker = unsafe { &*head };
+        let next = waker.next.load(Ordering::Relaxed);
+        waker.next.store(prev, Ordering::Relaxed);
+        prev = head;
+        head = next;
+    }
+    prev
+}
+
+/// Call `f` with the index of every waker in a log, and release the entries.
+///
+/// # Safety
+///
+/// The log must have been taken from the shared state.
+unsafe fn drain_log(mut head: *mut InlineWaker, mut f: impl FnMut(usize)) {
+    while !head.is_null() {
+        // SAFETY: every entry in the log owns a reference to its waker, which
+        // we take back here.
+        let waker = unsafe { Arc::from_raw(head) };
+        head = waker.next.load(Ordering::Relaxed);
+        waker.logged.store(false, Ordering::Release);
+        f(waker.index);
+    }
+}
+
+/// Allocate a chunk of cleared readiness bits.
+fn new_chunk(words: usize) -> Chunk {
+    (0..words).map(|_| AtomicUsize::new(0)).collect()
+}
+
+/// A mutable reference to the `Readiness`.
+#[derive(Debug)]
+pub(crate) struct ReadinessRef<'a> {
+    inner: &'a mut Readiness,
+}
+
+impl<'a> ReadinessRef<'a> {
+    pub(crate) fn new(inner: &'a mut Readiness) -> Self {
+        Self { inner }
+    }
+}
+
+impl Deref for ReadinessRef<'_> {
+    type Target = Readiness;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner
+    }
+}
+
+impl DerefMut for ReadinessRef<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner
+    }
+}
+
+/// An efficient waker which delegates wake events.
+#[derive(Debug)]
+struct InlineWaker {
+    chunk: Option<Chunk>,
+    offset: usize,
+    mask: usize,
+    shared: Arc<Shared>,
+    index: usize,
+    /// Whether this waker is in the log of woken wakers.
+    logged: AtomicBool,
+    /// The waker which was woken before this one, while in the log.
+    next: AtomicPtr<InlineWaker>,
+}
+
+impl InlineWaker {
+    fn word(&self) -> &AtomicUsize {
+        match &self.chunk {
+            Some(chunk) => &chunk[self.offset],
+            None => &self.shared.inline[self.offset],
+        }
+    }
+
+    /// Record this waker in the log of woken wakers.
+    fn log(self: &Arc<Self>) {
+        // A waker can only be in the log once, because the entry links to
+        // the next one through the waker itself.
+        if self.logged.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        let entry = Arc::into_raw(self.clone()).cast_mut();
+        let log = &self.shared.woken;
+        let mut head = log.load(Ordering::Acquire);
+        loop {
+            if head == closed() {
+                // SAFETY: the entry was never published, so we still own it.
+                drop(unsafe { Arc::from_raw(entry) });
+                return;
+            }
+            self.next.store(head, Ordering::Relaxed);
+            match log.compare_exchange_weak(head, entry, Ordering::Release, Ordering::Acquire) {
+                Ok(_) => return,
+                Err(current) => head = current,
+            }
+        }
+    }
+}
+
+impl Wake for InlineWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        if self.word().fetch_or(self.mask, Ordering::SeqCst) & self.mask == 0 {
+            self.shared.count.fetch_add(1, Ordering::AcqRel);
+            if self.shared.track_wakes.load(Ordering::SeqCst) {
+                self.log();
+            }
+            self.shared.parent_waker.notify();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+    use core::sync::atomic::AtomicBool;
+    use std::thread;
+
+    #[derive(Default)]
+    struct FlagWaker(AtomicBool);
+
+    impl Wake for FlagWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn resize() {
+        let mut readiness = Readiness::new(10);
+        assert!(readiness.any_ready());
+        readiness.clear_all_ready();
+        assert!(!readiness.any_ready());
+        readiness.set_ready(9);
+        assert!(readiness.any_ready());
+        readiness.resize(9);
+        assert!(!readiness.any_ready());
+        readiness.resize(10);
+        assert!(readiness.any_ready());
+    }
+
+    #[test]
+    fn grow_across_chunks() {
+        let mut readiness = Readiness::new(1);
+        readiness.resize(BITS * 7 + 1);
+        readiness.clear_all_ready();
+        assert!(!readiness.any_ready());
+
+        let waker = readiness.waker(BITS * 5);
+        readiness.resize(BITS * 20);
+        readiness.clear_all_ready();
+        waker.wake();
+        assert!(readiness.any_ready());
+        assert!(!readiness.clear_ready(BITS * 5 - 1));
+        assert!(readiness.clear_ready(BITS * 5));
+        assert!(!readiness.any_ready());
+    }
+
+    #[test]
+    fn track_wake_order() {
+        let mut readiness = Readiness::new(4);
+        readiness.clear_all_ready();
+        let wakers: Vec<_> = (0..4).map(|i| readiness.waker(i)).collect();
+
+        // Wakes aren't recorded until tracking is enabled.
+        wakers[3].wake_by_ref();
+        readiness.track_wakes(true);
+        for i in [2, 0, 3, 1, 2] {
+            wakers[i].wake_by_ref();
+        }
+        let mut woken = Vec::new();
+        readiness.take_woken(|i| woken.push(i));
+        assert_eq!(woken, [2, 0, 1]);
+
+        // Every waker is recorded again once its marker was cleared.
+        assert!(readiness.clear_ready(2));
+        wakers[2].wake_by_ref();
+        woken.clear();
+        readiness.take_woken(|i| woken.push(i));
+        assert_eq!(woken, [2]);
+
+        // Dropping the readiness releases the wakers still in the log.
+        assert!(readiness.clear_ready(0));
+        wakers[0].wake_by_ref();
+        drop(readiness);
+        wakers[0].wake_by_ref();
+    }
+
+    #[test]
+    fn wake_from_threads() {
+        let mut readiness = Readiness::new(100);
+        readiness.clear_all_ready();
+        let parent = Arc::new(FlagWaker::default());
+        readiness.set_waker(&Waker::from(parent.clone()));
+
+        let wakers: Vec<_> = (0..100).map(|i| readiness.waker(i)).collect();
+        thread::scope(|s| {
+            for waker in &wakers {
+                s.spawn(|| waker.wake_by_ref());
+            }
+        });
+
+        assert!(parent.0.load(Ordering::SeqCst));
+        assert!((0..100).all(|i| readiness.clear_ready(i)));
+        assert!(!readiness.any_ready());
+    }
+}
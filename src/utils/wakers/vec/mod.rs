@@ -1,17 +1,9 @@
-#[cfg(not(feature = "std"))]
+#[cfg(not(target_has_atomic = "ptr"))]
 mod no_std;
-#[cfg(feature = "std")]
-mod readiness_vec;
-#[cfg(feature = "std")]
-mod waker;
-#[cfg(feature = "std")]
+#[cfg(target_has_atomic = "ptr")]
 mod waker_vec;
 
-#[cfg(not(feature = "std"))]
+#[cfg(not(target_has_atomic = "ptr"))]
 pub(crate) use no_std::WakerVec;
-#[cfg(feature = "std")]
-pub(crate) use readiness_vec::ReadinessVec;
-#[cfg(feature = "std")]
-pub(crate) use waker::InlineWakerVec;
-#[cfg(feature = "std")]
+#[cfg(target_has_atomic = "ptr")]
 pub(crate) use waker_vec::WakerVec;
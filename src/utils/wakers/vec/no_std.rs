@@ -1,32 +1,96 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use alloc::vec;
 use core::ops::{Deref, DerefMut};
 use core::task::Waker;
 
+/// Tracks which entries are "ready" and should be polled.
+///
+/// The entries share the parent waker, so all we learn from being polled is
+/// that one of them was woken, not which one. Every entry is marked as ready
+/// again whenever a new parent waker is set.
 #[derive(Debug)]
 pub(crate) struct ReadinessVec {
+    ready: Vec<bool>,
+    count: usize,
     parent_waker: Option<Waker>,
+    /// Whether to record the order in which markers are set by a new waker.
+    track_wakes: bool,
+    woken: Vec<usize>,
 }
 
 impl ReadinessVec {
-    pub(crate) fn new() -> Self {
-        Self { parent_waker: None }
+    /// Create a new instance of readiness, with all markers set to ready.
+    pub(crate) fn new(len: usize) -> Self {
+        Self {
+            ready: vec![true; len],
+            count: len,
+            parent_waker: None,
+            track_wakes: false,
+            woken: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the given index is ready.
+    pub(crate) fn is_ready(&self, index: usize) -> bool {
+        self.ready[index]
     }
 
+    /// Set the ready state to `true` for the given index
+    ///
     /// Returns the old ready state for this id
-    pub(crate) fn set_ready(&mut self, _id: usize) -> bool {
-        false
+    pub(crate) fn set_ready(&mut self, index: usize) -> bool {
+        if self.ready[index] {
+            true
+        } else {
+            self.ready[index] = true;
+            self.count += 1;
+            false
+        }
     }
 
     /// Set all markers to ready.
-    pub(crate) fn set_all_ready(&mut self) {}
+    pub(crate) fn set_all_ready(&mut self) {
+        self.ready.fill(true);
+        self.count = self.ready.len();
+    }
 
+    /// Set the ready state to `false` for the given index
+    ///
     /// Returns whether the task id was previously ready
-    pub(crate) fn clear_ready(&mut self, _id: usize) -> bool {
-        true
+    pub(crate) fn clear_ready(&mut self, index: usize) -> bool {
+        if self.ready[index] {
+            self.ready[index] = false;
+            self.count -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Set all markers to not ready.
+    pub(crate) fn clear_all_ready(&mut self) {
+        self.ready.fill(false);
+        self.count = 0;
+    }
+
+    /// Start or stop recording the order in which markers are set by
+    /// [`ReadinessVec::set_waker`].
+    pub(crate) fn track_wakes(&mut self, enabled: bool) {
+        self.track_wakes = enabled;
+        self.woken.clear();
+    }
+
+    /// Call `f` with the index of every marker which was set by a new waker
+    /// since the last call.
+    pub(crate) fn take_woken(&mut self, f: impl FnMut(usize)) {
+        self.woken.drain(..).for_each(f);
     }
 
     /// Returns `true` if any of the wakers are ready.
     pub(crate) fn any_ready(&self) -> bool {
-        true
+        self.count != 0
     }
 
     /// Access the parent waker.
@@ -37,17 +101,32 @@ impl ReadinessVec {
 
     /// Set the parent `Waker`. This needs to be called at the start of every
     /// `poll` function.
+    ///
+    /// We're only polled once one of the entries was woken, so every entry is
+    /// marked as ready again.
     pub(crate) fn set_waker(&mut self, parent_waker: &Waker) {
         match &mut self.parent_waker {
             Some(prev) => prev.clone_from(parent_waker),
             None => self.parent_waker = Some(parent_waker.clone()),
         }
+        if self.track_wakes {
+            let woken = self.ready.iter().enumerate().filter(|(_, ready)| !**ready);
+            self.woken.extend(woken.map(|(index, _)| index));
+        }
+        self.set_all_ready();
     }
 
     /// Resize `readiness` to the new length.
     ///
     /// If new entries are created, they will be marked as 'ready'.
-    pub(crate) fn resize(&mut self, _len: usize) {}
+    pub(crate) fn resize(&mut self, len: usize) {
+        for index in len..self.ready.len() {
+            self.clear_ready(index);
+        }
+        let old_len = self.ready.len();
+        self.ready.resize(len, true);
+        self.count += len.saturating_sub(old_len);
+    }
 }
 
 pub(crate) struct ReadinessVecRef<'a> {
@@ -68,7 +147,12 @@ impl<'a> DerefMut for ReadinessVecRef<'a> {
     }
 }
 
-/// A collection of wakers which delegate to an in-line waker.
+/// A collection of wakers which all delegate to the parent waker.
+///
+/// Telling the entries apart when they're woken takes a waker per entry which
+/// can outlive the collection, and so atomic reference counts. Without those
+/// the entries are handed the parent waker, and readiness is tracked per entry
+/// only between two calls to `poll`.
 pub(crate) struct WakerVec {
     readiness: ReadinessVec,
 }
@@ -80,9 +164,9 @@ impl Default for WakerVec {
 }
 
 impl WakerVec {
-    /// Create a new instance of `WakerArray`.
-    pub(crate) fn new(_len: usize) -> Self {
-        let readiness = ReadinessVec::new();
+    /// Create a new instance of `WakerVec`.
+    pub(crate) fn new(len: usize) -> Self {
+        let readiness = ReadinessVec::new(len);
         Self { readiness }
     }
 
@@ -90,6 +174,11 @@ impl WakerVec {
         self.readiness.parent_waker()
     }
 
+    /// Returns `true` if the entry at the given index is ready to be polled.
+    pub(crate) fn is_ready(&self, index: usize) -> bool {
+        self.readiness.is_ready(index)
+    }
+
     /// Access the `Readiness`.
     pub(crate) fn readiness(&mut self) -> ReadinessVecRef<'_> {
         ReadinessVecRef {
@@ -101,4 +190,9 @@ impl WakerVec {
     pub(crate) fn resize(&mut self, len: usize) {
         self.readiness.resize(len);
     }
+
+    /// Release the memory of entries which were removed by shrinking.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.readiness.ready.shrink_to_fit();
+    }
 }
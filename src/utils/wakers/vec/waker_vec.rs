@@ -1,16 +1,15 @@
-#[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::vec::Vec;
-
-use alloc::sync::Arc;
 use core::task::Waker;
-use std::sync::{Mutex, MutexGuard};
+use smallvec::SmallVec;
+
+use super::super::{Readiness, ReadinessRef};
 
-use super::{InlineWakerVec, ReadinessVec};
+/// The number of wakers a `WakerVec` can store without allocating a vec.
+const MAX_INLINE_ENTRIES: usize = 8;
 
 /// A collection of wakers which delegate to an in-line waker.
 pub(crate) struct WakerVec {
-    wakers: Vec<Waker>,
-    readiness: Arc<Mutex<ReadinessVec>>,
+    wakers: SmallVec<[Waker; MAX_INLINE_ENTRIES]>,
+    readiness: Readiness,
 }
 
 impl Default for WakerVec {
@@ -22,10 +21,8 @@ impl Default for WakerVec {
 impl WakerVec {
     /// Create a new instance of `WakerVec`.
     pub(crate) fn new(len: usize) -> Self {
-        let readiness = Arc::new(Mutex::new(ReadinessVec::new(len)));
-        let wakers = (0..len)
-            .map(|i| Arc::new(InlineWakerVec::new(i, readiness.clone())).into())
-            .collect();
+        let readiness = Readiness::new(len);
+        let wakers = (0..len).map(|i| readiness.waker(i)).collect();
         Self { wakers, readiness }
     }
 
@@ -33,24 +30,32 @@ impl WakerVec {
         self.wakers.get(index)
     }
 
+    /// Returns `true` if the waker at the given index has been woken, and
+    /// hasn't been polled since.
+    pub(crate) fn is_ready(&self, index: usize) -> bool {
+        self.readiness.is_ready(index)
+    }
+
     /// Access the `Readiness`.
-    pub(crate) fn readiness(&self) -> MutexGuard<'_, ReadinessVec> {
-        self.readiness.lock().unwrap()
+    pub(crate) fn readiness(&mut self) -> ReadinessRef<'_> {
+        ReadinessRef::new(&mut self.readiness)
     }
 
     /// Resize the `WakerVec` to the new size.
     pub(crate) fn resize(&mut self, len: usize) {
-        // If we grow the vec we'll need to extend beyond the current index.
-        // Which means the first position is the current length, and every position
-        // beyond that is incremented by 1.
+        // Grow the readiness first, so the new wakers have a place to point to.
+        self.readiness.resize(len);
+        let readiness = &self.readiness;
         let mut index = self.wakers.len();
         self.wakers.resize_with(len, || {
-            let ret = Arc::new(InlineWakerVec::new(index, self.readiness.clone())).into();
+            let waker = readiness.waker(index);
             index += 1;
-            ret
+            waker
         });
+    }
 
-        let mut readiness = self.readiness.lock().unwrap();
-        readiness.resize(len);
+    /// Release the memory of wakers which were removed by shrinking.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.wakers.shrink_to_fit();
     }
 }